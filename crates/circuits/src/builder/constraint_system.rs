@@ -9,17 +9,21 @@ use binius_core::{
 		ConstraintSystem,
 	},
 	oracle::{
-		ConstraintSetBuilder, Error as OracleError, MultilinearOracleSet, OracleId,
-		ProjectionVariant, ShiftVariant,
+		ConstraintPredicate, ConstraintSetBuilder, Error as OracleError, MultilinearOracleSet,
+		OracleId, ProjectionVariant, ShiftVariant,
 	},
-	polynomial::MultivariatePoly,
-	transparent::step_down::StepDown,
+	polynomial::{ArithCircuitPoly, CachedPoly, MultivariatePoly},
+	transparent::{row_index::RowIndex, step_down::StepDown},
 	witness::MultilinearExtensionIndex,
 };
 use binius_field::{
-	as_packed_field::PackScalar, underlier::UnderlierType, BinaryField1b, TowerField,
+	as_packed_field::{PackScalar, PackedType},
+	underlier::UnderlierType,
+	BinaryField16b, BinaryField1b, BinaryField32b, BinaryField64b, BinaryField8b, ExtensionField,
+	Field, PackedField, TowerField,
 };
-use binius_math::ArithExpr;
+use binius_math::{ArithExpr, CompositionPoly};
+use binius_maybe_rayon::prelude::*;
 use binius_utils::bail;
 
 use crate::builder::witness;
@@ -38,6 +42,29 @@ where
 	witness: Option<witness::Builder<'arena, U, F>>,
 	next_channel_id: ChannelId,
 	namespace_path: Vec<String>,
+	degree_reduction: Option<DegreeReduction<'arena, U, F>>,
+	intermediate_columns: Vec<IntermediateColumn<F>>,
+	degree_reduction_errors: Vec<anyhow::Error>,
+	fixed_oracle_ids: Vec<OracleId>,
+}
+
+struct DegreeReduction<'arena, U, F>
+where
+	U: UnderlierType + PackScalar<F>,
+	F: TowerField,
+{
+	max_degree: usize,
+	fill_intermediate_column:
+		fn(&witness::Builder<'arena, U, F>, &IntermediateColumn<F>) -> anyhow::Result<()>,
+}
+
+/// A committed column introduced by degree reduction, together with the expression in the
+/// constraint's input columns that defines it.
+struct IntermediateColumn<F: TowerField> {
+	id: OracleId,
+	tower_level: usize,
+	inputs: Vec<OracleId>,
+	definition: ArithExpr<F>,
 }
 
 impl<'arena, U, F> ConstraintSystemBuilder<'arena, U, F>
@@ -60,6 +87,9 @@ where
 
 	#[allow(clippy::type_complexity)]
 	pub fn build(self) -> Result<ConstraintSystem<F>, anyhow::Error> {
+		if let Some(err) = self.degree_reduction_errors.into_iter().next() {
+			return Err(err);
+		}
 		let table_constraints = self.constraints.build(&self.oracles.borrow())?;
 		Ok(ConstraintSystem {
 			max_channel_id: self
@@ -76,6 +106,7 @@ where
 				})?
 				.into_inner(),
 			flushes: self.flushes,
+			fixed_oracle_ids: self.fixed_oracle_ids,
 		})
 	}
 
@@ -86,11 +117,42 @@ where
 	pub fn take_witness(
 		&mut self,
 	) -> Result<MultilinearExtensionIndex<'arena, U, F>, anyhow::Error> {
-		Option::take(&mut self.witness)
-			.ok_or_else(|| {
-				anyhow!("Witness is missing. Are you in verifier mode, or have you already extraced the witness?")
-			})?
-			.build()
+		let witness = Option::take(&mut self.witness).ok_or_else(|| {
+			anyhow!("Witness is missing. Are you in verifier mode, or have you already extraced the witness?")
+		})?;
+		if let Some(degree_reduction) = &self.degree_reduction {
+			for column in &self.intermediate_columns {
+				(degree_reduction.fill_intermediate_column)(&witness, column)?;
+			}
+		}
+		witness.build()
+	}
+
+	/// Bounds the degree of the compositions passed to [`Self::assert_zero`] and [`Self::assert_sum`].
+	///
+	/// Compositions of a higher degree are split by committing intermediate columns for some of
+	/// their subexpressions, each of which is tied to its definition by an extra zerocheck of degree
+	/// at most `max_degree`. The witness for the intermediate columns is computed in
+	/// [`Self::take_witness`], once the columns they depend on have been populated.
+	///
+	/// [`Self::build`] fails if a composition can't be reduced to `max_degree`, for instance because
+	/// it is over columns of different heights.
+	pub fn set_max_composition_degree(&mut self, max_degree: usize)
+	where
+		U: PackScalar<BinaryField1b>
+			+ PackScalar<BinaryField8b>
+			+ PackScalar<BinaryField16b>
+			+ PackScalar<BinaryField32b>
+			+ PackScalar<BinaryField64b>,
+		F: ExtensionField<BinaryField8b>
+			+ ExtensionField<BinaryField16b>
+			+ ExtensionField<BinaryField32b>
+			+ ExtensionField<BinaryField64b>,
+	{
+		self.degree_reduction = Some(DegreeReduction {
+			max_degree,
+			fill_intermediate_column: fill_intermediate_column::<U, F>,
+		});
 	}
 
 	pub fn flush(
@@ -197,10 +259,129 @@ where
 		oracle_ids: impl IntoIterator<Item = OracleId>,
 		composition: ArithExpr<F>,
 	) {
+		let name = name.to_string();
+		let (oracle_ids, composition) =
+			self.reduce_composition_degree(&name, oracle_ids.into_iter().collect(), composition);
 		self.constraints
 			.add_zerocheck(name, oracle_ids, composition);
 	}
 
+	pub fn assert_sum(
+		&mut self,
+		name: impl ToString,
+		oracle_ids: impl IntoIterator<Item = OracleId>,
+		composition: ArithExpr<F>,
+		sum: F,
+	) {
+		let name = name.to_string();
+		let (oracle_ids, composition) =
+			self.reduce_composition_degree(&name, oracle_ids.into_iter().collect(), composition);
+		self.constraints
+			.add_sumcheck(name, oracle_ids, composition, sum);
+	}
+
+	/// Like [`Self::assert_zero`], but the prover evaluates the composition with the kernels compiled
+	/// by [`binius_macros::arith_circuit_poly`] instead of interpreting its expression.
+	///
+	/// This is meant for hot constraints. The composition is neither simplified nor split by degree
+	/// reduction, as the kernels implement it as written.
+	pub fn assert_zero_compiled<FS, Inner>(
+		&mut self,
+		name: impl ToString,
+		oracle_ids: impl IntoIterator<Item = OracleId>,
+		composition: &CachedPoly<FS, Inner>,
+	) where
+		FS: Field,
+		F: ExtensionField<FS>,
+		Inner: CompositionPoly<FS>,
+	{
+		self.constraints.add_constraint(
+			name,
+			oracle_ids,
+			composition.expression(),
+			ConstraintPredicate::Zero,
+			composition.kernels().clone(),
+		);
+	}
+
+	/// Splits `composition` into constraints of degree at most the configured maximum, if any.
+	///
+	/// Returns the (possibly extended) oracle ids and the composition to constrain over them.
+	/// Compositions that can't be reduced, like ones over columns of different heights, are
+	/// returned unchanged, and the error is reported by [`Self::build`].
+	fn reduce_composition_degree(
+		&mut self,
+		name: &str,
+		mut oracle_ids: Vec<OracleId>,
+		composition: ArithExpr<F>,
+	) -> (Vec<OracleId>, ArithExpr<F>) {
+		let Some(max_degree) = self.degree_reduction.as_ref().map(|x| x.max_degree) else {
+			return (oracle_ids, composition);
+		};
+		if composition.degree() <= max_degree {
+			return (oracle_ids, composition);
+		}
+		let reduced = self
+			.log_rows(oracle_ids.iter().copied())
+			.and_then(|log_rows| {
+				let (reduced, definitions) =
+					composition.reduce_degree(max_degree, oracle_ids.len())?;
+				ensure!(
+					reduced.degree() <= max_degree,
+					"reduced composition has degree {}",
+					reduced.degree()
+				);
+				Ok((log_rows, reduced, definitions))
+			});
+		let (log_rows, composition, definitions) = match reduced {
+			Ok(reduced) => reduced,
+			Err(err) => {
+				self.degree_reduction_errors.push(err.context(format!(
+					"constraint {name} of degree {} can't be reduced to the maximum degree \
+					 {max_degree}",
+					composition.degree()
+				)));
+				return (oracle_ids, composition);
+			}
+		};
+
+		for (i, definition) in definitions.into_iter().enumerate() {
+			let inputs_tower_level = {
+				let oracles = self.oracles.borrow();
+				oracle_ids
+					.iter()
+					.map(|&id| oracles.values_tower_level(id).unwrap_or(F::TOWER_LEVEL))
+					.max()
+					.unwrap_or(0)
+			};
+			let tower_level = match inputs_tower_level.max(definition.binary_tower_level()) {
+				0 => BinaryField1b::TOWER_LEVEL,
+				1..=3 => BinaryField8b::TOWER_LEVEL,
+				4 => BinaryField16b::TOWER_LEVEL,
+				5 => BinaryField32b::TOWER_LEVEL,
+				6 => BinaryField64b::TOWER_LEVEL,
+				_ => F::TOWER_LEVEL,
+			};
+
+			let id = self.add_committed(format!("{name} intermediate {i}"), log_rows, tower_level);
+			let inputs = oracle_ids.clone();
+			oracle_ids.push(id);
+			self.constraints.add_zerocheck(
+				format!("{name} intermediate {i} definition"),
+				oracle_ids.iter().copied(),
+				ArithExpr::Var(inputs.len()) + definition.clone(),
+			);
+			self.intermediate_columns.push(IntermediateColumn {
+				id,
+				tower_level,
+				inputs,
+				definition,
+			});
+		}
+
+		(oracle_ids, composition)
+	}
+
 	pub fn assert_not_zero(&mut self, oracle_id: OracleId) {
 		self.non_zero_oracle_ids.push(oracle_id);
 	}
@@ -211,6 +392,66 @@ where
 		channel_id
 	}
 
+	/// Constrains every cell of `columns` to equal the cell that the copy permutation `sigma`
+	/// maps it to.
+	///
+	/// The cells of all the columns are labelled with the [`BinaryField32b`] element whose bits
+	/// are `(column << max_log_rows) | row`, where `column` is the position of the column in
+	/// `columns` and `max_log_rows` the height of the tallest column. The row `i` of `sigma[k]`
+	/// holds the label of the cell that must equal row `i` of `columns[k]`. `sigma` is usually a
+	/// transparent or a committed preprocessed column, as the constraint is only meaningful when
+	/// the verifier knows the permutation.
+	///
+	/// Every cell is pushed to a fresh channel together with its label and pulled together with
+	/// its label under the permutation, so that the channel's grand product argument proves the
+	/// multiset of `(value, label)` pairs unchanged by the wiring.
+	pub fn add_permutation_constraint(
+		&mut self,
+		name: impl ToString,
+		columns: impl IntoIterator<Item = OracleId>,
+		sigma: impl IntoIterator<Item = OracleId>,
+	) -> anyhow::Result<()>
+	where
+		U: PackScalar<BinaryField1b> + PackScalar<BinaryField32b>,
+		F: ExtensionField<BinaryField32b>,
+	{
+		let columns = columns.into_iter().collect::<Vec<_>>();
+		let sigma = sigma.into_iter().collect::<Vec<_>>();
+		ensure!(
+			columns.len() == sigma.len(),
+			"Permutation over {} columns has {} sigma columns",
+			columns.len(),
+			sigma.len()
+		);
+
+		let log_rows = columns
+			.iter()
+			.zip(&sigma)
+			.map(|(&column, &sigma)| self.log_rows([column, sigma]))
+			.collect::<Result<Vec<_>, _>>()?;
+		let Some(&max_log_rows) = log_rows.iter().max() else {
+			return Ok(());
+		};
+
+		self.push_namespace(name);
+		let channel = self.add_channel();
+		for (i, ((&column, &sigma), &n_vars)) in
+			columns.iter().zip(&sigma).zip(&log_rows).enumerate()
+		{
+			let row_index = RowIndex::new(n_vars, i << (max_log_rows - n_vars))?;
+			let index = self.add_transparent(format!("index_{i}"), row_index.clone())?;
+			if let Some(witness) = self.witness() {
+				row_index.populate(witness.new_column::<BinaryField32b>(index).packed());
+			}
+
+			self.send(channel, 1 << n_vars, [column, index])?;
+			self.receive(channel, 1 << n_vars, [column, sigma])?;
+		}
+		self.pop_namespace();
+
+		Ok(())
+	}
+
 	pub fn add_committed(
 		&mut self,
 		name: impl ToString,
@@ -235,6 +476,22 @@ where
 			.committed_multiple(n_vars, tower_level)
 	}
 
+	/// Adds a committed column with fixed values, such as a lookup table.
+	///
+	/// The witness of a fixed column is populated like that of any committed column. It must not
+	/// depend on the statement, as fixed columns are committed once with
+	/// [`binius_core::constraint_system::preprocess`] and the commitment is reused across proofs.
+	pub fn add_fixed(
+		&mut self,
+		name: impl ToString,
+		n_vars: usize,
+		tower_level: usize,
+	) -> OracleId {
+		let id = self.add_committed(name, n_vars, tower_level);
+		self.fixed_oracle_ids.push(id);
+		id
+	}
+
 	pub fn add_linear_combination(
 		&mut self,
 		name: impl ToString,
@@ -334,6 +591,30 @@ where
 			.zero_padded(id, n_vars)
 	}
 
+	pub fn add_interleaved(
+		&mut self,
+		name: impl ToString,
+		id0: OracleId,
+		id1: OracleId,
+	) -> Result<OracleId, OracleError> {
+		self.oracles
+			.borrow_mut()
+			.add_named(self.scoped_name(name))
+			.interleaved(id0, id1)
+	}
+
+	pub fn add_elementwise_product(
+		&mut self,
+		name: impl ToString,
+		id0: OracleId,
+		id1: OracleId,
+	) -> Result<OracleId, OracleError> {
+		self.oracles
+			.borrow_mut()
+			.add_named(self.scoped_name(name))
+			.elementwise_product(id0, id1)
+	}
+
 	fn scoped_name(&self, name: impl ToString) -> String {
 		let name = name.to_string();
 		if self.namespace_path.is_empty() {
@@ -397,3 +678,88 @@ where
 		Ok(log_rows)
 	}
 }
+
+fn fill_intermediate_column<U, F>(
+	witness: &witness::Builder<'_, U, F>,
+	column: &IntermediateColumn<F>,
+) -> anyhow::Result<()>
+where
+	U: UnderlierType
+		+ PackScalar<F>
+		+ PackScalar<BinaryField1b>
+		+ PackScalar<BinaryField8b>
+		+ PackScalar<BinaryField16b>
+		+ PackScalar<BinaryField32b>
+		+ PackScalar<BinaryField64b>,
+	F: TowerField
+		+ ExtensionField<BinaryField8b>
+		+ ExtensionField<BinaryField16b>
+		+ ExtensionField<BinaryField32b>
+		+ ExtensionField<BinaryField64b>,
+{
+	match column.tower_level {
+		0 => fill_intermediate_column_with::<U, F, BinaryField1b>(witness, column),
+		3 => fill_intermediate_column_with::<U, F, BinaryField8b>(witness, column),
+		4 => fill_intermediate_column_with::<U, F, BinaryField16b>(witness, column),
+		5 => fill_intermediate_column_with::<U, F, BinaryField32b>(witness, column),
+		6 => fill_intermediate_column_with::<U, F, BinaryField64b>(witness, column),
+		_ => fill_intermediate_column_with::<U, F, F>(witness, column),
+	}
+}
+
+fn fill_intermediate_column_with<U, F, FS>(
+	witness: &witness::Builder<'_, U, F>,
+	column: &IntermediateColumn<F>,
+) -> anyhow::Result<()>
+where
+	U: UnderlierType + PackScalar<F> + PackScalar<FS>,
+	F: TowerField + ExtensionField<FS>,
+	FS: TowerField,
+{
+	let inputs = column
+		.inputs
+		.iter()
+		.map(|&id| witness.get_multilin_poly(id))
+		.collect::<Result<Vec<_>, _>>()?;
+	let composition = ArithCircuitPoly::with_n_vars(inputs.len(), column.definition.clone())?;
+
+	// Every packed element of the column is computed from a subcube of the inputs, which is
+	// evaluated with the packed composition over the extension field.
+	let n_vars = inputs.first().map_or(0, |input| input.n_vars());
+	let subcube_vars = PackedType::<U, FS>::LOG_WIDTH.min(n_vars);
+	let n_packed_evals = 1 << subcube_vars.saturating_sub(PackedType::<U, F>::LOG_WIDTH);
+
+	let mut entry = witness.new_column::<FS>(column.id);
+	entry.packed().par_iter_mut().enumerate().try_for_each_init(
+		|| {
+			(
+				vec![vec![PackedType::<U, F>::zero(); n_packed_evals]; inputs.len()],
+				vec![PackedType::<U, F>::zero(); n_packed_evals],
+			)
+		},
+		|(queries, evals), (subcube_index, packed)| -> anyhow::Result<()> {
+			for (input, query) in inputs.iter().zip(queries.iter_mut()) {
+				input.subcube_evals(subcube_vars, subcube_index, 0, query)?;
+			}
+			let batch_query = queries.iter().map(Vec::as_slice).collect::<Vec<_>>();
+			CompositionPoly::batch_evaluate(&composition, &batch_query, evals)?;
+
+			for (i, value) in evals
+				.iter()
+				.flat_map(PackedField::iter)
+				.take(1 << subcube_vars)
+				.enumerate()
+			{
+				let mut bases = ExtensionField::<FS>::iter_bases(&value);
+				let base = bases.next().unwrap_or_default();
+				ensure!(
+					bases.all(|base| base == FS::ZERO),
+					"intermediate column {} takes a value outside of its tower level",
+					column.id
+				);
+				packed.set(i, base);
+			}
+			Ok(())
+		},
+	)
+}
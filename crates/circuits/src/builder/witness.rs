@@ -9,6 +9,7 @@ use binius_core::{
 };
 use binius_field::{
 	as_packed_field::{PackScalar, PackedType},
+	packed::set_packed_slice,
 	underlier::WithUnderlier,
 	ExtensionField, Field, PackedField, TowerField,
 };
@@ -119,6 +120,23 @@ where
 		})
 	}
 
+	/// Returns the multilinear extension of a column whose witness has already been populated.
+	pub fn get_multilin_poly(
+		&self,
+		id: OracleId,
+	) -> Result<MultilinearWitness<'arena, PackedType<U, FW>>, Error> {
+		let entries = self.entries.borrow();
+		let oracles = self.oracles.borrow();
+		let entry = entries
+			.get(id)
+			.and_then(|entry| entry.as_ref())
+			.ok_or_else(|| anyhow!("Witness for {} is missing", oracles.label(id)))?;
+		match &entry.witness {
+			Ok(witness) => Ok(witness.clone()),
+			Err(err) => bail!(anyhow!("Witness for {} is invalid: {err}", oracles.label(id))),
+		}
+	}
+
 	pub fn set<FS: TowerField>(
 		&self,
 		id: OracleId,
@@ -187,6 +205,14 @@ impl<'arena, U: PackScalar<FS>, FS: TowerField> WitnessEntry<'arena, U, FS> {
 	pub const fn low_rows(&self) -> usize {
 		self.log_rows
 	}
+
+	/// Iterates over the scalars of the column in order.
+	///
+	/// Unlike [`Self::as_slice`], this doesn't depend on the memory layout of the packed field,
+	/// so it also works with byte-sliced underliers.
+	pub fn scalars(&self) -> impl Iterator<Item = FS> + Send + 'arena {
+		PackedType::<U, FS>::iter_slice(self.packed())
+	}
 }
 
 impl<'arena, U: PackScalar<FS> + Pod, FS: TowerField> WitnessEntry<'arena, U, FS> {
@@ -221,6 +247,17 @@ where
 		PackedType::<U, FS>::from_underliers_ref_mut(self.underliers())
 	}
 
+	/// Writes the scalars of the column in order, starting from the first row.
+	///
+	/// Unlike [`Self::as_mut_slice`], this doesn't depend on the memory layout of the packed
+	/// field, so it also works with byte-sliced underliers.
+	pub fn set_scalars(&mut self, values: impl IntoIterator<Item = FS>) {
+		let packed = self.packed();
+		for (i, value) in values.into_iter().enumerate() {
+			set_packed_slice(packed, i, value);
+		}
+	}
+
 	#[inline]
 	fn underliers(&mut self) -> &mut [U] {
 		self.data
@@ -2,10 +2,7 @@
 
 use anyhow::Ok;
 use binius_core::oracle::OracleId;
-use binius_field::{
-	as_packed_field::{PackScalar, PackedType},
-	BinaryField1b, ExtensionField, PackedFieldIndexable, TowerField,
-};
+use binius_field::{as_packed_field::PackScalar, BinaryField1b, ExtensionField, TowerField};
 use itertools::Itertools;
 
 use super::lasso::lasso;
@@ -54,7 +51,6 @@ impl LookupBatch {
 	) -> Result<(), anyhow::Error>
 	where
 		U: PackScalar<FC> + PackScalar<F> + PackScalar<BinaryField1b>,
-		PackedType<U, FC>: PackedFieldIndexable,
 		FC: TowerField,
 		F: ExtensionField<FC> + TowerField,
 	{
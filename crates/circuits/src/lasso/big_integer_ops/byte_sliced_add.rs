@@ -2,28 +2,16 @@
 
 use alloy_primitives::U512;
 use anyhow::Result;
-use binius_core::oracle::OracleId;
-use binius_field::{
-	as_packed_field::{PackScalar, PackedType},
-	tower_levels::TowerLevel,
-	underlier::UnderlierType,
-	BinaryField, BinaryField16b, BinaryField1b, BinaryField32b, BinaryField8b, ExtensionField,
-	PackedFieldIndexable, TowerField,
-};
-use bytemuck::Pod;
+use binius_core::{oracle::OracleId, tower::TowerUnderlier};
+use binius_field::tower_levels::TowerLevel;
 
 use crate::{
 	builder::ConstraintSystemBuilder,
-	lasso::{batch::LookupBatch, u8add},
+	lasso::{batch::LookupBatch, u8add, IntegerTowerFamily},
 };
 
-type B1 = BinaryField1b;
-type B8 = BinaryField8b;
-type B16 = BinaryField16b;
-type B32 = BinaryField32b;
-
-pub fn byte_sliced_add<U, F, Level: TowerLevel<OracleId>>(
-	builder: &mut ConstraintSystemBuilder<U, F>,
+pub fn byte_sliced_add<U, Tower, Level: TowerLevel<OracleId>>(
+	builder: &mut ConstraintSystemBuilder<U, Tower::B128>,
 	name: impl ToString + Clone,
 	x_in: &Level::Data,
 	y_in: &Level::Data,
@@ -32,22 +20,20 @@ pub fn byte_sliced_add<U, F, Level: TowerLevel<OracleId>>(
 	lookup_batch_add: &mut LookupBatch,
 ) -> Result<(OracleId, Level::Data), anyhow::Error>
 where
-	U: Pod
-		+ UnderlierType
-		+ PackScalar<B1>
-		+ PackScalar<B8>
-		+ PackScalar<B16>
-		+ PackScalar<B32>
-		+ PackScalar<F>,
-	PackedType<U, B8>: PackedFieldIndexable,
-	PackedType<U, B16>: PackedFieldIndexable,
-	PackedType<U, B32>: PackedFieldIndexable,
-	F: TowerField + BinaryField + ExtensionField<B8> + ExtensionField<B16> + ExtensionField<B32>,
+	U: TowerUnderlier<Tower>,
+	Tower: IntegerTowerFamily,
 	Level::Data: Sized,
 {
 	if Level::WIDTH == 1 {
-		let (carry_out, sum) =
-			u8add(builder, lookup_batch_add, name, x_in[0], y_in[0], carry_in, log_size)?;
+		let (carry_out, sum) = u8add::<_, Tower>(
+			builder,
+			lookup_batch_add,
+			name,
+			x_in[0],
+			y_in[0],
+			carry_in,
+			log_size,
+		)?;
 		let mut sum_arr = Level::default();
 		sum_arr[0] = sum;
 		return Ok((carry_out, sum_arr));
@@ -58,7 +44,7 @@ where
 	let (lower_half_x, upper_half_x) = Level::split(x_in);
 	let (lower_half_y, upper_half_y) = Level::split(y_in);
 
-	let (internal_carry, lower_sum) = byte_sliced_add::<_, _, Level::Base>(
+	let (internal_carry, lower_sum) = byte_sliced_add::<_, Tower, Level::Base>(
 		builder,
 		format!("lower sum {}b", Level::Base::WIDTH),
 		lower_half_x,
@@ -68,7 +54,7 @@ where
 		lookup_batch_add,
 	)?;
 
-	let (carry_out, upper_sum) = byte_sliced_add::<_, _, Level::Base>(
+	let (carry_out, upper_sum) = byte_sliced_add::<_, Tower, Level::Base>(
 		builder,
 		format!("upper sum {}b", Level::Base::WIDTH),
 		upper_half_x,
@@ -82,53 +68,75 @@ where
 
 	// Everything below is for test assertions
 	if let Some(witness) = builder.witness() {
-		let x_bytes_as_u8 = (0..Level::WIDTH).map(|this_byte_idx| {
-			let this_byte_oracle = x_in[this_byte_idx];
-			witness
-				.get::<B8>(this_byte_oracle)
-				.unwrap()
-				.as_slice::<u8>()
-		});
-
-		let y_bytes_as_u8 = (0..Level::WIDTH).map(|this_byte_idx| {
-			let this_byte_oracle = y_in[this_byte_idx];
-			witness
-				.get::<B8>(this_byte_oracle)
-				.unwrap()
-				.as_slice::<u8>()
-		});
-
-		let sum_bytes_as_u8 = (0..Level::WIDTH).map(|this_byte_idx| {
-			let this_byte_oracle = sum[this_byte_idx];
-			witness
-				.get::<B8>(this_byte_oracle)
-				.unwrap()
-				.as_slice::<u8>()
-		});
-
-		let cin_as_u8_packed = witness.get::<B1>(carry_in).unwrap().as_slice::<u8>();
-
-		let cout_as_u8_packed = witness.get::<B1>(carry_out).unwrap().as_slice::<u8>();
+		let x_bytes_as_u8 = (0..Level::WIDTH)
+			.map(|this_byte_idx| {
+				let this_byte_oracle = x_in[this_byte_idx];
+				witness
+					.get::<Tower::B8>(this_byte_oracle)
+					.unwrap()
+					.scalars()
+					.map(Into::<u8>::into)
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+
+		let y_bytes_as_u8 = (0..Level::WIDTH)
+			.map(|this_byte_idx| {
+				let this_byte_oracle = y_in[this_byte_idx];
+				witness
+					.get::<Tower::B8>(this_byte_oracle)
+					.unwrap()
+					.scalars()
+					.map(Into::<u8>::into)
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+
+		let sum_bytes_as_u8 = (0..Level::WIDTH)
+			.map(|this_byte_idx| {
+				let this_byte_oracle = sum[this_byte_idx];
+				witness
+					.get::<Tower::B8>(this_byte_oracle)
+					.unwrap()
+					.scalars()
+					.map(Into::<u8>::into)
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+
+		let cin_as_u8 = witness
+			.get::<Tower::B1>(carry_in)
+			.unwrap()
+			.scalars()
+			.map(Into::<u8>::into)
+			.collect::<Vec<_>>();
+
+		let cout_as_u8 = witness
+			.get::<Tower::B1>(carry_out)
+			.unwrap()
+			.scalars()
+			.map(Into::<u8>::into)
+			.collect::<Vec<_>>();
 
 		for row_idx in 0..1 << log_size {
 			let mut x_u512 = U512::ZERO;
-			for (byte_idx, x_byte_column) in x_bytes_as_u8.clone().enumerate() {
+			for (byte_idx, x_byte_column) in x_bytes_as_u8.iter().enumerate() {
 				x_u512 |= U512::from(x_byte_column[row_idx]) << (8 * byte_idx);
 			}
 
 			let mut y_u512 = U512::ZERO;
-			for (byte_idx, y_byte_column) in y_bytes_as_u8.clone().enumerate() {
+			for (byte_idx, y_byte_column) in y_bytes_as_u8.iter().enumerate() {
 				y_u512 |= U512::from(y_byte_column[row_idx]) << (8 * byte_idx);
 			}
 
 			let mut sum_u512 = U512::ZERO;
-			for (byte_idx, sum_byte_column) in sum_bytes_as_u8.clone().enumerate() {
+			for (byte_idx, sum_byte_column) in sum_bytes_as_u8.iter().enumerate() {
 				sum_u512 |= U512::from(sum_byte_column[row_idx]) << (8 * byte_idx);
 			}
 
-			let cin_u512 = U512::from((cin_as_u8_packed[row_idx / 8] >> (row_idx % 8)) & 1);
+			let cin_u512 = U512::from(cin_as_u8[row_idx]);
 
-			let cout_u512 = U512::from((cout_as_u8_packed[row_idx / 8] >> (row_idx % 8)) & 1);
+			let cout_u512 = U512::from(cout_as_u8[row_idx]);
 
 			let expected_sum_u128 = x_u512 + y_u512 + cin_u512;
 
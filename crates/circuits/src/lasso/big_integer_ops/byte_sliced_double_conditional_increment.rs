@@ -2,29 +2,17 @@
 
 use alloy_primitives::U512;
 use anyhow::Result;
-use binius_core::oracle::OracleId;
-use binius_field::{
-	as_packed_field::{PackScalar, PackedType},
-	tower_levels::TowerLevel,
-	underlier::UnderlierType,
-	BinaryField, BinaryField16b, BinaryField1b, BinaryField32b, BinaryField8b, ExtensionField,
-	PackedFieldIndexable, TowerField,
-};
-use bytemuck::Pod;
+use binius_core::{oracle::OracleId, tower::TowerUnderlier};
+use binius_field::tower_levels::TowerLevel;
 
 use crate::{
 	builder::ConstraintSystemBuilder,
-	lasso::{batch::LookupBatch, u8_double_conditional_increment},
+	lasso::{batch::LookupBatch, u8_double_conditional_increment, IntegerTowerFamily},
 };
 
-type B1 = BinaryField1b;
-type B8 = BinaryField8b;
-type B16 = BinaryField16b;
-type B32 = BinaryField32b;
-
 #[allow(clippy::too_many_arguments)]
-pub fn byte_sliced_double_conditional_increment<U, F, Level: TowerLevel<OracleId>>(
-	builder: &mut ConstraintSystemBuilder<U, F>,
+pub fn byte_sliced_double_conditional_increment<U, Tower, Level: TowerLevel<OracleId>>(
+	builder: &mut ConstraintSystemBuilder<U, Tower::B128>,
 	name: impl ToString,
 	x_in: &Level::Data,
 	first_carry_in: OracleId,
@@ -34,21 +22,12 @@ pub fn byte_sliced_double_conditional_increment<U, F, Level: TowerLevel<OracleId
 	lookup_batch_dci: &mut LookupBatch,
 ) -> Result<(OracleId, Level::Data), anyhow::Error>
 where
-	U: Pod
-		+ UnderlierType
-		+ PackScalar<B1>
-		+ PackScalar<B8>
-		+ PackScalar<B16>
-		+ PackScalar<B32>
-		+ PackScalar<F>,
-	PackedType<U, B8>: PackedFieldIndexable,
-	PackedType<U, B16>: PackedFieldIndexable,
-	PackedType<U, B32>: PackedFieldIndexable,
-	F: TowerField + BinaryField + ExtensionField<B8> + ExtensionField<B16> + ExtensionField<B32>,
+	U: TowerUnderlier<Tower>,
+	Tower: IntegerTowerFamily,
 	Level::Data: Sized,
 {
 	if Level::WIDTH == 1 {
-		let (carry_out, sum) = u8_double_conditional_increment(
+		let (carry_out, sum) = u8_double_conditional_increment::<_, Tower>(
 			builder,
 			lookup_batch_dci,
 			"u8 DCI",
@@ -66,18 +45,19 @@ where
 
 	let (lower_half_x, upper_half_x) = Level::split(x_in);
 
-	let (internal_carry, lower_sum) = byte_sliced_double_conditional_increment::<_, _, Level::Base>(
-		builder,
-		format!("lower sum {}b", Level::Base::WIDTH),
-		lower_half_x,
-		first_carry_in,
-		second_carry_in,
-		log_size,
-		zero_oracle_carry,
-		lookup_batch_dci,
-	)?;
+	let (internal_carry, lower_sum) =
+		byte_sliced_double_conditional_increment::<_, Tower, Level::Base>(
+			builder,
+			format!("lower sum {}b", Level::Base::WIDTH),
+			lower_half_x,
+			first_carry_in,
+			second_carry_in,
+			log_size,
+			zero_oracle_carry,
+			lookup_batch_dci,
+		)?;
 
-	let (carry_out, upper_sum) = byte_sliced_double_conditional_increment::<_, _, Level::Base>(
+	let (carry_out, upper_sum) = byte_sliced_double_conditional_increment::<_, Tower, Level::Base>(
 		builder,
 		format!("upper sum {}b", Level::Base::WIDTH),
 		upper_half_x,
@@ -92,45 +72,66 @@ where
 
 	// Everything below is for test assertions
 	if let Some(witness) = builder.witness() {
-		let x_bytes_as_u8 = (0..Level::WIDTH).map(|this_byte_idx| {
-			let this_byte_oracle = x_in[this_byte_idx];
-			witness
-				.get::<B8>(this_byte_oracle)
-				.unwrap()
-				.as_slice::<u8>()
-		});
-
-		let sum_bytes_as_u8 = (0..Level::WIDTH).map(|this_byte_idx| {
-			let this_byte_oracle = sum[this_byte_idx];
-			witness
-				.get::<B8>(this_byte_oracle)
-				.unwrap()
-				.as_slice::<u8>()
-		});
-
-		let first_cin_as_u8_packed = witness.get::<B1>(first_carry_in).unwrap().as_slice::<u8>();
-		let second_cin_as_u8_packed = witness.get::<B1>(second_carry_in).unwrap().as_slice::<u8>();
-
-		let cout_as_u8_packed = witness.get::<B1>(carry_out).unwrap().as_slice::<u8>();
+		let x_bytes_as_u8 = (0..Level::WIDTH)
+			.map(|this_byte_idx| {
+				let this_byte_oracle = x_in[this_byte_idx];
+				witness
+					.get::<Tower::B8>(this_byte_oracle)
+					.unwrap()
+					.scalars()
+					.map(Into::<u8>::into)
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+
+		let sum_bytes_as_u8 = (0..Level::WIDTH)
+			.map(|this_byte_idx| {
+				let this_byte_oracle = sum[this_byte_idx];
+				witness
+					.get::<Tower::B8>(this_byte_oracle)
+					.unwrap()
+					.scalars()
+					.map(Into::<u8>::into)
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+
+		let first_cin_as_u8 = witness
+			.get::<Tower::B1>(first_carry_in)
+			.unwrap()
+			.scalars()
+			.map(Into::<u8>::into)
+			.collect::<Vec<_>>();
+		let second_cin_as_u8 = witness
+			.get::<Tower::B1>(second_carry_in)
+			.unwrap()
+			.scalars()
+			.map(Into::<u8>::into)
+			.collect::<Vec<_>>();
+
+		let cout_as_u8 = witness
+			.get::<Tower::B1>(carry_out)
+			.unwrap()
+			.scalars()
+			.map(Into::<u8>::into)
+			.collect::<Vec<_>>();
 
 		for row_idx in 0..1 << log_size {
 			let mut x_u512 = U512::ZERO;
-			for (byte_idx, x_byte_column) in x_bytes_as_u8.clone().enumerate() {
+			for (byte_idx, x_byte_column) in x_bytes_as_u8.iter().enumerate() {
 				x_u512 |= U512::from(x_byte_column[row_idx]) << (8 * byte_idx);
 			}
 
 			let mut sum_u512 = U512::ZERO;
-			for (byte_idx, sum_byte_column) in sum_bytes_as_u8.clone().enumerate() {
+			for (byte_idx, sum_byte_column) in sum_bytes_as_u8.iter().enumerate() {
 				sum_u512 |= U512::from(sum_byte_column[row_idx]) << (8 * byte_idx);
 			}
 
-			let first_cin_u512 =
-				U512::from((first_cin_as_u8_packed[row_idx / 8] >> (row_idx % 8)) & 1);
+			let first_cin_u512 = U512::from(first_cin_as_u8[row_idx]);
 
-			let second_cin_u512 =
-				U512::from((second_cin_as_u8_packed[row_idx / 8] >> (row_idx % 8)) & 1);
+			let second_cin_u512 = U512::from(second_cin_as_u8[row_idx]);
 
-			let cout_u512 = U512::from((cout_as_u8_packed[row_idx / 8] >> (row_idx % 8)) & 1);
+			let cout_u512 = U512::from(cout_as_u8[row_idx]);
 
 			let expected_sum_u128 = x_u512 + first_cin_u512 + second_cin_u512;
 
@@ -2,16 +2,11 @@
 
 use alloy_primitives::U512;
 use anyhow::Result;
-use binius_core::{oracle::OracleId, transparent::constant::Constant};
+use binius_core::{oracle::OracleId, tower::TowerUnderlier, transparent::constant::Constant};
 use binius_field::{
-	as_packed_field::{PackScalar, PackedType},
-	tower_levels::TowerLevel,
-	underlier::{UnderlierType, WithUnderlier},
-	BinaryField, BinaryField16b, BinaryField1b, BinaryField32b, BinaryField8b, ExtensionField,
-	PackedFieldIndexable, TowerField,
+	as_packed_field::PackedType, tower_levels::TowerLevel, PackedField, TowerField,
 };
 use binius_macros::arith_expr;
-use bytemuck::Pod;
 
 use super::{byte_sliced_add_carryfree, byte_sliced_mul};
 use crate::{
@@ -19,22 +14,18 @@ use crate::{
 	lasso::{
 		batch::LookupBatch,
 		lookups::u8_arithmetic::{add_carryfree_lookup, add_lookup, dci_lookup, mul_lookup},
+		IntegerTowerFamily,
 	},
 };
 
-type B1 = BinaryField1b;
-type B8 = BinaryField8b;
-type B16 = BinaryField16b;
-type B32 = BinaryField32b;
-
 #[allow(clippy::too_many_arguments)]
 pub fn byte_sliced_modular_mul<
 	U,
-	F,
+	Tower,
 	LevelIn: TowerLevel<OracleId>,
 	LevelOut: TowerLevel<OracleId, Base = LevelIn>,
 >(
-	builder: &mut ConstraintSystemBuilder<U, F>,
+	builder: &mut ConstraintSystemBuilder<U, Tower::B128>,
 	name: impl ToString,
 	mult_a: &LevelIn::Data,
 	mult_b: &LevelIn::Data,
@@ -44,30 +35,20 @@ pub fn byte_sliced_modular_mul<
 	zero_carry_oracle: OracleId,
 ) -> Result<LevelIn::Data, anyhow::Error>
 where
-	U: Pod
-		+ UnderlierType
-		+ PackScalar<B1>
-		+ PackScalar<B8>
-		+ PackScalar<B16>
-		+ PackScalar<B32>
-		+ PackScalar<F>,
-	PackedType<U, B8>: PackedFieldIndexable,
-	PackedType<U, B16>: PackedFieldIndexable,
-	PackedType<U, B32>: PackedFieldIndexable,
-	F: TowerField + BinaryField + ExtensionField<B8> + ExtensionField<B16> + ExtensionField<B32>,
-	<F as WithUnderlier>::Underlier: From<u8>,
+	U: TowerUnderlier<Tower>,
+	Tower: IntegerTowerFamily,
 {
 	builder.push_namespace(name);
 
-	let lookup_t_mul = mul_lookup(builder, "mul table")?;
-	let lookup_t_add = add_lookup(builder, "add table")?;
-	let lookup_t_add_carryfree = add_carryfree_lookup(builder, "add cf table")?;
+	let lookup_t_mul = mul_lookup::<_, Tower>(builder, "mul table")?;
+	let lookup_t_add = add_lookup::<_, Tower>(builder, "add table")?;
+	let lookup_t_add_carryfree = add_carryfree_lookup::<_, Tower>(builder, "add cf table")?;
 
 	// The double conditional increment wont be used if we're at the base of the tower
 	let lookup_t_dci = if LevelIn::WIDTH == 1 {
 		usize::MAX
 	} else {
-		dci_lookup(builder, "dci table")?
+		dci_lookup::<_, Tower>(builder, "dci table")?
 	};
 
 	let mut lookup_batch_mul = LookupBatch::new([lookup_t_mul]);
@@ -82,18 +63,15 @@ where
 	let mut modulus = LevelIn::default();
 
 	for byte_idx in 0..LevelIn::WIDTH {
-		quotient[byte_idx] = builder.add_committed("quotient", log_size, B8::TOWER_LEVEL);
-		remainder[byte_idx] = builder.add_committed("remainder", log_size, B8::TOWER_LEVEL);
+		quotient[byte_idx] = builder.add_committed("quotient", log_size, Tower::B8::TOWER_LEVEL);
+		remainder[byte_idx] = builder.add_committed("remainder", log_size, Tower::B8::TOWER_LEVEL);
 		modulus[byte_idx] = builder.add_transparent(
 			"modulus",
-			Constant::new(
-				log_size,
-				F::from_underlier(<u8 as Into<F::Underlier>>::into(modulus_input[byte_idx])),
-			),
+			Constant::new(log_size, Tower::B128::from(Tower::B8::from(modulus_input[byte_idx]))),
 		)?;
 	}
 
-	let ab = byte_sliced_mul::<_, _, LevelIn, LevelOut>(
+	let ab = byte_sliced_mul::<_, Tower, LevelIn, LevelOut>(
 		builder,
 		"ab",
 		mult_a,
@@ -110,41 +88,29 @@ where
 			.map(|this_byte_idx| {
 				let this_byte_oracle = ab[this_byte_idx];
 				witness
-					.get::<B8>(this_byte_oracle)
+					.get::<Tower::B8>(this_byte_oracle)
 					.unwrap()
-					.as_slice::<u8>()
-			})
-			.collect();
-
-		let mut quotient: Vec<_> = (0..LevelIn::WIDTH)
-			.map(|this_byte_idx| {
-				let this_byte_oracle = quotient[this_byte_idx];
-				witness.new_column::<B8>(this_byte_oracle)
-			})
-			.collect();
-
-		let mut remainder: Vec<_> = (0..LevelIn::WIDTH)
-			.map(|this_byte_idx| {
-				let this_byte_oracle: usize = remainder[this_byte_idx];
-				witness.new_column::<B8>(this_byte_oracle)
-			})
-			.collect();
-
-		let mut modulus: Vec<_> = (0..LevelIn::WIDTH)
-			.map(|this_byte_idx| {
-				let this_byte_oracle = modulus[this_byte_idx];
-				witness.new_column::<B8>(this_byte_oracle)
+					.scalars()
+					.map(Into::<u8>::into)
+					.collect::<Vec<_>>()
 			})
 			.collect();
 
 		let mut modulus_u512 = U512::ZERO;
 
-		for (byte_idx, modulus_byte_column) in modulus.iter_mut().enumerate() {
-			let modulus_byte_column_u8 = modulus_byte_column.as_mut_slice::<u8>();
+		for byte_idx in 0..LevelIn::WIDTH {
 			modulus_u512 |= U512::from(modulus_input[byte_idx]) << (8 * byte_idx);
-			modulus_byte_column_u8.fill(modulus_input[byte_idx]);
+			witness
+				.new_column::<Tower::B8>(modulus[byte_idx])
+				.packed()
+				.fill(PackedType::<U, Tower::B8>::broadcast(Tower::B8::from(
+					modulus_input[byte_idx],
+				)));
 		}
 
+		let mut quotient_bytes_u8 = vec![Vec::with_capacity(1 << log_size); LevelIn::WIDTH];
+		let mut remainder_bytes_u8 = vec![Vec::with_capacity(1 << log_size); LevelIn::WIDTH];
+
 		for row_idx in 0..1 << log_size {
 			let mut ab_u512 = U512::ZERO;
 			for (byte_idx, ab_byte_column) in ab_bytes_as_u8.iter().enumerate() {
@@ -154,19 +120,29 @@ where
 			let quotient_u512 = ab_u512 / modulus_u512;
 			let remainder_u512 = ab_u512 % modulus_u512;
 
-			for (byte_idx, quotient_byte_column) in quotient.iter_mut().enumerate() {
-				let quotient_byte_column_u8 = quotient_byte_column.as_mut_slice::<u8>();
-				quotient_byte_column_u8[row_idx] = quotient_u512.byte(byte_idx);
+			for (byte_idx, quotient_byte_column) in quotient_bytes_u8.iter_mut().enumerate() {
+				quotient_byte_column.push(quotient_u512.byte(byte_idx));
 			}
 
-			for (byte_idx, remainder_byte_column) in remainder.iter_mut().enumerate() {
-				let remainder_byte_column_u8 = remainder_byte_column.as_mut_slice::<u8>();
-				remainder_byte_column_u8[row_idx] = remainder_u512.byte(byte_idx);
+			for (byte_idx, remainder_byte_column) in remainder_bytes_u8.iter_mut().enumerate() {
+				remainder_byte_column.push(remainder_u512.byte(byte_idx));
 			}
 		}
+
+		for (byte_idx, quotient_byte_column) in quotient_bytes_u8.into_iter().enumerate() {
+			witness
+				.new_column::<Tower::B8>(quotient[byte_idx])
+				.set_scalars(quotient_byte_column.into_iter().map(Tower::B8::from));
+		}
+
+		for (byte_idx, remainder_byte_column) in remainder_bytes_u8.into_iter().enumerate() {
+			witness
+				.new_column::<Tower::B8>(remainder[byte_idx])
+				.set_scalars(remainder_byte_column.into_iter().map(Tower::B8::from));
+		}
 	}
 
-	let qm = byte_sliced_mul::<_, _, LevelIn, LevelOut>(
+	let qm = byte_sliced_mul::<_, Tower, LevelIn, LevelOut>(
 		builder,
 		"qm",
 		&quotient,
@@ -183,7 +159,7 @@ where
 		repeating_zero[byte_idx] = zero_byte_oracle;
 	}
 
-	let qm_plus_r = byte_sliced_add_carryfree::<_, _, LevelOut>(
+	let qm_plus_r = byte_sliced_add_carryfree::<_, Tower, LevelOut>(
 		builder,
 		"hi*lo",
 		&qm,
@@ -194,12 +170,12 @@ where
 		&mut lookup_batch_add_carryfree,
 	)?;
 
-	lookup_batch_mul.execute::<_, _, BinaryField32b>(builder)?;
-	lookup_batch_add.execute::<_, _, BinaryField32b>(builder)?;
-	lookup_batch_add_carryfree.execute::<_, _, BinaryField32b>(builder)?;
+	lookup_batch_mul.execute::<_, _, Tower::B32>(builder)?;
+	lookup_batch_add.execute::<_, _, Tower::B32>(builder)?;
+	lookup_batch_add_carryfree.execute::<_, _, Tower::B32>(builder)?;
 
 	if LevelIn::WIDTH != 1 {
-		lookup_batch_dci.execute::<_, _, BinaryField32b>(builder)?;
+		lookup_batch_dci.execute::<_, _, Tower::B32>(builder)?;
 	}
 
 	let consistency = arith_expr!([x, y] = x - y);
@@ -2,35 +2,23 @@
 
 use alloy_primitives::U512;
 use anyhow::Result;
-use binius_core::oracle::OracleId;
-use binius_field::{
-	as_packed_field::{PackScalar, PackedType},
-	tower_levels::TowerLevel,
-	underlier::UnderlierType,
-	BinaryField, BinaryField16b, BinaryField1b, BinaryField32b, BinaryField8b, ExtensionField,
-	PackedFieldIndexable, TowerField,
-};
-use bytemuck::Pod;
+use binius_core::{oracle::OracleId, tower::TowerUnderlier};
+use binius_field::tower_levels::TowerLevel;
 
 use super::{byte_sliced_add, byte_sliced_double_conditional_increment};
 use crate::{
 	builder::ConstraintSystemBuilder,
-	lasso::{batch::LookupBatch, u8mul::u8mul_bytesliced},
+	lasso::{batch::LookupBatch, u8mul::u8mul_bytesliced, IntegerTowerFamily},
 };
 
-type B1 = BinaryField1b;
-type B8 = BinaryField8b;
-type B16 = BinaryField16b;
-type B32 = BinaryField32b;
-
 #[allow(clippy::too_many_arguments)]
 pub fn byte_sliced_mul<
 	U,
-	F,
+	Tower,
 	LevelIn: TowerLevel<OracleId>,
 	LevelOut: TowerLevel<OracleId, Base = LevelIn>,
 >(
-	builder: &mut ConstraintSystemBuilder<U, F>,
+	builder: &mut ConstraintSystemBuilder<U, Tower::B128>,
 	name: impl ToString,
 	mult_a: &LevelIn::Data,
 	mult_b: &LevelIn::Data,
@@ -41,20 +29,11 @@ pub fn byte_sliced_mul<
 	lookup_batch_dci: &mut LookupBatch,
 ) -> Result<LevelOut::Data, anyhow::Error>
 where
-	U: Pod
-		+ UnderlierType
-		+ PackScalar<B1>
-		+ PackScalar<B8>
-		+ PackScalar<B16>
-		+ PackScalar<B32>
-		+ PackScalar<F>,
-	PackedType<U, B8>: PackedFieldIndexable,
-	PackedType<U, B16>: PackedFieldIndexable,
-	PackedType<U, B32>: PackedFieldIndexable,
-	F: TowerField + BinaryField + ExtensionField<B8> + ExtensionField<B16> + ExtensionField<B32>,
+	U: TowerUnderlier<Tower>,
+	Tower: IntegerTowerFamily,
 {
 	if LevelIn::WIDTH == 1 {
-		let result_of_u8mul = u8mul_bytesliced(
+		let result_of_u8mul = u8mul_bytesliced::<_, Tower>(
 			builder,
 			lookup_batch_mul,
 			"u8 mul",
@@ -77,7 +56,7 @@ where
 	let (mult_a_low, mult_a_high) = LevelIn::split(mult_a);
 	let (mult_b_low, mult_b_high) = LevelIn::split(mult_b);
 
-	let a_lo_b_lo = byte_sliced_mul::<_, _, LevelIn::Base, LevelOut::Base>(
+	let a_lo_b_lo = byte_sliced_mul::<_, Tower, LevelIn::Base, LevelOut::Base>(
 		builder,
 		format!("lo*lo {}b", LevelIn::Base::WIDTH),
 		mult_a_low,
@@ -88,7 +67,7 @@ where
 		lookup_batch_add,
 		lookup_batch_dci,
 	)?;
-	let a_lo_b_hi = byte_sliced_mul::<_, _, LevelIn::Base, LevelOut::Base>(
+	let a_lo_b_hi = byte_sliced_mul::<_, Tower, LevelIn::Base, LevelOut::Base>(
 		builder,
 		format!("lo*hi {}b", LevelIn::Base::WIDTH),
 		mult_a_low,
@@ -99,7 +78,7 @@ where
 		lookup_batch_add,
 		lookup_batch_dci,
 	)?;
-	let a_hi_b_lo = byte_sliced_mul::<_, _, LevelIn::Base, LevelOut::Base>(
+	let a_hi_b_lo = byte_sliced_mul::<_, Tower, LevelIn::Base, LevelOut::Base>(
 		builder,
 		format!("hi*lo {}b", LevelIn::Base::WIDTH),
 		mult_a_high,
@@ -110,7 +89,7 @@ where
 		lookup_batch_add,
 		lookup_batch_dci,
 	)?;
-	let a_hi_b_hi = byte_sliced_mul::<_, _, LevelIn::Base, LevelOut::Base>(
+	let a_hi_b_hi = byte_sliced_mul::<_, Tower, LevelIn::Base, LevelOut::Base>(
 		builder,
 		format!("hi*hi {}b", LevelIn::Base::WIDTH),
 		mult_a_high,
@@ -122,7 +101,7 @@ where
 		lookup_batch_dci,
 	)?;
 
-	let (karatsuba_carry_for_high_chunk, karatsuba_term) = byte_sliced_add::<_, _, LevelIn>(
+	let (karatsuba_carry_for_high_chunk, karatsuba_term) = byte_sliced_add::<_, Tower, LevelIn>(
 		builder,
 		format!("karastsuba addition {}b", LevelIn::WIDTH),
 		&a_lo_b_hi,
@@ -135,7 +114,7 @@ where
 	let (a_lo_b_lo_lower_half, a_lo_b_lo_upper_half) = LevelIn::split(&a_lo_b_lo);
 	let (a_hi_b_hi_lower_half, a_hi_b_hi_upper_half) = LevelIn::split(&a_hi_b_hi);
 
-	let (additional_carry_for_high_chunk, final_middle_chunk) = byte_sliced_add::<_, _, LevelIn>(
+	let (additional_carry_for_high_chunk, final_middle_chunk) = byte_sliced_add::<_, Tower, LevelIn>(
 		builder,
 		format!("post kartsuba middle term addition {}b", LevelIn::WIDTH),
 		&karatsuba_term,
@@ -145,7 +124,7 @@ where
 		lookup_batch_add,
 	)?;
 
-	let (_, final_high_chunk) = byte_sliced_double_conditional_increment::<_, _, LevelIn::Base>(
+	let (_, final_high_chunk) = byte_sliced_double_conditional_increment::<_, Tower, LevelIn::Base>(
 		builder,
 		format!("high chunk DCI {}b", LevelIn::Base::WIDTH),
 		a_hi_b_hi_upper_half,
@@ -167,43 +146,55 @@ where
 
 	// All of the code below is for test assertions
 	if let Some(witness) = builder.witness() {
-		let a_bytes_as_u8 = (0..LevelIn::WIDTH).map(|this_byte_idx| {
-			let this_byte_oracle = mult_a[this_byte_idx];
-			witness
-				.get::<B8>(this_byte_oracle)
-				.unwrap()
-				.as_slice::<u8>()
-		});
-
-		let b_bytes_as_u8 = (0..LevelIn::WIDTH).map(|this_byte_idx| {
-			let this_byte_oracle = mult_b[this_byte_idx];
-			witness
-				.get::<B8>(this_byte_oracle)
-				.unwrap()
-				.as_slice::<u8>()
-		});
-
-		let product_bytes_as_u8 = (0..LevelOut::WIDTH).map(|this_byte_idx| {
-			let this_byte_oracle = product[this_byte_idx];
-			witness
-				.get::<B8>(this_byte_oracle)
-				.unwrap()
-				.as_slice::<u8>()
-		});
+		let a_bytes_as_u8 = (0..LevelIn::WIDTH)
+			.map(|this_byte_idx| {
+				let this_byte_oracle = mult_a[this_byte_idx];
+				witness
+					.get::<Tower::B8>(this_byte_oracle)
+					.unwrap()
+					.scalars()
+					.map(Into::<u8>::into)
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+
+		let b_bytes_as_u8 = (0..LevelIn::WIDTH)
+			.map(|this_byte_idx| {
+				let this_byte_oracle = mult_b[this_byte_idx];
+				witness
+					.get::<Tower::B8>(this_byte_oracle)
+					.unwrap()
+					.scalars()
+					.map(Into::<u8>::into)
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+
+		let product_bytes_as_u8 = (0..LevelOut::WIDTH)
+			.map(|this_byte_idx| {
+				let this_byte_oracle = product[this_byte_idx];
+				witness
+					.get::<Tower::B8>(this_byte_oracle)
+					.unwrap()
+					.scalars()
+					.map(Into::<u8>::into)
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
 
 		for row_idx in 0..1 << log_size {
 			let mut a_u512 = U512::ZERO;
-			for (byte_idx, a_byte_column) in a_bytes_as_u8.clone().enumerate() {
+			for (byte_idx, a_byte_column) in a_bytes_as_u8.iter().enumerate() {
 				a_u512 |= U512::from(a_byte_column[row_idx]) << (8 * byte_idx);
 			}
 
 			let mut b_u512 = U512::ZERO;
-			for (byte_idx, b_byte_column) in b_bytes_as_u8.clone().enumerate() {
+			for (byte_idx, b_byte_column) in b_bytes_as_u8.iter().enumerate() {
 				b_u512 |= U512::from(b_byte_column[row_idx]) << (8 * byte_idx);
 			}
 
 			let mut product_u512 = U512::ZERO;
-			for (byte_idx, product_byte_column) in product_bytes_as_u8.clone().enumerate() {
+			for (byte_idx, product_byte_column) in product_bytes_as_u8.iter().enumerate() {
 				product_u512 |= U512::from(product_byte_column[row_idx]) << (8 * byte_idx);
 			}
 
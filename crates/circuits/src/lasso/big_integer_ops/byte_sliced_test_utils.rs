@@ -3,11 +3,11 @@
 use std::{array, fmt::Debug};
 
 use alloy_primitives::U512;
-use binius_core::{constraint_system::validate::validate_witness, oracle::OracleId};
-use binius_field::{
-	arch::OptimalUnderlier, tower_levels::TowerLevel, BinaryField128b, BinaryField1b,
-	BinaryField32b, BinaryField8b, Field, TowerField,
+use binius_core::{
+	constraint_system::validate::validate_witness, oracle::OracleId, tower::TowerUnderlier,
 };
+use binius_field::{tower_levels::TowerLevel, BinaryField1b, Field, TowerField};
+use bytemuck::Pod;
 use rand::{rngs::ThreadRng, thread_rng, Rng};
 
 use super::{
@@ -19,41 +19,37 @@ use crate::{
 	lasso::{
 		batch::LookupBatch,
 		lookups::u8_arithmetic::{add_carryfree_lookup, add_lookup, dci_lookup, mul_lookup},
+		IntegerTowerFamily,
 	},
 	transparent,
 	unconstrained::unconstrained,
 };
 
-type B8 = BinaryField8b;
-type B32 = BinaryField32b;
-
 pub fn random_u512(rng: &mut ThreadRng) -> U512 {
 	let limbs = array::from_fn(|_| rng.gen());
 	U512::from_limbs(limbs)
 }
 
-pub fn test_bytesliced_add<const WIDTH: usize, TL>()
+pub fn test_bytesliced_add<U, Tower, const WIDTH: usize, TL>()
 where
+	U: TowerUnderlier<Tower> + Pod,
+	Tower: IntegerTowerFamily,
 	TL: TowerLevel<OracleId, Data = [OracleId; WIDTH]>,
 {
-	type U = OptimalUnderlier;
-	type F = BinaryField128b;
 	let allocator = bumpalo::Bump::new();
-	let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+	let mut builder = ConstraintSystemBuilder::<U, Tower::B128>::new_with_witness(&allocator);
 	let log_size = 14;
 
-	let x_in = array::from_fn(|_| {
-		unconstrained::<_, _, BinaryField8b>(&mut builder, "x", log_size).unwrap()
-	});
-	let y_in = array::from_fn(|_| {
-		unconstrained::<_, _, BinaryField8b>(&mut builder, "y", log_size).unwrap()
-	});
+	let x_in =
+		array::from_fn(|_| unconstrained::<_, _, Tower::B8>(&mut builder, "x", log_size).unwrap());
+	let y_in =
+		array::from_fn(|_| unconstrained::<_, _, Tower::B8>(&mut builder, "y", log_size).unwrap());
 	let c_in = unconstrained::<_, _, BinaryField1b>(&mut builder, "cin first", log_size).unwrap();
 
-	let lookup_t_add = add_lookup(&mut builder, "add table").unwrap();
+	let lookup_t_add = add_lookup::<_, Tower>(&mut builder, "add table").unwrap();
 
 	let mut lookup_batch_add = LookupBatch::new([lookup_t_add]);
-	let _sum_and_cout = byte_sliced_add::<_, _, TL>(
+	let _sum_and_cout = byte_sliced_add::<_, Tower, TL>(
 		&mut builder,
 		"lasso_bytesliced_add",
 		&x_in,
@@ -64,7 +60,9 @@ where
 	)
 	.unwrap();
 
-	lookup_batch_add.execute::<_, _, B32>(&mut builder).unwrap();
+	lookup_batch_add
+		.execute::<_, _, Tower::B32>(&mut builder)
+		.unwrap();
 
 	let witness = builder.take_witness().unwrap();
 	let constraint_system = builder.build().unwrap();
@@ -72,31 +70,25 @@ where
 	validate_witness(&constraint_system, &boundaries, &witness).unwrap();
 }
 
-pub fn test_bytesliced_add_carryfree<const WIDTH: usize, TL>()
+pub fn test_bytesliced_add_carryfree<U, Tower, const WIDTH: usize, TL>()
 where
+	U: TowerUnderlier<Tower> + Pod,
+	Tower: IntegerTowerFamily,
 	TL: TowerLevel<OracleId, Data = [OracleId; WIDTH]>,
 {
-	type U = OptimalUnderlier;
-	type F = BinaryField128b;
 	let allocator = bumpalo::Bump::new();
-	let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+	let mut builder = ConstraintSystemBuilder::<U, Tower::B128>::new_with_witness(&allocator);
 	let log_size = 14;
-	let x_in = array::from_fn(|_| builder.add_committed("x", log_size, BinaryField8b::TOWER_LEVEL));
-	let y_in = array::from_fn(|_| builder.add_committed("y", log_size, BinaryField8b::TOWER_LEVEL));
+	let x_in = array::from_fn(|_| builder.add_committed("x", log_size, Tower::B8::TOWER_LEVEL));
+	let y_in = array::from_fn(|_| builder.add_committed("y", log_size, Tower::B8::TOWER_LEVEL));
 	let c_in = builder.add_committed("c", log_size, BinaryField1b::TOWER_LEVEL);
 
 	if let Some(witness) = builder.witness() {
-		let mut x_in: [_; WIDTH] =
-			array::from_fn(|byte_idx| witness.new_column::<BinaryField8b>(x_in[byte_idx]));
-		let mut y_in: [_; WIDTH] =
-			array::from_fn(|byte_idx| witness.new_column::<BinaryField8b>(y_in[byte_idx]));
-		let mut c_in = witness.new_column::<BinaryField1b>(c_in);
-
-		let x_in_bytes_u8: [_; WIDTH] = x_in.each_mut().map(|col| col.as_mut_slice::<u8>());
-		let y_in_bytes_u8: [_; WIDTH] = y_in.each_mut().map(|col| col.as_mut_slice::<u8>());
-		let c_in_u8 = c_in.as_mut_slice::<u8>();
+		let mut x_in_bytes_u8: [_; WIDTH] = array::from_fn(|_| Vec::with_capacity(1 << log_size));
+		let mut y_in_bytes_u8: [_; WIDTH] = array::from_fn(|_| Vec::with_capacity(1 << log_size));
+		let mut c_in_u8 = Vec::with_capacity(1 << log_size);
 
-		for row_idx in 0..1 << log_size {
+		for _ in 0..1 << log_size {
 			let mut rng = thread_rng();
 			let input_bitmask = (U512::from(1u8) << (8 * WIDTH)) - U512::from(1u8);
 			let mut x = random_u512(&mut rng);
@@ -115,22 +107,37 @@ where
 			}
 
 			for byte_idx in 0..WIDTH {
-				x_in_bytes_u8[byte_idx][row_idx] = x.byte(byte_idx);
+				x_in_bytes_u8[byte_idx].push(x.byte(byte_idx));
 
-				y_in_bytes_u8[byte_idx][row_idx] = y.byte(byte_idx);
+				y_in_bytes_u8[byte_idx].push(y.byte(byte_idx));
 			}
 
-			c_in_u8[row_idx / 8] |= (c as u8) << (row_idx % 8);
+			c_in_u8.push(c as u8);
+		}
+
+		for (byte_idx, x_in_byte_u8) in x_in_bytes_u8.into_iter().enumerate() {
+			witness
+				.new_column::<Tower::B8>(x_in[byte_idx])
+				.set_scalars(x_in_byte_u8.into_iter().map(Tower::B8::from));
 		}
+		for (byte_idx, y_in_byte_u8) in y_in_bytes_u8.into_iter().enumerate() {
+			witness
+				.new_column::<Tower::B8>(y_in[byte_idx])
+				.set_scalars(y_in_byte_u8.into_iter().map(Tower::B8::from));
+		}
+		witness
+			.new_column::<BinaryField1b>(c_in)
+			.set_scalars(c_in_u8.into_iter().map(BinaryField1b::from));
 	}
 
-	let lookup_t_add = add_lookup(&mut builder, "add table").unwrap();
-	let lookup_t_add_carryfree = add_carryfree_lookup(&mut builder, "add table").unwrap();
+	let lookup_t_add = add_lookup::<_, Tower>(&mut builder, "add table").unwrap();
+	let lookup_t_add_carryfree =
+		add_carryfree_lookup::<_, Tower>(&mut builder, "add table").unwrap();
 
 	let mut lookup_batch_add = LookupBatch::new([lookup_t_add]);
 	let mut lookup_batch_add_carryfree = LookupBatch::new([lookup_t_add_carryfree]);
 
-	let _sum_and_cout = byte_sliced_add_carryfree::<_, _, TL>(
+	let _sum_and_cout = byte_sliced_add_carryfree::<_, Tower, TL>(
 		&mut builder,
 		"lasso_bytesliced_add_carryfree",
 		&x_in,
@@ -142,9 +149,11 @@ where
 	)
 	.unwrap();
 
-	lookup_batch_add.execute::<_, _, B32>(&mut builder).unwrap();
+	lookup_batch_add
+		.execute::<_, _, Tower::B32>(&mut builder)
+		.unwrap();
 	lookup_batch_add_carryfree
-		.execute::<_, _, B32>(&mut builder)
+		.execute::<_, _, Tower::B32>(&mut builder)
 		.unwrap();
 
 	let witness = builder.take_witness().unwrap();
@@ -153,20 +162,18 @@ where
 	validate_witness(&constraint_system, &boundaries, &witness).unwrap();
 }
 
-pub fn test_bytesliced_double_conditional_increment<const WIDTH: usize, TL>()
+pub fn test_bytesliced_double_conditional_increment<U, Tower, const WIDTH: usize, TL>()
 where
+	U: TowerUnderlier<Tower> + Pod,
+	Tower: IntegerTowerFamily,
 	TL: TowerLevel<OracleId, Data = [OracleId; WIDTH]>,
 {
-	type U = OptimalUnderlier;
-	type F = BinaryField128b;
-
 	let allocator = bumpalo::Bump::new();
-	let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+	let mut builder = ConstraintSystemBuilder::<U, Tower::B128>::new_with_witness(&allocator);
 	let log_size = 14;
 
-	let x_in = array::from_fn(|_| {
-		unconstrained::<_, _, BinaryField8b>(&mut builder, "x", log_size).unwrap()
-	});
+	let x_in =
+		array::from_fn(|_| unconstrained::<_, _, Tower::B8>(&mut builder, "x", log_size).unwrap());
 
 	let first_c_in =
 		unconstrained::<_, _, BinaryField1b>(&mut builder, "cin first", log_size).unwrap();
@@ -176,11 +183,11 @@ where
 
 	let zero_oracle_carry =
 		transparent::constant(&mut builder, "zero carry", log_size, BinaryField1b::ZERO).unwrap();
-	let lookup_t_dci = dci_lookup(&mut builder, "add table").unwrap();
+	let lookup_t_dci = dci_lookup::<_, Tower>(&mut builder, "add table").unwrap();
 
 	let mut lookup_batch_dci = LookupBatch::new([lookup_t_dci]);
 
-	let _sum_and_cout = byte_sliced_double_conditional_increment::<_, _, TL>(
+	let _sum_and_cout = byte_sliced_double_conditional_increment::<_, Tower, TL>(
 		&mut builder,
 		"lasso_bytesliced_DCI",
 		&x_in,
@@ -192,7 +199,9 @@ where
 	)
 	.unwrap();
 
-	lookup_batch_dci.execute::<_, _, B32>(&mut builder).unwrap();
+	lookup_batch_dci
+		.execute::<_, _, Tower::B32>(&mut builder)
+		.unwrap();
 
 	let witness = builder.take_witness().unwrap();
 	let constraint_system = builder.build().unwrap();
@@ -200,37 +209,34 @@ where
 	validate_witness(&constraint_system, &boundaries, &witness).unwrap();
 }
 
-pub fn test_bytesliced_mul<const WIDTH: usize, TL>()
+pub fn test_bytesliced_mul<U, Tower, const WIDTH: usize, TL>()
 where
+	U: TowerUnderlier<Tower> + Pod,
+	Tower: IntegerTowerFamily,
 	TL: TowerLevel<OracleId>,
 	TL::Base: TowerLevel<OracleId, Data = [OracleId; WIDTH]>,
 {
-	type U = OptimalUnderlier;
-	type F = BinaryField128b;
-
 	let allocator = bumpalo::Bump::new();
-	let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+	let mut builder = ConstraintSystemBuilder::<U, Tower::B128>::new_with_witness(&allocator);
 	let log_size = 14;
 
-	let mult_a = array::from_fn(|_| {
-		unconstrained::<_, _, BinaryField8b>(&mut builder, "a", log_size).unwrap()
-	});
-	let mult_b = array::from_fn(|_| {
-		unconstrained::<_, _, BinaryField8b>(&mut builder, "b", log_size).unwrap()
-	});
+	let mult_a =
+		array::from_fn(|_| unconstrained::<_, _, Tower::B8>(&mut builder, "a", log_size).unwrap());
+	let mult_b =
+		array::from_fn(|_| unconstrained::<_, _, Tower::B8>(&mut builder, "b", log_size).unwrap());
 
 	let zero_oracle_carry =
 		transparent::constant(&mut builder, "zero carry", log_size, BinaryField1b::ZERO).unwrap();
 
-	let lookup_t_mul = mul_lookup(&mut builder, "mul lookup").unwrap();
-	let lookup_t_add = add_lookup(&mut builder, "add lookup").unwrap();
-	let lookup_t_dci = dci_lookup(&mut builder, "dci lookup").unwrap();
+	let lookup_t_mul = mul_lookup::<_, Tower>(&mut builder, "mul lookup").unwrap();
+	let lookup_t_add = add_lookup::<_, Tower>(&mut builder, "add lookup").unwrap();
+	let lookup_t_dci = dci_lookup::<_, Tower>(&mut builder, "dci lookup").unwrap();
 
 	let mut lookup_batch_mul = LookupBatch::new([lookup_t_mul]);
 	let mut lookup_batch_add = LookupBatch::new([lookup_t_add]);
 	let mut lookup_batch_dci = LookupBatch::new([lookup_t_dci]);
 
-	let _sum_and_cout = byte_sliced_mul::<_, _, TL::Base, TL>(
+	let _sum_and_cout = byte_sliced_mul::<_, Tower, TL::Base, TL>(
 		&mut builder,
 		"lasso_bytesliced_mul",
 		&mult_a,
@@ -249,40 +255,32 @@ where
 	validate_witness(&constraint_system, &boundaries, &witness).unwrap();
 }
 
-pub fn test_bytesliced_modular_mul<const WIDTH: usize, TL>()
+pub fn test_bytesliced_modular_mul<U, Tower, const WIDTH: usize, TL>()
 where
+	U: TowerUnderlier<Tower> + Pod,
+	Tower: IntegerTowerFamily,
 	TL: TowerLevel<OracleId>,
 	TL::Base: TowerLevel<OracleId, Data = [OracleId; WIDTH]>,
 	<TL as TowerLevel<usize>>::Data: Debug,
 {
-	type U = OptimalUnderlier;
-	type F = BinaryField128b;
-
 	let allocator = bumpalo::Bump::new();
-	let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+	let mut builder = ConstraintSystemBuilder::<U, Tower::B128>::new_with_witness(&allocator);
 	let log_size = 14;
 
 	let mut rng = thread_rng();
 
-	let mult_a = builder.add_committed_multiple::<WIDTH>("a", log_size, B8::TOWER_LEVEL);
-	let mult_b = builder.add_committed_multiple::<WIDTH>("b", log_size, B8::TOWER_LEVEL);
+	let mult_a = builder.add_committed_multiple::<WIDTH>("a", log_size, Tower::B8::TOWER_LEVEL);
+	let mult_b = builder.add_committed_multiple::<WIDTH>("b", log_size, Tower::B8::TOWER_LEVEL);
 
 	let input_bitmask = (U512::from(1u8) << (8 * WIDTH)) - U512::from(1u8);
 
 	let modulus = (random_u512(&mut rng) % input_bitmask) + U512::from(1u8);
 
 	if let Some(witness) = builder.witness() {
-		let mut mult_a: [_; WIDTH] =
-			array::from_fn(|byte_idx| witness.new_column::<BinaryField8b>(mult_a[byte_idx]));
-
-		let mult_a_u8 = mult_a.each_mut().map(|col| col.as_mut_slice::<u8>());
-
-		let mut mult_b: [_; WIDTH] =
-			array::from_fn(|byte_idx| witness.new_column::<BinaryField8b>(mult_b[byte_idx]));
+		let mut mult_a_u8: [_; WIDTH] = array::from_fn(|_| Vec::with_capacity(1 << log_size));
+		let mut mult_b_u8: [_; WIDTH] = array::from_fn(|_| Vec::with_capacity(1 << log_size));
 
-		let mult_b_u8 = mult_b.each_mut().map(|col| col.as_mut_slice::<u8>());
-
-		for row_idx in 0..1 << log_size {
+		for _ in 0..1 << log_size {
 			let mut a = random_u512(&mut rng);
 			let mut b = random_u512(&mut rng);
 
@@ -290,21 +288,32 @@ where
 			b %= modulus;
 
 			for byte_idx in 0..WIDTH {
-				mult_a_u8[byte_idx][row_idx] = a.byte(byte_idx);
-				mult_b_u8[byte_idx][row_idx] = b.byte(byte_idx);
+				mult_a_u8[byte_idx].push(a.byte(byte_idx));
+				mult_b_u8[byte_idx].push(b.byte(byte_idx));
 			}
 		}
+
+		for (byte_idx, mult_a_byte_u8) in mult_a_u8.into_iter().enumerate() {
+			witness
+				.new_column::<Tower::B8>(mult_a[byte_idx])
+				.set_scalars(mult_a_byte_u8.into_iter().map(Tower::B8::from));
+		}
+		for (byte_idx, mult_b_byte_u8) in mult_b_u8.into_iter().enumerate() {
+			witness
+				.new_column::<Tower::B8>(mult_b[byte_idx])
+				.set_scalars(mult_b_byte_u8.into_iter().map(Tower::B8::from));
+		}
 	}
 
 	let modulus_input: [_; WIDTH] = array::from_fn(|byte_idx| modulus.byte(byte_idx));
 
 	let zero_oracle_byte =
-		transparent::constant(&mut builder, "zero carry", log_size, BinaryField8b::ZERO).unwrap();
+		transparent::constant(&mut builder, "zero carry", log_size, Tower::B8::ZERO).unwrap();
 
 	let zero_oracle_carry =
 		transparent::constant(&mut builder, "zero carry", log_size, BinaryField1b::ZERO).unwrap();
 
-	let _modded_product = byte_sliced_modular_mul::<_, _, TL::Base, TL>(
+	let _modded_product = byte_sliced_modular_mul::<_, Tower, TL::Base, TL>(
 		&mut builder,
 		"lasso_bytesliced_mul",
 		&mult_a,
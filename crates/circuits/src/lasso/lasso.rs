@@ -3,9 +3,8 @@
 use anyhow::{ensure, Error, Result};
 use binius_core::{constraint_system::channel::ChannelId, oracle::OracleId};
 use binius_field::{
-	as_packed_field::{PackScalar, PackedType},
-	underlier::UnderlierType,
-	BinaryField1b, ExtensionField, PackedFieldIndexable, TowerField,
+	as_packed_field::PackScalar, underlier::UnderlierType, BinaryField1b, ExtensionField,
+	TowerField,
 };
 use itertools::{izip, Itertools};
 
@@ -23,7 +22,6 @@ pub fn lasso<U, F, FC>(
 where
 	U: UnderlierType + PackScalar<F> + PackScalar<FC> + PackScalar<BinaryField1b>,
 	F: TowerField + ExtensionField<FC> + From<FC>,
-	PackedType<U, FC>: PackedFieldIndexable,
 	FC: TowerField,
 {
 	if n_lookups.len() != lookups_u.len() {
@@ -79,37 +77,37 @@ where
 			Err(anyhow::Error::msg("u_log_rows and u_to_t_mappings must be of the same length"))?;
 		}
 
-		let mut lookup_f_witness = witness.new_column::<FC>(lookup_f);
-
-		let lookup_f_scalars = PackedType::<U, FC>::unpack_scalars_mut(lookup_f_witness.packed());
-
 		let alpha = FC::MULTIPLICATIVE_GENERATOR;
 
-		lookup_f_scalars.fill(FC::ONE);
+		let mut lookup_f_scalars = vec![FC::ONE; 1 << t_log_rows];
 
-		for (u_to_t_mapping, &n_lookups, &lookup_r, &lookup_w) in
-			izip!(u_to_t_mappings, n_lookups, &lookups_r, &lookups_w)
+		for (u_to_t_mapping, &n_lookups, &lookup_r, &lookup_w, &u_log_rows) in
+			izip!(u_to_t_mappings, n_lookups, &lookups_r, &lookups_w, &u_log_rows)
 		{
-			let mut lookup_r_witness = witness.new_column::<FC>(lookup_r);
-			let mut lookup_w_witness = witness.new_column::<FC>(lookup_w);
-
-			let lookup_r_scalars =
-				PackedType::<U, FC>::unpack_scalars_mut(lookup_r_witness.packed());
-			let lookup_w_scalars =
-				PackedType::<U, FC>::unpack_scalars_mut(lookup_w_witness.packed());
-
-			lookup_r_scalars.fill(FC::ONE);
-			lookup_w_scalars.fill(alpha);
+			let mut lookup_r_scalars = vec![FC::ONE; 1 << u_log_rows];
+			let mut lookup_w_scalars = vec![alpha; 1 << u_log_rows];
 
 			for (&index, r, w) in
-				izip!(u_to_t_mapping.as_ref(), lookup_r_scalars, lookup_w_scalars).take(n_lookups)
+				izip!(u_to_t_mapping.as_ref(), &mut lookup_r_scalars, &mut lookup_w_scalars)
+					.take(n_lookups)
 			{
 				let ts = lookup_f_scalars[index];
 				*r = ts;
 				*w = ts * alpha;
 				lookup_f_scalars[index] *= alpha;
 			}
+
+			witness
+				.new_column::<FC>(lookup_r)
+				.set_scalars(lookup_r_scalars);
+			witness
+				.new_column::<FC>(lookup_w)
+				.set_scalars(lookup_w_scalars);
 		}
+
+		witness
+			.new_column::<FC>(lookup_f)
+			.set_scalars(lookup_f_scalars);
 	}
 
 	lookups_r
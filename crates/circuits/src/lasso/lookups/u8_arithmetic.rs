@@ -1,43 +1,31 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use anyhow::Result;
-use binius_core::oracle::OracleId;
-use binius_field::{
-	as_packed_field::{PackScalar, PackedType},
-	underlier::UnderlierType,
-	BinaryField, BinaryField16b, BinaryField32b, BinaryField8b, ExtensionField,
-	PackedFieldIndexable, TowerField,
-};
-use bytemuck::Pod;
-
-use crate::builder::ConstraintSystemBuilder;
-
-type B8 = BinaryField8b;
-type B16 = BinaryField16b;
-type B32 = BinaryField32b;
+use binius_core::{oracle::OracleId, tower::TowerUnderlier};
+use binius_field::TowerField;
+
+use crate::{builder::ConstraintSystemBuilder, lasso::IntegerTowerFamily};
+
 const T_LOG_SIZE_MUL: usize = 16;
 const T_LOG_SIZE_ADD: usize = 17;
 const T_LOG_SIZE_DCI: usize = 10;
 
-pub fn mul_lookup<U, F>(
-	builder: &mut ConstraintSystemBuilder<U, F>,
+pub fn mul_lookup<U, Tower>(
+	builder: &mut ConstraintSystemBuilder<U, Tower::B128>,
 	name: impl ToString + Clone,
 ) -> Result<OracleId, anyhow::Error>
 where
-	U: Pod + UnderlierType + PackScalar<B8> + PackScalar<B16> + PackScalar<B32> + PackScalar<F>,
-	PackedType<U, B8>: PackedFieldIndexable,
-	PackedType<U, B16>: PackedFieldIndexable,
-	PackedType<U, B32>: PackedFieldIndexable,
-	F: TowerField + BinaryField + ExtensionField<B8> + ExtensionField<B16> + ExtensionField<B32>,
+	U: TowerUnderlier<Tower>,
+	Tower: IntegerTowerFamily,
 {
 	builder.push_namespace(name);
 
-	let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_MUL, B32::TOWER_LEVEL);
+	let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_MUL, Tower::B32::TOWER_LEVEL);
 
 	if let Some(witness) = builder.witness() {
-		let mut lookup_t = witness.new_column::<B32>(lookup_t);
+		let mut lookup_t = witness.new_column::<Tower::B32>(lookup_t);
 
-		let lookup_t_u32 = lookup_t.as_mut_slice::<u32>();
+		let mut lookup_t_u32 = vec![0u32; 1 << T_LOG_SIZE_MUL];
 
 		for (i, lookup_t) in lookup_t_u32.iter_mut().enumerate() {
 			let a_int = (i >> 8) & 0xff;
@@ -47,31 +35,30 @@ where
 			assert_eq!(lookup_index, i);
 			*lookup_t = (lookup_index << 16 | ab_product) as u32;
 		}
+
+		lookup_t.set_scalars(lookup_t_u32.into_iter().map(Tower::B32::from));
 	}
 
 	builder.pop_namespace();
 	Ok(lookup_t)
 }
 
-pub fn add_lookup<U, F>(
-	builder: &mut ConstraintSystemBuilder<U, F>,
+pub fn add_lookup<U, Tower>(
+	builder: &mut ConstraintSystemBuilder<U, Tower::B128>,
 	name: impl ToString + Clone,
 ) -> Result<OracleId, anyhow::Error>
 where
-	U: Pod + UnderlierType + PackScalar<B8> + PackScalar<B16> + PackScalar<B32> + PackScalar<F>,
-	PackedType<U, B8>: PackedFieldIndexable,
-	PackedType<U, B16>: PackedFieldIndexable,
-	PackedType<U, B32>: PackedFieldIndexable,
-	F: TowerField + BinaryField + ExtensionField<B8> + ExtensionField<B16> + ExtensionField<B32>,
+	U: TowerUnderlier<Tower>,
+	Tower: IntegerTowerFamily,
 {
 	builder.push_namespace(name);
 
-	let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_ADD, B32::TOWER_LEVEL);
+	let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_ADD, Tower::B32::TOWER_LEVEL);
 
 	if let Some(witness) = builder.witness() {
-		let mut lookup_t = witness.new_column::<B32>(lookup_t);
+		let mut lookup_t = witness.new_column::<Tower::B32>(lookup_t);
 
-		let lookup_t_u32 = lookup_t.as_mut_slice::<u32>();
+		let mut lookup_t_u32 = vec![0u32; 1 << T_LOG_SIZE_ADD];
 
 		for carry_in_usize in 0..(1 << 1) {
 			for x_in_usize in 0..(1 << 8) {
@@ -89,31 +76,30 @@ where
 				}
 			}
 		}
+
+		lookup_t.set_scalars(lookup_t_u32.into_iter().map(Tower::B32::from));
 	}
 
 	builder.pop_namespace();
 	Ok(lookup_t)
 }
 
-pub fn add_carryfree_lookup<U, F>(
-	builder: &mut ConstraintSystemBuilder<U, F>,
+pub fn add_carryfree_lookup<U, Tower>(
+	builder: &mut ConstraintSystemBuilder<U, Tower::B128>,
 	name: impl ToString + Clone,
 ) -> Result<OracleId, anyhow::Error>
 where
-	U: Pod + UnderlierType + PackScalar<B8> + PackScalar<B16> + PackScalar<B32> + PackScalar<F>,
-	PackedType<U, B8>: PackedFieldIndexable,
-	PackedType<U, B16>: PackedFieldIndexable,
-	PackedType<U, B32>: PackedFieldIndexable,
-	F: TowerField + BinaryField + ExtensionField<B8> + ExtensionField<B16> + ExtensionField<B32>,
+	U: TowerUnderlier<Tower>,
+	Tower: IntegerTowerFamily,
 {
 	builder.push_namespace(name);
 
-	let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_ADD, B32::TOWER_LEVEL);
+	let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_ADD, Tower::B32::TOWER_LEVEL);
 
 	if let Some(witness) = builder.witness() {
-		let mut lookup_t = witness.new_column::<B32>(lookup_t);
+		let mut lookup_t = witness.new_column::<Tower::B32>(lookup_t);
 
-		let lookup_t_u32 = lookup_t.as_mut_slice::<u32>();
+		let mut lookup_t_u32 = vec![0u32; 1 << T_LOG_SIZE_ADD];
 
 		for carry_in_usize in 0..(1 << 1) {
 			for x_in_usize in 0..(1 << 8) {
@@ -133,31 +119,30 @@ where
 				}
 			}
 		}
+
+		lookup_t.set_scalars(lookup_t_u32.into_iter().map(Tower::B32::from));
 	}
 
 	builder.pop_namespace();
 	Ok(lookup_t)
 }
 
-pub fn dci_lookup<U, F>(
-	builder: &mut ConstraintSystemBuilder<U, F>,
+pub fn dci_lookup<U, Tower>(
+	builder: &mut ConstraintSystemBuilder<U, Tower::B128>,
 	name: impl ToString + Clone,
 ) -> Result<OracleId, anyhow::Error>
 where
-	U: Pod + UnderlierType + PackScalar<B8> + PackScalar<B16> + PackScalar<B32> + PackScalar<F>,
-	PackedType<U, B8>: PackedFieldIndexable,
-	PackedType<U, B16>: PackedFieldIndexable,
-	PackedType<U, B32>: PackedFieldIndexable,
-	F: TowerField + BinaryField + ExtensionField<B8> + ExtensionField<B16> + ExtensionField<B32>,
+	U: TowerUnderlier<Tower>,
+	Tower: IntegerTowerFamily,
 {
 	builder.push_namespace(name);
 
-	let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_DCI, B32::TOWER_LEVEL);
+	let lookup_t = builder.add_committed("lookup_t", T_LOG_SIZE_DCI, Tower::B32::TOWER_LEVEL);
 
 	if let Some(witness) = builder.witness() {
-		let mut lookup_t = witness.new_column::<B32>(lookup_t);
+		let mut lookup_t = witness.new_column::<Tower::B32>(lookup_t);
 
-		let lookup_t_u32 = lookup_t.as_mut_slice::<u32>();
+		let mut lookup_t_u32 = vec![0u32; 1 << T_LOG_SIZE_DCI];
 
 		for first_carry_in_usize in 0..(1 << 1) {
 			for second_carry_in_usize in 0..(1 << 1) {
@@ -177,6 +162,8 @@ where
 				}
 			}
 		}
+
+		lookup_t.set_scalars(lookup_t_u32.into_iter().map(Tower::B32::from));
 	}
 
 	builder.pop_namespace();
@@ -11,9 +11,37 @@ pub mod u8add;
 pub mod u8add_carryfree;
 pub mod u8mul;
 
+use binius_core::tower::TowerFamily;
+use binius_field::BinaryField1b;
 pub use sha256::sha256;
 pub use u32add::u32add;
 pub use u8_double_conditional_increment::u8_double_conditional_increment;
 pub use u8add::u8add;
 pub use u8add_carryfree::u8add_carryfree;
 pub use u8mul::u8mul;
+
+/// A tower family whose 8-, 16- and 32-bit fields convert to and from the unsigned integers of the
+/// same width.
+///
+/// The byte gadgets treat these field elements as integers and pack bytes into the 32-bit lookup
+/// values along the `TowerField::basis(3, i)` elements. Both the canonical and the AES towers
+/// qualify, so the gadgets work with the byte-sliced underliers, which only pack AES fields.
+pub trait IntegerTowerFamily:
+	TowerFamily<
+	B1 = BinaryField1b,
+	B8: From<u8> + Into<u8>,
+	B16: From<u16> + Into<u16>,
+	B32: From<u32> + Into<u32>,
+>
+{
+}
+
+impl<T> IntegerTowerFamily for T where
+	T: TowerFamily<
+		B1 = BinaryField1b,
+		B8: From<u8> + Into<u8>,
+		B16: From<u16> + Into<u16>,
+		B32: From<u32> + Into<u32>,
+	>
+{
+}
@@ -1,25 +1,15 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use anyhow::Result;
-use binius_core::oracle::OracleId;
-use binius_field::{
-	as_packed_field::{PackScalar, PackedType},
-	underlier::UnderlierType,
-	BinaryField, BinaryField16b, BinaryField1b, BinaryField32b, BinaryField8b, ExtensionField,
-	PackedFieldIndexable, TowerField,
-};
-use bytemuck::Pod;
-
-use super::batch::LookupBatch;
-use crate::builder::ConstraintSystemBuilder;
+use binius_core::{oracle::OracleId, tower::TowerUnderlier};
+use binius_field::TowerField;
+use itertools::izip;
 
-type B1 = BinaryField1b;
-type B8 = BinaryField8b;
-type B16 = BinaryField16b;
-type B32 = BinaryField32b;
+use super::{batch::LookupBatch, IntegerTowerFamily};
+use crate::builder::ConstraintSystemBuilder;
 
-pub fn u8_double_conditional_increment<U, F>(
-	builder: &mut ConstraintSystemBuilder<U, F>,
+pub fn u8_double_conditional_increment<U, Tower>(
+	builder: &mut ConstraintSystemBuilder<U, Tower::B128>,
 	lookup_batch: &mut LookupBatch,
 	name: impl ToString + Clone,
 	x_in: OracleId,
@@ -28,59 +18,50 @@ pub fn u8_double_conditional_increment<U, F>(
 	log_size: usize,
 ) -> Result<(OracleId, OracleId), anyhow::Error>
 where
-	U: Pod
-		+ UnderlierType
-		+ PackScalar<B1>
-		+ PackScalar<B8>
-		+ PackScalar<B16>
-		+ PackScalar<B32>
-		+ PackScalar<F>,
-	PackedType<U, B8>: PackedFieldIndexable,
-	PackedType<U, B16>: PackedFieldIndexable,
-	PackedType<U, B32>: PackedFieldIndexable,
-	F: TowerField + BinaryField + ExtensionField<B8> + ExtensionField<B16> + ExtensionField<B32>,
+	U: TowerUnderlier<Tower>,
+	Tower: IntegerTowerFamily,
 {
 	builder.push_namespace(name);
 
-	let sum = builder.add_committed("sum", log_size, B8::TOWER_LEVEL);
+	let sum = builder.add_committed("sum", log_size, Tower::B8::TOWER_LEVEL);
 
-	let carry_out = builder.add_committed("cout", log_size, B1::TOWER_LEVEL);
+	let carry_out = builder.add_committed("cout", log_size, Tower::B1::TOWER_LEVEL);
 
 	let lookup_u = builder.add_linear_combination(
 		"lookup_u",
 		log_size,
 		[
-			(first_carry_in, <F as TowerField>::basis(0, 18)?),
-			(second_carry_in, <F as TowerField>::basis(0, 17)?),
-			(carry_out, <F as TowerField>::basis(3, 2)?),
-			(x_in, <F as TowerField>::basis(3, 1)?),
-			(sum, <F as TowerField>::basis(3, 0)?),
+			(first_carry_in, <Tower::B128 as TowerField>::basis(0, 18)?),
+			(second_carry_in, <Tower::B128 as TowerField>::basis(0, 17)?),
+			(carry_out, <Tower::B128 as TowerField>::basis(3, 2)?),
+			(x_in, <Tower::B128 as TowerField>::basis(3, 1)?),
+			(sum, <Tower::B128 as TowerField>::basis(3, 0)?),
 		],
 	)?;
 
 	let mut u_to_t_mapping = vec![];
 
 	if let Some(witness) = builder.witness() {
-		let mut sum_witness = witness.new_column::<B8>(sum);
-		let mut carry_out_witness = witness.new_column::<B1>(carry_out);
-		let mut lookup_u_witness = witness.new_column::<B32>(lookup_u);
-		let mut u_to_t_mapping_witness = vec![0; 1 << log_size];
-
-		let x_in_u8 = witness.get::<B8>(x_in)?.as_slice::<u8>();
-		let first_carry_in_u8_packed = witness.get::<B1>(first_carry_in)?.as_slice::<u8>();
-		let second_carry_in_u8_packed = witness.get::<B1>(second_carry_in)?.as_slice::<u8>();
-
-		let sum_u8 = sum_witness.as_mut_slice::<u8>();
-		let carry_out_u8_packed = carry_out_witness.as_mut_slice::<u8>();
-		let lookup_u_u32 = lookup_u_witness.as_mut_slice::<u32>();
-
-		for row_idx in 0..1 << log_size {
-			let first_carry_in_usize =
-				((first_carry_in_u8_packed[row_idx / 8] >> (row_idx % 8)) & 1) as usize;
-			let second_carry_in_usize =
-				((second_carry_in_u8_packed[row_idx / 8] >> (row_idx % 8)) & 1) as usize;
-
-			let x_in_usize = x_in_u8[row_idx] as usize;
+		let mut sum_witness = witness.new_column::<Tower::B8>(sum);
+		let mut carry_out_witness = witness.new_column::<Tower::B1>(carry_out);
+		let mut lookup_u_witness = witness.new_column::<Tower::B32>(lookup_u);
+
+		let x_in = witness.get::<Tower::B8>(x_in)?.scalars();
+		let first_carry_in = witness.get::<Tower::B1>(first_carry_in)?.scalars();
+		let second_carry_in = witness.get::<Tower::B1>(second_carry_in)?.scalars();
+
+		let mut sum_u8 = Vec::with_capacity(1 << log_size);
+		let mut carry_out_u8 = Vec::with_capacity(1 << log_size);
+		let mut lookup_u_u32 = Vec::with_capacity(1 << log_size);
+		let mut u_to_t_mapping_witness = Vec::with_capacity(1 << log_size);
+
+		for (x_in, first_carry_in, second_carry_in) in
+			izip!(x_in, first_carry_in, second_carry_in).take(1 << log_size)
+		{
+			let first_carry_in_usize = Into::<u8>::into(first_carry_in) as usize;
+			let second_carry_in_usize = Into::<u8>::into(second_carry_in) as usize;
+
+			let x_in_usize = Into::<u8>::into(x_in) as usize;
 			let sum_with_carry_out = x_in_usize + first_carry_in_usize + second_carry_in_usize;
 			let sum_usize = sum_with_carry_out & 0xff;
 			let carry_out_usize = sum_with_carry_out >> 8;
@@ -92,16 +73,19 @@ where
 				| (x_in_usize << 8)
 				| sum_usize;
 
-			lookup_u_u32[row_idx] = lookup_value as u32;
+			lookup_u_u32.push(lookup_value as u32);
 
-			sum_u8[row_idx] = sum_usize as u8;
+			sum_u8.push(sum_usize as u8);
 
-			// Write our value to the bit
-			carry_out_u8_packed[row_idx / 8] |= (carry_out_usize << (row_idx % 8)) as u8;
+			carry_out_u8.push(carry_out_usize as u8);
 
-			u_to_t_mapping_witness[row_idx] = lookup_index;
+			u_to_t_mapping_witness.push(lookup_index);
 		}
 
+		sum_witness.set_scalars(sum_u8.into_iter().map(Tower::B8::from));
+		carry_out_witness.set_scalars(carry_out_u8.into_iter().map(Tower::B1::from));
+		lookup_u_witness.set_scalars(lookup_u_u32.into_iter().map(Tower::B32::from));
+
 		u_to_t_mapping = u_to_t_mapping_witness;
 	}
 
@@ -22,11 +22,19 @@ pub mod sha256;
 pub mod transparent;
 pub mod u32fib;
 pub mod unconstrained;
+pub mod virtual_oracles;
 pub mod vision;
 
 #[cfg(test)]
 mod tests {
-	use std::array;
+	use std::{
+		array,
+		collections::HashMap,
+		sync::{
+			atomic::{AtomicUsize, Ordering},
+			Arc,
+		},
+	};
 
 	use binius_core::{
 		constraint_system::{
@@ -34,21 +42,25 @@ mod tests {
 			channel::{Boundary, FlushDirection},
 			validate::validate_witness,
 		},
-		fiat_shamir::HasherChallenger,
-		oracle::OracleId,
-		tower::CanonicalTowerFamily,
+		fiat_shamir::{HasherChallenger, Vision32bChallenger},
+		oracle::{MultilinearPolyVariant, OracleId},
+		polynomial::CachedPoly,
+		tower::{AESTowerFamily, CanonicalTowerFamily},
 	};
 	use binius_field::{
 		arch::OptimalUnderlier,
 		as_packed_field::PackedType,
 		tower_levels::{TowerLevel1, TowerLevel16, TowerLevel2, TowerLevel4, TowerLevel8},
 		underlier::WithUnderlier,
-		AESTowerField16b, BinaryField128b, BinaryField1b, BinaryField32b, BinaryField64b,
-		BinaryField8b, Field, TowerField,
+		AESTowerField128b, AESTowerField16b, BinaryField128b, BinaryField1b, BinaryField32b,
+		BinaryField64b, BinaryField8b, ByteSlicedAES32x128b, Field, TowerField,
 	};
 	use binius_hal::make_portable_backend;
-	use binius_hash::compress::Groestl256ByteCompression;
-	use binius_math::DefaultEvaluationDomainFactory;
+	use binius_hash::{
+		compress::Groestl256ByteCompression, Vision32bByteCompression, Vision32bByteHasher,
+	};
+	use binius_macros::arith_circuit_poly;
+	use binius_math::{ArithExpr, CompositionPolyOS, DefaultEvaluationDomainFactory};
 	use groestl_crypto::Groestl256;
 	use rand::{rngs::StdRng, Rng, SeedableRng};
 	use sha2::{compress256, digest::generic_array::GenericArray};
@@ -56,6 +68,7 @@ mod tests {
 	use crate::{
 		arithmetic, bitwise,
 		builder::ConstraintSystemBuilder,
+		collatz::Collatz,
 		groestl::groestl_p_permutation,
 		keccakf::{keccakf, KeccakfState},
 		lasso::{
@@ -71,13 +84,16 @@ mod tests {
 		},
 		plain_lookup,
 		sha256::sha256,
+		transparent,
 		u32fib::u32fib,
 		unconstrained::unconstrained,
+		virtual_oracles,
 		vision::vision_permutation,
 	};
 
 	type U = OptimalUnderlier;
 	type F = BinaryField128b;
+	type ByteSlicedUnderlier = <ByteSlicedAES32x128b as WithUnderlier>::Underlier;
 
 	#[test]
 	fn test_lasso_u8add_carryfree_rejects_carry() {
@@ -89,10 +105,13 @@ mod tests {
 		let y_in = unconstrained::<_, _, BinaryField8b>(&mut builder, "y", log_size).unwrap();
 		let c_in = unconstrained::<_, _, BinaryField1b>(&mut builder, "c", log_size).unwrap();
 
-		let lookup_t =
-			lookups::u8_arithmetic::add_carryfree_lookup(&mut builder, "add cf table").unwrap();
+		let lookup_t = lookups::u8_arithmetic::add_carryfree_lookup::<_, CanonicalTowerFamily>(
+			&mut builder,
+			"add cf table",
+		)
+		.unwrap();
 		let mut lookup_batch = LookupBatch::new([lookup_t]);
-		let _sum_and_cout = lasso::u8add_carryfree(
+		let _sum_and_cout = lasso::u8add_carryfree::<_, CanonicalTowerFamily>(
 			&mut builder,
 			&mut lookup_batch,
 			"lasso_u8add",
@@ -116,42 +135,80 @@ mod tests {
 
 	#[test]
 	fn test_lasso_add_bytesliced() {
-		test_bytesliced_add::<1, TowerLevel1>();
-		test_bytesliced_add::<2, TowerLevel2>();
-		test_bytesliced_add::<4, TowerLevel4>();
-		test_bytesliced_add::<8, TowerLevel8>();
+		test_bytesliced_add::<OptimalUnderlier, CanonicalTowerFamily, 1, TowerLevel1>();
+		test_bytesliced_add::<OptimalUnderlier, CanonicalTowerFamily, 2, TowerLevel2>();
+		test_bytesliced_add::<OptimalUnderlier, CanonicalTowerFamily, 4, TowerLevel4>();
+		test_bytesliced_add::<OptimalUnderlier, CanonicalTowerFamily, 8, TowerLevel8>();
+	}
+
+	#[test]
+	fn test_lasso_add_bytesliced_aes() {
+		test_bytesliced_add::<ByteSlicedUnderlier, AESTowerFamily, 1, TowerLevel1>();
+		test_bytesliced_add::<ByteSlicedUnderlier, AESTowerFamily, 4, TowerLevel4>();
 	}
 
 	#[test]
 	fn test_lasso_mul_bytesliced() {
-		test_bytesliced_mul::<1, TowerLevel2>();
-		test_bytesliced_mul::<2, TowerLevel4>();
-		test_bytesliced_mul::<4, TowerLevel8>();
-		test_bytesliced_mul::<8, TowerLevel16>();
+		test_bytesliced_mul::<OptimalUnderlier, CanonicalTowerFamily, 1, TowerLevel2>();
+		test_bytesliced_mul::<OptimalUnderlier, CanonicalTowerFamily, 2, TowerLevel4>();
+		test_bytesliced_mul::<OptimalUnderlier, CanonicalTowerFamily, 4, TowerLevel8>();
+		test_bytesliced_mul::<OptimalUnderlier, CanonicalTowerFamily, 8, TowerLevel16>();
+	}
+
+	#[test]
+	fn test_lasso_mul_bytesliced_aes() {
+		test_bytesliced_mul::<ByteSlicedUnderlier, AESTowerFamily, 1, TowerLevel2>();
+		test_bytesliced_mul::<ByteSlicedUnderlier, AESTowerFamily, 4, TowerLevel8>();
 	}
 
 	#[test]
 	fn test_lasso_modular_mul_bytesliced() {
-		test_bytesliced_modular_mul::<1, TowerLevel2>();
-		test_bytesliced_modular_mul::<2, TowerLevel4>();
-		test_bytesliced_modular_mul::<4, TowerLevel8>();
-		test_bytesliced_modular_mul::<8, TowerLevel16>();
+		test_bytesliced_modular_mul::<OptimalUnderlier, CanonicalTowerFamily, 1, TowerLevel2>();
+		test_bytesliced_modular_mul::<OptimalUnderlier, CanonicalTowerFamily, 2, TowerLevel4>();
+		test_bytesliced_modular_mul::<OptimalUnderlier, CanonicalTowerFamily, 4, TowerLevel8>();
+		test_bytesliced_modular_mul::<OptimalUnderlier, CanonicalTowerFamily, 8, TowerLevel16>();
+	}
+
+	#[test]
+	fn test_lasso_modular_mul_bytesliced_aes() {
+		test_bytesliced_modular_mul::<ByteSlicedUnderlier, AESTowerFamily, 1, TowerLevel2>();
+		test_bytesliced_modular_mul::<ByteSlicedUnderlier, AESTowerFamily, 4, TowerLevel8>();
 	}
 
 	#[test]
 	fn test_lasso_bytesliced_double_conditional_increment() {
-		test_bytesliced_double_conditional_increment::<1, TowerLevel1>();
-		test_bytesliced_double_conditional_increment::<2, TowerLevel2>();
-		test_bytesliced_double_conditional_increment::<4, TowerLevel4>();
-		test_bytesliced_double_conditional_increment::<8, TowerLevel8>();
+		test_bytesliced_double_conditional_increment::<
+			OptimalUnderlier,
+			CanonicalTowerFamily,
+			1,
+			TowerLevel1,
+		>();
+		test_bytesliced_double_conditional_increment::<
+			OptimalUnderlier,
+			CanonicalTowerFamily,
+			2,
+			TowerLevel2,
+		>();
+		test_bytesliced_double_conditional_increment::<
+			OptimalUnderlier,
+			CanonicalTowerFamily,
+			4,
+			TowerLevel4,
+		>();
+		test_bytesliced_double_conditional_increment::<
+			OptimalUnderlier,
+			CanonicalTowerFamily,
+			8,
+			TowerLevel8,
+		>();
 	}
 
 	#[test]
 	fn test_lasso_bytesliced_add_carryfree() {
-		test_bytesliced_add_carryfree::<1, TowerLevel1>();
-		test_bytesliced_add_carryfree::<2, TowerLevel2>();
-		test_bytesliced_add_carryfree::<4, TowerLevel4>();
-		test_bytesliced_add_carryfree::<8, TowerLevel8>();
+		test_bytesliced_add_carryfree::<OptimalUnderlier, CanonicalTowerFamily, 1, TowerLevel1>();
+		test_bytesliced_add_carryfree::<OptimalUnderlier, CanonicalTowerFamily, 2, TowerLevel2>();
+		test_bytesliced_add_carryfree::<OptimalUnderlier, CanonicalTowerFamily, 4, TowerLevel4>();
+		test_bytesliced_add_carryfree::<OptimalUnderlier, CanonicalTowerFamily, 8, TowerLevel8>();
 	}
 
 	#[test]
@@ -165,12 +222,15 @@ mod tests {
 		let mult_b =
 			unconstrained::<_, _, BinaryField8b>(&mut builder, "mult_b", log_size).unwrap();
 
-		let mul_lookup_table =
-			lookups::u8_arithmetic::mul_lookup(&mut builder, "mul table").unwrap();
+		let mul_lookup_table = lookups::u8_arithmetic::mul_lookup::<_, CanonicalTowerFamily>(
+			&mut builder,
+			"mul table",
+		)
+		.unwrap();
 
 		let mut lookup_batch = LookupBatch::new([mul_lookup_table]);
 
-		let _product = lasso::u8mul(
+		let _product = lasso::u8mul::<_, CanonicalTowerFamily>(
 			&mut builder,
 			&mut lookup_batch,
 			"lasso_u8mul",
@@ -195,8 +255,11 @@ mod tests {
 		let allocator = bumpalo::Bump::new();
 		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
 		let log_size = 10;
-		let mul_lookup_table =
-			lookups::u8_arithmetic::mul_lookup(&mut builder, "mul table").unwrap();
+		let mul_lookup_table = lookups::u8_arithmetic::mul_lookup::<_, CanonicalTowerFamily>(
+			&mut builder,
+			"mul table",
+		)
+		.unwrap();
 
 		let mut lookup_batch = LookupBatch::new([mul_lookup_table]);
 
@@ -206,7 +269,7 @@ mod tests {
 			let mult_b =
 				unconstrained::<_, _, BinaryField8b>(&mut builder, "mult_b", log_size).unwrap();
 
-			let _product = lasso::u8mul(
+			let _product = lasso::u8mul::<_, CanonicalTowerFamily>(
 				&mut builder,
 				&mut lookup_batch,
 				"lasso_u8mul",
@@ -234,8 +297,11 @@ mod tests {
 		let log_size = 10;
 
 		// We try to feed in the add table instead
-		let mul_lookup_table =
-			lookups::u8_arithmetic::add_lookup(&mut builder, "mul table").unwrap();
+		let mul_lookup_table = lookups::u8_arithmetic::add_lookup::<_, CanonicalTowerFamily>(
+			&mut builder,
+			"mul table",
+		)
+		.unwrap();
 
 		let mut lookup_batch = LookupBatch::new([mul_lookup_table]);
 
@@ -246,7 +312,7 @@ mod tests {
 			let mult_b =
 				unconstrained::<_, _, BinaryField8b>(&mut builder, "mult_b", log_size).unwrap();
 
-			let _product = lasso::u8mul(
+			let _product = lasso::u8mul::<_, CanonicalTowerFamily>(
 				&mut builder,
 				&mut lookup_batch,
 				"lasso_u8mul",
@@ -634,6 +700,916 @@ mod tests {
 		.unwrap();
 	}
 
+	#[test]
+	fn test_prove_verify_converted_tower() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_size = 10;
+
+		let mult_a =
+			unconstrained::<_, _, BinaryField8b>(&mut builder, "mult_a", log_size).unwrap();
+		let mult_b =
+			unconstrained::<_, _, BinaryField8b>(&mut builder, "mult_b", log_size).unwrap();
+
+		let mul_lookup_table = lookups::u8_arithmetic::mul_lookup::<_, CanonicalTowerFamily>(
+			&mut builder,
+			"mul table",
+		)
+		.unwrap();
+
+		let mut lookup_batch = LookupBatch::new([mul_lookup_table]);
+
+		let _product = lasso::u8mul::<_, CanonicalTowerFamily>(
+			&mut builder,
+			&mut lookup_batch,
+			"lasso_u8mul",
+			mult_a,
+			mult_b,
+			1 << log_size,
+		)
+		.unwrap();
+
+		lookup_batch
+			.execute::<_, _, BinaryField32b>(&mut builder)
+			.unwrap();
+
+		// A constraint with a constant that is not in the prime subfield.
+		let scale = BinaryField8b::new(0x53);
+		let scaled = builder.add_committed("scaled", log_size, BinaryField8b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			let scaled_scalars = witness
+				.get::<BinaryField8b>(mult_a)
+				.unwrap()
+				.scalars()
+				.map(|a| a * scale)
+				.collect::<Vec<_>>();
+			witness
+				.new_column::<BinaryField8b>(scaled)
+				.set_scalars(scaled_scalars);
+		}
+		builder.assert_zero(
+			"scaled",
+			[mult_a, scaled],
+			ArithExpr::Var(0) * ArithExpr::Const(F::from(scale)) + ArithExpr::Var(1),
+		);
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+
+		let aes_witness = witness
+			.convert_tower::<CanonicalTowerFamily, AESTowerFamily>()
+			.unwrap();
+		let aes_constraint_system = constraint_system
+			.convert_field::<AESTowerField128b>()
+			.unwrap();
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+
+		let proof = constraint_system::prove::<
+			U,
+			CanonicalTowerFamily,
+			_,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(&constraint_system, 1, 10, &[], witness, &domain_factory, &backend)
+		.unwrap();
+
+		constraint_system::verify::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&constraint_system, 1, 10, &[], proof)
+		.unwrap();
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let proof = constraint_system::prove::<
+			U,
+			AESTowerFamily,
+			_,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(&aes_constraint_system, 1, 10, &[], aes_witness, &domain_factory, &backend)
+		.unwrap();
+
+		constraint_system::verify::<
+			U,
+			AESTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&aes_constraint_system, 1, 10, &[], proof)
+		.unwrap();
+	}
+
+	#[test]
+	fn test_interleaved_and_elementwise_product() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_size = 8;
+
+		let lhs = unconstrained::<_, _, BinaryField8b>(&mut builder, "lhs", log_size).unwrap();
+		let rhs = unconstrained::<_, _, BinaryField8b>(&mut builder, "rhs", log_size).unwrap();
+		let expected_product =
+			builder.add_committed("expected_product", log_size, BinaryField8b::TOWER_LEVEL);
+		let expected_interleaved =
+			builder.add_committed("expected_interleaved", log_size + 1, BinaryField8b::TOWER_LEVEL);
+
+		if let Some(witness) = builder.witness() {
+			let lhs = witness.get::<BinaryField8b>(lhs).unwrap();
+			let rhs = witness.get::<BinaryField8b>(rhs).unwrap();
+			let (lhs, rhs) = (lhs.as_slice::<u8>(), rhs.as_slice::<u8>());
+
+			let mut product = witness.new_column::<BinaryField8b>(expected_product);
+			for (product, &lhs, &rhs) in itertools::izip!(product.as_mut_slice::<u8>(), lhs, rhs) {
+				*product = (BinaryField8b::new(lhs) * BinaryField8b::new(rhs)).val();
+			}
+
+			let mut interleaved = witness.new_column::<BinaryField8b>(expected_interleaved);
+			for (rows, &lhs, &rhs) in
+				itertools::izip!(interleaved.as_mut_slice::<u8>().chunks_exact_mut(2), lhs, rhs)
+			{
+				rows.copy_from_slice(&[lhs, rhs]);
+			}
+		}
+
+		let product = virtual_oracles::elementwise_product::<_, _, BinaryField8b>(
+			&mut builder,
+			"product",
+			lhs,
+			rhs,
+		)
+		.unwrap();
+		let interleaved = virtual_oracles::interleave::<_, _, BinaryField8b>(
+			&mut builder,
+			"interleaved",
+			lhs,
+			rhs,
+		)
+		.unwrap();
+
+		builder.assert_zero(
+			"product",
+			[product, expected_product],
+			ArithExpr::Var(0) + ArithExpr::Var(1),
+		);
+		builder.assert_zero(
+			"interleaved",
+			[interleaved, expected_interleaved],
+			ArithExpr::Var(0) + ArithExpr::Var(1),
+		);
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		let boundaries = vec![];
+		validate_witness(&constraint_system, &boundaries, &witness).unwrap();
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+
+		let proof = constraint_system::prove::<
+			U,
+			CanonicalTowerFamily,
+			_,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(&constraint_system, 1, 10, &boundaries, witness, &domain_factory, &backend)
+		.unwrap();
+
+		constraint_system::verify::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&constraint_system, 1, 10, &boundaries, proof)
+		.unwrap();
+	}
+
+	#[test]
+	fn test_lasso_rejects_out_of_table_value_queried_twice() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+
+		let log_size = PackedType::<U, BinaryField1b>::LOG_WIDTH;
+		assert!(log_size < 8);
+		let channel_id = builder.add_channel();
+
+		let table = builder.add_committed("table", log_size, BinaryField8b::TOWER_LEVEL);
+		let queries = builder.add_committed("queries", log_size, BinaryField8b::TOWER_LEVEL);
+
+		// The value 0xff is not in the table, and is claimed to be row 0 by both of its queries
+		let out_of_table = 0xffu8;
+		let mut u_to_t_mapping = (0..1 << log_size).collect::<Vec<usize>>();
+		u_to_t_mapping[0] = 0;
+		u_to_t_mapping[1] = 0;
+
+		if let Some(witness) = builder.witness() {
+			let mut table = witness.new_column::<BinaryField8b>(table);
+			for (i, row) in table.as_mut_slice::<u8>().iter_mut().enumerate() {
+				*row = i as u8;
+			}
+
+			let mut queries = witness.new_column::<BinaryField8b>(queries);
+			let queries_u8 = queries.as_mut_slice::<u8>();
+			for (i, query) in queries_u8.iter_mut().enumerate() {
+				*query = i as u8;
+			}
+			queries_u8[0] = out_of_table;
+			queries_u8[1] = out_of_table;
+		}
+
+		lasso::lasso::lasso::<_, _, BinaryField32b>(
+			&mut builder,
+			"lasso",
+			&[1 << log_size],
+			&[u_to_t_mapping],
+			&[[queries]],
+			[table],
+			channel_id,
+		)
+		.unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		let boundaries = vec![];
+		validate_witness(&constraint_system, &boundaries, &witness)
+			.expect_err("channel is unbalanced");
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+
+		let proof = constraint_system::prove::<
+			U,
+			CanonicalTowerFamily,
+			_,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(&constraint_system, 1, 10, &boundaries, witness, &domain_factory, &backend)
+		.unwrap();
+
+		constraint_system::verify::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&constraint_system, 1, 10, &boundaries, proof)
+		.expect_err("channel is unbalanced");
+	}
+
+	fn prove_verify_permutation(break_wiring: bool) -> anyhow::Result<()> {
+		use rand::seq::SliceRandom;
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let mut rng = StdRng::seed_from_u64(0);
+
+		// Columns of different heights, so that wiring crosses between tables
+		let max_log_rows = PackedType::<U, BinaryField1b>::LOG_WIDTH;
+		let log_rows = [max_log_rows, max_log_rows - 1];
+		let label = |column: usize, row: usize| (column << max_log_rows) | row;
+		let cells = log_rows
+			.iter()
+			.enumerate()
+			.flat_map(|(column, &n_vars)| (0..1 << n_vars).map(move |row| label(column, row)))
+			.collect::<Vec<_>>();
+
+		let mut targets = cells.clone();
+		targets.shuffle(&mut rng);
+		let sigma = cells
+			.iter()
+			.copied()
+			.zip(targets)
+			.collect::<HashMap<_, _>>();
+
+		// Cells on the same cycle of the permutation hold the same value
+		let mut values = HashMap::new();
+		for &start in &cells {
+			if values.contains_key(&start) {
+				continue;
+			}
+			let value = BinaryField32b::new(rng.gen());
+			let mut cell = start;
+			while values.insert(cell, value).is_none() {
+				cell = sigma[&cell];
+			}
+		}
+		if break_wiring {
+			let &cell = cells
+				.iter()
+				.find(|&cell| sigma[cell] != *cell)
+				.expect("permutation is not the identity");
+			values.insert(cell, values[&cell] + BinaryField32b::ONE);
+		}
+
+		let mut columns = Vec::new();
+		let mut sigma_columns = Vec::new();
+		for (column, &n_vars) in log_rows.iter().enumerate() {
+			let id = builder.add_committed(format!("column_{column}"), n_vars, 5);
+			if let Some(witness) = builder.witness() {
+				let mut entry = witness.new_column::<BinaryField32b>(id);
+				for (row, value) in entry
+					.as_mut_slice::<BinaryField32b>()
+					.iter_mut()
+					.enumerate()
+				{
+					*value = values[&label(column, row)];
+				}
+			}
+			columns.push(id);
+
+			let sigma_values = (0..1 << n_vars)
+				.map(|row| BinaryField32b::new(sigma[&label(column, row)] as u32))
+				.collect::<Vec<_>>();
+			sigma_columns.push(transparent::make_transparent(
+				&mut builder,
+				format!("sigma_{column}"),
+				&sigma_values,
+			)?);
+		}
+
+		builder.add_permutation_constraint("wiring", columns, sigma_columns)?;
+
+		let witness = builder.take_witness()?;
+		let constraint_system = builder.build()?;
+
+		let validation = validate_witness(&constraint_system, &[], &witness);
+		assert_eq!(validation.is_err(), break_wiring);
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+
+		let proof = constraint_system::prove::<
+			U,
+			CanonicalTowerFamily,
+			_,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(&constraint_system, 1, 10, &[], witness, &domain_factory, &backend)?;
+
+		constraint_system::verify::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&constraint_system, 1, 10, &[], proof)?;
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_permutation_constraint() {
+		prove_verify_permutation(false).unwrap();
+	}
+
+	#[test]
+	fn test_permutation_constraint_rejects_broken_wiring() {
+		prove_verify_permutation(true).expect_err("copied cells differ");
+	}
+
+	/// Builds a circuit that checks a committed column against a fixed table holding
+	/// `i + table_offset` in row `i`.
+	fn fixed_table_circuit(
+		allocator: &bumpalo::Bump,
+		table_offset: u8,
+	) -> anyhow::Result<(
+		constraint_system::ConstraintSystem<F>,
+		binius_core::witness::MultilinearExtensionIndex<U, F>,
+	)> {
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(allocator);
+		let log_size = 8;
+
+		let table = builder.add_fixed("table", log_size, 3);
+		let column = builder.add_committed("column", log_size, 3);
+		if let Some(witness) = builder.witness() {
+			let mut table = witness.new_column::<BinaryField8b>(table);
+			for (i, row) in table.as_mut_slice::<u8>().iter_mut().enumerate() {
+				*row = (i as u8).wrapping_add(table_offset);
+			}
+
+			let mut column = witness.new_column::<BinaryField8b>(column);
+			for (i, row) in column.as_mut_slice::<u8>().iter_mut().enumerate() {
+				*row = i as u8;
+			}
+		}
+
+		builder.assert_zero(
+			"column matches table",
+			[table, column],
+			ArithExpr::Var(0) + ArithExpr::Var(1),
+		);
+
+		let witness = builder.take_witness()?;
+		let constraint_system = builder.build()?;
+		Ok((constraint_system, witness))
+	}
+
+	fn prove_verify_with_fixed_table(verifier_table_offset: u8) -> anyhow::Result<()> {
+		let allocator = bumpalo::Bump::new();
+		let (constraint_system, witness) = fixed_table_circuit(&allocator, 0)?;
+		let backend = make_portable_backend();
+
+		let proving_key = constraint_system::preprocess::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			_,
+		>(&constraint_system, 1, 10, &witness, &backend)?;
+
+		// The verifying key is computed from the fixed columns alone
+		let verifier_allocator = bumpalo::Bump::new();
+		let (_, verifier_witness) =
+			fixed_table_circuit(&verifier_allocator, verifier_table_offset)?;
+		let verifying_key = constraint_system::preprocess::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			_,
+		>(&constraint_system, 1, 10, &verifier_witness, &backend)?
+		.verifying_key()
+		.clone();
+		assert_eq!(
+			verifying_key.fixed_commitment == proving_key.verifying_key().fixed_commitment,
+			verifier_table_offset == 0
+		);
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let proof =
+			constraint_system::prove_with_key::<
+				U,
+				CanonicalTowerFamily,
+				_,
+				Groestl256,
+				Groestl256ByteCompression,
+				HasherChallenger<Groestl256>,
+				_,
+			>(&constraint_system, 1, 10, &[], witness, &proving_key, &domain_factory, &backend)?;
+
+		constraint_system::verify_with_key::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&constraint_system, 1, 10, &[], &verifying_key, proof)?;
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_fixed_columns_with_preprocessed_commitment() {
+		prove_verify_with_fixed_table(0).unwrap();
+	}
+
+	#[test]
+	fn test_fixed_columns_reject_wrong_verifying_key() {
+		prove_verify_with_fixed_table(1).expect_err("fixed table differs from the verifying key");
+	}
+
+	#[test]
+	fn test_fixed_columns_without_preprocessing() {
+		let allocator = bumpalo::Bump::new();
+		let (constraint_system, witness) = fixed_table_circuit(&allocator, 0).unwrap();
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+		let proof = constraint_system::prove::<
+			U,
+			CanonicalTowerFamily,
+			_,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(&constraint_system, 1, 10, &[], witness, &domain_factory, &backend)
+		.unwrap();
+
+		constraint_system::verify::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&constraint_system, 1, 10, &[], proof)
+		.unwrap();
+	}
+
+	#[test]
+	fn test_prove_verify_with_vision_hash() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+
+		let mut collatz = Collatz::new(27);
+		let advice = collatz.init_prover();
+		let boundaries = collatz.build(&mut builder, advice).unwrap();
+
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+		let constraint_system = builder.build().unwrap();
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+
+		let proof = constraint_system::prove::<
+			U,
+			CanonicalTowerFamily,
+			_,
+			Vision32bByteHasher,
+			Vision32bByteCompression,
+			Vision32bChallenger,
+			_,
+		>(&constraint_system, 1, 10, &boundaries, witness, &domain_factory, &backend)
+		.unwrap();
+
+		constraint_system::verify::<
+			U,
+			CanonicalTowerFamily,
+			Vision32bByteHasher,
+			Vision32bByteCompression,
+			Vision32bChallenger,
+		>(&constraint_system, 1, 10, &boundaries, proof)
+		.unwrap();
+	}
+
+	#[test]
+	fn test_prove_verify_table_sum() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+
+		// The flushes of the Collatz circuit are batched together with the table sum constraint
+		let mut collatz = Collatz::new(27);
+		let advice = collatz.init_prover();
+		let boundaries = collatz.build(&mut builder, advice).unwrap();
+
+		let log_size = 8;
+		let a = unconstrained::<_, _, BinaryField32b>(&mut builder, "a", log_size).unwrap();
+		let b = unconstrained::<_, _, BinaryField32b>(&mut builder, "b", log_size).unwrap();
+
+		let witness = builder.witness().unwrap();
+		let a_values = witness.get::<BinaryField32b>(a).unwrap().as_slice::<u32>();
+		let b_values = witness.get::<BinaryField32b>(b).unwrap().as_slice::<u32>();
+		let inner_product = a_values
+			.iter()
+			.zip(b_values)
+			.map(|(&a, &b)| F::from(BinaryField32b::new(a)) * F::from(BinaryField32b::new(b)))
+			.sum::<F>();
+
+		builder.assert_sum(
+			"inner_product",
+			[a, b],
+			ArithExpr::Var(0) * ArithExpr::Var(1),
+			inner_product,
+		);
+
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &boundaries, &witness).unwrap();
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+
+		let proof = constraint_system::prove::<
+			U,
+			CanonicalTowerFamily,
+			_,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(&constraint_system, 1, 10, &boundaries, witness, &domain_factory, &backend)
+		.unwrap();
+
+		constraint_system::verify::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&constraint_system, 1, 10, &boundaries, proof)
+		.unwrap();
+	}
+
+	#[test]
+	fn test_table_sum_rejects_wrong_sum() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+
+		let log_size = 6;
+		let a = unconstrained::<_, _, BinaryField32b>(&mut builder, "a", log_size).unwrap();
+
+		let witness = builder.witness().unwrap();
+		let total = witness
+			.get::<BinaryField32b>(a)
+			.unwrap()
+			.as_slice::<u32>()
+			.iter()
+			.map(|&a| F::from(BinaryField32b::new(a)))
+			.sum::<F>();
+
+		builder.assert_sum("total", [a], ArithExpr::Var(0), total + F::ONE);
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		let boundaries = vec![];
+		validate_witness(&constraint_system, &boundaries, &witness).unwrap_err();
+	}
+
+	#[test]
+	fn test_prove_verify_with_degree_reduction() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		builder.set_max_composition_degree(2);
+
+		let log_size = 7;
+		let a = unconstrained::<_, _, BinaryField8b>(&mut builder, "a", log_size).unwrap();
+		let b = unconstrained::<_, _, BinaryField32b>(&mut builder, "b", log_size).unwrap();
+
+		let witness = builder.witness().unwrap();
+		let a_values = witness.get::<BinaryField8b>(a).unwrap().as_slice::<u8>();
+		let b_values = witness.get::<BinaryField32b>(b).unwrap().as_slice::<u32>();
+		let sum = a_values
+			.iter()
+			.zip(b_values)
+			.map(|(&a, &b)| {
+				let a = F::from(BinaryField8b::new(a));
+				let b = F::from(BinaryField32b::new(b));
+				a.pow([3]) * b.pow([2]) + a * b
+			})
+			.sum::<F>();
+
+		builder.assert_sum(
+			"high_degree",
+			[a, b],
+			ArithExpr::Var(0).pow(3) * ArithExpr::Var(1).pow(2)
+				+ ArithExpr::Var(0) * ArithExpr::Var(1),
+			sum,
+		);
+
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+		let constraint_system = builder.build().unwrap();
+		assert!(constraint_system.oracles.size() > 2);
+		for constraint in constraint_system
+			.table_constraints
+			.iter()
+			.flat_map(|constraint_set| &constraint_set.constraints)
+		{
+			assert!(constraint.composition.degree() <= 2);
+		}
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+
+		let proof = constraint_system::prove::<
+			U,
+			CanonicalTowerFamily,
+			_,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(&constraint_system, 1, 10, &[], witness, &domain_factory, &backend)
+		.unwrap();
+
+		constraint_system::verify::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&constraint_system, 1, 10, &[], proof)
+		.unwrap();
+	}
+
+	#[test]
+	fn test_degree_reduction_failure_fails_build() {
+		let mut builder = ConstraintSystemBuilder::<U, F>::new();
+		builder.set_max_composition_degree(2);
+
+		// Columns of different heights can't share an intermediate column
+		let a = builder.add_committed("a", 7, BinaryField8b::TOWER_LEVEL);
+		let b = builder.add_committed("b", 8, BinaryField8b::TOWER_LEVEL);
+		builder.assert_zero("cubic", [a, b], ArithExpr::Var(0).pow(3) + ArithExpr::Var(1));
+
+		builder
+			.build()
+			.expect_err("constraint exceeds the maximum degree");
+	}
+
+	#[test]
+	fn test_prove_verify_with_compiled_composition() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+
+		let log_size = 7;
+		let a = unconstrained::<_, _, BinaryField8b>(&mut builder, "a", log_size).unwrap();
+		let b = builder.add_committed("b", log_size, BinaryField8b::TOWER_LEVEL);
+		if let Some(witness) = builder.witness() {
+			let a_values = witness.get::<BinaryField8b>(a).unwrap().as_slice::<u8>();
+			let mut b_column = witness.new_column::<BinaryField8b>(b);
+			for (b, &a) in b_column.as_mut_slice::<u8>().iter_mut().zip(a_values) {
+				let a = BinaryField8b::new(a);
+				*b = (a * a * a * BinaryField8b::new(0x35) + BinaryField8b::ONE).val();
+			}
+		}
+
+		// Wrap the compiled kernels to count how often the prover evaluates them
+		let kernel = Arc::new(arith_circuit_poly!([a, b] = a.pow(3) * 0x35 + 1 - b, BinaryField8b));
+		let kernel_evaluations = Arc::new(AtomicUsize::new(0));
+		let mut composition =
+			CachedPoly::new(arith_circuit_poly!([a, b] = a.pow(3) * 0x35 + 1 - b, BinaryField8b));
+		composition.register::<PackedType<U, BinaryField8b>>(CountingKernel {
+			inner: kernel.clone(),
+			evaluations: kernel_evaluations.clone(),
+		});
+		composition.register::<PackedType<U, BinaryField128b>>(CountingKernel {
+			inner: kernel,
+			evaluations: kernel_evaluations.clone(),
+		});
+		builder.assert_zero_compiled("cubic", [a, b], &composition);
+
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+		let constraint_system = builder.build().unwrap();
+		assert!(constraint_system
+			.table_constraints
+			.iter()
+			.flat_map(|constraint_set| &constraint_set.constraints)
+			.all(|constraint| !constraint.kernels.is_empty()));
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+
+		let proof = constraint_system::prove::<
+			U,
+			CanonicalTowerFamily,
+			_,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(&constraint_system, 1, 10, &[], witness, &domain_factory, &backend)
+		.unwrap();
+		assert!(kernel_evaluations.load(Ordering::Relaxed) > 0);
+
+		constraint_system::verify::<
+			U,
+			CanonicalTowerFamily,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&constraint_system, 1, 10, &[], proof)
+		.unwrap();
+	}
+
+	/// A composition kernel that counts its evaluations.
+	#[derive(Debug)]
+	struct CountingKernel<C> {
+		inner: Arc<C>,
+		evaluations: Arc<AtomicUsize>,
+	}
+
+	impl<P, C> CompositionPolyOS<P> for CountingKernel<C>
+	where
+		P: binius_field::PackedField,
+		C: CompositionPolyOS<P>,
+	{
+		fn n_vars(&self) -> usize {
+			self.inner.n_vars()
+		}
+
+		fn degree(&self) -> usize {
+			self.inner.degree()
+		}
+
+		fn binary_tower_level(&self) -> usize {
+			self.inner.binary_tower_level()
+		}
+
+		fn expression(&self) -> ArithExpr<P::Scalar> {
+			self.inner.expression()
+		}
+
+		fn evaluate(&self, query: &[P]) -> Result<P, binius_math::Error> {
+			self.evaluations.fetch_add(1, Ordering::Relaxed);
+			self.inner.evaluate(query)
+		}
+
+		fn batch_evaluate(
+			&self,
+			batch_query: &[&[P]],
+			evals: &mut [P],
+		) -> Result<(), binius_math::Error> {
+			self.evaluations.fetch_add(1, Ordering::Relaxed);
+			self.inner.batch_evaluate(batch_query, evals)
+		}
+	}
+
+	#[test]
+	fn test_analyze_estimates_proof_size() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_size = 10;
+
+		let add_a = unconstrained::<_, _, BinaryField1b>(&mut builder, "add_a", log_size).unwrap();
+		let add_b = unconstrained::<_, _, BinaryField1b>(&mut builder, "add_b", log_size).unwrap();
+		let _sum = lasso::u32add::<_, _, BinaryField1b, BinaryField1b>(
+			&mut builder,
+			"lasso_u32add",
+			add_a,
+			add_b,
+		)
+		.unwrap();
+		let _sum = arithmetic::u32::add(
+			&mut builder,
+			"u32add",
+			add_a,
+			add_b,
+			arithmetic::Flags::Unchecked,
+		)
+		.unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+
+		let analysis = constraint_system
+			.analyze::<CanonicalTowerFamily, Groestl256, Groestl256ByteCompression>(1, 10)
+			.unwrap();
+		assert_eq!(
+			analysis
+				.committed_columns
+				.iter()
+				.map(|columns| columns.count)
+				.sum::<usize>(),
+			constraint_system
+				.oracles
+				.iter()
+				.filter(|oracle| matches!(oracle.variant, MultilinearPolyVariant::Committed))
+				.count()
+		);
+		assert_eq!(
+			analysis
+				.channel_flushes
+				.iter()
+				.map(|channel| channel.n_push + channel.n_pull)
+				.sum::<usize>(),
+			constraint_system.flushes.len()
+		);
+		assert!(analysis
+			.channel_flushes
+			.iter()
+			.all(|channel| channel.n_push > 0 && channel.n_pull > 0));
+		assert!(analysis
+			.constraint_sets
+			.iter()
+			.any(|constraint_set| constraint_set.n_zero_constraints > 0
+				&& constraint_set.max_degree == 2));
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+		let proof = constraint_system::prove::<
+			U,
+			CanonicalTowerFamily,
+			_,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(&constraint_system, 1, 10, &[], witness, &domain_factory, &backend)
+		.unwrap();
+
+		// The estimate is within 10% of the actual proof size, and is reported by the analysis
+		let estimate = analysis.proof_size.total();
+		let actual = proof.get_proof_size();
+		assert!(estimate.abs_diff(actual) * 10 < actual, "estimate {estimate}, actual {actual}");
+		assert!(analysis
+			.to_string()
+			.contains(&format!("estimated proof size: {estimate} bytes")));
+	}
+
 	#[test]
 	fn test_plain_u8_mul_lookup() {
 		const MAX_LOG_MULTIPLICITY: usize = 18;
@@ -0,0 +1,74 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+//! Gadgets for virtual columns that are derived from other columns without being committed.
+
+use anyhow::Result;
+use binius_core::oracle::OracleId;
+use binius_field::{
+	as_packed_field::PackScalar,
+	packed::{get_packed_slice, set_packed_slice},
+	underlier::UnderlierType,
+	ExtensionField, TowerField,
+};
+use binius_maybe_rayon::prelude::*;
+
+use crate::builder::ConstraintSystemBuilder;
+
+/// Interleaves the rows of two columns of the same height into a column with twice as many rows,
+/// taking the rows of `even` at even indices and the rows of `odd` at odd ones.
+pub fn interleave<U, F, FS>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	even: OracleId,
+	odd: OracleId,
+) -> Result<OracleId>
+where
+	U: UnderlierType + PackScalar<F> + PackScalar<FS>,
+	F: TowerField + ExtensionField<FS>,
+	FS: TowerField,
+{
+	let log_rows = builder.log_rows([even, odd])?;
+	let interleaved = builder.add_interleaved(name, even, odd)?;
+
+	if let Some(witness) = builder.witness() {
+		let even_witness = witness.get::<FS>(even)?;
+		let odd_witness = witness.get::<FS>(odd)?;
+		let (even_values, odd_values) = (even_witness.packed(), odd_witness.packed());
+
+		let mut interleaved_witness = witness.new_column::<FS>(interleaved);
+		let interleaved_values = interleaved_witness.packed();
+		for i in 0..1 << log_rows {
+			set_packed_slice(interleaved_values, 2 * i, get_packed_slice(even_values, i));
+			set_packed_slice(interleaved_values, 2 * i + 1, get_packed_slice(odd_values, i));
+		}
+	}
+
+	Ok(interleaved)
+}
+
+/// Multiplies two columns of the same height row by row.
+pub fn elementwise_product<U, F, FS>(
+	builder: &mut ConstraintSystemBuilder<U, F>,
+	name: impl ToString,
+	lhs: OracleId,
+	rhs: OracleId,
+) -> Result<OracleId>
+where
+	U: UnderlierType + PackScalar<F> + PackScalar<FS>,
+	F: TowerField + ExtensionField<FS>,
+	FS: TowerField,
+{
+	let product = builder.add_elementwise_product(name, lhs, rhs)?;
+
+	if let Some(witness) = builder.witness() {
+		let lhs_witness = witness.get::<FS>(lhs)?;
+		let rhs_witness = witness.get::<FS>(rhs)?;
+
+		let mut product_witness = witness.new_column::<FS>(product);
+		(product_witness.packed(), lhs_witness.packed(), rhs_witness.packed())
+			.into_par_iter()
+			.for_each(|(product, &lhs, &rhs)| *product = lhs * rhs);
+	}
+
+	Ok(product)
+}
@@ -0,0 +1,50 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_core::reed_solomon::reed_solomon::ReedSolomonCode;
+use binius_field::{arch::ArchOptimal, BinaryField128b, BinaryField32b, PackedField};
+use binius_hal::make_portable_backend;
+use binius_ntt::{NTTOptions, ThreadingSettings};
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use rand::thread_rng;
+
+type P = <BinaryField32b as ArchOptimal>::OptimalThroughputPacked;
+type PE = <BinaryField128b as ArchOptimal>::OptimalThroughputPacked;
+
+fn bench_encode_ext_batch_inplace(c: &mut Criterion) {
+	let mut group = c.benchmark_group("reed_solomon_encode_ext");
+	group.sample_size(10);
+
+	let backend = make_portable_backend();
+	let log_inv_rate = 1;
+	for log_len in [20, 24, 26] {
+		let log_dim = log_len - log_inv_rate;
+		let mut rng = thread_rng();
+		let mut code = std::iter::repeat_with(|| PE::random(&mut rng))
+			.take(1 << (log_len - PE::LOG_WIDTH))
+			.collect::<Vec<_>>();
+		group.throughput(Throughput::Bytes((code.len() * size_of::<PE>()) as u64));
+
+		// Codes of length at least 2^24 are encoded with the cache-blocked NTT kernel.
+		let rs_code = ReedSolomonCode::<P>::new(
+			log_dim,
+			log_inv_rate,
+			NTTOptions {
+				precompute_twiddles: true,
+				thread_settings: ThreadingSettings::SingleThreaded,
+			},
+		)
+		.unwrap();
+
+		group.bench_function(format!("single-thread/log_len={log_len}"), |bench| {
+			bench.iter(|| {
+				rs_code
+					.encode_ext_batch_inplace(&backend, &mut code, 0)
+					.unwrap()
+			});
+		});
+	}
+	group.finish()
+}
+
+criterion_group!(reed_solomon, bench_encode_ext_batch_inplace);
+criterion_main!(reed_solomon);
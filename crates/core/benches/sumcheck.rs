@@ -0,0 +1,230 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use std::{iter::repeat_with, sync::Arc};
+
+use binius_core::{
+	fiat_shamir::HasherChallenger,
+	polynomial::ArithCircuitPoly,
+	protocols::sumcheck::{
+		prove::{
+			batch_prove, small_field_rounds_crossover, RegularSumcheckProver, RoundEvaluation,
+			UnivariateZerocheck,
+		},
+		CompositeSumClaim,
+	},
+	transcript::ProverTranscript,
+};
+use binius_field::{
+	BinaryField128b, BinaryField8b, ExtensionField, Field, PackedBinaryField128x1b,
+	PackedBinaryField1x128b, PackedBinaryField2x128b, PackedField,
+};
+use binius_hal::make_portable_backend;
+use binius_math::{
+	ArithExpr, IsomorphicEvaluationDomainFactory, MLEDirectAdapter, MultilinearExtension,
+	MultilinearPoly,
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use groestl_crypto::Groestl256;
+use rand::{rngs::StdRng, SeedableRng};
+
+type P = PackedBinaryField2x128b;
+
+const N_VARS: usize = 14;
+const DEGREES: [usize; 4] = [2, 3, 4, 7];
+
+fn bench_product_sumcheck(c: &mut Criterion) {
+	let mut group = c.benchmark_group("sumcheck_product");
+	let mut rng = StdRng::seed_from_u64(0);
+	let backend = make_portable_backend();
+	let domain_factory = IsomorphicEvaluationDomainFactory::<BinaryField8b>::default();
+
+	for degree in DEGREES {
+		let multilins = repeat_with(|| {
+			let values = repeat_with(|| P::random(&mut rng))
+				.take(1 << (N_VARS - P::LOG_WIDTH))
+				.collect::<Vec<_>>();
+			MultilinearExtension::new(N_VARS, values).unwrap()
+		})
+		.take(degree)
+		.collect::<Vec<_>>();
+		let composition =
+			ArithCircuitPoly::<BinaryField128b>::new((0..degree).map(ArithExpr::Var).product());
+
+		group.throughput(Throughput::Elements((degree << N_VARS) as u64));
+		for round_evaluation in [
+			RoundEvaluation::Composition,
+			RoundEvaluation::ProductOfLinears,
+		] {
+			group.bench_with_input(
+				BenchmarkId::new(format!("{round_evaluation:?}"), format!("degree={degree}")),
+				&round_evaluation,
+				|bench, &round_evaluation| {
+					bench.iter(|| {
+						let multilins = multilins
+							.iter()
+							.map(|multilin| MLEDirectAdapter::from(multilin.to_ref()))
+							.collect::<Vec<_>>();
+						// The claimed sum doesn't affect the work of the prover.
+						let prover = RegularSumcheckProver::<BinaryField8b, _, _, _, _>::new(
+							multilins,
+							[CompositeSumClaim {
+								composition: &composition,
+								sum: BinaryField128b::ZERO,
+							}],
+							domain_factory.clone(),
+							|_| 1,
+							&backend,
+						)
+						.unwrap()
+						.with_round_evaluation(round_evaluation);
+
+						let mut transcript =
+							ProverTranscript::<HasherChallenger<Groestl256>>::new();
+						batch_prove(vec![prover], &mut transcript).unwrap()
+					});
+				},
+			);
+		}
+	}
+	group.finish()
+}
+
+type B1 = PackedBinaryField128x1b;
+
+const SMALL_FIELD_N_VARS: usize = 16;
+
+/// A constraint over 1-bit columns, given by the function computing the last column from the
+/// others and the zero constraint relating them.
+struct BitConstraint {
+	name: &'static str,
+	n_inputs: usize,
+	output: fn(&[B1]) -> B1,
+	constraint: fn() -> ArithExpr<BinaryField8b>,
+}
+
+/// The shapes of the constraints in the `bitwise` and `sha256` circuits.
+const BIT_CONSTRAINTS: [BitConstraint; 4] = [
+	BitConstraint {
+		name: "bitwise_and",
+		n_inputs: 2,
+		output: |v| v[0] * v[1],
+		constraint: || ArithExpr::Var(0) * ArithExpr::Var(1) - ArithExpr::Var(2),
+	},
+	BitConstraint {
+		name: "bitwise_or",
+		n_inputs: 2,
+		output: |v| v[0] + v[1] + v[0] * v[1],
+		constraint: || {
+			ArithExpr::Var(0) + ArithExpr::Var(1) + ArithExpr::Var(0) * ArithExpr::Var(1)
+				- ArithExpr::Var(2)
+		},
+	},
+	BitConstraint {
+		name: "sha256_ch",
+		n_inputs: 3,
+		output: |v| v[2] + v[0] * (v[1] + v[2]),
+		constraint: || {
+			ArithExpr::Var(2) + ArithExpr::Var(0) * (ArithExpr::Var(1) + ArithExpr::Var(2))
+				- ArithExpr::Var(3)
+		},
+	},
+	BitConstraint {
+		name: "sha256_maj",
+		n_inputs: 3,
+		output: |v| v[0] * (v[1] + v[2]) + v[1] * v[2],
+		constraint: || {
+			ArithExpr::Var(3) - ArithExpr::Var(0) * (ArithExpr::Var(1) + ArithExpr::Var(2))
+				+ ArithExpr::Var(1) * ArithExpr::Var(2)
+		},
+	},
+];
+
+fn bench_small_field_zerocheck(c: &mut Criterion) {
+	type P = PackedBinaryField1x128b;
+
+	let mut group = c.benchmark_group("zerocheck_small_field");
+	let mut rng = StdRng::seed_from_u64(0);
+	let backend = make_portable_backend();
+	let domain_factory = IsomorphicEvaluationDomainFactory::<BinaryField8b>::default();
+	let log_embedding_degree = <BinaryField128b as ExtensionField<BinaryField8b>>::LOG_DEGREE;
+
+	for bit_constraint in BIT_CONSTRAINTS {
+		let mut columns = repeat_with(|| {
+			repeat_with(|| B1::random(&mut rng))
+				.take(1 << (SMALL_FIELD_N_VARS - B1::LOG_WIDTH))
+				.collect::<Vec<_>>()
+		})
+		.take(bit_constraint.n_inputs)
+		.collect::<Vec<_>>();
+		let output = (0..columns[0].len())
+			.map(|i| {
+				let inputs = columns.iter().map(|column| column[i]).collect::<Vec<_>>();
+				(bit_constraint.output)(&inputs)
+			})
+			.collect();
+		columns.push(output);
+
+		let multilins = columns
+			.into_iter()
+			.map(|column| {
+				MultilinearExtension::new(SMALL_FIELD_N_VARS, column)
+					.unwrap()
+					.specialize_arc_dyn::<P>()
+			})
+			.collect::<Vec<Arc<dyn MultilinearPoly<P> + Send + Sync>>>();
+		let constraint = (bit_constraint.constraint)();
+		let composition = ArithCircuitPoly::<BinaryField128b>::new(constraint.convert_field());
+		let composition_base = ArithCircuitPoly::<BinaryField8b>::new(constraint.clone());
+		let challenges = repeat_with(|| <BinaryField128b as Field>::random(&mut rng))
+			.take(SMALL_FIELD_N_VARS)
+			.collect::<Vec<_>>();
+		let crossover = small_field_rounds_crossover(constraint.degree(), log_embedding_degree);
+
+		group.throughput(Throughput::Elements((multilins.len() << SMALL_FIELD_N_VARS) as u64));
+		for n_small_field_rounds in 0..=crossover {
+			group.bench_with_input(
+				BenchmarkId::new(
+					bit_constraint.name,
+					format!("small_field_rounds={n_small_field_rounds}"),
+				),
+				&n_small_field_rounds,
+				|bench, &n_small_field_rounds| {
+					bench.iter(|| {
+						let mut transcript =
+							ProverTranscript::<HasherChallenger<Groestl256>>::new();
+						let prover = UnivariateZerocheck::<
+							BinaryField8b,
+							BinaryField8b,
+							P,
+							_,
+							_,
+							_,
+							_,
+						>::new(
+							multilins.clone(),
+							[(
+								bit_constraint.name.into(),
+								composition_base.clone(),
+								composition.clone(),
+							)],
+							&challenges,
+							domain_factory.clone(),
+							|_| 1,
+							&backend,
+						)
+						.unwrap()
+						.into_regular_zerocheck()
+						.unwrap()
+						.with_small_field_rounds(n_small_field_rounds)
+						.unwrap();
+						batch_prove(vec![prover], &mut transcript).unwrap()
+					});
+				},
+			);
+		}
+	}
+	group.finish()
+}
+
+criterion_group!(sumcheck, bench_product_sumcheck, bench_small_field_zerocheck);
+criterion_main!(sumcheck);
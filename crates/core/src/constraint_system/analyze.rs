@@ -0,0 +1,490 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use std::{collections::BTreeMap, fmt};
+
+use binius_field::{BinaryField, TowerField};
+use binius_hash::PseudoCompressionFunction;
+use binius_utils::checked_arithmetics::log2_ceil_usize;
+use digest::{core_api::BlockSizeUser, Digest, Output};
+use itertools::chain;
+
+use super::{
+	channel::FlushDirection,
+	common::{FDomain, FEncode, FExt},
+	error::Error,
+	prove::arith_expr_base_tower_level,
+	ConstraintSystem,
+};
+use crate::{
+	merkle_tree::BinaryMerkleTreeScheme,
+	oracle::{ConstraintPredicate, MultilinearPolyVariant, OracleId},
+	piop,
+	protocols::fri::vcs_optimal_layers_depths_iter,
+	tower::TowerFamily,
+};
+
+/// Degree of the composition in the layer sumchecks of the grand product argument.
+const GPA_SUMCHECK_DEGREE: usize = 3;
+
+/// Degree of the composition in the flush sumchecks.
+const FLUSH_SUMCHECK_DEGREE: usize = 2;
+
+/// Degree of the composition in the PIOP sumcheck over the committed polynomials.
+const PIOP_SUMCHECK_DEGREE: usize = 2;
+
+/// Report on the cost of proving a [`ConstraintSystem`], see [`ConstraintSystem::analyze`].
+#[derive(Debug, Clone)]
+pub struct ConstraintSystemAnalysis {
+	pub constraint_sets: Vec<ConstraintSetAnalysis>,
+	/// Committed columns grouped by number of variables and tower level.
+	pub committed_columns: Vec<CommittedColumns>,
+	/// The total number of variables of the interpolating multilinear of all committed columns.
+	pub commit_total_vars: usize,
+	/// Flush counts, one entry for every channel with flushes.
+	pub channel_flushes: Vec<ChannelFlushes>,
+	/// Evalcheck fan-out of the virtual oracles, one entry for every virtual oracle.
+	pub virtual_oracles: Vec<VirtualOracleFanOut>,
+	pub proof_size: ProofSizeEstimate,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConstraintSetAnalysis {
+	pub n_vars: usize,
+	pub n_oracles: usize,
+	pub n_zero_constraints: usize,
+	pub n_sum_constraints: usize,
+	pub max_degree: usize,
+	/// The tower level of the compositions' constants, as used by the zerocheck prover.
+	pub composition_tower_level: usize,
+	/// The tower level that the zerocheck prover evaluates the compositions over, which also
+	/// accounts for the tower levels of the oracles.
+	pub base_tower_level: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommittedColumns {
+	pub n_vars: usize,
+	pub tower_level: usize,
+	pub count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelFlushes {
+	pub channel_id: usize,
+	pub n_push: usize,
+	pub n_pull: usize,
+	/// The total number of flushed rows, summed over all the flushes of the channel.
+	pub n_flushed_rows: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct VirtualOracleFanOut {
+	pub id: OracleId,
+	pub label: String,
+	/// The number of evaluation claims on other oracles that an evaluation claim on this oracle
+	/// is reduced to.
+	pub fan_out: usize,
+}
+
+/// Estimated byte sizes of the proof components.
+///
+/// The estimate follows the structure of the proof, but it assumes for simplicity that every
+/// virtual oracle is evaluated at a single point, so it is not exact.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProofSizeEstimate {
+	pub commitment: usize,
+	pub grand_products: usize,
+	pub sumchecks: usize,
+	pub evalcheck: usize,
+	pub ring_switch: usize,
+	pub fri: usize,
+}
+
+impl ProofSizeEstimate {
+	pub const fn total(&self) -> usize {
+		self.commitment
+			+ self.grand_products
+			+ self.sumchecks
+			+ self.evalcheck
+			+ self.ring_switch
+			+ self.fri
+	}
+}
+
+impl<F: TowerField> ConstraintSystem<F> {
+	/// Analyzes the cost of proving the constraint system without a witness.
+	///
+	/// The proof size is estimated for the FRI-based PCS with the given parameters, as used by
+	/// [`super::prove`] and [`super::verify`].
+	pub fn analyze<Tower, Hash, Compress>(
+		&self,
+		log_inv_rate: usize,
+		security_bits: usize,
+	) -> Result<ConstraintSystemAnalysis, Error>
+	where
+		Tower: TowerFamily<B128 = F>,
+		Hash: Digest + BlockSizeUser,
+		Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	{
+		analyze::<Tower, Hash, Compress>(self, log_inv_rate, security_bits)
+	}
+}
+
+fn analyze<Tower, Hash, Compress>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+) -> Result<ConstraintSystemAnalysis, Error>
+where
+	Tower: TowerFamily,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+{
+	let constraint_sets = constraint_system
+		.table_constraints
+		.iter()
+		.map(|constraint_set| {
+			let n_zero_constraints = constraint_set
+				.constraints
+				.iter()
+				.filter(|constraint| matches!(constraint.predicate, ConstraintPredicate::Zero))
+				.count();
+			let composition_tower_level = constraint_set
+				.constraints
+				.iter()
+				.map(|constraint| arith_expr_base_tower_level::<Tower>(&constraint.composition))
+				.max()
+				.unwrap_or(0);
+			let oracles_tower_level = constraint_set
+				.oracle_ids
+				.iter()
+				.map(|&id| constraint_system.oracles.tower_level(id))
+				.max()
+				.unwrap_or(0);
+			ConstraintSetAnalysis {
+				n_vars: constraint_set.n_vars,
+				n_oracles: constraint_set.oracle_ids.len(),
+				n_zero_constraints,
+				n_sum_constraints: constraint_set.constraints.len() - n_zero_constraints,
+				max_degree: constraint_set
+					.constraints
+					.iter()
+					.map(|constraint| constraint.composition.degree())
+					.max()
+					.unwrap_or(0),
+				composition_tower_level,
+				base_tower_level: composition_tower_level.max(oracles_tower_level),
+			}
+		})
+		.collect::<Vec<_>>();
+
+	let mut committed_counts = BTreeMap::<(usize, usize), usize>::new();
+	for oracle in constraint_system.oracles.iter() {
+		if matches!(oracle.variant, MultilinearPolyVariant::Committed) {
+			*committed_counts
+				.entry((oracle.n_vars(), oracle.binary_tower_level()))
+				.or_default() += 1;
+		}
+	}
+	let committed_columns = committed_counts
+		.into_iter()
+		.map(|((n_vars, tower_level), count)| CommittedColumns {
+			n_vars,
+			tower_level,
+			count,
+		})
+		.collect::<Vec<_>>();
+
+	let mut channel_flushes = BTreeMap::<usize, ChannelFlushes>::new();
+	for flush in &constraint_system.flushes {
+		let entry = channel_flushes
+			.entry(flush.channel_id)
+			.or_insert(ChannelFlushes {
+				channel_id: flush.channel_id,
+				n_push: 0,
+				n_pull: 0,
+				n_flushed_rows: 0,
+			});
+		match flush.direction {
+			FlushDirection::Push => entry.n_push += 1,
+			FlushDirection::Pull => entry.n_pull += 1,
+		}
+		if let Some(&id) = flush.oracles.first() {
+			entry.n_flushed_rows += 1 << constraint_system.oracles.n_vars(id);
+		}
+	}
+	let channel_flushes = channel_flushes.into_values().collect::<Vec<_>>();
+
+	let virtual_oracles = constraint_system
+		.oracles
+		.iter()
+		.filter_map(|oracle| {
+			let fan_out = match &oracle.variant {
+				MultilinearPolyVariant::Committed | MultilinearPolyVariant::Transparent(_) => {
+					return None
+				}
+				MultilinearPolyVariant::LinearCombination(linear_combination) => {
+					linear_combination.n_polys()
+				}
+				MultilinearPolyVariant::Repeating { .. }
+				| MultilinearPolyVariant::Projected(_)
+				| MultilinearPolyVariant::Shifted(_)
+				| MultilinearPolyVariant::Packed(_)
+				| MultilinearPolyVariant::ZeroPadded(_) => 1,
+				MultilinearPolyVariant::Interleaved(..)
+				| MultilinearPolyVariant::ElementwiseProduct(..) => 2,
+			};
+			Some(VirtualOracleFanOut {
+				id: oracle.id(),
+				label: oracle.label(),
+				fan_out,
+			})
+		})
+		.collect::<Vec<_>>();
+
+	let proof_size = estimate_proof_size::<Tower, Hash, Compress>(
+		constraint_system,
+		&constraint_sets,
+		&committed_columns,
+		&virtual_oracles,
+		log_inv_rate,
+		security_bits,
+	)?;
+
+	let (commit_meta, _) = piop::make_oracle_commit_meta(&constraint_system.oracles)?;
+
+	Ok(ConstraintSystemAnalysis {
+		constraint_sets,
+		committed_columns,
+		commit_total_vars: commit_meta.total_vars(),
+		channel_flushes,
+		virtual_oracles,
+		proof_size,
+	})
+}
+
+fn estimate_proof_size<Tower, Hash, Compress>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	constraint_sets: &[ConstraintSetAnalysis],
+	committed_columns: &[CommittedColumns],
+	virtual_oracles: &[VirtualOracleFanOut],
+	log_inv_rate: usize,
+	security_bits: usize,
+) -> Result<ProofSizeEstimate, Error>
+where
+	Tower: TowerFamily,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+{
+	let scalar_size = size_of::<FExt<Tower>>();
+	let digest_size = <Hash as Digest>::output_size();
+
+	// Grand products for the flushes and the non-zero checks, proven with a GKR layer sumcheck
+	// for every variable.
+	let gpa_n_vars = chain!(
+		constraint_system
+			.flushes
+			.iter()
+			.filter_map(|flush| flush.oracles.first()),
+		&constraint_system.non_zero_oracle_ids
+	)
+	.map(|&id| constraint_system.oracles.n_vars(id))
+	.collect::<Vec<_>>();
+	let gpa_max_n_vars = gpa_n_vars.iter().copied().max().unwrap_or(0);
+	let gpa_scalars = gpa_n_vars.len()
+		+ (0..gpa_max_n_vars)
+			.map(|layer| {
+				let n_claims = gpa_n_vars.iter().filter(|&&n_vars| n_vars > layer).count();
+				layer * GPA_SUMCHECK_DEGREE + 2 * n_claims
+			})
+			.sum::<usize>();
+
+	// Flush sumchecks batched with the table sum constraints.
+	let (sum_sets, zero_sets): (Vec<_>, Vec<_>) = constraint_sets
+		.iter()
+		.partition(|constraint_set| constraint_set.n_zero_constraints == 0);
+	let flush_sumcheck_scalars = {
+		let n_rounds = chain!(
+			constraint_system
+				.flushes
+				.iter()
+				.filter_map(|flush| flush.oracles.first())
+				.map(|&id| constraint_system.oracles.n_vars(id)),
+			sum_sets.iter().map(|constraint_set| constraint_set.n_vars)
+		)
+		.max()
+		.unwrap_or(0);
+		let degree = sum_sets
+			.iter()
+			.map(|constraint_set| constraint_set.max_degree)
+			.fold(FLUSH_SUMCHECK_DEGREE, usize::max);
+		// Every flush is evaluated together with its selector
+		let n_evals = 2 * constraint_system.flushes.len()
+			+ sum_sets
+				.iter()
+				.map(|constraint_set| constraint_set.n_oracles)
+				.sum::<usize>();
+		n_rounds * degree + n_evals
+	};
+
+	// Zerochecks, with the univariate skip over the first rounds and the univariatizing
+	// reduction afterwards.
+	let zerocheck_scalars = {
+		let max_n_vars = zero_sets
+			.iter()
+			.map(|constraint_set| constraint_set.n_vars)
+			.max()
+			.unwrap_or(0);
+		let max_degree = zero_sets
+			.iter()
+			.map(|constraint_set| constraint_set.max_degree)
+			.max()
+			.unwrap_or(0);
+		let skip_rounds = zero_sets
+			.iter()
+			.map(|constraint_set| {
+				max_n_vars - constraint_set.n_vars
+					+ FDomain::<Tower>::N_BITS
+						.saturating_sub(log2_ceil_usize(constraint_set.max_degree))
+			})
+			.min()
+			.unwrap_or(0)
+			.min(max_n_vars);
+		let n_evals = zero_sets
+			.iter()
+			.map(|constraint_set| constraint_set.n_oracles)
+			.sum::<usize>();
+		(max_degree << skip_rounds)
+			+ (max_n_vars - skip_rounds) * (max_degree + 1)
+			+ n_evals + skip_rounds * 2
+			+ n_evals
+	};
+
+	// Evalcheck sends the evaluations of the children of virtual oracles.
+	let evalcheck_scalars = virtual_oracles
+		.iter()
+		.map(|virtual_oracle| virtual_oracle.fan_out)
+		.sum::<usize>();
+
+	// The ring-switching reduction sends a tensor algebra element for every committed column
+	// of a smaller tower level, and the partial sums by number of variables.
+	let ring_switch_scalars = committed_columns
+		.iter()
+		.map(|columns| columns.count << (FExt::<Tower>::TOWER_LEVEL - columns.tower_level))
+		.sum::<usize>()
+		+ committed_columns.len();
+
+	// The sumcheck over the committed columns and FRI.
+	let (commit_meta, _) = piop::make_oracle_commit_meta(&constraint_system.oracles)?;
+	let n_committed = commit_meta.total_multilins();
+	let piop_sumcheck_scalars = committed_columns
+		.iter()
+		.map(|columns| columns.n_vars + columns.tower_level - FExt::<Tower>::TOWER_LEVEL)
+		.max()
+		.unwrap_or(0)
+		* PIOP_SUMCHECK_DEGREE
+		+ 2 * n_committed;
+
+	let fri = if n_committed == 0 {
+		0
+	} else {
+		let merkle_scheme =
+			BinaryMerkleTreeScheme::<FExt<Tower>, Hash, _>::new(Compress::default());
+		let fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
+			&commit_meta,
+			&merkle_scheme,
+			security_bits,
+			log_inv_rate,
+		)?;
+
+		let terminate_codeword_len =
+			1 << (fri_params.n_final_challenges() + fri_params.rs_code().log_inv_rate());
+		let mut tree_depth = fri_params.log_len();
+		let mut query_size = 0;
+		let mut layers_size = 0;
+		for (&arity, layer_depth) in fri_params
+			.fold_arities()
+			.iter()
+			.zip(vcs_optimal_layers_depths_iter(&fri_params, &merkle_scheme))
+		{
+			tree_depth -= arity;
+			query_size +=
+				(1 << arity) * scalar_size + tree_depth.saturating_sub(layer_depth) * digest_size;
+			layers_size += (1 << layer_depth) * digest_size;
+		}
+
+		fri_params.n_oracles() * digest_size
+			+ terminate_codeword_len * scalar_size
+			+ layers_size
+			+ fri_params.n_test_queries() * query_size
+	};
+
+	Ok(ProofSizeEstimate {
+		commitment: digest_size,
+		grand_products: gpa_scalars * scalar_size,
+		sumchecks: (flush_sumcheck_scalars + zerocheck_scalars + piop_sumcheck_scalars)
+			* scalar_size,
+		evalcheck: evalcheck_scalars * scalar_size,
+		ring_switch: ring_switch_scalars * scalar_size,
+		fri,
+	})
+}
+
+impl fmt::Display for ConstraintSystemAnalysis {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		writeln!(f, "constraint sets:")?;
+		for set in &self.constraint_sets {
+			writeln!(
+				f,
+				"  n_vars={} oracles={} zero={} sum={} max_degree={} tower_level={} (compositions {})",
+				set.n_vars,
+				set.n_oracles,
+				set.n_zero_constraints,
+				set.n_sum_constraints,
+				set.max_degree,
+				set.base_tower_level,
+				set.composition_tower_level,
+			)?;
+		}
+		writeln!(f, "committed columns (total_vars={}):", self.commit_total_vars)?;
+		for columns in &self.committed_columns {
+			writeln!(
+				f,
+				"  n_vars={} tower_level={} count={}",
+				columns.n_vars, columns.tower_level, columns.count
+			)?;
+		}
+		writeln!(f, "channels:")?;
+		for channel in &self.channel_flushes {
+			writeln!(
+				f,
+				"  channel_id={} push={} pull={} rows={}",
+				channel.channel_id, channel.n_push, channel.n_pull, channel.n_flushed_rows
+			)?;
+		}
+		writeln!(
+			f,
+			"virtual oracles: {} with total evalcheck fan-out {}",
+			self.virtual_oracles.len(),
+			self.virtual_oracles
+				.iter()
+				.map(|virtual_oracle| virtual_oracle.fan_out)
+				.sum::<usize>()
+		)?;
+		let ProofSizeEstimate {
+			commitment,
+			grand_products,
+			sumchecks,
+			evalcheck,
+			ring_switch,
+			fri,
+		} = &self.proof_size;
+		write!(
+			f,
+			"estimated proof size: {} bytes (commitment {commitment}, grand products \
+			 {grand_products}, sumchecks {sumchecks}, evalcheck {evalcheck}, ring switch \
+			 {ring_switch}, fri {fri})",
+			self.proof_size.total()
+		)
+	}
+}
@@ -76,6 +76,24 @@ pub struct Boundary<F: TowerField> {
 	pub multiplicity: u64,
 }
 
+impl<F: TowerField> Boundary<F> {
+	/// Maps the boundary values into an isomorphic tower field.
+	pub fn convert_field<FTo: TowerField + From<F>>(self) -> Boundary<FTo> {
+		let Self {
+			values,
+			channel_id,
+			direction,
+			multiplicity,
+		} = self;
+		Boundary {
+			values: values.into_iter().map(FTo::from).collect(),
+			channel_id,
+			direction,
+			multiplicity,
+		}
+	}
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum FlushDirection {
 	Push,
@@ -242,7 +260,7 @@ impl<F: TowerField> Boundary<F> {
 
 #[cfg(test)]
 mod tests {
-	use binius_field::BinaryField64b;
+	use binius_field::{AESTowerField128b, BinaryField128b, BinaryField64b};
 
 	use super::*;
 
@@ -363,4 +381,22 @@ mod tests {
 		assert_eq!(channel.multiplicities.get(&values).unwrap(), &1);
 		assert_eq!(channel.multiplicities.get(&values2).unwrap(), &-1);
 	}
+
+	#[test]
+	fn test_boundary_convert_field_round_trip() {
+		let boundary = Boundary {
+			values: vec![BinaryField128b::new(3), BinaryField128b::new(0xdeadbeef)],
+			channel_id: 1,
+			direction: FlushDirection::Pull,
+			multiplicity: 2,
+		};
+
+		let aes_boundary = boundary.clone().convert_field::<AESTowerField128b>();
+		assert_eq!(aes_boundary.values[1], AESTowerField128b::from(boundary.values[1]));
+
+		let round_trip = aes_boundary.convert_field::<BinaryField128b>();
+		assert_eq!(round_trip.values, boundary.values);
+		assert_eq!(round_trip.channel_id, boundary.channel_id);
+		assert_eq!(round_trip.multiplicity, boundary.multiplicity);
+	}
 }
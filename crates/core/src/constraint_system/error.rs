@@ -49,6 +49,8 @@ pub enum Error {
 	#[error("cannot commit tower level {tower_level}")]
 	CannotCommitTowerLevel { tower_level: usize },
 
+	#[error("the constraint system has no fixed oracles to preprocess")]
+	NoFixedOracles,
 	#[error("{oracle} underlier witness data does not match")]
 	PackedUnderlierMismatch { oracle: String },
 
@@ -1,18 +1,25 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+mod analyze;
 pub mod channel;
 mod common;
 pub mod error;
+mod preprocess;
 mod prove;
 pub mod validate;
 mod verify;
 
+pub use analyze::{
+	ChannelFlushes, CommittedColumns, ConstraintSetAnalysis, ConstraintSystemAnalysis,
+	ProofSizeEstimate, VirtualOracleFanOut,
+};
 use binius_field::TowerField;
 use channel::{ChannelId, Flush};
-pub use prove::prove;
-pub use verify::verify;
+pub use preprocess::{preprocess, ProvingKey, VerifyingKey};
+pub use prove::{prove, prove_with_key};
+pub use verify::{verify, verify_with_key};
 
-use crate::oracle::{ConstraintSet, MultilinearOracleSet, OracleId};
+use crate::oracle::{ConstraintSet, Error as OracleError, MultilinearOracleSet, OracleId};
 
 /// Contains the 3 things that place constraints on witness data in Binius
 /// - virtual oracles
@@ -28,12 +35,45 @@ pub struct ConstraintSystem<F: TowerField> {
 	pub non_zero_oracle_ids: Vec<OracleId>,
 	pub flushes: Vec<Flush>,
 	pub max_channel_id: ChannelId,
+	/// The committed oracles that hold fixed values, such as lookup tables or selector patterns.
+	///
+	/// Fixed oracles are committed once by [`preprocess`] and opened against the resulting key by
+	/// [`prove_with_key`] and [`verify_with_key`]. [`prove`] and [`verify`] commit them in every
+	/// proof like the other committed oracles.
+	pub fixed_oracle_ids: Vec<OracleId>,
 }
 
 impl<F: TowerField> ConstraintSystem<F> {
 	pub const fn no_base_constraints(self) -> Self {
 		self
 	}
+
+	/// Maps the constraint system into an isomorphic tower field.
+	///
+	/// Together with [`MultilinearExtensionIndex::convert_tower`] and [`Boundary::convert_field`],
+	/// this allows proving the same circuit under another tower family. See
+	/// [`MultilinearOracleSet::convert_field`] for the requirements on the isomorphism.
+	///
+	/// [`MultilinearExtensionIndex::convert_tower`]: crate::witness::MultilinearExtensionIndex::convert_tower
+	/// [`Boundary::convert_field`]: channel::Boundary::convert_field
+	pub fn convert_field<FTo>(&self) -> Result<ConstraintSystem<FTo>, OracleError>
+	where
+		F: From<FTo>,
+		FTo: TowerField + From<F>,
+	{
+		Ok(ConstraintSystem {
+			oracles: self.oracles.convert_field()?,
+			table_constraints: self
+				.table_constraints
+				.iter()
+				.map(ConstraintSet::convert_field)
+				.collect(),
+			non_zero_oracle_ids: self.non_zero_oracle_ids.clone(),
+			flushes: self.flushes.clone(),
+			max_channel_id: self.max_channel_id,
+			fixed_oracle_ids: self.fixed_oracle_ids.clone(),
+		})
+	}
 }
 
 /// Constraint system proof that has been serialized into bytes
@@ -0,0 +1,129 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use binius_field::{as_packed_field::PackedType, TowerField};
+use binius_hal::ComputationBackend;
+use binius_hash::PseudoCompressionFunction;
+use binius_utils::{bail, sparse_index::SparseIndex};
+use digest::{core_api::BlockSizeUser, Digest, FixedOutputReset, Output};
+use tracing::instrument;
+
+use super::{
+	common::{FEncode, FExt},
+	error::Error,
+	ConstraintSystem,
+};
+use crate::{
+	merkle_tree::{BinaryMerkleTree, BinaryMerkleTreeProver},
+	oracle::{MultilinearOracleSet, OracleId},
+	piop::{self, CommitMeta},
+	protocols::fri::CommitOutput,
+	tower::{ProverTowerFamily, ProverTowerUnderlier},
+	witness::MultilinearExtensionIndex,
+};
+
+/// The commitment to the fixed oracles of a constraint system, together with the parameters of
+/// the commitment scheme it was computed with.
+#[derive(Debug, Clone)]
+pub struct VerifyingKey<Digest> {
+	pub log_inv_rate: usize,
+	pub security_bits: usize,
+	pub fixed_commitment: Digest,
+}
+
+/// The data the prover needs to open the fixed oracles against a [`VerifyingKey`].
+#[derive(Debug, Clone)]
+pub struct ProvingKey<P, Digest> {
+	pub(super) verifying_key: VerifyingKey<Digest>,
+	pub(super) committed: BinaryMerkleTree<Digest>,
+	pub(super) codeword: Vec<P>,
+}
+
+impl<P, Digest> ProvingKey<P, Digest> {
+	pub const fn verifying_key(&self) -> &VerifyingKey<Digest> {
+		&self.verifying_key
+	}
+}
+
+/// The [`ProvingKey`] for a witness with underlier `U` committed with hash function `Hash`.
+pub(super) type TowerProvingKey<U, Tower, Hash> =
+	ProvingKey<PackedType<U, FExt<Tower>>, Output<Hash>>;
+
+/// Commits the fixed oracles of a constraint system.
+///
+/// Only the witnesses of the fixed oracles are read from `witness`. The returned key is passed to
+/// [`super::prove_with_key`], and its [`VerifyingKey`] to [`super::verify_with_key`], in place of
+/// committing the fixed oracles in every proof.
+///
+/// The evaluation claims on the fixed oracles are not batched into the FRI opening of the
+/// per-proof commitment. Every proof opens them with a second ring-switching reduction and FRI
+/// proof against the fixed commitment, so preprocessing saves the commitment cost of the fixed
+/// oracles but adds an opening to the proof size and verifier cost.
+#[instrument("constraint_system::preprocess", skip_all, level = "debug")]
+pub fn preprocess<U, Tower, Hash, Compress, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	witness: &MultilinearExtensionIndex<U, FExt<Tower>>,
+	backend: &Backend,
+) -> Result<TowerProvingKey<U, Tower, Hash>, Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Hash: Digest + BlockSizeUser + FixedOutputReset,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Backend: ComputationBackend,
+{
+	let ConstraintSystem {
+		oracles,
+		fixed_oracle_ids,
+		..
+	} = constraint_system;
+	if fixed_oracle_ids.is_empty() {
+		bail!(Error::NoFixedOracles);
+	}
+
+	let merkle_prover =
+		BinaryMerkleTreeProver::<_, Hash, _, _>::with_backend(Compress::default(), backend);
+	let (commit_meta, oracle_to_commit_index) = make_fixed_commit_meta(oracles, fixed_oracle_ids)?;
+	let fixed_multilins =
+		piop::collect_committed_witnesses(&commit_meta, &oracle_to_commit_index, oracles, witness)?;
+
+	let fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
+		&commit_meta,
+		merkle_prover.scheme(),
+		security_bits,
+		log_inv_rate,
+	)?;
+	let CommitOutput {
+		commitment,
+		committed,
+		codeword,
+	} = piop::commit(&fri_params, &merkle_prover, &fixed_multilins, backend)?;
+
+	Ok(ProvingKey {
+		verifying_key: VerifyingKey {
+			log_inv_rate,
+			security_bits,
+			fixed_commitment: commitment,
+		},
+		committed,
+		codeword,
+	})
+}
+
+/// Indexes the committed oracles that are committed in every proof, which are all but the fixed
+/// ones.
+pub(super) fn make_trace_commit_meta<F: TowerField>(
+	oracles: &MultilinearOracleSet<F>,
+	fixed_oracle_ids: &[OracleId],
+) -> Result<(CommitMeta, SparseIndex<usize>), Error> {
+	Ok(piop::make_filtered_oracle_commit_meta(oracles, |id| !fixed_oracle_ids.contains(&id))?)
+}
+
+/// Indexes the fixed committed oracles.
+pub(super) fn make_fixed_commit_meta<F: TowerField>(
+	oracles: &MultilinearOracleSet<F>,
+	fixed_oracle_ids: &[OracleId],
+) -> Result<(CommitMeta, SparseIndex<usize>), Error> {
+	Ok(piop::make_filtered_oracle_commit_meta(oracles, |id| fixed_oracle_ids.contains(&id))?)
+}
@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{cmp::Reverse, env, marker::PhantomData, slice::from_mut};
+use std::{cmp::Reverse, env, iter, marker::PhantomData, slice::from_mut};
 
 use binius_field::{
 	as_packed_field::{PackScalar, PackedType},
@@ -25,11 +25,13 @@ use tracing::instrument;
 use super::{
 	channel::Boundary,
 	error::Error,
+	preprocess::{make_fixed_commit_meta, make_trace_commit_meta, TowerProvingKey},
 	verify::{
 		get_post_flush_sumcheck_eval_claims_without_eq, make_flush_oracles,
-		max_n_vars_and_skip_rounds, reorder_for_flushing_by_n_vars,
+		max_n_vars_and_skip_rounds, merge_sorted_by_n_vars, reorder_for_flushing_by_n_vars,
+		split_merged_sumcheck_output,
 	},
-	ConstraintSystem, Proof,
+	ConstraintSystem, Proof, VerifyingKey,
 };
 use crate::{
 	constraint_system::{
@@ -38,7 +40,10 @@ use crate::{
 	},
 	fiat_shamir::{CanSample, Challenger},
 	merkle_tree::BinaryMerkleTreeProver,
-	oracle::{Constraint, MultilinearOracleSet, MultilinearPolyVariant, OracleId},
+	oracle::{
+		split_constraint_sets_by_predicate, Constraint, MultilinearOracleSet,
+		MultilinearPolyVariant, OracleId,
+	},
 	piop,
 	protocols::{
 		fri::CommitOutput,
@@ -49,7 +54,10 @@ use crate::{
 		greedy_evalcheck,
 		sumcheck::{
 			self, constraint_set_zerocheck_claim,
-			prove::{SumcheckProver, UnivariateZerocheckProver},
+			prove::{
+				oracles::{constraint_sets_sumcheck_provers_metas, SumcheckProversWithMetas},
+				SumcheckProver, UnivariateZerocheckProver,
+			},
 			standard_switchover_heuristic, zerocheck,
 		},
 	},
@@ -60,13 +68,124 @@ use crate::{
 };
 
 /// Generates a proof that a witness satisfies a constraint system with the standard FRI PCS.
-#[instrument("constraint_system::prove", skip_all, level = "debug")]
+#[instrument(
+	"constraint_system::prove",
+	skip_all,
+	level = "debug",
+	fields(
+		scratch_allocations = tracing::field::Empty,
+		scratch_reuses = tracing::field::Empty,
+		scratch_releases = tracing::field::Empty,
+	)
+)]
 pub fn prove<U, Tower, DomainFactory, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	boundaries: &[Boundary<FExt<Tower>>],
+	witness: MultilinearExtensionIndex<U, FExt<Tower>>,
+	domain_factory: DomainFactory,
+	backend: &Backend,
+) -> Result<Proof, Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	DomainFactory: EvaluationDomainFactory<FDomain<Tower>>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>
+		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>,
+	PackedType<U, Tower::FastB128>:
+		PackedFieldIndexable + PackedTransformationFactory<PackedType<U, Tower::B128>>,
+	PackedType<U, Tower::B8>: PackedFieldIndexable,
+	PackedType<U, Tower::B16>: PackedFieldIndexable,
+	PackedType<U, Tower::B32>: PackedFieldIndexable,
+	PackedType<U, Tower::B64>: PackedFieldIndexable,
+{
+	prove_impl::<U, Tower, DomainFactory, Hash, Compress, Challenger_, Backend>(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+		boundaries,
+		witness,
+		None,
+		domain_factory,
+		backend,
+	)
+}
+
+/// Generates a proof that a witness satisfies a constraint system whose fixed oracles were
+/// committed by [`super::preprocess`].
+///
+/// The fixed oracles are opened against the commitment in `proving_key` instead of being committed
+/// along with the rest of the witness.
+#[allow(clippy::too_many_arguments)]
+#[instrument("constraint_system::prove", skip_all, level = "debug")]
+pub fn prove_with_key<U, Tower, DomainFactory, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	boundaries: &[Boundary<FExt<Tower>>],
+	witness: MultilinearExtensionIndex<U, FExt<Tower>>,
+	proving_key: &TowerProvingKey<U, Tower, Hash>,
+	domain_factory: DomainFactory,
+	backend: &Backend,
+) -> Result<Proof, Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	DomainFactory: EvaluationDomainFactory<FDomain<Tower>>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>
+		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>,
+	PackedType<U, Tower::FastB128>:
+		PackedFieldIndexable + PackedTransformationFactory<PackedType<U, Tower::B128>>,
+	PackedType<U, Tower::B8>: PackedFieldIndexable,
+	PackedType<U, Tower::B16>: PackedFieldIndexable,
+	PackedType<U, Tower::B32>: PackedFieldIndexable,
+	PackedType<U, Tower::B64>: PackedFieldIndexable,
+{
+	prove_impl::<U, Tower, DomainFactory, Hash, Compress, Challenger_, Backend>(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+		boundaries,
+		witness,
+		Some(proving_key),
+		domain_factory,
+		backend,
+	)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn prove_impl<U, Tower, DomainFactory, Hash, Compress, Challenger_, Backend>(
 	constraint_system: &ConstraintSystem<FExt<Tower>>,
 	log_inv_rate: usize,
 	security_bits: usize,
 	boundaries: &[Boundary<FExt<Tower>>],
 	mut witness: MultilinearExtensionIndex<U, FExt<Tower>>,
+	proving_key: Option<&TowerProvingKey<U, Tower, Hash>>,
 	domain_factory: DomainFactory,
 	backend: &Backend,
 ) -> Result<Proof, Error>
@@ -100,6 +219,7 @@ where
 		rayon_threads = binius_maybe_rayon::current_num_threads(),
 		"using computation backend: {backend:?}"
 	);
+	let scratch_stats_start = backend.scratch_stats();
 
 	let fast_domain_factory = IsomorphicEvaluationDomainFactory::<FFastExt<Tower>>::default();
 
@@ -109,6 +229,9 @@ where
 		for boundary in boundaries {
 			boundary.write_to(&mut observer);
 		}
+		if let Some(proving_key) = proving_key {
+			observer.write(&proving_key.verifying_key.fixed_commitment);
+		}
 	}
 
 	let ConstraintSystem {
@@ -117,16 +240,27 @@ where
 		mut flushes,
 		non_zero_oracle_ids,
 		max_channel_id,
+		fixed_oracle_ids,
 	} = constraint_system.clone();
+	// Without a proving key, the fixed oracles are committed along with the trace
+	let fixed_oracle_ids = if proving_key.is_some() {
+		fixed_oracle_ids
+	} else {
+		vec![]
+	};
 
 	// Stable sort constraint sets in descending order by number of variables.
 	table_constraints.sort_by_key(|constraint_set| Reverse(constraint_set.n_vars));
+	let (table_constraints, table_sum_constraints) =
+		split_constraint_sets_by_predicate(table_constraints);
 
 	// Commit polynomials
-	let merkle_prover = BinaryMerkleTreeProver::<_, Hash, _>::new(Compress::default());
+	let merkle_prover =
+		BinaryMerkleTreeProver::<_, Hash, _, _>::with_backend(Compress::default(), backend);
 	let merkle_scheme = merkle_prover.scheme();
 
-	let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(&oracles)?;
+	let (commit_meta, oracle_to_commit_index) =
+		make_trace_commit_meta(&oracles, &fixed_oracle_ids)?;
 	let committed_multilins = piop::collect_committed_witnesses(
 		&commit_meta,
 		&oracle_to_commit_index,
@@ -144,10 +278,10 @@ where
 		commitment,
 		committed,
 		codeword,
-	} = piop::commit(&fri_params, &merkle_prover, &committed_multilins)?;
+	} = piop::commit(&fri_params, &merkle_prover, &committed_multilins, backend)?;
 
 	// Observe polynomial commitment
-	let mut writer = transcript.message();
+	let mut writer = transcript.message_labeled("constraint_system.commitment");
 	writer.write(&commitment);
 
 	// Grand product arguments
@@ -204,7 +338,9 @@ where
 		.collect::<Result<Vec<_>, _>>()?;
 	let flush_products = gkr_gpa::get_grand_products_from_witnesses(&flush_prodcheck_witnesses);
 
-	transcript.message().write_scalar_slice(&flush_products);
+	transcript
+		.message_labeled("constraint_system.flush_products")
+		.write_scalar_slice(&flush_products);
 
 	let flush_prodcheck_claims =
 		gkr_gpa::construct_grand_product_claims(&flush_oracle_ids, &oracles, &flush_products)?;
@@ -247,20 +383,76 @@ where
 		);
 
 	let FlushSumcheckProvers {
-		provers,
+		provers: flush_provers,
 		flush_selectors_unique_by_claim,
 		flush_oracle_ids_by_claim,
 	} = get_flush_sumcheck_provers::<_, _, FDomain<Tower>, _, _>(
-		&mut oracles,
+		&oracles,
 		&flush_oracle_ids,
 		&flush_selectors,
 		&flush_final_layer_claims,
-		&mut witness,
+		&witness,
+		&domain_factory,
+		backend,
+	)?;
+
+	let switchover_fn = standard_switchover_heuristic(-2);
+
+	// Table sum constraints are batched together with the flush sumchecks
+	let table_sum_small_field_rounds = table_sum_constraints
+		.iter()
+		.map(|constraint_set| {
+			let (constraints, multilinears) =
+				sumcheck::prove::split_constraint_set(constraint_set.clone(), &witness)?;
+			Ok(sumcheck::prove::small_field_rounds_for::<_, FDomain<Tower>, _, _>(
+				&multilinears,
+				constraints
+					.into_iter()
+					.map(|constraint| constraint.composition),
+			))
+		})
+		.collect::<Result<Vec<_>, Error>>()?;
+
+	let SumcheckProversWithMetas {
+		provers: table_sumcheck_provers,
+		metas: table_sumcheck_metas,
+	} = constraint_sets_sumcheck_provers_metas::<_, _, FDomain<Tower>, _>(
+		table_sum_constraints,
+		&witness,
 		&domain_factory,
+		switchover_fn,
 		backend,
 	)?;
+	let table_sumcheck_provers = iter::zip(table_sumcheck_provers, table_sum_small_field_rounds)
+		.map(|(prover, n_small_field_rounds)| prover.with_small_field_rounds(n_small_field_rounds))
+		.collect::<Result<Vec<_>, _>>()?;
+
+	let flush_max_n_vars = flush_provers.first().map_or(0, |prover| prover.n_vars());
+	let table_sumcheck_max_n_vars = table_sumcheck_metas.first().map_or(0, |meta| meta.n_vars);
+
+	let (provers, is_flush_prover) = merge_sorted_by_n_vars(
+		flush_provers
+			.into_iter()
+			.map(|prover| Box::new(prover) as TypeErasedSumcheck<_>)
+			.collect(),
+		table_sumcheck_provers
+			.into_iter()
+			.map(|prover| Box::new(prover) as TypeErasedSumcheck<_>)
+			.collect(),
+		|prover| prover.n_vars(),
+	);
 
-	let flush_sumcheck_output = sumcheck::prove::batch_prove(provers, &mut transcript)?;
+	let sumcheck_output = sumcheck::prove::batch_prove(provers, &mut transcript)?;
+
+	let (flush_sumcheck_output, table_sumcheck_output) = split_merged_sumcheck_output(
+		sumcheck_output,
+		&is_flush_prover,
+		flush_max_n_vars,
+		table_sumcheck_max_n_vars,
+	);
+
+	let table_sumcheck_eval_claims =
+		sumcheck::make_eval_claims(table_sumcheck_metas, table_sumcheck_output)?;
 
 	let flush_eval_claims = get_post_flush_sumcheck_eval_claims_without_eq(
 		&oracles,
@@ -283,8 +475,6 @@ where
 
 	let zerocheck_challenges = transcript.sample_vec(max_n_vars - skip_rounds);
 
-	let switchover_fn = standard_switchover_heuristic(-2);
-
 	let mut univariate_provers = Vec::new();
 	let mut tail_regular_zerocheck_provers = Vec::new();
 	let mut univariatized_multilinears = Vec::new();
@@ -409,16 +599,24 @@ where
 	let eval_claims = greedy_evalcheck::prove::<_, _, FDomain<Tower>, _, _>(
 		&mut oracles,
 		&mut witness,
-		[non_zero_prodcheck_eval_claims, flush_eval_claims]
-			.concat()
-			.into_iter()
-			.chain(zerocheck_eval_claims),
+		[
+			non_zero_prodcheck_eval_claims,
+			flush_eval_claims,
+			table_sumcheck_eval_claims,
+		]
+		.concat()
+		.into_iter()
+		.chain(zerocheck_eval_claims),
 		switchover_fn,
 		&mut transcript,
 		&domain_factory,
 		backend,
 	)?;
 
+	let (fixed_eval_claims, eval_claims): (Vec<_>, Vec<_>) = eval_claims
+		.into_iter()
+		.partition(|claim| fixed_oracle_ids.contains(&claim.id));
+
 	// Reduce committed evaluation claims to PIOP sumcheck claims
 	let system = ring_switch::EvalClaimSystem::new(
 		&oracles,
@@ -441,7 +639,7 @@ where
 	piop::prove::<_, FDomain<Tower>, _, _, _, _, _, _, _, _>(
 		&fri_params,
 		&merkle_prover,
-		domain_factory,
+		domain_factory.clone(),
 		&commit_meta,
 		committed,
 		&codeword,
@@ -452,12 +650,76 @@ where
 		&backend,
 	)?;
 
+	// Prove evaluation claims on the fixed oracles against the preprocessed commitment
+	if let Some(proving_key) = proving_key {
+		let VerifyingKey {
+			log_inv_rate,
+			security_bits,
+			..
+		} = proving_key.verifying_key;
+		let (fixed_commit_meta, oracle_to_fixed_commit_index) =
+			make_fixed_commit_meta(&oracles, &fixed_oracle_ids)?;
+		let fixed_multilins = piop::collect_committed_witnesses(
+			&fixed_commit_meta,
+			&oracle_to_fixed_commit_index,
+			&oracles,
+			&witness,
+		)?;
+		let fixed_fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
+			&fixed_commit_meta,
+			merkle_scheme,
+			security_bits,
+			log_inv_rate,
+		)?;
+
+		let system = ring_switch::EvalClaimSystem::new(
+			&oracles,
+			&fixed_commit_meta,
+			oracle_to_fixed_commit_index,
+			&fixed_eval_claims,
+		)?;
+
+		let ring_switch::ReducedWitness {
+			transparents: transparent_multilins,
+			sumcheck_claims: piop_sumcheck_claims,
+		} = ring_switch::prove::<_, _, _, Tower, _, _>(
+			&system,
+			&fixed_multilins,
+			&mut transcript,
+			backend,
+		)?;
+
+		piop::prove::<_, FDomain<Tower>, _, _, _, _, _, _, _, _>(
+			&fixed_fri_params,
+			&merkle_prover,
+			domain_factory,
+			&fixed_commit_meta,
+			proving_key.committed.clone(),
+			&proving_key.codeword,
+			&fixed_multilins,
+			&transparent_multilins,
+			&piop_sumcheck_claims,
+			&mut transcript,
+			&backend,
+		)?;
+	}
+
+	// Scratch buffer usage of this proof, recorded on the `constraint_system::prove` span. The
+	// counters belong to the backend instance, so they also count concurrent proofs sharing it.
+	let scratch_stats = backend.scratch_stats() - scratch_stats_start;
+	tracing::Span::current()
+		.record("scratch_allocations", scratch_stats.allocations)
+		.record("scratch_reuses", scratch_stats.reuses)
+		.record("scratch_releases", scratch_stats.releases);
+
 	Ok(Proof {
 		transcript: transcript.finalize(),
 	})
 }
 
-fn arith_expr_base_tower_level<Tower: TowerFamily>(composition: &ArithExpr<FExt<Tower>>) -> usize {
+pub(super) fn arith_expr_base_tower_level<Tower: TowerFamily>(
+	composition: &ArithExpr<FExt<Tower>>,
+) -> usize {
 	if composition.try_convert_field::<Tower::B1>().is_ok() {
 		return 0;
 	}
@@ -504,7 +766,7 @@ impl<'a, P, F, FDomain, DomainFactory, SwitchoverFn, Backend>
 where
 	F: Field,
 	P: PackedFieldIndexable<Scalar = F>,
-	FDomain: TowerField,
+	FDomain: TowerField + TryFrom<F>,
 	DomainFactory: EvaluationDomainFactory<FDomain>,
 	SwitchoverFn: Fn(usize) -> usize + Clone,
 	Backend: ComputationBackend,
@@ -520,6 +782,13 @@ where
 			+ PackedExtension<FBase, PackedSubfield: PackedFieldIndexable>,
 		F: TowerField + ExtensionField<FDomain> + ExtensionField<FBase>,
 	{
+		let n_small_field_rounds = sumcheck::prove::small_field_rounds_for::<_, FDomain, _, _>(
+			&self.multilinears,
+			self.constraints
+				.iter()
+				.map(|constraint| constraint.composition.clone()),
+		);
+
 		let univariate_prover =
 			sumcheck::prove::constraint_set_zerocheck_prover::<_, _, FBase, _, _>(
 				self.constraints,
@@ -536,7 +805,9 @@ where
 
 			Either::Left(type_erased_univariate_prover)
 		} else {
-			let zerocheck_prover = univariate_prover.into_regular_zerocheck()?;
+			let zerocheck_prover = univariate_prover
+				.into_regular_zerocheck()?
+				.with_small_field_rounds(n_small_field_rounds)?;
 			let type_erased_zerocheck_prover =
 				Box::new(zerocheck_prover) as TypeErasedSumcheck<'a, P::Scalar>;
 
@@ -700,11 +971,11 @@ pub struct FlushSumcheckProvers<Prover> {
 
 #[instrument(skip_all, level = "debug")]
 fn get_flush_sumcheck_provers<'a, 'b, U, Tower, FDomain, DomainFactory, Backend>(
-	oracles: &mut MultilinearOracleSet<Tower::B128>,
+	oracles: &MultilinearOracleSet<Tower::B128>,
 	flush_oracle_ids: &[OracleId],
 	flush_selectors: &[OracleId],
 	final_layer_claims: &[LayerClaim<Tower::B128>],
-	witness: &mut MultilinearExtensionIndex<'a, U, Tower::B128>,
+	witness: &MultilinearExtensionIndex<'a, U, Tower::B128>,
 	domain_factory: DomainFactory,
 	backend: &'b Backend,
 ) -> Result<FlushSumcheckProvers<impl SumcheckProver<Tower::B128> + 'b>, Error>
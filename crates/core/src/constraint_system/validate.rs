@@ -18,7 +18,10 @@ use crate::{
 		ProjectionVariant, ShiftVariant,
 	},
 	polynomial::{test_utils::decompose_index_to_hypercube_point, ArithCircuitPoly},
-	protocols::sumcheck::prove::zerocheck,
+	protocols::sumcheck::{
+		prove::{regular_sumcheck, zerocheck},
+		CompositeSumClaim,
+	},
 	witness::MultilinearExtensionIndex,
 };
 
@@ -40,19 +43,29 @@ where
 			.collect::<Result<Vec<_>, _>>()?;
 
 		let mut zero_claims = vec![];
+		let mut sum_claims = vec![];
 		for constraint in &constraint_set.constraints {
+			let composition =
+				ArithCircuitPoly::with_n_vars(multilinears.len(), constraint.composition.clone())?;
 			match constraint.predicate {
-				ConstraintPredicate::Zero => zero_claims.push((
-					constraint.name.clone(),
-					ArithCircuitPoly::with_n_vars(
-						multilinears.len(),
-						constraint.composition.clone(),
-					)?,
-				)),
-				ConstraintPredicate::Sum(_) => unimplemented!(),
+				ConstraintPredicate::Zero => {
+					zero_claims.push((constraint.name.clone(), composition))
+				}
+				ConstraintPredicate::Sum(sum) => {
+					sum_claims.push(CompositeSumClaim { composition, sum })
+				}
 			}
 		}
 		zerocheck::validate_witness(&multilinears, &zero_claims)?;
+		regular_sumcheck::validate_witness(
+			&multilinears,
+			sum_claims
+				.iter()
+				.map(|CompositeSumClaim { composition, sum }| CompositeSumClaim {
+					composition,
+					sum: *sum,
+				}),
+		)?;
 	}
 
 	// Check that nonzero oracles are non-zero over the entire hypercube
@@ -235,6 +248,36 @@ where
 				check_eval(oracle_label, i, F::ZERO, poly.evaluate_on_hypercube(i)?)?;
 			}
 		}
+		MultilinearPolyVariant::Interleaved(id0, id1) => {
+			let even_poly = witness.get_multilin_poly(id0)?;
+			let odd_poly = witness.get_multilin_poly(id1)?;
+			for i in 0..1 << (n_vars - 1) {
+				check_eval(
+					oracle_label,
+					2 * i,
+					even_poly.evaluate_on_hypercube(i)?,
+					poly.evaluate_on_hypercube(2 * i)?,
+				)?;
+				check_eval(
+					oracle_label,
+					2 * i + 1,
+					odd_poly.evaluate_on_hypercube(i)?,
+					poly.evaluate_on_hypercube(2 * i + 1)?,
+				)?;
+			}
+		}
+		MultilinearPolyVariant::ElementwiseProduct(id0, id1) => {
+			let poly_0 = witness.get_multilin_poly(id0)?;
+			let poly_1 = witness.get_multilin_poly(id1)?;
+			for i in 0..1 << n_vars {
+				check_eval(
+					oracle_label,
+					i,
+					poly_0.evaluate_on_hypercube(i)? * poly_1.evaluate_on_hypercube(i)?,
+					poly.evaluate_on_hypercube(i)?,
+				)?;
+			}
+		}
 		MultilinearPolyVariant::Packed(ref packed) => {
 			let expected = witness.get_multilin_poly(packed.id())?;
 			let got = witness.get_multilin_poly(oracle.id())?;
@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{cmp::Reverse, iter};
+use std::{cmp::Reverse, iter, sync::Arc};
 
 use binius_field::{BinaryField, PackedField, TowerField};
 use binius_hash::PseudoCompressionFunction;
@@ -13,7 +13,8 @@ use tracing::instrument;
 use super::{
 	channel::Boundary,
 	error::{Error, VerificationError},
-	ConstraintSystem, Proof,
+	preprocess::{make_fixed_commit_meta, make_trace_commit_meta},
+	ConstraintSystem, Proof, VerifyingKey,
 };
 use crate::{
 	composition::IndexComposition,
@@ -23,7 +24,7 @@ use crate::{
 	},
 	fiat_shamir::{CanSample, Challenger},
 	merkle_tree::BinaryMerkleTreeScheme,
-	oracle::{MultilinearOracleSet, OracleId},
+	oracle::{split_constraint_sets_by_predicate, MultilinearOracleSet, OracleId},
 	piop,
 	polynomial::MultivariatePoly,
 	protocols::{
@@ -32,9 +33,10 @@ use crate::{
 		gkr_gpa::LayerClaim,
 		greedy_evalcheck,
 		sumcheck::{
-			self, constraint_set_zerocheck_claim,
+			self, constraint_set_sumcheck_claims, constraint_set_zerocheck_claim,
 			zerocheck::{self, ExtraProduct},
-			BatchSumcheckOutput, CompositeSumClaim, SumcheckClaim, ZerocheckClaim,
+			BatchSumcheckOutput, CompositeSumClaim, SumcheckClaim, SumcheckClaimsWithMeta,
+			ZerocheckClaim,
 		},
 	},
 	ring_switch,
@@ -59,6 +61,60 @@ where
 	Hash: Digest + BlockSizeUser,
 	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
 	Challenger_: Challenger + Default,
+{
+	verify_impl::<Tower, Hash, Compress, Challenger_>(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+		boundaries,
+		None,
+		proof,
+	)
+}
+
+/// Verifies a proof against a constraint system whose fixed oracles were committed by
+/// [`super::preprocess`].
+#[instrument("constraint_system::verify", skip_all, level = "debug")]
+pub fn verify_with_key<U, Tower, Hash, Compress, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	boundaries: &[Boundary<FExt<Tower>>],
+	verifying_key: &VerifyingKey<Output<Hash>>,
+	proof: Proof,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+{
+	verify_impl::<Tower, Hash, Compress, Challenger_>(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+		boundaries,
+		Some(verifying_key),
+		proof,
+	)
+}
+
+fn verify_impl<Tower, Hash, Compress, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	boundaries: &[Boundary<FExt<Tower>>],
+	verifying_key: Option<&VerifyingKey<Output<Hash>>>,
+	proof: Proof,
+) -> Result<(), Error>
+where
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
 {
 	let ConstraintSystem {
 		mut oracles,
@@ -66,11 +122,20 @@ where
 		mut flushes,
 		non_zero_oracle_ids,
 		max_channel_id,
+		fixed_oracle_ids,
 		..
 	} = constraint_system.clone();
+	// Without a verifying key, the fixed oracles are committed along with the trace
+	let fixed_oracle_ids = if verifying_key.is_some() {
+		fixed_oracle_ids
+	} else {
+		vec![]
+	};
 
 	// Stable sort constraint sets in descending order by number of variables.
 	table_constraints.sort_by_key(|constraint_set| Reverse(constraint_set.n_vars));
+	let (table_constraints, table_sum_constraints) =
+		split_constraint_sets_by_predicate(table_constraints);
 
 	let Proof { transcript } = proof;
 
@@ -80,10 +145,14 @@ where
 		for boundary in boundaries {
 			boundary.write_to(&mut observer);
 		}
+		if let Some(verifying_key) = verifying_key {
+			observer.write(&verifying_key.fixed_commitment);
+		}
 	}
 
 	let merkle_scheme = BinaryMerkleTreeScheme::<_, Hash, _>::new(Compress::default());
-	let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(&oracles)?;
+	let (commit_meta, oracle_to_commit_index) =
+		make_trace_commit_meta(&oracles, &fixed_oracle_ids)?;
 	let fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
 		&commit_meta,
 		&merkle_scheme,
@@ -92,7 +161,7 @@ where
 	)?;
 
 	// Read polynomial commitment polynomials
-	let mut reader = transcript.message();
+	let mut reader = transcript.message_labeled("constraint_system.commitment")?;
 	let commitment = reader.read::<Output<Hash>>()?;
 
 	// Grand product arguments
@@ -125,7 +194,7 @@ where
 		.collect::<Vec<_>>();
 
 	let flush_products = transcript
-		.message()
+		.message_labeled("constraint_system.flush_products")?
 		.read_scalar_slice(flush_oracle_ids.len())?;
 	verify_channels_balance(
 		&flushes,
@@ -168,13 +237,38 @@ where
 	)?;
 
 	let DedupSumcheckClaims {
-		sumcheck_claims,
+		sumcheck_claims: flush_sumcheck_claims,
 		gkr_eval_points,
 		flush_selectors_unique_by_claim,
 		flush_oracle_ids_by_claim,
 	} = get_flush_dedup_sumcheck_claims(flush_sumcheck_metas)?;
 
-	let flush_sumcheck_output = sumcheck::batch_verify(&sumcheck_claims, &mut transcript)?;
+	// Table sum constraints are batched together with the flush sumchecks
+	let SumcheckClaimsWithMeta {
+		claims: table_sumcheck_claims,
+		metas: table_sumcheck_metas,
+	} = constraint_set_sumcheck_claims(table_sum_constraints)?;
+
+	let flush_max_n_vars = max_sumcheck_claims_n_vars(&flush_sumcheck_claims);
+	let table_sumcheck_max_n_vars = max_sumcheck_claims_n_vars(&table_sumcheck_claims);
+
+	let (sumcheck_claims, is_flush_claim) = merge_sorted_by_n_vars(
+		type_erase_sumcheck_claims(flush_sumcheck_claims)?,
+		type_erase_sumcheck_claims(table_sumcheck_claims)?,
+		|claim| claim.n_vars(),
+	);
+
+	let sumcheck_output = sumcheck::batch_verify(&sumcheck_claims, &mut transcript)?;
+
+	let (flush_sumcheck_output, table_sumcheck_output) = split_merged_sumcheck_output(
+		sumcheck_output,
+		&is_flush_claim,
+		flush_max_n_vars,
+		table_sumcheck_max_n_vars,
+	);
+
+	let table_sumcheck_eval_claims =
+		sumcheck::make_eval_claims(table_sumcheck_metas, table_sumcheck_output)?;
 
 	let flush_eval_claims = get_post_flush_sumcheck_eval_claims_without_eq(
 		&oracles,
@@ -273,13 +367,21 @@ where
 	// Evalcheck
 	let eval_claims = greedy_evalcheck::verify(
 		&mut oracles,
-		[non_zero_prodcheck_eval_claims, flush_eval_claims]
-			.concat()
-			.into_iter()
-			.chain(zerocheck_eval_claims),
+		[
+			non_zero_prodcheck_eval_claims,
+			flush_eval_claims,
+			table_sumcheck_eval_claims,
+		]
+		.concat()
+		.into_iter()
+		.chain(zerocheck_eval_claims),
 		&mut transcript,
 	)?;
 
+	let (fixed_eval_claims, eval_claims): (Vec<_>, Vec<_>) = eval_claims
+		.into_iter()
+		.partition(|claim| fixed_oracle_ids.contains(&claim.id));
+
 	// Reduce committed evaluation claims to PIOP sumcheck claims
 	let system = ring_switch::EvalClaimSystem::new(
 		&oracles,
@@ -304,11 +406,146 @@ where
 		&mut transcript,
 	)?;
 
+	// Verify evaluation claims on the fixed oracles against the preprocessed commitment
+	if let Some(verifying_key) = verifying_key {
+		let (fixed_commit_meta, oracle_to_fixed_commit_index) =
+			make_fixed_commit_meta(&oracles, &fixed_oracle_ids)?;
+		let fixed_fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
+			&fixed_commit_meta,
+			&merkle_scheme,
+			verifying_key.security_bits,
+			verifying_key.log_inv_rate,
+		)?;
+
+		let system = ring_switch::EvalClaimSystem::new(
+			&oracles,
+			&fixed_commit_meta,
+			oracle_to_fixed_commit_index,
+			&fixed_eval_claims,
+		)?;
+
+		let ring_switch::ReducedClaim {
+			transparents,
+			sumcheck_claims: piop_sumcheck_claims,
+		} = ring_switch::verify::<_, Tower, _>(&system, &mut transcript)?;
+
+		piop::verify(
+			&fixed_commit_meta,
+			&merkle_scheme,
+			&fixed_fri_params,
+			&verifying_key.fixed_commitment,
+			&transparents,
+			&piop_sumcheck_claims,
+			&mut transcript,
+		)?;
+	}
+
 	transcript.finalize()?;
 
 	Ok(())
 }
 
+fn max_sumcheck_claims_n_vars<F, Composition>(claims: &[SumcheckClaim<F, Composition>]) -> usize
+where
+	F: TowerField,
+	Composition: CompositionPolyOS<F>,
+{
+	claims.first().map_or(0, |claim| claim.n_vars())
+}
+
+type TypeErasedSumcheckClaim<F> = SumcheckClaim<F, Arc<dyn CompositionPolyOS<F>>>;
+
+fn type_erase_sumcheck_claims<F, Composition>(
+	claims: Vec<SumcheckClaim<F, Composition>>,
+) -> Result<Vec<TypeErasedSumcheckClaim<F>>, Error>
+where
+	F: TowerField,
+	Composition: CompositionPolyOS<F> + 'static,
+{
+	claims
+		.into_iter()
+		.map(|claim| {
+			claim.map_compositions(|composition| {
+				Arc::new(composition) as Arc<dyn CompositionPolyOS<F>>
+			})
+		})
+		.collect::<Result<_, _>>()
+		.map_err(Error::from)
+}
+
+/// Merges two sequences of sumcheck claims or provers, each sorted in descending order by number
+/// of variables, into a single sequence in the same order so that they can be batched together.
+///
+/// Among elements with the same number of variables, the elements of `lhs` come first. Returns the
+/// merged sequence along with a flag for every element that is set if it originates from `lhs`.
+pub fn merge_sorted_by_n_vars<T>(
+	lhs: Vec<T>,
+	rhs: Vec<T>,
+	n_vars: impl Fn(&T) -> usize,
+) -> (Vec<T>, Vec<bool>) {
+	let mut merged = Vec::with_capacity(lhs.len() + rhs.len());
+	let mut is_lhs = Vec::with_capacity(lhs.len() + rhs.len());
+
+	let mut lhs = lhs.into_iter().peekable();
+	let mut rhs = rhs.into_iter().peekable();
+	loop {
+		let take_lhs = match (lhs.peek(), rhs.peek()) {
+			(Some(lhs_elem), Some(rhs_elem)) => n_vars(lhs_elem) >= n_vars(rhs_elem),
+			(Some(_), None) => true,
+			(None, Some(_)) => false,
+			(None, None) => break,
+		};
+
+		let elem = if take_lhs { lhs.next() } else { rhs.next() };
+		merged.extend(elem);
+		is_lhs.push(take_lhs);
+	}
+
+	(merged, is_lhs)
+}
+
+/// Splits the output of a batched sumcheck over sequences merged with [`merge_sorted_by_n_vars`]
+/// back into the outputs of the two sequences.
+///
+/// The challenges of each output are truncated to the number of variables of the largest claim in
+/// the respective sequence, as expected by the evalcheck claim constructors.
+pub fn split_merged_sumcheck_output<F: TowerField>(
+	output: BatchSumcheckOutput<F>,
+	is_lhs: &[bool],
+	lhs_max_n_vars: usize,
+	rhs_max_n_vars: usize,
+) -> (BatchSumcheckOutput<F>, BatchSumcheckOutput<F>) {
+	let BatchSumcheckOutput {
+		challenges,
+		multilinear_evals,
+	} = output;
+
+	debug_assert_eq!(is_lhs.len(), multilinear_evals.len());
+
+	let (lhs_evals, rhs_evals): (Vec<_>, Vec<_>) =
+		izip!(is_lhs, multilinear_evals).partition_map(|(&is_lhs, evals)| {
+			if is_lhs {
+				itertools::Either::Left(evals)
+			} else {
+				itertools::Either::Right(evals)
+			}
+		});
+
+	let truncated_challenges =
+		|max_n_vars: usize| challenges[challenges.len() - max_n_vars..].to_vec();
+
+	(
+		BatchSumcheckOutput {
+			challenges: truncated_challenges(lhs_max_n_vars),
+			multilinear_evals: lhs_evals,
+		},
+		BatchSumcheckOutput {
+			challenges: truncated_challenges(rhs_max_n_vars),
+			multilinear_evals: rhs_evals,
+		},
+	)
+}
+
 pub fn max_n_vars_and_skip_rounds<F, Composition>(
 	zerocheck_claims: &[ZerocheckClaim<F, Composition>],
 	domain_bits: usize,
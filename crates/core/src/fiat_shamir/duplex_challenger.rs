@@ -0,0 +1,382 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::cmp::min;
+
+use binius_field::{deserialize_canonical, serialize_canonical, BinaryField32b, TowerField};
+use binius_hash::{permutation::CryptographicPermutation, Vision32bPermutation};
+use bytes::{buf::UninitSlice, Buf, BufMut};
+
+use super::{CanSample, CanSampleBits, Challenger};
+use crate::transcript::sample_bits_reader;
+
+/// Challenger over the [Vision Mark-32] permutation, operating as a duplex sponge on
+/// `BinaryField32b` elements with a rate of 16 and a capacity of 8 elements.
+///
+/// Every operation of this challenger is a field operation over the Vision state, which keeps the
+/// Fiat-Shamir transformation cheap to verify inside a Binius constraint system.
+///
+/// [Vision Mark-32]: <https://eprint.iacr.org/2024/633>
+pub type Vision32bChallenger = DuplexChallenger<BinaryField32b, Vision32bPermutation, 24, 16>;
+
+/// Challenger built from a cryptographic permutation over `W` field elements, operating as a
+/// duplex sponge with a rate of `RATE` elements.
+///
+/// Observed bytes are grouped into chunks of the canonical encoding size of `T`, deserialized as
+/// field elements, and absorbed in overwrite mode into the first `RATE` state elements. When
+/// switching from observing to sampling, the pending input is padded with a `0x01` byte followed
+/// by zeros up to the rate boundary and absorbed, so that the sampled values bind the exact
+/// observed byte string, including its length. Sampled bytes are the canonical encodings of the
+/// rate elements, and the state is permuted whenever the rate is exhausted.
+///
+/// Switching from sampling back to observing does not permute the state; the next absorption
+/// overwrites the rate, which already contains values revealed to the prover, and is followed by a
+/// permutation before anything else is sampled.
+#[derive(Debug)]
+pub struct DuplexChallenger<T, P, const W: usize, const RATE: usize> {
+	sponge: DuplexSponge<T, P, W, RATE>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SpongeMode {
+	Absorbing,
+	Squeezing,
+}
+
+#[derive(Debug)]
+struct DuplexSponge<T, P, const W: usize, const RATE: usize> {
+	permutation: P,
+	state: [T; W],
+	/// Pending input bytes while absorbing, or the squeezed rate bytes while squeezing.
+	buffer: Vec<u8>,
+	/// Invariant: while absorbing, `index` is always strictly less than `buffer.len()`.
+	index: usize,
+	mode: SpongeMode,
+}
+
+impl<T, P, const W: usize, const RATE: usize> DuplexChallenger<T, P, W, RATE>
+where
+	T: TowerField,
+	P: CryptographicPermutation<[T; W]>,
+{
+	/// Constructs a new challenger with an all-zero initial state.
+	///
+	/// ## Preconditions
+	///
+	/// * `RATE` must be non-zero and strictly less than `W`
+	/// * `T` must be at least one byte wide
+	pub fn new(permutation: P) -> Self {
+		assert!(RATE > 0 && RATE < W, "the rate must be non-zero and less than the state width");
+		assert!(T::N_BITS >= 8, "the state elements must be at least one byte wide");
+
+		Self {
+			sponge: DuplexSponge {
+				permutation,
+				state: [T::ZERO; W],
+				buffer: vec![0; RATE * T::N_BITS / 8],
+				index: 0,
+				mode: SpongeMode::Absorbing,
+			},
+		}
+	}
+}
+
+impl<T, P, const W: usize, const RATE: usize> Default for DuplexChallenger<T, P, W, RATE>
+where
+	T: TowerField,
+	P: CryptographicPermutation<[T; W]> + Default,
+{
+	fn default() -> Self {
+		Self::new(P::default())
+	}
+}
+
+impl<T, P, const W: usize, const RATE: usize> Challenger for DuplexChallenger<T, P, W, RATE>
+where
+	T: TowerField,
+	P: CryptographicPermutation<[T; W]>,
+{
+	fn sampler(&mut self) -> &mut impl Buf {
+		if self.sponge.mode == SpongeMode::Absorbing {
+			self.sponge.start_squeezing();
+		}
+		&mut self.sponge
+	}
+
+	fn observer(&mut self) -> &mut impl BufMut {
+		if self.sponge.mode == SpongeMode::Squeezing {
+			self.sponge.start_absorbing();
+		}
+		&mut self.sponge
+	}
+}
+
+impl<F, T, P, const W: usize, const RATE: usize> CanSample<F> for DuplexChallenger<T, P, W, RATE>
+where
+	F: TowerField,
+	T: TowerField,
+	P: CryptographicPermutation<[T; W]>,
+{
+	fn sample(&mut self) -> F {
+		deserialize_canonical(self.sampler()).expect("challenger has infinite buffer")
+	}
+}
+
+impl<T, P, const W: usize, const RATE: usize> CanSampleBits<usize>
+	for DuplexChallenger<T, P, W, RATE>
+where
+	T: TowerField,
+	P: CryptographicPermutation<[T; W]>,
+{
+	fn sample_bits(&mut self, bits: usize) -> usize {
+		sample_bits_reader(self.sampler(), bits)
+	}
+}
+
+impl<T, P, const W: usize, const RATE: usize> DuplexSponge<T, P, W, RATE>
+where
+	T: TowerField,
+	P: CryptographicPermutation<[T; W]>,
+{
+	fn absorb_buffer(&mut self) {
+		let mut reader = self.buffer.as_slice();
+		for elem in &mut self.state[..RATE] {
+			*elem = deserialize_canonical(&mut reader).expect("buffer holds RATE elements");
+		}
+		self.permutation.permute_mut(&mut self.state);
+		self.index = 0;
+	}
+
+	fn fill_buffer(&mut self) {
+		let mut writer = self.buffer.as_mut_slice();
+		for &elem in &self.state[..RATE] {
+			serialize_canonical(elem, &mut writer).expect("buffer holds RATE elements");
+		}
+		self.index = 0;
+	}
+
+	fn start_squeezing(&mut self) {
+		self.buffer[self.index] = 0x01;
+		self.buffer[self.index + 1..].fill(0);
+		self.absorb_buffer();
+		self.fill_buffer();
+		self.mode = SpongeMode::Squeezing;
+	}
+
+	fn start_absorbing(&mut self) {
+		self.index = 0;
+		self.mode = SpongeMode::Absorbing;
+	}
+}
+
+impl<T, P, const W: usize, const RATE: usize> Buf for DuplexSponge<T, P, W, RATE>
+where
+	T: TowerField,
+	P: CryptographicPermutation<[T; W]>,
+{
+	fn remaining(&self) -> usize {
+		usize::MAX
+	}
+
+	fn chunk(&self) -> &[u8] {
+		&self.buffer[self.index..]
+	}
+
+	fn advance(&mut self, mut cnt: usize) {
+		debug_assert_eq!(self.mode, SpongeMode::Squeezing);
+
+		// Must handle the case when `cnt` is 0
+		if self.index == self.buffer.len() {
+			self.permutation.permute_mut(&mut self.state);
+			self.fill_buffer();
+		}
+
+		while cnt > 0 {
+			let remaining = min(self.buffer.len() - self.index, cnt);
+			if remaining == 0 {
+				self.permutation.permute_mut(&mut self.state);
+				self.fill_buffer();
+				continue;
+			}
+			cnt -= remaining;
+			self.index += remaining;
+		}
+	}
+}
+
+unsafe impl<T, P, const W: usize, const RATE: usize> BufMut for DuplexSponge<T, P, W, RATE>
+where
+	T: TowerField,
+	P: CryptographicPermutation<[T; W]>,
+{
+	fn remaining_mut(&self) -> usize {
+		usize::MAX
+	}
+
+	unsafe fn advance_mut(&mut self, mut cnt: usize) {
+		debug_assert_eq!(self.mode, SpongeMode::Absorbing);
+
+		while cnt > 0 {
+			let remaining = min(self.buffer.len() - self.index, cnt);
+			cnt -= remaining;
+			self.index += remaining;
+			if self.index == self.buffer.len() {
+				self.absorb_buffer();
+			}
+		}
+	}
+
+	fn chunk_mut(&mut self) -> &mut UninitSlice {
+		let buffer = &mut self.buffer[self.index..];
+		buffer.into()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField128b, BinaryField64b, Field};
+	use binius_hash::permutation::Permutation;
+	use hex_literal::hex;
+	use rand::{thread_rng, RngCore};
+
+	use super::*;
+	use crate::transcript::ProverTranscript;
+
+	const RATE_BYTES: usize = 64;
+
+	/// Straight-line reference for observing `input` once and then sampling `n_out` bytes.
+	fn sponge_hash(input: &[u8], n_out: usize) -> Vec<u8> {
+		let permutation = Vision32bPermutation::default();
+		let mut padded = input.to_vec();
+		padded.push(0x01);
+		padded.resize(padded.len().next_multiple_of(RATE_BYTES), 0);
+
+		let mut state = [BinaryField32b::ZERO; 24];
+		for block in padded.chunks_exact(RATE_BYTES) {
+			for (elem, chunk) in state.iter_mut().zip(block.chunks_exact(4)) {
+				*elem = BinaryField32b::new(u32::from_le_bytes(chunk.try_into().unwrap()));
+			}
+			permutation.permute_mut(&mut state);
+		}
+
+		let mut out = Vec::with_capacity(n_out);
+		loop {
+			for elem in &state[..16] {
+				out.extend_from_slice(&elem.val().to_le_bytes());
+			}
+			if out.len() >= n_out {
+				out.truncate(n_out);
+				return out;
+			}
+			permutation.permute_mut(&mut state);
+		}
+	}
+
+	#[test]
+	fn test_known_answers() {
+		let mut challenger = Vision32bChallenger::default();
+		let sample: BinaryField128b = challenger.sample();
+		assert_eq!(sample, BinaryField128b::new(0xce785ff8ace89a862ca09c8ba76fdeac));
+
+		challenger.observer().put_slice(b"binius");
+		let sample: BinaryField64b = challenger.sample();
+		assert_eq!(sample, BinaryField64b::new(0xa805e00a8f7a7335));
+		assert_eq!(challenger.sample_bits(20), 162150);
+
+		let mut out = [0u8; 32];
+		challenger.observer().put_slice(&[0xFF; 100]);
+		challenger.sampler().copy_to_slice(&mut out);
+		assert_eq!(out, hex!("624ac6c94b1c3aa79ef7a0b5581b7a0fc63cc8f2a540e3cd4325a2a2d869ea93"));
+	}
+
+	#[test]
+	fn test_matches_reference_sponge() {
+		let mut rng = thread_rng();
+		for len in [0, 3, 63, 64, 100, 128] {
+			let mut input = vec![0u8; len];
+			rng.fill_bytes(&mut input);
+
+			let mut challenger = Vision32bChallenger::default();
+			challenger.observer().put_slice(&input);
+			let mut out = vec![0u8; 150];
+			challenger.sampler().copy_to_slice(&mut out);
+
+			assert_eq!(out, sponge_hash(&input, 150));
+		}
+	}
+
+	#[test]
+	fn test_split_observations_and_samples() {
+		let mut rng = thread_rng();
+		let mut input = [0u8; 200];
+		rng.fill_bytes(&mut input);
+
+		let mut challenger_1 = Vision32bChallenger::default();
+		let mut challenger_2 = Vision32bChallenger::default();
+
+		challenger_1.observer().put_slice(&input);
+		for chunk in input.chunks(7) {
+			challenger_2.observer().put_slice(chunk);
+		}
+
+		let mut out_1 = [0u8; 100];
+		let mut out_2 = [0u8; 100];
+		challenger_1.sampler().copy_to_slice(&mut out_1);
+		for chunk in out_2.chunks_mut(9) {
+			challenger_2.sampler().copy_to_slice(chunk);
+		}
+		assert_eq!(out_1, out_2);
+
+		challenger_1.observer().put_slice(&input[..10]);
+		challenger_2.observer().put_slice(&input[..10]);
+		assert_eq!(challenger_1.sampler().get_u64_le(), challenger_2.sampler().get_u64_le());
+	}
+
+	#[test]
+	fn test_absorb_squeeze_domain_separation() {
+		// Observing nothing differs from observing a zero byte.
+		let mut challenger_1 = Vision32bChallenger::default();
+		let mut challenger_2 = Vision32bChallenger::default();
+		challenger_2.observer().put_u8(0);
+		assert_ne!(challenger_1.sampler().get_u64_le(), challenger_2.sampler().get_u64_le());
+
+		// Observing a full block of zeros differs from observing nothing.
+		let mut challenger_1 = Vision32bChallenger::default();
+		let mut challenger_2 = Vision32bChallenger::default();
+		challenger_2.observer().put_slice(&[0; RATE_BYTES]);
+		assert_ne!(challenger_1.sampler().get_u64_le(), challenger_2.sampler().get_u64_le());
+
+		// Sampling in between observations changes the subsequent samples.
+		let mut challenger_1 = Vision32bChallenger::default();
+		let mut challenger_2 = Vision32bChallenger::default();
+		challenger_1.observer().put_slice(&[1, 2]);
+		challenger_2.observer().put_u8(1);
+		let _ = challenger_2.sampler().get_u8();
+		challenger_2.observer().put_u8(2);
+		assert_ne!(challenger_1.sampler().get_u64_le(), challenger_2.sampler().get_u64_le());
+	}
+
+	#[test]
+	fn test_transcript_round_trip() {
+		let mut prover_transcript = ProverTranscript::<Vision32bChallenger>::new();
+		prover_transcript
+			.message()
+			.write_scalar(BinaryField128b::new(0x55669900112233550000CCDDFFEEAABB));
+		let sampled_1: BinaryField128b = prover_transcript.sample();
+		prover_transcript.observe().write_bytes(b"statement");
+		prover_transcript
+			.message()
+			.write_scalar(BinaryField32b::new(0xDEADBEEF));
+		let sampled_2: BinaryField32b = prover_transcript.sample();
+		let sampled_bits = prover_transcript.sample_bits(13);
+
+		let mut verifier_transcript = prover_transcript.into_verifier();
+		let _: BinaryField128b = verifier_transcript.message().read_scalar().unwrap();
+		assert_eq!(CanSample::<BinaryField128b>::sample(&mut verifier_transcript), sampled_1);
+		verifier_transcript.observe().write_bytes(b"statement");
+		let _: BinaryField32b = verifier_transcript.message().read_scalar().unwrap();
+		assert_eq!(CanSample::<BinaryField32b>::sample(&mut verifier_transcript), sampled_2);
+		assert_eq!(verifier_transcript.sample_bits(13), sampled_bits);
+
+		verifier_transcript.finalize().unwrap();
+	}
+}
@@ -1,9 +1,11 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+mod duplex_challenger;
 mod hasher_challenger;
 mod sampling;
 
 use bytes::{Buf, BufMut};
+pub use duplex_challenger::{DuplexChallenger, Vision32bChallenger};
 pub use hasher_challenger::HasherChallenger;
 pub use sampling::*;
 
@@ -1,10 +1,11 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{array, fmt::Debug, mem::MaybeUninit};
+use std::{fmt::Debug, mem::MaybeUninit};
 
-use binius_field::{serialize_canonical, TowerField};
-use binius_hash::{HashBuffer, PseudoCompressionFunction};
-use binius_maybe_rayon::{prelude::*, slice::ParallelSlice};
+use binius_field::TowerField;
+use binius_hal::ComputationBackend;
+use binius_hash::PseudoCompressionFunction;
+use binius_maybe_rayon::prelude::*;
 use binius_utils::{bail, checked_arithmetics::log2_strict_usize};
 use digest::{crypto_common::BlockSizeUser, Digest, FixedOutputReset, Output};
 use tracing::instrument;
@@ -25,6 +26,7 @@ pub struct BinaryMerkleTree<D> {
 }
 
 pub fn build<F, H, C>(
+	backend: &impl ComputationBackend,
 	compression: &C,
 	elements: &[F],
 	batch_size: usize,
@@ -47,13 +49,15 @@ where
 	let log_len = log2_strict_usize(len);
 
 	internal_build(
+		backend,
 		compression,
-		|inner_nodes| hash_interleaved::<_, H>(elements, inner_nodes),
+		|inner_nodes| Ok(backend.merkle_hash_leaves::<_, H>(elements, inner_nodes)?),
 		log_len,
 	)
 }
 
 fn internal_build<Digest, C>(
+	backend: &impl ComputationBackend,
 	compression: &C,
 	// Must either successfully initialize the passed in slice or return error
 	hash_leaves: impl FnOnce(&mut [MaybeUninit<Digest>]) -> Result<(), Error>,
@@ -78,7 +82,7 @@ where
 		let (next_layer, next_remaining) = remaining.split_at_mut(1 << (log_len - i));
 		remaining = next_remaining;
 
-		compress_layer(compression, prev_layer, next_layer);
+		compress_layer(backend, compression, prev_layer, next_layer)?;
 
 		prev_layer = unsafe {
 			// SAFETY: next_layer was just initialized by compress_layer
@@ -100,6 +104,7 @@ where
 
 #[instrument("BinaryMerkleTree::build", skip_all, level = "debug")]
 pub fn build_from_iterator<F, H, C, ParIter>(
+	backend: &impl ComputationBackend,
 	compression: &C,
 	iterated_chunks: ParIter,
 	log_len: usize,
@@ -111,8 +116,11 @@ where
 	ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
 {
 	internal_build(
+		backend,
 		compression,
-		|inner_nodes| hash_iterated::<F, H, _>(iterated_chunks, inner_nodes),
+		|inner_nodes| {
+			Ok(backend.merkle_hash_iterated_leaves::<F, H, _>(iterated_chunks, inner_nodes)?)
+		},
 		log_len,
 	)
 }
@@ -156,67 +164,17 @@ impl<D: Clone> BinaryMerkleTree<D> {
 }
 
 #[tracing::instrument("MerkleTree::compress_layer", skip_all, level = "debug")]
-fn compress_layer<D, C>(compression: &C, prev_layer: &[D], next_layer: &mut [MaybeUninit<D>])
+fn compress_layer<D, C>(
+	backend: &impl ComputationBackend,
+	compression: &C,
+	prev_layer: &[D],
+	next_layer: &mut [MaybeUninit<D>],
+) -> Result<(), Error>
 where
 	D: Clone + Send + Sync,
 	C: PseudoCompressionFunction<D, 2> + Sync,
 {
-	prev_layer
-		.par_chunks_exact(2)
-		.zip(next_layer.par_iter_mut())
-		.for_each(|(prev_pair, next_digest)| {
-			next_digest.write(compression.compress(array::from_fn(|i| prev_pair[i].clone())));
-		})
-}
-
-/// Hashes the elements in chunks of a vector into digests.
-///
-/// Given a vector of elements and an output buffer of N hash digests, this splits the elements
-/// into N equal-sized chunks and hashes each chunks into the corresponding output digest. This
-/// returns the number of elements hashed into each digest.
-#[tracing::instrument("hash_interleaved", skip_all, level = "debug")]
-fn hash_interleaved<F, H>(elems: &[F], digests: &mut [MaybeUninit<Output<H>>]) -> Result<(), Error>
-where
-	F: TowerField,
-	H: Digest + BlockSizeUser + FixedOutputReset,
-{
-	if elems.len() % digests.len() != 0 {
-		return Err(Error::IncorrectVectorLen {
-			expected: digests.len(),
-		});
-	}
-	let batch_size = elems.len() / digests.len();
-	hash_iterated::<F, H, _>(
-		elems
-			.par_chunks(batch_size)
-			.map(|chunk| chunk.iter().copied()),
-		digests,
-	)
-}
-
-fn hash_iterated<F, H, ParIter>(
-	iterated_chunks: ParIter,
-	digests: &mut [MaybeUninit<Output<H>>],
-) -> Result<(), Error>
-where
-	F: TowerField,
-	H: Digest + BlockSizeUser + FixedOutputReset,
-	ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
-{
-	digests
-		.par_iter_mut()
-		.zip(iterated_chunks)
-		.for_each_init(H::new, |hasher, (digest, elems)| {
-			{
-				let mut hash_buffer = HashBuffer::new(hasher);
-				for elem in elems {
-					serialize_canonical(elem, &mut hash_buffer)
-						.expect("HashBuffer has infinite capacity");
-				}
-			}
-			digest.write(Digest::finalize_reset(hasher));
-		});
-	Ok(())
+	Ok(backend.merkle_compress_layer(compression, prev_layer, next_layer)?)
 }
 
 /// This can be removed when MaybeUninit::slice_assume_init_mut is stabilized
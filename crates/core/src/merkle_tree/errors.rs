@@ -16,6 +16,8 @@ pub enum Error {
 	IncorrectLayerDepth,
 	#[error("transcript error: {0}")]
 	Transcript(#[from] transcript::Error),
+	#[error("HAL error: {0}")]
+	Hal(#[from] binius_hal::Error),
 	#[error("verification failure: {0}")]
 	Verification(#[from] VerificationError),
 }
@@ -1,6 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use binius_field::TowerField;
+use binius_hal::{make_portable_backend, ComputationBackend, CpuBackend};
 use binius_hash::PseudoCompressionFunction;
 use binius_maybe_rayon::iter::IndexedParallelIterator;
 use bytes::BufMut;
@@ -17,24 +18,33 @@ use super::{
 use crate::transcript::TranscriptWriter;
 
 #[derive(Debug, Getters)]
-pub struct BinaryMerkleTreeProver<T, H, C> {
+pub struct BinaryMerkleTreeProver<T, H, C, Backend = CpuBackend> {
 	#[getset(get = "pub")]
 	scheme: BinaryMerkleTreeScheme<T, H, C>,
+	backend: Backend,
 }
 
 impl<T, C, H> BinaryMerkleTreeProver<T, H, C> {
 	pub fn new(compression: C) -> Self {
+		Self::with_backend(compression, make_portable_backend())
+	}
+}
+
+impl<T, C, H, Backend> BinaryMerkleTreeProver<T, H, C, Backend> {
+	pub fn with_backend(compression: C, backend: Backend) -> Self {
 		Self {
 			scheme: BinaryMerkleTreeScheme::new(compression),
+			backend,
 		}
 	}
 }
 
-impl<F, H, C> MerkleTreeProver<F> for BinaryMerkleTreeProver<F, H, C>
+impl<F, H, C, Backend> MerkleTreeProver<F> for BinaryMerkleTreeProver<F, H, C, Backend>
 where
 	F: TowerField,
 	H: Digest + BlockSizeUser + FixedOutputReset,
 	C: PseudoCompressionFunction<Output<H>, 2> + Sync,
+	Backend: ComputationBackend,
 {
 	type Scheme = BinaryMerkleTreeScheme<F, H, C>;
 	type Committed = BinaryMerkleTree<Output<H>>;
@@ -48,8 +58,12 @@ where
 		data: &[F],
 		batch_size: usize,
 	) -> Result<(Commitment<Output<H>>, Self::Committed), Error> {
-		let tree =
-			binary_merkle_tree::build::<_, H, _>(self.scheme.compression(), data, batch_size)?;
+		let tree = binary_merkle_tree::build::<_, H, _>(
+			&self.backend,
+			self.scheme.compression(),
+			data,
+			batch_size,
+		)?;
 
 		let commitment = Commitment {
 			root: tree.root(),
@@ -93,6 +107,7 @@ where
 		ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
 	{
 		let tree = binary_merkle_tree::build_from_iterator::<F, H, C, _>(
+			&self.backend,
 			self.scheme.compression(),
 			iterated_chunks,
 			log_len,
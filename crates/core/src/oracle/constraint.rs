@@ -9,6 +9,7 @@ use binius_utils::bail;
 use itertools::Itertools;
 
 use super::{Error, MultilinearOracleSet, MultilinearPolyVariant, OracleId};
+use crate::polynomial::CompositionKernels;
 
 /// Composition trait object that can be used to create lists of compositions of differing
 /// concrete types.
@@ -20,6 +21,8 @@ pub struct Constraint<F: Field> {
 	pub name: Arc<str>,
 	pub composition: ArithExpr<F>,
 	pub predicate: ConstraintPredicate<F>,
+	/// Compiled implementations of the composition, empty unless provided by the constraint author
+	pub kernels: CompositionKernels,
 }
 
 /// Predicate can either be a sum of values of a composition on the hypercube (sumcheck) or equality to zero
@@ -38,6 +41,73 @@ pub struct ConstraintSet<F: Field> {
 	pub constraints: Vec<Constraint<F>>,
 }
 
+impl<F: Field> ConstraintSet<F> {
+	/// Splits the constraint set into one with the zerocheck constraints and one with the sumcheck
+	/// constraints, both over the same oracles. Sets without constraints are omitted.
+	pub fn split_by_predicate(self) -> (Option<Self>, Option<Self>) {
+		let Self {
+			n_vars,
+			oracle_ids,
+			constraints,
+		} = self;
+
+		let (zero_constraints, sum_constraints) =
+			constraints
+				.into_iter()
+				.partition::<Vec<_>, _>(|constraint| {
+					matches!(constraint.predicate, ConstraintPredicate::Zero)
+				});
+
+		let make_set = |constraints: Vec<Constraint<F>>| {
+			(!constraints.is_empty()).then(|| Self {
+				n_vars,
+				oracle_ids: oracle_ids.clone(),
+				constraints,
+			})
+		};
+
+		(make_set(zero_constraints), make_set(sum_constraints))
+	}
+
+	/// Maps the constants of the constraints into an isomorphic field.
+	///
+	/// The compiled kernels are specific to the packed fields of `F` and are dropped.
+	pub fn convert_field<FTo: Field + From<F>>(&self) -> ConstraintSet<FTo> {
+		ConstraintSet {
+			n_vars: self.n_vars,
+			oracle_ids: self.oracle_ids.clone(),
+			constraints: self
+				.constraints
+				.iter()
+				.map(|constraint| Constraint {
+					name: constraint.name.clone(),
+					composition: constraint.composition.convert_field(),
+					predicate: match constraint.predicate {
+						ConstraintPredicate::Sum(sum) => ConstraintPredicate::Sum(sum.into()),
+						ConstraintPredicate::Zero => ConstraintPredicate::Zero,
+					},
+					kernels: CompositionKernels::default(),
+				})
+				.collect(),
+		}
+	}
+}
+
+/// Partitions constraint sets into zerocheck constraint sets and sumcheck constraint sets,
+/// preserving their relative order.
+pub fn split_constraint_sets_by_predicate<F: Field>(
+	constraint_sets: impl IntoIterator<Item = ConstraintSet<F>>,
+) -> (Vec<ConstraintSet<F>>, Vec<ConstraintSet<F>>) {
+	let mut zerocheck_sets = Vec::new();
+	let mut sumcheck_sets = Vec::new();
+	for constraint_set in constraint_sets {
+		let (zerocheck_set, sumcheck_set) = constraint_set.split_by_predicate();
+		zerocheck_sets.extend(zerocheck_set);
+		sumcheck_sets.extend(sumcheck_set);
+	}
+	(zerocheck_sets, sumcheck_sets)
+}
+
 // A deferred constraint constructor that instantiates index composition after the superset of oracles is known
 #[allow(clippy::type_complexity)]
 struct UngroupedConstraint<F: Field> {
@@ -45,6 +115,7 @@ struct UngroupedConstraint<F: Field> {
 	oracle_ids: Vec<OracleId>,
 	composition: ArithExpr<F>,
 	predicate: ConstraintPredicate<F>,
+	kernels: CompositionKernels,
 }
 
 /// A builder struct that turns individual compositions over oraclized multilinears into a set of
@@ -63,16 +134,18 @@ impl<F: Field> ConstraintSetBuilder<F> {
 
 	pub fn add_sumcheck(
 		&mut self,
+		name: impl ToString,
 		oracle_ids: impl IntoIterator<Item = OracleId>,
 		composition: ArithExpr<F>,
 		sum: F,
 	) {
-		self.constraints.push(UngroupedConstraint {
-			name: "sumcheck".into(),
-			oracle_ids: oracle_ids.into_iter().collect(),
+		self.add_constraint(
+			name,
+			oracle_ids,
 			composition,
-			predicate: ConstraintPredicate::Sum(sum),
-		});
+			ConstraintPredicate::Sum(sum),
+			CompositionKernels::default(),
+		);
 	}
 
 	pub fn add_zerocheck(
@@ -80,12 +153,35 @@ impl<F: Field> ConstraintSetBuilder<F> {
 		name: impl ToString,
 		oracle_ids: impl IntoIterator<Item = OracleId>,
 		composition: ArithExpr<F>,
+	) {
+		self.add_constraint(
+			name,
+			oracle_ids,
+			composition,
+			ConstraintPredicate::Zero,
+			CompositionKernels::default(),
+		);
+	}
+
+	/// Adds a constraint with compiled implementations of its composition.
+	///
+	/// The kernels are evaluated over the oracles in the order of `oracle_ids` and are used by the
+	/// prover in place of interpreting `composition`. Unlike the compositions of the other
+	/// constraints, `composition` is kept as is and not simplified.
+	pub fn add_constraint(
+		&mut self,
+		name: impl ToString,
+		oracle_ids: impl IntoIterator<Item = OracleId>,
+		composition: ArithExpr<F>,
+		predicate: ConstraintPredicate<F>,
+		kernels: CompositionKernels,
 	) {
 		self.constraints.push(UngroupedConstraint {
 			name: name.to_string().into(),
 			oracle_ids: oracle_ids.into_iter().collect(),
 			composition,
-			predicate: ConstraintPredicate::Zero,
+			predicate,
+			kernels,
 		});
 	}
 
@@ -127,20 +223,23 @@ impl<F: Field> ConstraintSetBuilder<F> {
 
 		// at this point the superset of oracles is known and index compositions
 		// may be finally instantiated
-		let constraints =
-			self.constraints
-				.into_iter()
-				.map(|constraint| Constraint {
+		let constraints = self
+			.constraints
+			.into_iter()
+			.map(|constraint| {
+				let positions = positions(&constraint.oracle_ids, &oracle_ids)
+					.expect("precondition: oracle_ids is a superset of constraint.oracle_ids");
+				Constraint {
 					name: constraint.name,
 					composition: constraint
 						.composition
-						.remap_vars(&positions(&constraint.oracle_ids, &oracle_ids).expect(
-							"precondition: oracle_ids is a superset of constraint.oracle_ids",
-						))
+						.remap_vars(&positions)
 						.expect("Infallible by ConstraintSetBuilder invariants."),
 					predicate: constraint.predicate,
-				})
-				.collect();
+					kernels: constraint.kernels.remap_vars(&positions),
+				}
+			})
+			.collect();
 
 		Ok(ConstraintSet {
 			n_vars,
@@ -152,9 +251,13 @@ impl<F: Field> ConstraintSetBuilder<F> {
 	/// Create one ConstraintSet for every unique n_vars used.
 	///
 	/// Note that you can't mix oracles with different n_vars in a single constraint.
-	pub fn build(
+	///
+	/// The compositions are simplified using the subfields that the values of the oracles are known
+	/// to lie in, see [`ArithExpr::reduce_subfield_powers`]. Zero constraints that become trivial
+	/// are dropped.
+	pub fn build<FO: TowerField>(
 		self,
-		oracles: &MultilinearOracleSet<impl TowerField>,
+		oracles: &MultilinearOracleSet<FO>,
 	) -> Result<Vec<ConstraintSet<F>>, Error> {
 		let connected_oracle_chunks = self
 			.constraints
@@ -215,7 +318,7 @@ impl<F: Field> ConstraintSetBuilder<F> {
 
 		let constraint_sets = grouped_constraints
 			.into_iter()
-			.map(|(_, grouped_constraints)| {
+			.filter_map(|(_, grouped_constraints)| {
 				let mut constraints = vec![];
 				let mut oracle_ids = vec![];
 
@@ -229,25 +332,48 @@ impl<F: Field> ConstraintSetBuilder<F> {
 				oracle_ids.sort();
 				oracle_ids.dedup();
 
+				let var_tower_levels = oracle_ids
+					.iter()
+					.map(|&id| oracles.values_tower_level(id).unwrap_or(FO::TOWER_LEVEL))
+					.collect::<Vec<_>>();
+
 				let constraints = constraints
 					.into_iter()
-					.map(|constraint| Constraint {
-						name: constraint.name,
-						composition: constraint
+					.map(|constraint| {
+						let positions = positions(&constraint.oracle_ids, &oracle_ids).expect(
+							"precondition: oracle_ids is a superset of constraint.oracle_ids",
+						);
+						let composition = constraint
 							.composition
-							.remap_vars(&positions(&constraint.oracle_ids, &oracle_ids).expect(
-								"precondition: oracle_ids is a superset of constraint.oracle_ids",
-							))
-							.expect("Infallible by ConstraintSetBuilder invariants."),
-						predicate: constraint.predicate,
+							.remap_vars(&positions)
+							.expect("Infallible by ConstraintSetBuilder invariants.");
+						// The kernels implement the composition as given, so it can't be rewritten
+						// into one that only agrees with it on the hypercube.
+						let composition = if constraint.kernels.is_empty() {
+							composition.reduce_subfield_powers(&var_tower_levels)
+						} else {
+							composition
+						};
+						Constraint {
+							name: constraint.name,
+							composition,
+							predicate: constraint.predicate,
+							kernels: constraint.kernels.remap_vars(&positions),
+						}
+					})
+					// Drop the zero constraints that hold trivially after the simplification, eg.
+					// the boolean checks of variables that are known to be boolean.
+					.filter(|constraint| {
+						!matches!(constraint.predicate, ConstraintPredicate::Zero)
+							|| constraint.composition != ArithExpr::zero()
 					})
-					.collect();
+					.collect::<Vec<_>>();
 
-				ConstraintSet {
+				(!constraints.is_empty()).then_some(ConstraintSet {
 					constraints,
 					oracle_ids,
 					n_vars,
-				}
+				})
 			})
 			.collect();
 
@@ -269,3 +395,60 @@ fn positions<T: Eq>(subset: &[T], superset: &[T]) -> Option<Vec<usize>> {
 		})
 		.collect()
 }
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField128b;
+
+	use super::*;
+
+	type F = BinaryField128b;
+
+	#[test]
+	fn test_build_reduces_subfield_powers() {
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let bit = oracles.add_committed(4, 0);
+		let byte = oracles.add_committed(4, 3);
+		let bits_sum = oracles
+			.add_linear_combination(4, [(bit, F::ONE), (byte, F::ONE)])
+			.unwrap();
+		let scaled_bit = oracles
+			.add_linear_combination(4, [(bit, F::new(1 << 64))])
+			.unwrap();
+
+		let mut builder = ConstraintSetBuilder::<F>::new();
+		builder.add_zerocheck("boolean", [bit], ArithExpr::Var(0).pow(2) - ArithExpr::Var(0));
+		builder.add_zerocheck(
+			"product",
+			[bit, byte],
+			ArithExpr::Var(0).pow(3) * ArithExpr::Var(1).pow(257),
+		);
+		builder.add_zerocheck("sum", [bits_sum], ArithExpr::Var(0).pow(512));
+		builder.add_zerocheck("scaled", [scaled_bit], ArithExpr::Var(0).pow(2));
+
+		let constraint_sets = builder.build(&oracles).unwrap();
+		assert_eq!(constraint_sets.len(), 1);
+
+		let ConstraintSet {
+			oracle_ids,
+			constraints,
+			..
+		} = &constraint_sets[0];
+		assert_eq!(oracle_ids, &[bit, byte, bits_sum, scaled_bit]);
+
+		// The boolean check is dropped as it holds trivially
+		let compositions = constraints
+			.iter()
+			.map(|constraint| constraint.composition.clone())
+			.collect::<Vec<_>>();
+		assert_eq!(
+			compositions,
+			[
+				ArithExpr::Var(0) * ArithExpr::Var(1).pow(2),
+				ArithExpr::Var(2).pow(2),
+				// The coefficient isn't in the 8-bit subfield, so the values aren't either
+				ArithExpr::Var(3).pow(2),
+			]
+		);
+	}
+}
@@ -20,6 +20,8 @@ pub enum Error {
 	NotEnoughVarsForPacking { n_vars: usize, log_degree: usize },
 	#[error("no oracle exists in this MultilinearOracleSet with id {0}")]
 	InvalidOracleId(OracleId),
+	#[error("packed oracle {id} packs sub-byte values, which depend on the basis of the field")]
+	SubBytePackedOracle { id: OracleId },
 	#[error("tower_level ({tower_level}) exceeds maximum")]
 	TowerLevelTooHigh { tower_level: usize },
 	#[error("constraint set is empty")]
@@ -254,6 +254,39 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 		Ok(self.mut_ref.add_to_set(oracle))
 	}
 
+	pub fn interleaved(self, id0: OracleId, id1: OracleId) -> Result<OracleId, Error> {
+		let [inner_0, inner_1] = self.mut_ref.same_n_vars_pair(id0, id1)?;
+
+		let oracle = |id: OracleId| MultilinearPolyOracle {
+			id,
+			n_vars: inner_0.n_vars + 1,
+			tower_level: inner_0.tower_level.max(inner_1.tower_level),
+			name: self.name,
+			variant: MultilinearPolyVariant::Interleaved(id0, id1),
+		};
+
+		Ok(self.mut_ref.add_to_set(oracle))
+	}
+
+	pub fn elementwise_product(self, id0: OracleId, id1: OracleId) -> Result<OracleId, Error> {
+		let [inner_0, inner_1] = self.mut_ref.same_n_vars_pair(id0, id1)?;
+
+		// The evaluation claims are reduced with a sumcheck, which needs at least one variable
+		if inner_0.n_vars == 0 {
+			bail!(Error::IncorrectNumberOfVariables { expected: 1 });
+		}
+
+		let oracle = |id: OracleId| MultilinearPolyOracle {
+			id,
+			n_vars: inner_0.n_vars,
+			tower_level: inner_0.tower_level.max(inner_1.tower_level),
+			name: self.name,
+			variant: MultilinearPolyVariant::ElementwiseProduct(id0, id1),
+		};
+
+		Ok(self.mut_ref.add_to_set(oracle))
+	}
+
 	fn add_committed_with_name(
 		&mut self,
 		n_vars: usize,
@@ -332,6 +365,25 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		self.oracles[id].clone()
 	}
 
+	fn same_n_vars_pair(
+		&self,
+		id0: OracleId,
+		id1: OracleId,
+	) -> Result<[Arc<MultilinearPolyOracle<F>>; 2], Error> {
+		for id in [id0, id1] {
+			if id >= self.oracles.len() {
+				bail!(Error::InvalidOracleId(id));
+			}
+		}
+		let inner = [self.get_from_set(id0), self.get_from_set(id1)];
+		if inner[0].n_vars != inner[1].n_vars {
+			bail!(Error::IncorrectNumberOfVariables {
+				expected: inner[0].n_vars
+			});
+		}
+		Ok(inner)
+	}
+
 	pub fn add_transparent(
 		&mut self,
 		poly: impl MultivariatePoly<F> + 'static,
@@ -400,6 +452,18 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		self.add().zero_padded(id, n_vars)
 	}
 
+	pub fn add_interleaved(&mut self, id0: OracleId, id1: OracleId) -> Result<OracleId, Error> {
+		self.add().interleaved(id0, id1)
+	}
+
+	pub fn add_elementwise_product(
+		&mut self,
+		id0: OracleId,
+		id1: OracleId,
+	) -> Result<OracleId, Error> {
+		self.add().elementwise_product(id0, id1)
+	}
+
 	pub fn oracle(&self, id: OracleId) -> MultilinearPolyOracle<F> {
 		(*self.oracles[id]).clone()
 	}
@@ -416,6 +480,157 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 	pub fn tower_level(&self, id: OracleId) -> usize {
 		self.oracles[id].binary_tower_level()
 	}
+
+	/// The tower level of a subfield that the oracle's values over the boolean hypercube are
+	/// guaranteed to lie in, if one is known.
+	///
+	/// Unlike [`Self::tower_level`], this doesn't trust the declared tower levels of derived oracles
+	/// that may take values outside of it, like projections onto challenges or linear combinations
+	/// with coefficients in larger fields.
+	pub fn values_tower_level(&self, id: OracleId) -> Option<usize> {
+		let oracle = &self.oracles[id];
+		match &oracle.variant {
+			MultilinearPolyVariant::Committed | MultilinearPolyVariant::Transparent(_) => {
+				Some(oracle.binary_tower_level())
+			}
+			MultilinearPolyVariant::Repeating { id, .. }
+			| MultilinearPolyVariant::ZeroPadded(id) => self.values_tower_level(*id),
+			MultilinearPolyVariant::Shifted(shifted) => self.values_tower_level(shifted.id()),
+			MultilinearPolyVariant::Packed(packed) => self
+				.values_tower_level(packed.id())
+				.map(|level| level + packed.log_degree()),
+			MultilinearPolyVariant::LinearCombination(linear_combination) => {
+				let constants_level = linear_combination
+					.coefficients()
+					.chain([linear_combination.offset()])
+					.map(TowerField::min_tower_level)
+					.max()
+					.unwrap_or(0);
+				linear_combination
+					.polys()
+					.map(|id| self.values_tower_level(id))
+					.try_fold(constants_level, |acc, level| Some(acc.max(level?)))
+			}
+			MultilinearPolyVariant::Interleaved(id0, id1)
+			| MultilinearPolyVariant::ElementwiseProduct(id0, id1) => {
+				let level_0 = self.values_tower_level(*id0)?;
+				let level_1 = self.values_tower_level(*id1)?;
+				Some(level_0.max(level_1))
+			}
+			MultilinearPolyVariant::Projected(_) => None,
+		}
+	}
+
+	/// Maps the oracles into an isomorphic tower field.
+	///
+	/// The isomorphism must map every level of the tower onto the same level of the target tower
+	/// and preserve the tower bases from `B8` upwards, like the isomorphisms between the tower
+	/// families do. The constants of the oracles are mapped and the transparent polynomials are
+	/// conjugated by the isomorphism. Packed oracles over sub-byte values are rejected, because
+	/// they depend on the bit basis of `B8`, which the isomorphism doesn't preserve.
+	pub fn convert_field<FTo>(&self) -> Result<MultilinearOracleSet<FTo>, Error>
+	where
+		F: From<FTo>,
+		FTo: TowerField + From<F>,
+	{
+		let oracles = self
+			.oracles
+			.iter()
+			.map(|oracle| {
+				let variant = match &oracle.variant {
+					MultilinearPolyVariant::Committed => MultilinearPolyVariant::Committed,
+					MultilinearPolyVariant::Transparent(transparent) => {
+						MultilinearPolyVariant::Transparent(TransparentPolyOracle {
+							poly: Arc::new(FieldConvertedPoly {
+								inner: transparent.poly.clone(),
+							}),
+						})
+					}
+					MultilinearPolyVariant::Repeating { id, log_count } => {
+						MultilinearPolyVariant::Repeating {
+							id: *id,
+							log_count: *log_count,
+						}
+					}
+					MultilinearPolyVariant::Projected(projected) => {
+						MultilinearPolyVariant::Projected(Projected {
+							id: projected.id,
+							values: projected.values.iter().map(|&value| value.into()).collect(),
+							projection_variant: projected.projection_variant,
+						})
+					}
+					MultilinearPolyVariant::Shifted(shifted) => {
+						MultilinearPolyVariant::Shifted(shifted.clone())
+					}
+					MultilinearPolyVariant::Packed(packed) => {
+						if self.tower_level(packed.id) < 3 {
+							bail!(Error::SubBytePackedOracle { id: oracle.id });
+						}
+						MultilinearPolyVariant::Packed(packed.clone())
+					}
+					MultilinearPolyVariant::LinearCombination(linear_combination) => {
+						MultilinearPolyVariant::LinearCombination(LinearCombination {
+							n_vars: linear_combination.n_vars,
+							offset: linear_combination.offset.into(),
+							inner: linear_combination
+								.inner
+								.iter()
+								.map(|&(id, coeff)| (id, coeff.into()))
+								.collect(),
+						})
+					}
+					MultilinearPolyVariant::ZeroPadded(id) => {
+						MultilinearPolyVariant::ZeroPadded(*id)
+					}
+					MultilinearPolyVariant::Interleaved(id0, id1) => {
+						MultilinearPolyVariant::Interleaved(*id0, *id1)
+					}
+					MultilinearPolyVariant::ElementwiseProduct(id0, id1) => {
+						MultilinearPolyVariant::ElementwiseProduct(*id0, *id1)
+					}
+				};
+
+				Ok(Arc::new(MultilinearPolyOracle {
+					id: oracle.id,
+					name: oracle.name.clone(),
+					n_vars: oracle.n_vars,
+					tower_level: oracle.tower_level,
+					variant,
+				}))
+			})
+			.collect::<Result<_, Error>>()?;
+
+		Ok(MultilinearOracleSet { oracles })
+	}
+}
+
+/// A transparent polynomial conjugated by a field isomorphism, $x \mapsto \phi(p(\phi^{-1}(x)))$.
+#[derive(Debug)]
+struct FieldConvertedPoly<F: Field> {
+	inner: Arc<dyn MultivariatePoly<F>>,
+}
+
+impl<F, FTo> MultivariatePoly<FTo> for FieldConvertedPoly<F>
+where
+	F: Field + From<FTo>,
+	FTo: Field + From<F>,
+{
+	fn n_vars(&self) -> usize {
+		self.inner.n_vars()
+	}
+
+	fn degree(&self) -> usize {
+		self.inner.degree()
+	}
+
+	fn evaluate(&self, query: &[FTo]) -> Result<FTo, PolynomialError> {
+		let query = query.iter().map(|&x| x.into()).collect::<Vec<F>>();
+		self.inner.evaluate(&query).map(Into::into)
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		self.inner.binary_tower_level()
+	}
 }
 
 /// A multilinear polynomial oracle in the polynomial IOP model.
@@ -451,12 +666,21 @@ pub struct MultilinearPolyOracle<F: TowerField> {
 pub enum MultilinearPolyVariant<F: TowerField> {
 	Committed,
 	Transparent(TransparentPolyOracle<F>),
-	Repeating { id: usize, log_count: usize },
+	Repeating {
+		id: usize,
+		log_count: usize,
+	},
 	Projected(Projected<F>),
 	Shifted(Shifted),
 	Packed(Packed),
 	LinearCombination(LinearCombination<F>),
 	ZeroPadded(OracleId),
+	/// Interleaves two oracles with the same number of variables into one with an extra lowest
+	/// variable, taking the values of the first oracle at even indices and of the second at odd
+	/// ones.
+	Interleaved(OracleId, OracleId),
+	/// The pointwise product of two oracles with the same number of variables over the hypercube.
+	ElementwiseProduct(OracleId, OracleId),
 }
 
 /// A transparent multilinear polynomial oracle.
@@ -664,6 +888,8 @@ impl<F: TowerField> MultilinearPolyOracle<F> {
 			MultilinearPolyVariant::Packed { .. } => "Packed",
 			MultilinearPolyVariant::LinearCombination { .. } => "LinearCombination",
 			MultilinearPolyVariant::ZeroPadded { .. } => "ZeroPadded",
+			MultilinearPolyVariant::Interleaved { .. } => "Interleaved",
+			MultilinearPolyVariant::ElementwiseProduct { .. } => "ElementwiseProduct",
 		}
 	}
 
@@ -685,9 +911,11 @@ impl<F: TowerField> MultilinearPolyOracle<F> {
 
 #[cfg(test)]
 mod tests {
-	use binius_field::{BinaryField128b, BinaryField1b, Field, TowerField};
+	use binius_field::{
+		AESTowerField128b, BinaryField128b, BinaryField1b, BinaryField8b, Field, TowerField,
+	};
 
-	use super::{MultilinearOracleSet, ProjectionVariant};
+	use super::{Error, MultilinearOracleSet, ProjectionVariant};
 
 	#[test]
 	fn add_projection_with_all_vars() {
@@ -703,4 +931,20 @@ mod tests {
 			.unwrap();
 		let _ = oracles.oracle(projected);
 	}
+
+	#[test]
+	fn convert_field_rejects_sub_byte_packing() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let bits = oracles.add_committed(8, BinaryField1b::TOWER_LEVEL);
+		let bytes = oracles.add_committed(8, BinaryField8b::TOWER_LEVEL);
+		oracles.add_packed(bytes, 2).unwrap();
+		assert!(oracles.convert_field::<AESTowerField128b>().is_ok());
+
+		let packed_bits = oracles.add_packed(bits, 3).unwrap();
+		assert!(matches!(
+			oracles.convert_field::<AESTowerField128b>(),
+			Err(Error::SubBytePackedOracle { id }) if id == packed_bits
+		));
+	}
 }
@@ -9,7 +9,7 @@ use binius_utils::sparse_index::SparseIndex;
 
 use super::{error::Error, util::ResizeableIndex, verify::CommitMeta};
 use crate::{
-	oracle::{MultilinearOracleSet, MultilinearPolyOracle, MultilinearPolyVariant},
+	oracle::{MultilinearOracleSet, MultilinearPolyOracle, MultilinearPolyVariant, OracleId},
 	witness::{MultilinearExtensionIndex, MultilinearWitness},
 };
 
@@ -19,6 +19,18 @@ use crate::{
 /// 2. a sparse index mapping oracle IDs to committed IDs in the commit metadata
 pub fn make_oracle_commit_meta<F: TowerField>(
 	oracles: &MultilinearOracleSet<F>,
+) -> Result<(CommitMeta, SparseIndex<usize>), Error> {
+	make_filtered_oracle_commit_meta(oracles, |_| true)
+}
+
+/// Indexes the committed oracles in a [`MultilinearOracleSet`] that satisfy a predicate.
+///
+/// This is the same as [`make_oracle_commit_meta`], except that committed oracles whose IDs do not
+/// satisfy `include` are left out of the commit metadata and the index. It is used to commit
+/// disjoint sets of committed oracles separately.
+pub fn make_filtered_oracle_commit_meta<F: TowerField>(
+	oracles: &MultilinearOracleSet<F>,
+	include: impl Fn(OracleId) -> bool,
 ) -> Result<(CommitMeta, SparseIndex<usize>), Error> {
 	// We need to construct two structures:
 	//
@@ -42,7 +54,7 @@ pub fn make_oracle_commit_meta<F: TowerField>(
 	let mut first_pass_index = SparseIndex::new(oracles.size());
 	let mut n_multilins_by_vars = ResizeableIndex::<usize>::new();
 	for oracle in oracles.iter() {
-		if matches!(oracle.variant, MultilinearPolyVariant::Committed) {
+		if matches!(oracle.variant, MultilinearPolyVariant::Committed) && include(oracle.id()) {
 			let n_packed_vars = n_packed_vars_for_committed_oracle(&oracle)?;
 			let n_multilins_for_vars = n_multilins_by_vars.get_mut(n_packed_vars);
 
@@ -156,4 +168,25 @@ mod tests {
 		assert_eq!(index.get(batch_2_2_ids[1]).copied(), Some(11));
 		assert_eq!(index.get(repeat).copied(), None);
 	}
+
+	#[test]
+	fn test_make_filtered_oracle_commit_meta() {
+		let mut oracles = MultilinearOracleSet::<BinaryField128b>::new();
+
+		let trace_ids = oracles.add_committed_multiple::<2>(8, 0);
+		let fixed_id = oracles.add_committed(10, 2);
+
+		let (commit_meta, index) =
+			make_filtered_oracle_commit_meta(&oracles, |id| id != fixed_id).unwrap();
+		assert_eq!(commit_meta.n_multilins_by_vars(), &[0, 2]);
+		assert_eq!(index.get(trace_ids[0]).copied(), Some(0));
+		assert_eq!(index.get(trace_ids[1]).copied(), Some(1));
+		assert_eq!(index.get(fixed_id).copied(), None);
+
+		let (commit_meta, index) =
+			make_filtered_oracle_commit_meta(&oracles, |id| id == fixed_id).unwrap();
+		assert_eq!(commit_meta.n_multilins_by_vars(), &[0, 0, 0, 0, 0, 1]);
+		assert_eq!(index.get(trace_ids[0]).copied(), None);
+		assert_eq!(index.get(fixed_id).copied(), Some(0));
+	}
 }
@@ -94,11 +94,13 @@ where
 /// * `multilins` - a batch of multilinear polynomials to commit. The multilinears provided may be
 ///     defined over subfields of `F`. They must be in ascending order by the number of variables
 ///     in the packed multilinear (ie. number of variables minus log extension degree).
+/// * `backend` - the computation backend used to encode the committed codeword
 #[tracing::instrument("piop::commit", skip_all)]
-pub fn commit<F, FEncode, P, M, MTScheme, MTProver>(
+pub fn commit<F, FEncode, P, M, MTScheme, MTProver, Backend>(
 	fri_params: &FRIParams<F, FEncode>,
 	merkle_prover: &MTProver,
 	multilins: &[M],
+	backend: &Backend,
 ) -> Result<fri::CommitOutput<P, MTScheme::Digest, MTProver::Committed>, Error>
 where
 	F: BinaryField + ExtensionField<FEncode>,
@@ -107,6 +109,7 @@ where
 	M: MultilinearPoly<P>,
 	MTScheme: MerkleTreeScheme<F>,
 	MTProver: MerkleTreeProver<F, Scheme = MTScheme>,
+	Backend: ComputationBackend,
 {
 	for (i, multilin) in multilins.iter().enumerate() {
 		if multilin.n_vars() < multilin.log_extension_degree() {
@@ -138,10 +141,13 @@ where
 			thread_settings: ThreadingSettings::MultithreadedDefault,
 		},
 	)?;
-	let output =
-		fri::commit_interleaved_with(&rs_code, fri_params, merkle_prover, |message_buffer| {
-			merge_multilins(multilins, message_buffer)
-		})?;
+	let output = fri::commit_interleaved_with(
+		&rs_code,
+		fri_params,
+		merkle_prover,
+		|message_buffer| merge_multilins(multilins, message_buffer),
+		backend,
+	)?;
 
 	Ok(output)
 }
@@ -236,12 +242,14 @@ where
 		codeword,
 		committed,
 		transcript,
+		backend,
 	)?;
 
 	Ok(())
 }
 
-fn prove_interleaved_fri_sumcheck<F, FEncode, P, MTScheme, MTProver, Challenger_>(
+#[allow(clippy::too_many_arguments)]
+fn prove_interleaved_fri_sumcheck<F, FEncode, P, MTScheme, MTProver, Challenger_, Backend>(
 	n_rounds: usize,
 	fri_params: &FRIParams<F, FEncode>,
 	merkle_prover: &MTProver,
@@ -249,6 +257,7 @@ fn prove_interleaved_fri_sumcheck<F, FEncode, P, MTScheme, MTProver, Challenger_
 	codeword: &[P],
 	committed: MTProver::Committed,
 	transcript: &mut ProverTranscript<Challenger_>,
+	backend: &Backend,
 ) -> Result<(), Error>
 where
 	F: TowerField + ExtensionField<FEncode>,
@@ -257,9 +266,15 @@ where
 	MTScheme: MerkleTreeScheme<F, Digest: SerializeBytes>,
 	MTProver: MerkleTreeProver<F, Scheme = MTScheme>,
 	Challenger_: Challenger,
+	Backend: ComputationBackend,
 {
-	let mut fri_prover =
-		FRIFolder::new(fri_params, merkle_prover, P::unpack_scalars(codeword), &committed)?;
+	let mut fri_prover = FRIFolder::new(
+		fri_params,
+		merkle_prover,
+		P::unpack_scalars(codeword),
+		&committed,
+		backend,
+	)?;
 
 	let mut sumcheck_batch_prover = SumcheckBatchProver::new(sumcheck_provers, transcript)?;
 
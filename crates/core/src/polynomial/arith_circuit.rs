@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{fmt::Debug, mem::MaybeUninit, sync::Arc};
+use std::{collections::HashMap, fmt::Debug, mem::MaybeUninit, sync::Arc};
 
 use binius_field::{ExtensionField, Field, PackedField, TowerField};
 use binius_math::{ArithExpr, CompositionPoly, CompositionPolyOS, Error};
@@ -9,56 +9,75 @@ use stackalloc::{
 	stackalloc_uninit,
 };
 
-use super::MultivariatePoly;
+use super::{CompositionKernels, MultivariatePoly};
 
 /// Convert the expression to a sequence of arithmetic operations that can be evaluated in sequence.
+///
+/// Common sub-expressions are evaluated only once, so the resulting steps form a directed acyclic
+/// graph rather than a tree.
 fn circuit_steps_for_expr<F: Field>(
 	expr: &ArithExpr<F>,
 ) -> (Vec<CircuitStep<F>>, CircuitStepArgument<F>) {
 	let mut steps = Vec::new();
+	let mut cache = HashMap::new();
 
-	fn to_circuit_inner<F: Field>(
-		expr: &ArithExpr<F>,
+	fn to_circuit_inner<'a, F: Field>(
+		expr: &'a ArithExpr<F>,
 		result: &mut Vec<CircuitStep<F>>,
+		cache: &mut HashMap<&'a ArithExpr<F>, usize>,
 	) -> CircuitStepArgument<F> {
-		match expr {
-			ArithExpr::Const(value) => CircuitStepArgument::Const(*value),
-			ArithExpr::Var(index) => CircuitStepArgument::Expr(CircuitNode::Var(*index)),
+		if let Some(&slot) = cache.get(expr) {
+			return CircuitStepArgument::Expr(CircuitNode::Slot(slot));
+		}
+
+		let slot = match expr {
+			ArithExpr::Const(value) => return CircuitStepArgument::Const(*value),
+			ArithExpr::Var(index) => return CircuitStepArgument::Expr(CircuitNode::Var(*index)),
 			ArithExpr::Add(left, right) => match &**right {
-				ArithExpr::Mul(mleft, mright) if left.is_composite() => {
+				// The `AddMul` step accumulates into the slot of the left operand, which is only
+				// possible if that slot isn't shared with other sub-expressions.
+				ArithExpr::Mul(mleft, mright)
+					if left.is_composite() && !cache.contains_key(&**left) =>
+				{
 					// Only handling e1 + (e2 * e3), not (e1 * e2) + e3, as latter was not observed in practice
 					// (the former can be enforced by rewriting expression).
-					let left = to_circuit_inner(left, result);
-					let CircuitStepArgument::Expr(CircuitNode::Slot(left)) = left else {
+					let left_arg = to_circuit_inner(left, result, cache);
+					let CircuitStepArgument::Expr(CircuitNode::Slot(left_slot)) = left_arg else {
 						unreachable!("guaranteed by `is_composite` check above")
 					};
-					let mleft = to_circuit_inner(mleft, result);
-					let mright = to_circuit_inner(mright, result);
-					result.push(CircuitStep::AddMul(left, mleft, mright));
-					CircuitStepArgument::Expr(CircuitNode::Slot(left))
+					let mleft = to_circuit_inner(mleft, result, cache);
+					let mright = to_circuit_inner(mright, result, cache);
+					result.push(CircuitStep::AddMul(left_slot, mleft, mright));
+					// The slot no longer holds the value of the left operand.
+					cache.remove(&**left);
+					left_slot
 				}
 				_ => {
-					let left = to_circuit_inner(left, result);
-					let right = to_circuit_inner(right, result);
+					let left = to_circuit_inner(left, result, cache);
+					let right = to_circuit_inner(right, result, cache);
 					result.push(CircuitStep::Add(left, right));
-					CircuitStepArgument::Expr(CircuitNode::Slot(result.len() - 1))
+					result.len() - 1
 				}
 			},
 			ArithExpr::Mul(left, right) => {
-				let left = to_circuit_inner(left, result);
-				let right = to_circuit_inner(right, result);
+				let left = to_circuit_inner(left, result, cache);
+				let right = to_circuit_inner(right, result, cache);
 				result.push(CircuitStep::Mul(left, right));
-				CircuitStepArgument::Expr(CircuitNode::Slot(result.len() - 1))
+				result.len() - 1
 			}
 			ArithExpr::Pow(id, exp) => {
-				let id = to_circuit_inner(id, result);
+				let id = to_circuit_inner(id, result, cache);
 				result.push(CircuitStep::Pow(id, *exp));
-				CircuitStepArgument::Expr(CircuitNode::Slot(result.len() - 1))
+				result.len() - 1
 			}
-		}
+		};
+
+		cache.insert(expr, slot);
+		CircuitStepArgument::Expr(CircuitNode::Slot(slot))
 	}
 
-	let ret = to_circuit_inner(&expr.optimize(), &mut steps);
+	let expr = expr.optimize();
+	let ret = to_circuit_inner(&expr, &mut steps, &mut cache);
 	(steps, ret)
 }
 
@@ -119,6 +138,8 @@ pub struct ArithCircuitPoly<F: Field> {
 	retval: CircuitStepArgument<F>,
 	degree: usize,
 	n_vars: usize,
+	/// Compiled implementations of the expression that take precedence over interpreting the steps
+	kernels: CompositionKernels,
 }
 
 impl<F: Field> ArithCircuitPoly<F> {
@@ -133,6 +154,7 @@ impl<F: Field> ArithCircuitPoly<F> {
 			retval,
 			degree,
 			n_vars,
+			kernels: CompositionKernels::default(),
 		}
 	}
 
@@ -156,8 +178,18 @@ impl<F: Field> ArithCircuitPoly<F> {
 			retval,
 			n_vars,
 			degree,
+			kernels: CompositionKernels::default(),
 		})
 	}
+
+	/// Attaches compiled implementations of the expression, which are used instead of the circuit
+	/// for the packed field types they are registered for.
+	///
+	/// The kernels must evaluate to the same values as the expression, see
+	/// `binius_macros::arith_circuit_poly` for a way to generate them.
+	pub fn with_kernels(self, kernels: CompositionKernels) -> Self {
+		Self { kernels, ..self }
+	}
 }
 
 impl<F: TowerField> CompositionPoly<F> for ArithCircuitPoly<F> {
@@ -183,6 +215,9 @@ impl<F: TowerField> CompositionPoly<F> for ArithCircuitPoly<F> {
 				expected: self.n_vars,
 			});
 		}
+		if let Some(result) = self.kernels.try_evaluate(query) {
+			return result;
+		}
 
 		fn write_result<T>(target: &mut [MaybeUninit<T>], value: T) {
 			// Safety: The index is guaranteed to be within bounds because
@@ -250,6 +285,9 @@ impl<F: TowerField> CompositionPoly<F> for ArithCircuitPoly<F> {
 		if batch_query.iter().any(|row| row.len() != row_len) {
 			return Err(Error::BatchEvaluateSizeMismatch);
 		}
+		if let Some(result) = self.kernels.try_batch_evaluate(batch_query, evals) {
+			return result;
+		}
 
 		// `stackalloc_uninit` throws a debug assert if `size` is 0, so set minimum of 1.
 		stackalloc_uninit::<P, (), _>((self.steps.len() * row_len).max(1), |sparse_evals| {
@@ -468,6 +506,7 @@ mod tests {
 	};
 	use binius_math::CompositionPolyOS;
 	use binius_utils::felts;
+	use rand::{rngs::StdRng, SeedableRng};
 
 	use super::*;
 
@@ -764,4 +803,35 @@ mod tests {
 			P::from_scalars(felts!(BinaryField16b[0, 1, 1, 1, 20, 152, 41, 170])),
 		);
 	}
+
+	#[test]
+	fn test_common_subexpressions() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		let var = ArithExpr::<F>::Var;
+
+		// (x0 * x1 + x2)^2 * x3 + (x0 * x1 + x2) * x1 + (x0 * x3 + x1 * x2) * (x0 * x3)
+		let shared = var(0) * var(1) + var(2);
+		let expr = shared.clone().pow(2) * var(3)
+			+ shared * var(1)
+			+ (var(0) * var(3) + var(1) * var(2)) * (var(0) * var(3));
+		let circuit = ArithCircuitPoly::<F>::new(expr);
+
+		// The shared sub-expression `x0 * x1 + x2` is evaluated once, while `x0 * x3` is evaluated
+		// a second time because its slot is accumulated into by `x0 * x3 + x1 * x2`.
+		assert_eq!(circuit.steps.len(), 9);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let query = (0..4).map(|_| P::random(&mut rng)).collect::<Vec<_>>();
+		let [x0, x1, x2, x3] = query.clone().try_into().unwrap();
+		let expected =
+			(x0 * x1 + x2).square() * x3 + (x0 * x1 + x2) * x1 + (x0 * x3 + x1 * x2) * (x0 * x3);
+		assert_eq!(CompositionPoly::evaluate(&circuit, &query).unwrap(), expected);
+
+		let batch_query = query.iter().map(std::slice::from_ref).collect::<Vec<_>>();
+		let mut batch_result = [P::zero()];
+		CompositionPoly::batch_evaluate(&circuit, &batch_query, &mut batch_result).unwrap();
+		assert_eq!(batch_result, [expected]);
+	}
 }
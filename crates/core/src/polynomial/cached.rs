@@ -5,10 +5,12 @@ use std::{
 	collections::HashMap,
 	fmt::Debug,
 	marker::PhantomData,
+	sync::Arc,
 };
 
 use binius_field::{ExtensionField, Field, PackedField};
 use binius_math::{ArithExpr, CompositionPoly, CompositionPolyOS, Error};
+use stackalloc::stackalloc_with_iter;
 
 /// Cached composition poly wrapper.
 ///
@@ -17,7 +19,8 @@ use binius_math::{ArithExpr, CompositionPoly, CompositionPolyOS, Error};
 #[derive(Default, Debug)]
 pub struct CachedPoly<F: Field, Inner: CompositionPoly<F>> {
 	inner: Inner,
-	cache: PackedFieldCache<F>,
+	cache: CompositionKernels,
+	_marker: PhantomData<F>,
 }
 
 impl<F: Field, Inner: CompositionPoly<F>> CachedPoly<F, Inner> {
@@ -26,6 +29,7 @@ impl<F: Field, Inner: CompositionPoly<F>> CachedPoly<F, Inner> {
 		Self {
 			inner,
 			cache: Default::default(),
+			_marker: PhantomData,
 		}
 	}
 
@@ -36,6 +40,11 @@ impl<F: Field, Inner: CompositionPoly<F>> CachedPoly<F, Inner> {
 	) {
 		self.cache.register(composition);
 	}
+
+	/// The efficient implementations registered so far.
+	pub const fn kernels(&self) -> &CompositionKernels {
+		&self.cache
+	}
 }
 
 impl<F: Field, Inner: CompositionPoly<F>> CompositionPoly<F> for CachedPoly<F, Inner> {
@@ -104,63 +113,121 @@ impl<F: Field, Inner: CompositionPoly<F>, P: PackedField<Scalar: ExtensionField<
 	}
 }
 
-#[derive(Default)]
-struct PackedFieldCache<F> {
+/// Efficient implementations of a composition polynomial for some known set of packed field types.
+///
+/// The kernels are type-erased and keyed by the packed field type, so that they can be attached to
+/// runtime representations of the composition, like [`super::ArithCircuitPoly`]. Cloning is cheap.
+#[derive(Default, Clone)]
+pub struct CompositionKernels {
 	/// Map from the packed field type 'P to the efficient implementation of the composition polynomial
 	/// with actual type `Box<dyn CompositionPolyOS<P>>`.
-	entries: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
-	_pd: PhantomData<F>,
+	entries: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+	/// Positions of the variables of the kernels in the queries they are evaluated on. `None` stands
+	/// for the identity mapping.
+	var_positions: Option<Arc<[usize]>>,
 }
 
-impl<F: Field> PackedFieldCache<F> {
+impl CompositionKernels {
 	/// Register efficient implementations for the `P` packed field type in the cache.
-	fn register<P: PackedField<Scalar: ExtensionField<F>>>(
-		&mut self,
-		composition: impl CompositionPolyOS<P> + 'static,
-	) {
+	pub fn register<P: PackedField>(&mut self, composition: impl CompositionPolyOS<P> + 'static) {
 		let boxed_composition = Box::new(composition) as Box<dyn CompositionPolyOS<P>>;
 		self.entries
-			.insert(TypeId::of::<P>(), Box::new(boxed_composition) as Box<dyn Any + Send + Sync>);
+			.insert(TypeId::of::<P>(), Arc::new(boxed_composition) as Arc<dyn Any + Send + Sync>);
+	}
+
+	/// Returns true if no kernels are registered.
+	pub fn is_empty(&self) -> bool {
+		self.entries.is_empty()
+	}
+
+	/// Returns the kernels for a query in which the `i`-th variable of the kernels is located at
+	/// position `positions[i]`.
+	///
+	/// This is the counterpart of [`ArithExpr::remap_vars`] for the compiled kernels.
+	pub fn remap_vars(self, positions: &[usize]) -> Self {
+		if self.is_empty() {
+			return self;
+		}
+		let var_positions = match &self.var_positions {
+			Some(var_positions) => var_positions.iter().map(|&i| positions[i]).collect(),
+			None => positions.into(),
+		};
+		Self {
+			var_positions: Some(var_positions),
+			..self
+		}
+	}
+
+	fn get<P: PackedField>(&self) -> Option<&dyn CompositionPolyOS<P>> {
+		if self.entries.is_empty() {
+			return None;
+		}
+		let entry = self.entries.get(&TypeId::of::<P>())?;
+		let entry = entry
+			.downcast_ref::<Box<dyn CompositionPolyOS<P>>>()
+			.expect("cast must succeed");
+		Some(entry.as_ref())
 	}
 
 	/// Try to evaluate the expression using the efficient implementation for the `P` packed field type.
 	/// If no implementation is found, return None.
-	fn try_evaluate<P: PackedField<Scalar: ExtensionField<F>>>(
-		&self,
-		query: &[P],
-	) -> Option<Result<P, Error>> {
-		if let Some(entry) = self.entries.get(&TypeId::of::<P>()) {
-			let entry = entry
-				.downcast_ref::<Box<dyn CompositionPolyOS<P>>>()
-				.expect("cast must succeed");
-			Some(entry.evaluate(query))
-		} else {
-			None
-		}
+	pub fn try_evaluate<P: PackedField>(&self, query: &[P]) -> Option<Result<P, Error>> {
+		let kernel = self.get::<P>()?;
+		let result = match &self.var_positions {
+			Some(var_positions) => {
+				if let Err(err) = check_var_positions(var_positions, query.len()) {
+					return Some(Err(err));
+				}
+				stackalloc_with_iter(
+					var_positions.len(),
+					var_positions.iter().map(|&i| query[i]),
+					|query| kernel.evaluate(query),
+				)
+			}
+			None => kernel.evaluate(query),
+		};
+		Some(result)
 	}
 
 	/// Try to batch evaluate the expression using the efficient implementation for the `P` packed field type.
 	/// If no implementation is found, return None.
-	fn try_batch_evaluate<P: PackedField<Scalar: ExtensionField<F>>>(
+	pub fn try_batch_evaluate<P: PackedField>(
 		&self,
 		batch_query: &[&[P]],
 		evals: &mut [P],
 	) -> Option<Result<(), Error>> {
-		if let Some(entry) = self.entries.get(&TypeId::of::<P>()) {
-			let entry = entry
-				.downcast_ref::<Box<dyn CompositionPolyOS<P>>>()
-				.expect("cast must succeed");
-			Some(entry.batch_evaluate(batch_query, evals))
-		} else {
-			None
-		}
+		let kernel = self.get::<P>()?;
+		let result = match &self.var_positions {
+			Some(var_positions) => {
+				if let Err(err) = check_var_positions(var_positions, batch_query.len()) {
+					return Some(Err(err));
+				}
+				stackalloc_with_iter(
+					var_positions.len(),
+					var_positions.iter().map(|&i| batch_query[i]),
+					|batch_query| kernel.batch_evaluate(batch_query, evals),
+				)
+			}
+			None => kernel.batch_evaluate(batch_query, evals),
+		};
+		Some(result)
 	}
 }
 
-impl<F: Field> Debug for PackedFieldCache<F> {
+fn check_var_positions(var_positions: &[usize], query_len: usize) -> Result<(), Error> {
+	if var_positions.iter().any(|&i| i >= query_len) {
+		return Err(Error::IncorrectQuerySize {
+			expected: var_positions.len(),
+		});
+	}
+	Ok(())
+}
+
+impl Debug for CompositionKernels {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		f.debug_struct("PackedFieldCache")
+		f.debug_struct("CompositionKernels")
 			.field("cached_implementations", &self.entries.len())
+			.field("var_positions", &self.var_positions)
 			.finish()
 	}
 }
@@ -269,4 +336,28 @@ mod tests {
 		let batch_query = batch_query.iter().map(|q| q.as_slice()).collect::<Vec<_>>();
 		ensure_equal_batch_eval_results(&circuit, &cached_circuit, &batch_query);
 	}
+
+	#[test]
+	fn test_remapped_kernels() {
+		type P = PackedBinaryField16x8b;
+
+		let expr = ArithExpr::Const(BinaryField8b::new(123)) + ArithExpr::Var(0);
+		let mut kernels = CompositionKernels::default();
+		kernels.register::<P>(AddComposition);
+
+		// The kernel reads the variable at position 2 of a query of 3 variables
+		let remapped_expr = expr.remap_vars(&[2]).unwrap();
+		let circuit = ArithCircuitPoly::<BinaryField8b>::with_n_vars(3, remapped_expr).unwrap();
+		let compiled_circuit = circuit.clone().with_kernels(kernels.remap_vars(&[2]));
+
+		let batch_query = (0..3)
+			.map(|i| {
+				(0..16)
+					.map(|j| P::broadcast(BinaryField8b::new(i * 16 + j)))
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+		let batch_query = batch_query.iter().map(|q| q.as_slice()).collect::<Vec<_>>();
+		ensure_equal_batch_eval_results(&circuit, &compiled_circuit, &batch_query);
+	}
 }
@@ -35,6 +35,8 @@ enum EvalcheckNumerics {
 	Repeating,
 	LinearCombination,
 	ZeroPadded,
+	Interleaved,
+	ElementwiseProduct,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -48,6 +50,9 @@ pub enum EvalcheckProof<F: Field> {
 		subproofs: Vec<(F, EvalcheckProof<F>)>,
 	},
 	ZeroPadded(F, Box<EvalcheckProof<F>>),
+	/// Evaluations of the two interleaved oracles and their subproofs.
+	Interleaved(Box<[(F, EvalcheckProof<F>); 2]>),
+	ElementwiseProduct,
 }
 
 impl<F: Field> EvalcheckProof<F> {
@@ -67,6 +72,10 @@ impl<F: Field> EvalcheckProof<F> {
 			Self::ZeroPadded(eval, proof) => {
 				EvalcheckProof::ZeroPadded(eval.into(), Box::new(proof.isomorphic()))
 			}
+			Self::Interleaved(subproofs) => EvalcheckProof::Interleaved(Box::new(
+				subproofs.map(|(eval, proof)| (eval.into(), proof.isomorphic())),
+			)),
+			Self::ElementwiseProduct => EvalcheckProof::ElementwiseProduct,
 		}
 	}
 }
@@ -81,6 +90,8 @@ impl EvalcheckNumerics {
 			5 => Ok(Self::Repeating),
 			6 => Ok(Self::LinearCombination),
 			7 => Ok(Self::ZeroPadded),
+			8 => Ok(Self::Interleaved),
+			9 => Ok(Self::ElementwiseProduct),
 			_ => Err(Error::EvalcheckSerializationError),
 		}
 	}
@@ -122,6 +133,16 @@ pub fn serialize_evalcheck_proof<B: BufMut, F: TowerField>(
 			transcript.write_scalar(*val);
 			serialize_evalcheck_proof(transcript, subproof);
 		}
+		EvalcheckProof::Interleaved(subproofs) => {
+			transcript.write_bytes(&[EvalcheckNumerics::Interleaved as u8]);
+			for (scalar, subproof) in subproofs.iter() {
+				transcript.write_scalar(*scalar);
+				serialize_evalcheck_proof(transcript, subproof);
+			}
+		}
+		EvalcheckProof::ElementwiseProduct => {
+			transcript.write_bytes(&[EvalcheckNumerics::ElementwiseProduct as u8]);
+		}
 	}
 }
 
@@ -158,6 +179,17 @@ pub fn deserialize_evalcheck_proof<B: Buf, F: TowerField>(
 			let subproof = deserialize_evalcheck_proof(transcript)?;
 			Ok(EvalcheckProof::ZeroPadded(scalar, Box::new(subproof)))
 		}
+		EvalcheckNumerics::Interleaved => {
+			let mut read_subproof = || -> Result<_, Error> {
+				let scalar = transcript.read_scalar()?;
+				let subproof = deserialize_evalcheck_proof(transcript)?;
+				Ok((scalar, subproof))
+			};
+			let even = read_subproof()?;
+			let odd = read_subproof()?;
+			Ok(EvalcheckProof::Interleaved(Box::new([even, odd])))
+		}
+		EvalcheckNumerics::ElementwiseProduct => Ok(EvalcheckProof::ElementwiseProduct),
 	}
 }
 
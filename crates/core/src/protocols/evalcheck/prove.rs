@@ -24,7 +24,8 @@ use crate::{
 		MultilinearPolyOracle, MultilinearPolyVariant, OracleId, ProjectionVariant,
 	},
 	protocols::evalcheck::subclaims::{
-		packed_sumcheck_meta, process_packed_sumcheck, process_shifted_sumcheck,
+		elementwise_product_sumcheck_meta, packed_sumcheck_meta,
+		process_elementwise_product_sumcheck, process_packed_sumcheck, process_shifted_sumcheck,
 		shifted_sumcheck_meta,
 	},
 	witness::MultilinearExtensionIndex,
@@ -56,6 +57,7 @@ where
 	claims_without_evals: Vec<(MultilinearPolyOracle<F>, EvalPoint<F>)>,
 	claims_without_evals_dedup: EvalPointOracleIdMap<(), F>,
 	projected_bivariate_claims: Vec<EvalcheckMultilinearClaim<F>>,
+	elementwise_product_claims: Vec<EvalcheckMultilinearClaim<F>>,
 
 	new_sumchecks_constraints: Vec<ConstraintSetBuilder<F>>,
 	memoized_queries: MemoizedQueries<PackedType<U, F>, Backend>,
@@ -87,12 +89,18 @@ where
 			claims_without_evals: Vec::new(),
 			claims_without_evals_dedup: EvalPointOracleIdMap::new(),
 			projected_bivariate_claims: Vec::new(),
+			elementwise_product_claims: Vec::new(),
 			memoized_queries: MemoizedQueries::new(),
 			backend,
 			incomplete_proof_claims: EvalPointOracleIdMap::new(),
 		}
 	}
 
+	/// Returns the memoized tensor product expansions to the backend for reuse.
+	pub fn release_memoized_queries(&mut self) {
+		self.memoized_queries.release(self.backend);
+	}
+
 	/// A helper method to move out sumcheck constraints
 	pub fn take_new_sumchecks_constraints(&mut self) -> Result<Vec<ConstraintSet<F>>, OracleError> {
 		self.new_sumchecks_constraints
@@ -117,6 +125,9 @@ where
 	///  * they are always a product of two multilins (composition polynomial is `BivariateProduct`)
 	///  * one multilin (the multiplier) is transparent (`shift_ind`, `eq_ind`, or tower basis)
 	///  * other multilin is a projection of one of the evalcheck claim multilins to its first variables
+	///
+	/// except for the ones reducing evaluations of elementwise products, which multiply both factors
+	/// with the `eq_ind` at the evaluation point.
 	#[instrument(skip_all, name = "EvalcheckProver::prove", level = "debug")]
 	pub fn prove(
 		&mut self,
@@ -209,7 +220,7 @@ where
 			.cloned()
 			.for_each(|claim| self.collect_projected_committed(claim));
 
-		// Step 3: Process projected_bivariate_claims
+		// Step 3: Process projected_bivariate_claims, followed by elementwise_product_claims
 
 		let projected_bivariate_metas = self
 			.projected_bivariate_claims
@@ -233,6 +244,10 @@ where
 			self.process_sumcheck(claim, meta, projected)?;
 		}
 
+		for claim in std::mem::take(&mut self.elementwise_product_claims) {
+			self.process_elementwise_product(claim)?;
+		}
+
 		// Step 4: Find and return the proofs of the original claims.
 
 		Ok(evalcheck_claims
@@ -383,6 +398,24 @@ where
 				self.incomplete_proof_claims
 					.insert(multilinear_id, eval_point, evalcheck_claim);
 			}
+
+			MultilinearPolyVariant::Interleaved(id0, id1) => {
+				let inner_eval_point = eval_point.slice(1..eval_point.len());
+				for id in [id0, id1] {
+					self.claims_without_evals
+						.push((self.oracles.oracle(id), inner_eval_point.clone()));
+				}
+				self.incomplete_proof_claims
+					.insert(multilinear_id, eval_point, evalcheck_claim);
+			}
+
+			MultilinearPolyVariant::ElementwiseProduct(..) => {
+				self.finalized_proofs.insert(
+					multilinear_id,
+					eval_point,
+					(eval, EvalcheckProof::ElementwiseProduct),
+				);
+			}
 		};
 	}
 
@@ -460,6 +493,22 @@ where
 						);
 					})
 			}
+
+			MultilinearPolyVariant::Interleaved(id0, id1) => {
+				let inner_eval_point = &evalcheck_claim.eval_point[1..];
+				let subproof = |id| {
+					self.finalized_proofs
+						.get(id, inner_eval_point)
+						.map(|(eval, subproof)| (*eval, subproof.clone()))
+				};
+				subproof(id0).zip(subproof(id1)).map(|subproofs| {
+					self.finalized_proofs.insert(
+						evalcheck_claim.id,
+						eval_point,
+						(eval, EvalcheckProof::Interleaved(Box::new(subproofs.into()))),
+					);
+				})
+			}
 			_ => unreachable!(),
 		};
 		res.is_some()
@@ -517,6 +566,24 @@ where
 			MultilinearPolyVariant::Shifted { .. } | MultilinearPolyVariant::Packed { .. } => {
 				self.projected_bivariate_claims.push(evalcheck_claim)
 			}
+			MultilinearPolyVariant::ElementwiseProduct(..) => {
+				self.elementwise_product_claims.push(evalcheck_claim)
+			}
+			MultilinearPolyVariant::Interleaved(id0, id1) => {
+				let inner_eval_point = eval_point.slice(1..eval_point.len());
+				for id in [id0, id1] {
+					let (eval, _) = self
+						.finalized_proofs
+						.get(id, &inner_eval_point)
+						.expect("finalized_proofs contains all the proofs");
+					let subclaim = EvalcheckMultilinearClaim {
+						id,
+						eval_point: inner_eval_point.clone(),
+						eval: *eval,
+					};
+					self.collect_projected_committed(subclaim);
+				}
+			}
 			MultilinearPolyVariant::LinearCombination(linear_combination) => {
 				for id in linear_combination.polys() {
 					let (eval, _) = self
@@ -606,6 +673,40 @@ where
 		Ok(())
 	}
 
+	fn process_elementwise_product(
+		&mut self,
+		evalcheck_claim: EvalcheckMultilinearClaim<F>,
+	) -> Result<(), Error> {
+		let EvalcheckMultilinearClaim {
+			id,
+			eval_point,
+			eval,
+		} = evalcheck_claim;
+
+		let MultilinearPolyVariant::ElementwiseProduct(id0, id1) = self.oracles.oracle(id).variant
+		else {
+			unreachable!("elementwise_product_claims only contains elementwise product claims");
+		};
+
+		let meta = elementwise_product_sumcheck_meta(self.oracles, [id0, id1], &eval_point)?;
+		let eq_ind = MultilinearExtension::new(
+			eval_point.len(),
+			self.memoized_queries
+				.full_query(&eval_point, self.backend)?
+				.expansion()
+				.to_vec(),
+		)?;
+
+		process_elementwise_product_sumcheck(
+			meta,
+			&eval_point,
+			eval,
+			self.witness_index,
+			&mut self.new_sumchecks_constraints,
+			eq_ind,
+		)
+	}
+
 	fn make_new_eval_claim(
 		oracle_id: OracleId,
 		eval_point: EvalPoint<F>,
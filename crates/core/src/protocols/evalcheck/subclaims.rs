@@ -8,6 +8,9 @@
 //!  * they are always a product of two multilins (composition polynomial is `BivariateProduct`)
 //!  * one multilin (the multiplier) is transparent (`shift_ind`, `eq_ind`, or tower basis)
 //!  * other multilin is a projection of one of the evalcheck claim multilins to its first variables
+//!
+//! Elementwise product virtual polynomials are reduced with a trivariate sumcheck instead, over the
+//! two factors and the `eq_ind` at the evaluation point.
 
 use std::collections::HashSet;
 
@@ -37,7 +40,9 @@ use crate::{
 		Error as SumcheckError,
 	},
 	transcript::ProverTranscript,
-	transparent::{shift_ind::ShiftIndPartialEval, tower_basis::TowerBasis},
+	transparent::{
+		eq_ind::EqIndPartialEval, shift_ind::ShiftIndPartialEval, tower_basis::TowerBasis,
+	},
 	witness::{MultilinearExtensionIndex, MultilinearWitness},
 };
 
@@ -145,7 +150,12 @@ fn add_bivariate_sumcheck_to_constraint_builder<F: Field>(
 	eval: F,
 ) {
 	let bivariate_product = ArithExpr::Var(0) * ArithExpr::Var(1);
-	constraint_builder.add_sumcheck(meta.oracle_ids(), bivariate_product, eval);
+	constraint_builder.add_sumcheck(
+		"bivariate_product",
+		meta.oracle_ids(),
+		bivariate_product,
+		eval,
+	);
 }
 
 /// Creates bivariate witness and adds them to the witness index, and add bivariate sumcheck constraint to the [`ConstraintSetBuilder`]
@@ -183,6 +193,82 @@ where
 	Ok(())
 }
 
+/// Create the `eq_ind` oracle for the sumcheck reducing an evaluation of an elementwise product.
+pub fn elementwise_product_sumcheck_meta<F: TowerField>(
+	oracles: &mut MultilinearOracleSet<F>,
+	factor_ids: [OracleId; 2],
+	eval_point: &[F],
+) -> Result<ElementwiseProductMeta, Error> {
+	let eq_ind = EqIndPartialEval::new(eval_point.len(), eval_point.to_vec())?;
+	let eq_ind_id = oracles.add_transparent(eq_ind)?;
+
+	Ok(ElementwiseProductMeta {
+		factor_ids,
+		eq_ind_id,
+	})
+}
+
+/// Adds the `eq_ind` witness to the witness index, and adds the elementwise product sumcheck
+/// constraint to the [`ConstraintSetBuilder`]
+pub fn process_elementwise_product_sumcheck<U, F>(
+	meta: ElementwiseProductMeta,
+	eval_point: &[F],
+	eval: F,
+	witness_index: &mut MultilinearExtensionIndex<U, F>,
+	constraint_builders: &mut Vec<ConstraintSetBuilder<F>>,
+	eq_ind: MultilinearExtension<PackedType<U, F>>,
+) -> Result<(), Error>
+where
+	U: UnderlierType + PackScalar<F>,
+	F: TowerField,
+{
+	if !witness_index.has(meta.eq_ind_id) {
+		witness_index.update_multilin_poly(vec![(
+			meta.eq_ind_id,
+			MLEDirectAdapter::from(eq_ind).upcast_arc_dyn(),
+		)])?;
+	}
+	add_elementwise_product_sumcheck_to_constraints(
+		meta,
+		constraint_builders,
+		eval_point.len(),
+		eval,
+	);
+	Ok(())
+}
+
+pub fn add_elementwise_product_sumcheck_to_constraints<F: Field>(
+	meta: ElementwiseProductMeta,
+	constraint_builders: &mut Vec<ConstraintSetBuilder<F>>,
+	n_vars: usize,
+	eval: F,
+) {
+	if n_vars > constraint_builders.len() {
+		constraint_builders.resize_with(n_vars, || ConstraintSetBuilder::new());
+	}
+
+	let trivariate_product = ArithExpr::Var(0) * ArithExpr::Var(1) * ArithExpr::Var(2);
+	constraint_builders[n_vars - 1].add_sumcheck(
+		"elementwise_product",
+		meta.oracle_ids(),
+		trivariate_product,
+		eval,
+	);
+}
+
+#[derive(Clone, Copy)]
+pub struct ElementwiseProductMeta {
+	factor_ids: [OracleId; 2],
+	eq_ind_id: OracleId,
+}
+
+impl ElementwiseProductMeta {
+	pub const fn oracle_ids(&self) -> [OracleId; 3] {
+		let [factor_0, factor_1] = self.factor_ids;
+		[factor_0, factor_1, self.eq_ind_id]
+	}
+}
+
 #[derive(Clone, Copy)]
 pub struct ProjectedBivariateMeta {
 	inner_id: OracleId,
@@ -395,6 +481,13 @@ impl<P: PackedField, Backend: ComputationBackend> MemoizedQueries<P, Backend> {
 
 		Ok(())
 	}
+
+	/// Returns the memory of all the memoized queries to the backend for reuse.
+	pub fn release(&mut self, backend: &Backend) {
+		for (_, query) in self.memo.drain(..) {
+			backend.release_hal_slice(query.into_expansion());
+		}
+	}
 }
 
 type SumcheckProofEvalcheckClaims<F> = Vec<EvalcheckMultilinearClaim<F>>;
@@ -454,6 +454,138 @@ fn test_evalcheck_zero_padded() {
 	verifier_state.verify(vec![claim], proof).unwrap();
 }
 
+fn random_committed_witness(
+	rng: &mut StdRng,
+	n_vars: usize,
+) -> MultilinearExtension<PackedBinaryField1x128b> {
+	let values = repeat_with(|| PackedBinaryField1x128b::random(&mut *rng))
+		.take(1 << n_vars)
+		.collect();
+	MultilinearExtension::from_values(values).unwrap()
+}
+
+#[test]
+fn test_evalcheck_interleaved() {
+	let n_vars = 5;
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let mut oracles = MultilinearOracleSet::<FExtension>::new();
+	let even_id = oracles.add_committed(n_vars, FExtension::TOWER_LEVEL);
+	let odd_id = oracles.add_committed(n_vars, FExtension::TOWER_LEVEL);
+	let interleaved_id = oracles.add_interleaved(even_id, odd_id).unwrap();
+	assert_eq!(oracles.n_vars(interleaved_id), n_vars + 1);
+
+	let even = random_committed_witness(&mut rng, n_vars);
+	let odd = random_committed_witness(&mut rng, n_vars);
+	let interleaved_values = even
+		.evals()
+		.iter()
+		.zip(odd.evals())
+		.flat_map(|(&even, &odd)| [even, odd])
+		.collect();
+	let interleaved = MultilinearExtension::from_values(interleaved_values).unwrap();
+
+	let eval_point = repeat_with(|| <FExtension as Field>::random(&mut rng))
+		.take(n_vars + 1)
+		.collect::<Vec<_>>();
+	let query = MultilinearQuery::<PackedBinaryField1x128b>::expand(&eval_point);
+	let eval = interleaved.evaluate(&query).unwrap();
+
+	let mut witness_index = MultilinearExtensionIndex::<U, FExtension>::new();
+	witness_index
+		.update_multilin_poly(vec![
+			(even_id, even.specialize_arc_dyn()),
+			(odd_id, odd.specialize_arc_dyn()),
+			(interleaved_id, interleaved.specialize_arc_dyn()),
+		])
+		.unwrap();
+
+	let claim = EvalcheckMultilinearClaim {
+		id: interleaved_id,
+		eval_point: eval_point.into(),
+		eval,
+	};
+
+	let backend = make_portable_backend();
+	let mut prover_state = EvalcheckProver::new(&mut oracles, &mut witness_index, &backend);
+	let proof = prover_state.prove(vec![claim.clone()]).unwrap();
+	assert_matches!(proof[0], EvalcheckProof::Interleaved(_));
+	assert_eq!(prover_state.committed_eval_claims().len(), 2);
+
+	let mut verifier_state = EvalcheckVerifier::<FExtension>::new(&mut oracles);
+	verifier_state
+		.verify(vec![claim.clone()], proof.clone())
+		.unwrap();
+	assert_eq!(verifier_state.committed_eval_claims().len(), 2);
+
+	let wrong_claim = EvalcheckMultilinearClaim {
+		eval: claim.eval + FExtension::ONE,
+		..claim
+	};
+	assert!(verifier_state.verify(vec![wrong_claim], proof).is_err());
+}
+
+#[test]
+fn test_evalcheck_elementwise_product() {
+	let n_vars = 5;
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let mut oracles = MultilinearOracleSet::<FExtension>::new();
+	let [lhs_id, rhs_id] = oracles.add_committed_multiple(n_vars, FExtension::TOWER_LEVEL);
+	let product_id = oracles.add_elementwise_product(lhs_id, rhs_id).unwrap();
+
+	let lhs = random_committed_witness(&mut rng, n_vars);
+	let rhs = random_committed_witness(&mut rng, n_vars);
+	let product_values = lhs
+		.evals()
+		.iter()
+		.zip(rhs.evals())
+		.map(|(&lhs, &rhs)| lhs * rhs)
+		.collect();
+	let product = MultilinearExtension::from_values(product_values).unwrap();
+
+	let eval_point = repeat_with(|| <FExtension as Field>::random(&mut rng))
+		.take(n_vars)
+		.collect::<Vec<_>>();
+	let query = MultilinearQuery::<PackedBinaryField1x128b>::expand(&eval_point);
+	let eval = product.evaluate(&query).unwrap();
+
+	let mut witness_index = MultilinearExtensionIndex::<U, FExtension>::new();
+	witness_index
+		.update_multilin_poly(vec![
+			(lhs_id, lhs.specialize_arc_dyn()),
+			(rhs_id, rhs.specialize_arc_dyn()),
+			(product_id, product.specialize_arc_dyn()),
+		])
+		.unwrap();
+
+	let claim = EvalcheckMultilinearClaim {
+		id: product_id,
+		eval_point: eval_point.into(),
+		eval,
+	};
+
+	let mut verifier_oracles = oracles.clone();
+
+	let backend = make_portable_backend();
+	let mut prover_state = EvalcheckProver::new(&mut oracles, &mut witness_index, &backend);
+	let proof = prover_state.prove(vec![claim.clone()]).unwrap();
+	assert_matches!(proof[0], EvalcheckProof::ElementwiseProduct);
+	let prover_constraints = prover_state.take_new_sumchecks_constraints().unwrap();
+
+	let mut verifier_state = EvalcheckVerifier::<FExtension>::new(&mut verifier_oracles);
+	verifier_state.verify(vec![claim], proof).unwrap();
+	let verifier_constraints = verifier_state.take_new_sumcheck_constraints().unwrap();
+
+	// The evaluation is reduced to a sumcheck over both factors and the eq indicator
+	assert_eq!(prover_constraints.len(), 1);
+	assert_eq!(verifier_constraints.len(), 1);
+	assert_eq!(prover_constraints[0].n_vars, n_vars);
+	assert_eq!(prover_constraints[0].oracle_ids, verifier_constraints[0].oracle_ids);
+	assert_eq!(prover_constraints[0].oracle_ids.len(), 3);
+	assert!(witness_index.has(prover_constraints[0].oracle_ids[2]));
+}
+
 // Test evalcheck serialization
 #[test]
 fn test_evalcheck_serialization() {
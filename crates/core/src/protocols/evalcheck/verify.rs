@@ -11,7 +11,8 @@ use super::{
 	error::{Error, VerificationError},
 	evalcheck::{EvalcheckMultilinearClaim, EvalcheckProof},
 	subclaims::{
-		add_bivariate_sumcheck_to_constraints, packed_sumcheck_meta, shifted_sumcheck_meta,
+		add_bivariate_sumcheck_to_constraints, add_elementwise_product_sumcheck_to_constraints,
+		elementwise_product_sumcheck_meta, packed_sumcheck_meta, shifted_sumcheck_meta,
 	},
 };
 use crate::oracle::{
@@ -35,6 +36,7 @@ where
 	committed_eval_claims: Vec<EvalcheckMultilinearClaim<F>>,
 
 	new_sumcheck_constraints: Vec<ConstraintSetBuilder<F>>,
+	elementwise_product_claims: Vec<EvalcheckMultilinearClaim<F>>,
 }
 
 impl<'a, F: TowerField> EvalcheckVerifier<'a, F> {
@@ -46,6 +48,7 @@ impl<'a, F: TowerField> EvalcheckVerifier<'a, F> {
 			oracles,
 			committed_eval_claims: Vec::new(),
 			new_sumcheck_constraints: Vec::new(),
+			elementwise_product_claims: Vec::new(),
 		}
 	}
 
@@ -75,6 +78,24 @@ impl<'a, F: TowerField> EvalcheckVerifier<'a, F> {
 			self.verify_multilinear(claim, proof)?;
 		}
 
+		// The prover reduces the elementwise product claims after all the bivariate ones, so that
+		// the new oracles are added to the set in the same order on both sides.
+		for claim in mem::take(&mut self.elementwise_product_claims) {
+			let MultilinearPolyVariant::ElementwiseProduct(id0, id1) =
+				self.oracles.oracle(claim.id).variant
+			else {
+				unreachable!("only elementwise product claims are deferred");
+			};
+			let meta =
+				elementwise_product_sumcheck_meta(self.oracles, [id0, id1], &claim.eval_point)?;
+			add_elementwise_product_sumcheck_to_constraints(
+				meta,
+				&mut self.new_sumcheck_constraints,
+				claim.eval_point.len(),
+				claim.eval,
+			);
+		}
+
 		Ok(())
 	}
 
@@ -216,6 +237,43 @@ impl<'a, F: TowerField> EvalcheckVerifier<'a, F> {
 						self.verify_multilinear_subclaim(eval, subproof, suboracle_id, &eval_point)
 					})?;
 			}
+			MultilinearPolyVariant::Interleaved(id0, id1) => {
+				let subproofs = match evalcheck_proof {
+					EvalcheckProof::Interleaved(subproofs) => subproofs,
+					_ => return Err(VerificationError::SubproofMismatch.into()),
+				};
+				let [(even_eval, even_subproof), (odd_eval, odd_subproof)] = *subproofs;
+
+				let (&selector, subclaim_eval_point) = eval_point
+					.split_first()
+					.expect("interleaved oracles have at least one variable");
+				if extrapolate_line_scalar::<F, F>(even_eval, odd_eval, selector) != eval {
+					return Err(VerificationError::IncorrectEvaluation(multilinear.label()).into());
+				}
+
+				self.verify_multilinear_subclaim(
+					even_eval,
+					even_subproof,
+					id0,
+					subclaim_eval_point,
+				)?;
+				self.verify_multilinear_subclaim(odd_eval, odd_subproof, id1, subclaim_eval_point)?;
+			}
+
+			MultilinearPolyVariant::ElementwiseProduct(..) => {
+				match evalcheck_proof {
+					EvalcheckProof::ElementwiseProduct => {}
+					_ => return Err(VerificationError::SubproofMismatch.into()),
+				};
+
+				self.elementwise_product_claims
+					.push(EvalcheckMultilinearClaim {
+						id,
+						eval_point,
+						eval,
+					});
+			}
+
 			MultilinearPolyVariant::ZeroPadded(inner) => {
 				let (inner_eval, subproof) = match evalcheck_proof {
 					EvalcheckProof::ZeroPadded(eval, subproof) => (eval, subproof),
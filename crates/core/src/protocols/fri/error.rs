@@ -36,6 +36,8 @@ pub enum Error {
 	Verification(#[from] VerificationError),
 	#[error("transcript error: {0}")]
 	TranscriptError(#[from] transcript::Error),
+	#[error("HAL error: {0}")]
+	HalError(#[from] binius_hal::Error),
 }
 
 #[derive(Debug, thiserror::Error)]
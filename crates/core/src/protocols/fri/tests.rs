@@ -42,6 +42,7 @@ fn test_commit_prove_verify_success<U, F, FA>(
 	PackedType<U, FA>: PackedFieldIndexable,
 {
 	let mut rng = StdRng::seed_from_u64(0);
+	let backend = make_portable_backend();
 
 	let committed_rs_code_packed = ReedSolomonCode::<PackedType<U, FA>>::new(
 		log_dimension,
@@ -72,7 +73,8 @@ fn test_commit_prove_verify_success<U, F, FA>(
 		commitment: mut codeword_commitment,
 		committed: codeword_committed,
 		codeword,
-	} = fri::commit_interleaved(&committed_rs_code_packed, &params, &merkle_prover, &msg).unwrap();
+	} = fri::commit_interleaved(&committed_rs_code_packed, &params, &merkle_prover, &msg, &backend)
+		.unwrap();
 
 	// Run the prover to generate the proximity proof
 	let mut round_prover = FRIFolder::new(
@@ -80,6 +82,7 @@ fn test_commit_prove_verify_success<U, F, FA>(
 		&merkle_prover,
 		<PackedType<U, F>>::unpack_scalars(&codeword),
 		&codeword_committed,
+		&backend,
 	)
 	.unwrap();
 
@@ -117,7 +120,6 @@ fn test_commit_prove_verify_success<U, F, FA>(
 
 	// check c == t(r'_0, ..., r'_{\ell-1})
 	// note that the prover is claiming that the final_message is [c]
-	let backend = make_portable_backend();
 	let eval_query = backend
 		.multilinear_query::<F>(&verifier_challenges)
 		.unwrap();
@@ -3,7 +3,10 @@
 use std::iter;
 
 use binius_field::{BinaryField, ExtensionField, TowerField};
-use binius_hal::{make_portable_backend, ComputationBackend};
+use binius_hal::{
+	fri::{fold_chunk, fold_interleaved_chunk},
+	make_portable_backend, ComputationBackend,
+};
 use binius_utils::{bail, serialization::DeserializeBytes};
 use bytes::Buf;
 use itertools::izip;
@@ -13,7 +16,7 @@ use super::{common::vcs_optimal_layers_depths_iter, error::Error, VerificationEr
 use crate::{
 	fiat_shamir::{CanSampleBits, Challenger},
 	merkle_tree::MerkleTreeScheme,
-	protocols::fri::common::{fold_chunk, fold_interleaved_chunk, FRIParams},
+	protocols::fri::common::FRIParams,
 	transcript::{TranscriptReader, VerifierTranscript},
 };
 
@@ -164,7 +167,7 @@ where
 				.enumerate()
 				.map(|(i, coset_values)| {
 					fold_chunk(
-						self.params.rs_code(),
+						self.params.rs_code().get_ntt(),
 						n_prior_challenges,
 						i,
 						coset_values,
@@ -184,7 +187,7 @@ where
 				.enumerate()
 				.map(|(i, chunk)| {
 					fold_interleaved_chunk(
-						self.params.rs_code(),
+						self.params.rs_code().get_ntt(),
 						self.params.log_batch_size(),
 						i,
 						chunk,
@@ -277,7 +280,7 @@ where
 			advice,
 		)?;
 		let mut next_value = fold_interleaved_chunk(
-			self.params.rs_code(),
+			self.params.rs_code().get_ntt(),
 			self.params.log_batch_size(),
 			index,
 			&values,
@@ -313,7 +316,7 @@ where
 			}
 
 			next_value = fold_chunk(
-				self.params.rs_code(),
+				self.params.rs_code().get_ntt(),
 				fold_round,
 				coset_index,
 				&values,
@@ -173,14 +173,6 @@ where
 		self.eq_ind_eval *= eq(alpha, challenge);
 	}
 
-	#[instrument(skip_all, name = "GPAProver::fold_partial_eq_ind", level = "trace")]
-	fn fold_partial_eq_ind(&mut self) {
-		common::fold_partial_eq_ind::<P, Backend>(
-			self.n_rounds_remaining(),
-			&mut self.partial_eq_ind_evals,
-		);
-	}
-
 	fn round(&self) -> usize {
 		self.n_vars - self.n_rounds_remaining()
 	}
@@ -269,8 +261,10 @@ where
 	}
 
 	fn finish(self: Box<Self>) -> Result<Vec<F>, SumcheckError> {
+		let backend = self.state.backend();
 		let mut evals = self.state.finish()?;
 		evals.push(self.eq_ind_eval);
+		backend.release_hal_slice(self.partial_eq_ind_evals);
 		Ok(evals)
 	}
 }
@@ -78,6 +78,8 @@ where
 		}
 	}
 
+	evalcheck_prover.release_memoized_queries();
+
 	let committed_claims = evalcheck_prover
 		.committed_eval_claims_mut()
 		.drain(..)
@@ -88,6 +88,26 @@ where
 	pub fn composite_sums(&self) -> &[CompositeSumClaim<F, Composition>] {
 		&self.composite_sums
 	}
+
+	/// Maps the composition polynomials of the claim, for example to type-erase them so that the
+	/// claim can be batched with claims over other composition types.
+	pub fn map_compositions<NewComposition>(
+		self,
+		mut f: impl FnMut(Composition) -> NewComposition,
+	) -> Result<SumcheckClaim<F, NewComposition>, Error>
+	where
+		NewComposition: CompositionPolyOS<F>,
+	{
+		let composite_sums = self
+			.composite_sums
+			.into_iter()
+			.map(|CompositeSumClaim { composition, sum }| CompositeSumClaim {
+				composition: f(composition),
+				sum,
+			})
+			.collect();
+		SumcheckClaim::new(self.n_vars, self.n_multilinears, composite_sums)
+	}
 }
 
 /// A univariate polynomial in monomial basis.
@@ -53,6 +53,8 @@ pub enum Error {
 	BaseAndExtensionFieldConstraintSetsMismatch,
 	#[error("some multilinear evals cannot be embedded into base field in the first round")]
 	MultilinearEvalsCannotBeEmbeddedInBaseField,
+	#[error("small field rounds require unfolded multilinears and must be set up before the first round")]
+	SmallFieldRoundsAfterFirstRound,
 	#[error("zerocheck challenges number does not equal number of variables")]
 	IncorrectZerocheckChallengesLength,
 	#[error("number of specified multilinears and switchover rounds does not match")]
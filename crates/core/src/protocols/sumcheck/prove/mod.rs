@@ -7,6 +7,7 @@ pub mod front_loaded;
 pub mod oracles;
 pub mod prover_state;
 pub mod regular_sumcheck;
+pub mod small_field;
 pub mod univariate;
 pub mod zerocheck;
 
@@ -18,6 +19,7 @@ pub use oracles::{
 	constraint_set_sumcheck_prover, constraint_set_zerocheck_prover, split_constraint_set,
 };
 pub use prover_state::{ProverState, SumcheckInterpolator};
-pub use regular_sumcheck::RegularSumcheckProver;
+pub use regular_sumcheck::{RegularSumcheckProver, RoundEvaluation};
+pub use small_field::{small_field_rounds_crossover, small_field_rounds_for, SmallFieldRounds};
 pub use univariate::{reduce_to_skipped_projection, univariatizing_reduction_prover};
 pub use zerocheck::{UnivariateZerocheck, ZerocheckProver};
@@ -65,6 +65,7 @@ where
 		composition,
 		predicate,
 		name,
+		kernels,
 	} in constraints
 	{
 		let composition_base = composition
@@ -75,8 +76,10 @@ where
 			ConstraintPredicate::Zero => {
 				zeros.push((
 					name,
-					ArithCircuitPoly::with_n_vars(multilinears.len(), composition_base)?,
-					ArithCircuitPoly::with_n_vars(multilinears.len(), composition)?,
+					ArithCircuitPoly::with_n_vars(multilinears.len(), composition_base)?
+						.with_kernels(kernels.clone()),
+					ArithCircuitPoly::with_n_vars(multilinears.len(), composition)?
+						.with_kernels(kernels),
 				));
 			}
 			_ => bail!(Error::MixedBatchingNotSupported),
@@ -116,12 +119,14 @@ where
 	for Constraint {
 		composition,
 		predicate,
+		kernels,
 		..
 	} in constraints
 	{
 		match predicate {
 			ConstraintPredicate::Sum(sum) => sums.push(CompositeSumClaim {
-				composition: ArithCircuitPoly::with_n_vars(multilinears.len(), composition)?,
+				composition: ArithCircuitPoly::with_n_vars(multilinears.len(), composition)?
+					.with_kernels(kernels),
 				sum,
 			}),
 			_ => bail!(Error::MixedBatchingNotSupported),
@@ -174,8 +179,8 @@ where
 
 pub struct SumcheckProversWithMetas<'a, U, FW, FDomain, Backend>
 where
-	U: UnderlierType + PackScalar<FW>,
-	FW: TowerField,
+	U: UnderlierType + PackScalar<FW> + PackScalar<FDomain>,
+	FW: TowerField + ExtensionField<FDomain>,
 	FDomain: Field,
 	Backend: ComputationBackend,
 {
@@ -1,14 +1,16 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use std::{
-	iter,
+	iter, mem,
+	ops::Range,
 	sync::atomic::{AtomicBool, Ordering},
 };
 
-use binius_field::{util::powers, ExtensionField, Field, PackedExtension, PackedField};
+use binius_field::{util::powers, ExtensionField, Field, PackedExtension, PackedField, TowerField};
 use binius_hal::{ComputationBackend, RoundEvals, SumcheckEvaluator, SumcheckMultilinear};
 use binius_math::{
-	evaluate_univariate, CompositionPolyOS, MLEDirectAdapter, MultilinearPoly, MultilinearQuery,
+	evaluate_univariate, ArithExpr, CompositionPolyOS, MLEDirectAdapter, MultilinearPoly,
+	MultilinearQuery,
 };
 use binius_maybe_rayon::prelude::*;
 use binius_utils::bail;
@@ -16,10 +18,14 @@ use getset::CopyGetters;
 use itertools::izip;
 use tracing::instrument;
 
+use super::small_field::SmallFieldRounds;
 use crate::{
 	polynomial::Error as PolynomialError,
 	protocols::sumcheck::{
-		common::{determine_switchovers, equal_n_vars_check, RoundCoeffs},
+		common::{
+			determine_switchovers, equal_n_vars_check, small_field_embedding_degree_check,
+			RoundCoeffs,
+		},
 		error::Error,
 	},
 };
@@ -64,6 +70,7 @@ where
 	evaluation_points: Vec<FDomain>,
 	tensor_query: Option<MultilinearQuery<P>>,
 	last_coeffs_or_sums: ProverStateCoeffsOrSums<P::Scalar>,
+	#[getset(get_copy = "pub")]
 	backend: &'a Backend,
 }
 
@@ -130,6 +137,78 @@ where
 		})
 	}
 
+	/// Prepare the first `n_rounds` rounds to be evaluated over the small field `FDomain`.
+	///
+	/// This checks that the multilinears can be embedded in `FDomain`, converts the composition
+	/// `expressions` to `FDomain` and postpones the multilinear switchover to the last of these
+	/// rounds, so that [`Self::calculate_small_field_round_evals`] has access to the original
+	/// multilinears. Returns `None` if there are no rounds to evaluate over the small field.
+	pub fn prepare_small_field_rounds(
+		&mut self,
+		n_rounds: usize,
+		expressions: impl IntoIterator<Item = ArithExpr<F>>,
+	) -> Result<Option<SmallFieldRounds<FDomain, P>>, Error>
+	where
+		FDomain: TowerField + TryFrom<F>,
+	{
+		let n_rounds = n_rounds.min(self.n_vars);
+		if n_rounds == 0 {
+			return Ok(None);
+		}
+
+		let Some(tensor_query) = self.tensor_query.as_ref() else {
+			bail!(Error::SmallFieldRoundsAfterFirstRound);
+		};
+		if tensor_query.n_vars() != 0 {
+			bail!(Error::SmallFieldRoundsAfterFirstRound);
+		}
+
+		let multilinears = self.transparent_multilinears()?;
+		small_field_embedding_degree_check::<_, FDomain, P, _>(&multilinears)?;
+		let small_field_rounds = SmallFieldRounds::new(
+			n_rounds,
+			multilinears.len(),
+			expressions,
+			&self.evaluation_points,
+		)?;
+
+		let min_switchover_round = n_rounds - 1;
+		for multilinear in &mut self.multilinears {
+			if let SumcheckMultilinear::Transparent {
+				switchover_round, ..
+			} = multilinear
+			{
+				*switchover_round = (*switchover_round).max(min_switchover_round);
+			}
+		}
+
+		let max_switchover_round = self
+			.multilinears
+			.iter()
+			.map(|multilinear| match multilinear {
+				SumcheckMultilinear::Transparent {
+					switchover_round, ..
+				} => *switchover_round,
+				SumcheckMultilinear::Folded { .. } => 0,
+			})
+			.max()
+			.unwrap_or_default();
+		self.tensor_query = Some(MultilinearQuery::with_capacity(max_switchover_round + 1));
+		Ok(Some(small_field_rounds))
+	}
+
+	fn transparent_multilinears(&self) -> Result<Vec<&M>, Error> {
+		self.multilinears
+			.iter()
+			.map(|multilinear| match multilinear {
+				SumcheckMultilinear::Transparent { multilinear, .. } => Ok(multilinear),
+				SumcheckMultilinear::Folded { .. } => {
+					bail!(Error::SmallFieldRoundsAfterFirstRound)
+				}
+			})
+			.collect()
+	}
+
 	#[instrument(skip_all, name = "ProverState::fold", level = "debug")]
 	pub fn fold(&mut self, challenge: F) -> Result<(), Error> {
 		if self.n_vars == 0 {
@@ -155,6 +234,8 @@ where
 			self.tensor_query = Some(tensor_query.update(&[challenge])?);
 		}
 
+		let zeroth_variable_query = MultilinearQuery::<P>::expand(&[challenge]);
+
 		// Use Relaxed ordering for writes and the read, because:
 		// * all writes can only update this value in the same direction of false->true
 		// * the barrier at the end of rayon "parallel for" is a big enough synchronization point to be Relaxed about memory ordering of accesses to this Atomic.
@@ -175,9 +256,11 @@ where
 
 							// At switchover, perform inner products in large field and save them in a
 							// newly created MLE.
-							let large_field_folded_multilinear = MLEDirectAdapter::from(
-								inner_multilinear.evaluate_partial_low(tensor_query.to_ref())?,
-							);
+							let large_field_folded_multilinear =
+								MLEDirectAdapter::from(self.backend.evaluate_partial_low(
+									inner_multilinear,
+									tensor_query.to_ref(),
+								)?);
 
 							*multilinear = SumcheckMultilinear::Folded {
 								large_field_folded_multilinear,
@@ -190,17 +273,26 @@ where
 					SumcheckMultilinear::Folded {
 						ref mut large_field_folded_multilinear,
 					} => {
-						// Post-switchover, simply plug in challenge for the zeroth variable.
-						*large_field_folded_multilinear = MLEDirectAdapter::from(
-							large_field_folded_multilinear.evaluate_zeroth_variable(challenge)?,
-						);
+						// Post-switchover, simply plug in challenge for the zeroth variable. The
+						// buffer of the previous round is released to the backend for reuse.
+						let folded_multilinear =
+							MLEDirectAdapter::from(self.backend.evaluate_partial_low(
+								large_field_folded_multilinear,
+								zeroth_variable_query.to_ref(),
+							)?);
+						let prev_multilinear =
+							mem::replace(large_field_folded_multilinear, folded_multilinear);
+						self.backend
+							.release_scratch(prev_multilinear.into_inner().into_evals());
 					}
 				};
 				Ok::<(), Error>(())
 			})?;
 
 		if !any_transparent_left.load(Ordering::Relaxed) {
-			self.tensor_query = None;
+			if let Some(tensor_query) = self.tensor_query.take() {
+				self.backend.release_scratch(tensor_query.into_expansion());
+			}
 		}
 
 		self.n_vars -= 1;
@@ -219,7 +311,8 @@ where
 		};
 
 		let empty_query = MultilinearQuery::with_capacity(0);
-		self.multilinears
+		let evals = self
+			.multilinears
 			.into_iter()
 			.map(|multilinear| {
 				match multilinear {
@@ -235,11 +328,23 @@ where
 					}
 					SumcheckMultilinear::Folded {
 						large_field_folded_multilinear,
-					} => large_field_folded_multilinear.evaluate(empty_query.to_ref()),
+					} => {
+						let eval = large_field_folded_multilinear.evaluate(empty_query.to_ref());
+						self.backend.release_scratch(
+							large_field_folded_multilinear.into_inner().into_evals(),
+						);
+						eval
+					}
 				}
 				.map_err(Error::MathError)
 			})
-			.collect()
+			.collect();
+
+		if let Some(tensor_query) = self.tensor_query {
+			self.backend.release_scratch(tensor_query.into_expansion());
+		}
+
+		evals
 	}
 
 	/// Calculate the accumulated evaluations for an arbitrary sumcheck round.
@@ -261,6 +366,41 @@ where
 		)?)
 	}
 
+	/// Calculate the accumulated evaluations for one of the rounds prepared with
+	/// [`Self::prepare_small_field_rounds`].
+	///
+	/// See [`SmallFieldRounds::calculate_round_evals`].
+	pub fn calculate_small_field_round_evals(
+		&self,
+		small_field_rounds: &SmallFieldRounds<FDomain, P>,
+		eval_point_indices: &[Range<usize>],
+		partial_eq_ind_evals: Option<&[P]>,
+	) -> Result<Vec<RoundEvals<F>>, Error> {
+		small_field_rounds.calculate_round_evals(
+			&self.transparent_multilinears()?,
+			eval_point_indices,
+			partial_eq_ind_evals,
+		)
+	}
+
+	/// Calculate the accumulated evaluations for an arbitrary sumcheck round over sums of products
+	/// of the multilinears.
+	///
+	/// See [`ComputationBackend::sumcheck_compute_product_round_evals`].
+	#[instrument(skip_all, level = "debug")]
+	pub fn calculate_product_round_evals(
+		&self,
+		products: &[Vec<usize>],
+	) -> Result<Vec<RoundEvals<F>>, Error> {
+		Ok(self.backend.sumcheck_compute_product_round_evals(
+			self.n_vars,
+			self.tensor_query.as_ref().map(Into::into),
+			&self.multilinears,
+			products,
+			&self.evaluation_points,
+		)?)
+	}
+
 	/// Calculate the batched round coefficients from the domain evaluations.
 	///
 	/// This both performs the polynomial interpolation over the evaluations and the mixing with
@@ -2,7 +2,7 @@
 
 use std::{marker::PhantomData, ops::Range};
 
-use binius_field::{ExtensionField, Field, PackedExtension, PackedField};
+use binius_field::{ExtensionField, Field, PackedExtension, PackedField, TowerField};
 use binius_hal::{ComputationBackend, SumcheckEvaluator};
 use binius_math::{
 	CompositionPolyOS, EvaluationDomainFactory, InterpolationDomain, MultilinearPoly,
@@ -13,7 +13,9 @@ use itertools::izip;
 use stackalloc::stackalloc_with_default;
 use tracing::instrument;
 
-use super::{batch_prove::SumcheckProver, prover_state::ProverState};
+use super::{
+	batch_prove::SumcheckProver, prover_state::ProverState, small_field::SmallFieldRounds,
+};
 use crate::{
 	polynomial::{Error as PolynomialError, MultilinearComposite},
 	protocols::sumcheck::{
@@ -66,10 +68,27 @@ where
 	Ok(())
 }
 
+/// The method used by a [`RegularSumcheckProver`] to evaluate the round polynomials.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RoundEvaluation {
+	/// Evaluate the compositions over the extrapolated multilinears.
+	#[default]
+	Composition,
+	/// Evaluate the compositions that are products of multilinears from their linear factors,
+	/// multiplying pairs of factors with the Karatsuba identity. Other compositions, and products
+	/// of degree less than [`MIN_PRODUCT_DEGREE`], are evaluated as with
+	/// [`RoundEvaluation::Composition`].
+	ProductOfLinears,
+}
+
+/// The minimum degree of the products evaluated from their linear factors, below which it takes
+/// no fewer multiplications than evaluating the composition.
+pub const MIN_PRODUCT_DEGREE: usize = 4;
+
 pub struct RegularSumcheckProver<'a, FDomain, P, Composition, M, Backend>
 where
 	FDomain: Field,
-	P: PackedField,
+	P: PackedField<Scalar: ExtensionField<FDomain>> + PackedExtension<FDomain>,
 	M: MultilinearPoly<P> + Send + Sync,
 	Backend: ComputationBackend,
 {
@@ -77,6 +96,9 @@ where
 	state: ProverState<'a, FDomain, P, M, Backend>,
 	compositions: Vec<Composition>,
 	domains: Vec<InterpolationDomain<FDomain>>,
+	/// The multilinear indices of the factors of the compositions evaluated as products.
+	product_factors: Vec<Option<Vec<usize>>>,
+	small_field_rounds: Option<SmallFieldRounds<FDomain, P>>,
 }
 
 impl<'a, F, FDomain, P, Composition, M, Backend>
@@ -154,13 +176,52 @@ where
 		)?;
 		let n_vars = state.n_vars();
 
+		let product_factors = vec![None; domains.len()];
+
 		Ok(Self {
 			n_vars,
 			state,
 			compositions,
 			domains,
+			product_factors,
+			small_field_rounds: None,
 		})
 	}
+
+	/// Selects the method used to evaluate the round polynomials.
+	pub fn with_round_evaluation(mut self, round_evaluation: RoundEvaluation) -> Self {
+		self.product_factors = self
+			.compositions
+			.iter()
+			.map(|composition| match round_evaluation {
+				RoundEvaluation::Composition => None,
+				RoundEvaluation::ProductOfLinears => composition
+					.expression()
+					.product_factors()
+					.filter(|factors| factors.len() >= MIN_PRODUCT_DEGREE),
+			})
+			.collect();
+		self
+	}
+
+	/// Evaluates the first `n_rounds` rounds over the small field `FDomain`, see
+	/// [`SmallFieldRounds`].
+	///
+	/// The multilinears must be embeddable in `FDomain`, and the compositions must be defined over
+	/// it. The number of rounds worth evaluating this way is given by
+	/// [`small_field_rounds_crossover`](super::small_field::small_field_rounds_crossover).
+	pub fn with_small_field_rounds(mut self, n_rounds: usize) -> Result<Self, Error>
+	where
+		FDomain: TowerField + TryFrom<F>,
+	{
+		self.small_field_rounds = self.state.prepare_small_field_rounds(
+			n_rounds,
+			self.compositions
+				.iter()
+				.map(|composition| composition.expression()),
+		)?;
+		Ok(self)
+	}
 }
 
 impl<F, FDomain, P, Composition, M, Backend> SumcheckProver<F>
@@ -179,6 +240,9 @@ where
 
 	#[instrument("RegularSumcheckProver::fold", skip_all, level = "debug")]
 	fn fold(&mut self, challenge: F) -> Result<(), Error> {
+		if let Some(small_field_rounds) = &mut self.small_field_rounds {
+			small_field_rounds.fold(challenge);
+		}
 		self.state.fold(challenge)?;
 		Ok(())
 	}
@@ -193,7 +257,69 @@ where
 			})
 			.collect::<Vec<_>>();
 
-		let evals = self.state.calculate_round_evals(&evaluators)?;
+		if let Some(small_field_rounds) = self
+			.small_field_rounds
+			.as_ref()
+			.filter(|small_field_rounds| small_field_rounds.is_active())
+		{
+			let eval_point_indices = evaluators
+				.iter()
+				.map(|evaluator| evaluator.eval_point_indices())
+				.collect::<Vec<_>>();
+			let evals = self.state.calculate_small_field_round_evals(
+				small_field_rounds,
+				&eval_point_indices,
+				None,
+			)?;
+			return self
+				.state
+				.calculate_round_coeffs_from_evals(&evaluators, batch_coeff, evals);
+		}
+
+		let products = self
+			.product_factors
+			.iter()
+			.flatten()
+			.cloned()
+			.collect::<Vec<_>>();
+
+		let evals = if products.is_empty() {
+			self.state.calculate_round_evals(&evaluators)?
+		} else {
+			let mut product_evals = self
+				.state
+				.calculate_product_round_evals(&products)?
+				.into_iter();
+
+			let composition_evaluators =
+				izip!(&self.compositions, &self.domains, &self.product_factors)
+					.filter(|(_, _, product_factors)| product_factors.is_none())
+					.map(|(composition, interpolation_domain, _)| RegularSumcheckEvaluator {
+						composition,
+						interpolation_domain,
+						_marker: PhantomData,
+					})
+					.collect::<Vec<_>>();
+			let mut composition_evals = if composition_evaluators.is_empty() {
+				Vec::new()
+			} else {
+				self.state.calculate_round_evals(&composition_evaluators)?
+			}
+			.into_iter();
+
+			self.product_factors
+				.iter()
+				.map(|product_factors| {
+					if product_factors.is_some() {
+						product_evals.next()
+					} else {
+						composition_evals.next()
+					}
+					.expect("one round evals entry per composition")
+				})
+				.collect()
+		};
+
 		self.state
 			.calculate_round_coeffs_from_evals(&evaluators, batch_coeff, evals)
 	}
@@ -0,0 +1,513 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use std::{ops::Range, sync::Arc};
+
+use binius_field::{
+	get_packed_subfields_at_pe_idx,
+	packed::{get_packed_slice, set_packed_slice},
+	ExtensionField, Field, PackedExtension, PackedField, PackedSubfield, TowerField,
+};
+use binius_hal::RoundEvals;
+use binius_math::{ArithExpr, CompositionPolyOS, EvaluationDomain, MultilinearPoly};
+use binius_maybe_rayon::prelude::*;
+use binius_utils::bail;
+use bytemuck::zeroed_vec;
+use itertools::izip;
+use stackalloc::stackalloc_with_iter;
+use tracing::instrument;
+
+use crate::{
+	polynomial::ArithCircuitPoly,
+	protocols::sumcheck::{
+		common::{equal_n_vars_check, small_field_embedding_degree_check},
+		Error,
+	},
+};
+
+/// The maximum number of hypercube variables, beyond the extrapolated ones, processed at once.
+const MAX_LOG_BATCH: usize = 8;
+
+/// Returns the number of initial rounds for which [`SmallFieldRounds`] are expected to be cheaper
+/// than the regular sumcheck rounds.
+///
+/// Round $i$ over small field values evaluates the compositions at $(d + 1)^{i + 1}$ points of
+/// each $2^{i + 1}$-sized subcube, whereas a regular round evaluates them at $d$ points of each
+/// 2-sized subcube over the extension field. Packed extension field arithmetic is taken to cost
+/// about the square root of the embedding degree times more than the small field one, which
+/// matches the `zerocheck_small_field` benchmark better than the ratio of the packing widths.
+pub const fn small_field_rounds_crossover(max_degree: usize, log_embedding_degree: usize) -> usize {
+	if max_degree == 0 {
+		return 0;
+	}
+
+	let mut n_rounds = 0;
+	let mut small_field_points = max_degree + 1;
+	let mut extension_points = max_degree << log_embedding_degree.div_ceil(2);
+	while small_field_points < extension_points {
+		n_rounds += 1;
+		small_field_points = small_field_points.saturating_mul(max_degree + 1);
+		extension_points = extension_points.saturating_mul(2);
+	}
+	n_rounds
+}
+
+/// Returns the number of initial rounds worth evaluating with [`SmallFieldRounds`] for the given
+/// multilinears and composition expressions, see [`small_field_rounds_crossover`].
+///
+/// This is zero if some multilinear can't be embedded in `FDomain` or some composition isn't
+/// defined over it.
+pub fn small_field_rounds_for<F, FDomain, P, M>(
+	multilinears: &[M],
+	expressions: impl IntoIterator<Item = ArithExpr<F>>,
+) -> usize
+where
+	F: Field + ExtensionField<FDomain>,
+	FDomain: TowerField + TryFrom<F>,
+	P: PackedField<Scalar = F>,
+	M: MultilinearPoly<P>,
+{
+	if small_field_embedding_degree_check::<F, FDomain, P, M>(multilinears).is_err() {
+		return 0;
+	}
+
+	let mut max_degree = 0;
+	for expr in expressions {
+		if expr.try_convert_field::<FDomain>().is_err() {
+			return 0;
+		}
+		max_degree = max_degree.max(expr.degree());
+	}
+	small_field_rounds_crossover(max_degree, <F as ExtensionField<FDomain>>::LOG_DEGREE)
+}
+
+/// Evaluates the initial sumcheck rounds over multilinears with values in the small field `FDomain`
+/// without lifting them to the extension field, in the style of the "small value" sumcheck
+/// optimization of Bagad, Domb and Thaler.
+///
+/// In round $i$, given the challenges $r$ of the previous rounds, the round polynomial of a
+/// composition $C$ of degree $d$ is
+/// $$s_i(X) = \sum_x C(p_0(r, X, x), \ldots) = \sum_{v \in G^i} L_v(r) \sum_x C(p_0(v, X, x), \ldots),$$
+/// where $G$ are the first $d + 1$ points of the evaluation domain and $L_v$ are the tensor
+/// products of Lagrange polynomials over $G$. The inner sums only depend on the multilinear values
+/// extrapolated to the points of $G$ and are computed in `FDomain`, leaving just $(d + 1)^i$
+/// extension field multiplications per evaluation point to combine them. As the number of inner
+/// sums grows exponentially with the round number, only the first
+/// [`small_field_rounds_crossover`] rounds are worth evaluating this way.
+///
+/// Zerocheck round polynomials weigh the inner sums with the equality indicator evaluations,
+/// which costs a multiplication of an extension field element by a small field one per term.
+#[derive(Debug)]
+pub struct SmallFieldRounds<FDomain, P>
+where
+	FDomain: Field,
+	P: PackedField<Scalar: ExtensionField<FDomain>> + PackedExtension<FDomain>,
+{
+	n_rounds: usize,
+	compositions: Vec<Arc<dyn CompositionPolyOS<PackedSubfield<P, FDomain>>>>,
+	/// Per composition, the domain of its degree plus one points.
+	domains: Vec<EvaluationDomain<FDomain>>,
+	/// The points all multilinears are extrapolated to, a superset of each of the domains.
+	grid_points: Vec<FDomain>,
+	challenges: Vec<P::Scalar>,
+}
+
+impl<F, FDomain, P> SmallFieldRounds<FDomain, P>
+where
+	F: Field + ExtensionField<FDomain>,
+	FDomain: Field,
+	P: PackedField<Scalar = F> + PackedExtension<FDomain>,
+{
+	/// Create the evaluator of the first `n_rounds` rounds.
+	///
+	/// The `evaluation_points` are the finite points of the largest composition domain.
+	pub fn new(
+		n_rounds: usize,
+		n_multilinears: usize,
+		expressions: impl IntoIterator<Item = ArithExpr<F>>,
+		evaluation_points: &[FDomain],
+	) -> Result<Self, Error>
+	where
+		FDomain: TowerField + TryFrom<F>,
+	{
+		let compositions = expressions
+			.into_iter()
+			.map(|expr| {
+				let expr = expr
+					.try_convert_field::<FDomain>()
+					.map_err(|_| Error::CircuitFieldDowncastFailed)?;
+				let composition = ArithCircuitPoly::with_n_vars(n_multilinears, expr)?;
+				Ok(Arc::new(composition) as Arc<dyn CompositionPolyOS<PackedSubfield<P, FDomain>>>)
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		let max_degree = compositions
+			.iter()
+			.map(|composition| composition.degree())
+			.max()
+			.unwrap_or(0);
+
+		if evaluation_points.len() <= max_degree {
+			bail!(Error::LagrangeDomainTooSmall);
+		}
+
+		let grid_points = evaluation_points[..=max_degree].to_vec();
+		let domains = compositions
+			.iter()
+			.map(|composition| {
+				EvaluationDomain::from_points(grid_points[..=composition.degree()].to_vec(), false)
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		Ok(Self {
+			n_rounds,
+			compositions,
+			domains,
+			grid_points,
+			challenges: Vec::new(),
+		})
+	}
+
+	/// The number of rounds evaluated over the small field.
+	pub const fn n_rounds(&self) -> usize {
+		self.n_rounds
+	}
+
+	/// Whether the current round is evaluated over the small field.
+	pub fn is_active(&self) -> bool {
+		self.challenges.len() < self.n_rounds
+	}
+
+	pub fn fold(&mut self, challenge: F) {
+		if self.is_active() {
+			self.challenges.push(challenge);
+		}
+	}
+
+	/// Calculate the evaluations of the current round polynomials at the `eval_point_indices`
+	/// of each composition, optionally weighted by the zerocheck equality indicator evaluations
+	/// over the remaining hypercube variables.
+	#[instrument(
+		skip_all,
+		level = "debug",
+		name = "SmallFieldRounds::calculate_round_evals"
+	)]
+	pub fn calculate_round_evals<M>(
+		&self,
+		multilinears: &[&M],
+		eval_point_indices: &[Range<usize>],
+		partial_eq_ind_evals: Option<&[P]>,
+	) -> Result<Vec<RoundEvals<F>>, Error>
+	where
+		M: MultilinearPoly<P> + Send + Sync,
+	{
+		if eval_point_indices.len() != self.compositions.len() {
+			bail!(Error::IncorrectNumberOfEvaluators {
+				expected: self.compositions.len(),
+			});
+		}
+
+		let round = self.challenges.len();
+		let n_vars = equal_n_vars_check(multilinears)?;
+		if round >= n_vars.min(self.n_rounds) {
+			bail!(Error::ExpectedFold);
+		}
+
+		let grid_vars = round + 1;
+		let log_batch = (n_vars - grid_vars).min(MAX_LOG_BATCH);
+		let subcube_vars = grid_vars + log_batch;
+		let log_embedding_degree = <F as ExtensionField<FDomain>>::LOG_DEGREE;
+
+		let grid_size = self.grid_points.len();
+		let n_grid_points = grid_size.pow(grid_vars as u32);
+		let row_len = 1 << log_batch.saturating_sub(PackedSubfield::<P, FDomain>::LOG_WIDTH);
+
+		// Per composition, the indices of the extrapolated grid points it is evaluated at, with the
+		// indices of the previous rounds' coordinates varying fastest.
+		let composition_grid_indices = izip!(&self.domains, eval_point_indices)
+			.map(|(domain, eval_point_indices)| {
+				let size = domain.size();
+				let mut indices = vec![0];
+				for _ in 0..round {
+					indices = (0..size)
+						.flat_map(|v| indices.iter().map(move |&index| index * grid_size + v))
+						.collect();
+				}
+				eval_point_indices
+					.clone()
+					.flat_map(|u| indices.iter().map(move |&index| index * grid_size + u))
+					.map(|index| reverse_digits(index, grid_size, grid_vars))
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+
+		let inner_sums = (0..1 << (n_vars - subcube_vars))
+			.into_par_iter()
+			.try_fold(
+				|| {
+					ParFoldStates::<FDomain, P>::new(
+						multilinears.len(),
+						subcube_vars,
+						log_embedding_degree,
+						n_grid_points * row_len,
+						row_len,
+						&composition_grid_indices,
+					)
+				},
+				|mut par_fold_states, subcube_index| -> Result<_, Error> {
+					let ParFoldStates {
+						evals,
+						extrapolated_evals,
+						scratch,
+						composition_evals,
+						inner_sums,
+					} = &mut par_fold_states;
+
+					for (multilinear, extrapolated_evals) in
+						izip!(multilinears, extrapolated_evals.iter_mut())
+					{
+						multilinear.subcube_evals(
+							subcube_vars,
+							subcube_index,
+							log_embedding_degree,
+							evals,
+						)?;
+
+						let evals_base = <P as PackedExtension<FDomain>>::cast_bases(evals);
+						extrapolate_grid(
+							&self.grid_points,
+							grid_vars,
+							log_batch,
+							evals_base,
+							extrapolated_evals,
+							scratch,
+						);
+					}
+
+					for (composition, grid_indices, inner_sums) in
+						izip!(&self.compositions, &composition_grid_indices, inner_sums.iter_mut())
+					{
+						for (&grid_index, inner_sum) in izip!(grid_indices, inner_sums.iter_mut()) {
+							let query = extrapolated_evals
+								.iter()
+								.map(|evals| &evals[grid_index * row_len..][..row_len]);
+							stackalloc_with_iter(multilinears.len(), query, |batch_query| {
+								composition.batch_evaluate(batch_query, composition_evals)
+							})?;
+
+							*inner_sum += match partial_eq_ind_evals {
+								Some(partial_eq_ind_evals) => weighted_sum::<F, FDomain, P>(
+									composition_evals,
+									partial_eq_ind_evals,
+									subcube_index << log_batch,
+									log_batch,
+								),
+								None => F::from(unweighted_sum(composition_evals, log_batch)),
+							};
+						}
+					}
+
+					Ok(par_fold_states)
+				},
+			)
+			.map(|states| -> Result<_, Error> { Ok(states?.inner_sums) })
+			.try_reduce(
+				|| {
+					composition_grid_indices
+						.iter()
+						.map(|grid_indices| vec![F::ZERO; grid_indices.len()])
+						.collect()
+				},
+				|lhs, rhs| -> Result<_, Error> {
+					Ok(izip!(lhs, rhs)
+						.map(|(mut lhs, rhs)| {
+							for (lhs, rhs) in izip!(&mut lhs, rhs) {
+								*lhs += rhs;
+							}
+							lhs
+						})
+						.collect())
+				},
+			)?;
+
+		// Combine the inner sums with the tensor product of Lagrange polynomial evaluations at
+		// the previous rounds' challenges.
+		let round_evals = izip!(&self.domains, inner_sums)
+			.map(|(domain, inner_sums)| {
+				let mut lagrange_tensor = vec![F::ONE];
+				for &challenge in &self.challenges {
+					let lagrange_evals = domain.lagrange_evals(challenge);
+					lagrange_tensor = lagrange_evals
+						.iter()
+						.flat_map(|&lagrange_eval| {
+							lagrange_tensor
+								.iter()
+								.map(move |&eval| eval * lagrange_eval)
+						})
+						.collect();
+				}
+
+				let evals = inner_sums
+					.chunks_exact(lagrange_tensor.len())
+					.map(|inner_sums| {
+						izip!(&lagrange_tensor, inner_sums)
+							.map(|(&lagrange, &inner_sum)| lagrange * inner_sum)
+							.sum()
+					})
+					.collect();
+				RoundEvals(evals)
+			})
+			.collect();
+
+		Ok(round_evals)
+	}
+}
+
+struct ParFoldStates<FDomain, P>
+where
+	FDomain: Field,
+	P: PackedField<Scalar: ExtensionField<FDomain>> + PackedExtension<FDomain>,
+{
+	/// Evaluations of a multilinear subcube, embedded into P (see MultilinearPoly::subcube_evals). Scratch space.
+	evals: Vec<P>,
+	/// Subcube evaluations extrapolated to the grid, with the remaining hypercube variables of
+	/// each grid point in a contiguous row, per multilinear.
+	extrapolated_evals: Vec<Vec<PackedSubfield<P, FDomain>>>,
+	/// Partially extrapolated evaluations. Scratch space.
+	scratch: Vec<PackedSubfield<P, FDomain>>,
+	/// Evals of a single composition over an extrapolated row. Scratch space.
+	composition_evals: Vec<PackedSubfield<P, FDomain>>,
+	/// Inner sums accumulators, per composition and grid point.
+	inner_sums: Vec<Vec<P::Scalar>>,
+}
+
+impl<FDomain, P> ParFoldStates<FDomain, P>
+where
+	FDomain: Field,
+	P: PackedField<Scalar: ExtensionField<FDomain>> + PackedExtension<FDomain>,
+{
+	fn new(
+		n_multilinears: usize,
+		subcube_vars: usize,
+		log_embedding_degree: usize,
+		extrapolated_len: usize,
+		row_len: usize,
+		composition_grid_indices: &[Vec<usize>],
+	) -> Self {
+		Self {
+			evals: zeroed_vec(
+				1 << subcube_vars.saturating_sub(P::LOG_WIDTH + log_embedding_degree),
+			),
+			extrapolated_evals: vec![zeroed_vec(extrapolated_len); n_multilinears],
+			scratch: zeroed_vec(extrapolated_len),
+			composition_evals: zeroed_vec(row_len),
+			inner_sums: composition_grid_indices
+				.iter()
+				.map(|grid_indices| vec![P::Scalar::ZERO; grid_indices.len()])
+				.collect(),
+		}
+	}
+}
+
+/// Reverse the order of the `n_digits` base `radix` digits of `index`.
+fn reverse_digits(mut index: usize, radix: usize, n_digits: usize) -> usize {
+	let mut reversed = 0;
+	for _ in 0..n_digits {
+		reversed = reversed * radix + index % radix;
+		index /= radix;
+	}
+	reversed
+}
+
+/// Extrapolate the evaluations of a subcube in the lowest `grid_vars` variables to the
+/// `grid_points` in each of them.
+///
+/// The result holds a row of `2^log_batch` evaluations over the remaining variables per grid
+/// point, where grid points are indexed by their coordinates in base `grid_points.len()`, with the
+/// first coordinate being the least significant digit.
+fn extrapolate_grid<PBase: PackedField>(
+	grid_points: &[PBase::Scalar],
+	grid_vars: usize,
+	log_batch: usize,
+	subcube_evals: &[PBase],
+	extrapolated_evals: &mut [PBase],
+	scratch: &mut [PBase],
+) {
+	let grid_size = grid_points.len();
+	let row_len = 1 << log_batch.saturating_sub(PBase::LOG_WIDTH);
+
+	// Transpose the subcube to rows over the remaining variables.
+	let (mut src, mut dst) = if grid_vars % 2 == 0 {
+		(extrapolated_evals, scratch)
+	} else {
+		(scratch, extrapolated_evals)
+	};
+	for hypercube_index in 0..1 << grid_vars {
+		let row = &mut src[hypercube_index * row_len..][..row_len];
+		for batch_index in 0..1 << log_batch {
+			let eval = get_packed_slice(subcube_evals, hypercube_index | batch_index << grid_vars);
+			set_packed_slice(row, batch_index, eval);
+		}
+	}
+
+	// Extrapolate one variable at a time, each step replacing its pair of rows with a row per grid
+	// point while keeping the variable's position.
+	for var in 0..grid_vars {
+		let n_lo = grid_size.pow(var as u32);
+		let n_hi = 1 << (grid_vars - var - 1);
+		for hi in 0..n_hi {
+			for lo in 0..n_lo {
+				let row_0 = &src[(lo + n_lo * 2 * hi) * row_len..][..row_len];
+				let row_1 = &src[(lo + n_lo * (2 * hi + 1)) * row_len..][..row_len];
+				for (k, &point) in grid_points.iter().enumerate() {
+					let point = PBase::broadcast(point);
+					let row = &mut dst[(lo + n_lo * (k + grid_size * hi)) * row_len..][..row_len];
+					for (eval, &eval_0, &eval_1) in izip!(row, row_0, row_1) {
+						*eval = eval_0 + (eval_1 - eval_0) * point;
+					}
+				}
+			}
+		}
+		(src, dst) = (dst, src);
+	}
+}
+
+/// Sum the first `2^log_batch` scalars of `evals`.
+fn unweighted_sum<PBase: PackedField>(evals: &[PBase], log_batch: usize) -> PBase::Scalar {
+	if log_batch >= PBase::LOG_WIDTH {
+		evals.iter().copied().sum::<PBase>().iter().sum()
+	} else {
+		evals[0].iter().take(1 << log_batch).sum()
+	}
+}
+
+/// Calculate the inner product of the first `2^log_batch` scalars of `evals` with the partial
+/// equality indicator evaluations starting at `offset`.
+fn weighted_sum<F, FDomain, P>(
+	evals: &[PackedSubfield<P, FDomain>],
+	partial_eq_ind_evals: &[P],
+	offset: usize,
+	log_batch: usize,
+) -> F
+where
+	F: Field + ExtensionField<FDomain>,
+	FDomain: Field,
+	P: PackedField<Scalar = F> + PackedExtension<FDomain>,
+{
+	if log_batch >= P::LOG_WIDTH {
+		let partial_eq_ind_evals = &partial_eq_ind_evals[offset >> P::LOG_WIDTH..];
+		let sum = (0..1 << (log_batch - P::LOG_WIDTH))
+			.map(|i| {
+				// SAFETY: evals hold 2^log_batch scalars, which is at least P::WIDTH * (i + 1).
+				let evals = unsafe { get_packed_subfields_at_pe_idx::<P, FDomain>(evals, i) };
+				<P as PackedExtension<FDomain>>::cast_base(partial_eq_ind_evals[i]) * evals
+			})
+			.sum::<PackedSubfield<P, FDomain>>();
+		<P as PackedExtension<FDomain>>::cast_ext(sum).iter().sum()
+	} else {
+		(0..1 << log_batch)
+			.map(|i| {
+				get_packed_slice(partial_eq_ind_evals, offset + i) * get_packed_slice(evals, i)
+			})
+			.sum()
+	}
+}
@@ -7,7 +7,7 @@ use binius_field::{
 	ExtensionField, Field, PackedExtension, PackedField, PackedFieldIndexable, PackedSubfield,
 	TowerField,
 };
-use binius_hal::{ComputationBackend, SumcheckEvaluator};
+use binius_hal::{ComputationBackend, RoundEvals, SumcheckEvaluator};
 use binius_math::{
 	CompositionPolyOS, EvaluationDomainFactory, InterpolationDomain, MLEDirectAdapter,
 	MultilinearPoly, MultilinearQuery,
@@ -26,6 +26,7 @@ use crate::{
 		common::{determine_switchovers, equal_n_vars_check},
 		prove::{
 			common::fold_partial_eq_ind,
+			small_field::SmallFieldRounds,
 			univariate::{
 				zerocheck_univariate_evals, ZerocheckUnivariateEvalsOutput,
 				ZerocheckUnivariateFoldResult,
@@ -360,12 +361,13 @@ where
 		let lagrange_coeffs_query =
 			MultilinearQuery::with_expansion(skip_rounds, packed_subcube_lagrange_coeffs)?;
 
+		let backend = self.backend;
 		let partial_low_multilinears = self
 			.multilinears
 			.into_par_iter()
 			.map(|multilinear| -> Result<_, Error> {
 				let multilinear =
-					multilinear.evaluate_partial_low(lagrange_coeffs_query.to_ref())?;
+					backend.evaluate_partial_low(&multilinear, lagrange_coeffs_query.to_ref())?;
 				let mle_adapter = Arc::new(MLEDirectAdapter::from(multilinear));
 				Ok(mle_adapter as MultilinearWitness<'static, P>)
 			})
@@ -430,7 +432,7 @@ enum RegularFirstRound {
 pub struct ZerocheckProver<'a, FDomain, P, Composition, M, Backend>
 where
 	FDomain: Field,
-	P: PackedField,
+	P: PackedField<Scalar: ExtensionField<FDomain>> + PackedExtension<FDomain>,
 	M: MultilinearPoly<P> + Send + Sync,
 	Backend: ComputationBackend,
 {
@@ -442,6 +444,7 @@ where
 	compositions: Vec<Composition>,
 	domains: Vec<InterpolationDomain<FDomain>>,
 	first_round: RegularFirstRound,
+	small_field_rounds: Option<SmallFieldRounds<FDomain, P>>,
 }
 
 impl<'a, F, FDomain, P, Composition, M, Backend>
@@ -505,9 +508,29 @@ where
 			compositions,
 			domains,
 			first_round,
+			small_field_rounds: None,
 		})
 	}
 
+	/// Evaluates the first `n_rounds` rounds over the small field `FDomain`, see
+	/// [`SmallFieldRounds`].
+	///
+	/// The multilinears must be embeddable in `FDomain`, and the compositions must be defined over
+	/// it. The number of rounds worth evaluating this way is given by
+	/// [`small_field_rounds_crossover`](super::small_field::small_field_rounds_crossover).
+	pub fn with_small_field_rounds(mut self, n_rounds: usize) -> Result<Self, Error>
+	where
+		FDomain: TowerField + TryFrom<F>,
+	{
+		self.small_field_rounds = self.state.prepare_small_field_rounds(
+			n_rounds,
+			self.compositions
+				.iter()
+				.map(|composition| composition.expression()),
+		)?;
+		Ok(self)
+	}
+
 	fn round(&self) -> usize {
 		self.n_vars - self.n_rounds_remaining()
 	}
@@ -525,6 +548,29 @@ where
 		self.eq_ind_eval *= eq(alpha, challenge);
 	}
 
+	fn calculate_round_evals<Evaluator>(
+		&self,
+		evaluators: &[Evaluator],
+	) -> Result<Vec<RoundEvals<F>>, Error>
+	where
+		Evaluator: SumcheckEvaluator<P, Composition> + Sync,
+	{
+		match &self.small_field_rounds {
+			Some(small_field_rounds) if small_field_rounds.is_active() => {
+				let eval_point_indices = evaluators
+					.iter()
+					.map(|evaluator| evaluator.eval_point_indices())
+					.collect::<Vec<_>>();
+				self.state.calculate_small_field_round_evals(
+					small_field_rounds,
+					&eval_point_indices,
+					Some(&self.partial_eq_ind_evals),
+				)
+			}
+			_ => self.state.calculate_round_evals(evaluators),
+		}
+	}
+
 	#[instrument(skip_all, level = "debug")]
 	fn fold_partial_eq_ind(&mut self) {
 		fold_partial_eq_ind::<P, Backend>(
@@ -551,6 +597,9 @@ where
 	#[instrument(skip_all, name = "ZerocheckProver::fold", level = "debug")]
 	fn fold(&mut self, challenge: F) -> Result<(), Error> {
 		self.update_eq_ind_eval(challenge);
+		if let Some(small_field_rounds) = &mut self.small_field_rounds {
+			small_field_rounds.fold(challenge);
+		}
 		self.state.fold(challenge)?;
 
 		// This must happen after state fold, which decrements n_rounds_remaining.
@@ -572,7 +621,7 @@ where
 					partial_eq_ind_evals: &self.partial_eq_ind_evals,
 				})
 				.collect::<Vec<_>>();
-			let evals = self.state.calculate_round_evals(&evaluators)?;
+			let evals = self.calculate_round_evals(&evaluators)?;
 			self.state
 				.calculate_round_coeffs_from_evals(&evaluators, batch_coeff, evals)?
 		} else {
@@ -584,7 +633,7 @@ where
 					round_zerocheck_challenge: self.zerocheck_challenges[round],
 				})
 				.collect::<Vec<_>>();
-			let evals = self.state.calculate_round_evals(&evaluators)?;
+			let evals = self.calculate_round_evals(&evaluators)?;
 			self.state
 				.calculate_round_coeffs_from_evals(&evaluators, batch_coeff, evals)?
 		};
@@ -608,8 +657,10 @@ where
 
 	#[instrument(skip_all, name = "ZerocheckProver::finish", level = "debug")]
 	fn finish(self: Box<Self>) -> Result<Vec<F>, Error> {
+		let backend = self.state.backend();
 		let mut evals = self.state.finish()?;
 		evals.push(self.eq_ind_eval);
+		backend.release_hal_slice(self.partial_eq_ind_evals);
 		Ok(evals)
 	}
 }
@@ -1,6 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use std::{
+	fmt::Debug,
 	iter::{self, repeat_with},
 	sync::Arc,
 };
@@ -9,15 +10,22 @@ use binius_field::{
 	arch::{OptimalUnderlier128b, OptimalUnderlier512b},
 	as_packed_field::{PackScalar, PackedType},
 	packed::set_packed_slice,
-	underlier::UnderlierType,
-	BinaryField, BinaryField128b, BinaryField32b, BinaryField8b, ExtensionField, Field,
-	PackedBinaryField1x128b, PackedBinaryField4x32b, PackedExtension, PackedField,
-	RepackedExtension, TowerField,
+	underlier::{UnderlierType, WithUnderlier},
+	AESTowerField128b, AESTowerField32b, AESTowerField8b, BinaryField, BinaryField128b,
+	BinaryField32b, BinaryField8b, ByteSlicedAES32x128b, ExtensionField, Field,
+	PackedBinaryField128x1b, PackedBinaryField16x8b, PackedBinaryField1x128b,
+	PackedBinaryField4x32b, PackedExtension, PackedField, RepackedExtension, TowerField,
+};
+use binius_hal::{
+	make_portable_backend,
+	numa::{NumaNode, NumaTopology},
+	ComputationBackend, ComputationBackendExt, CpuBackend, NumaCpuBackend, RoundEvals,
+	SumcheckEvaluator, SumcheckMultilinear,
 };
-use binius_hal::{make_portable_backend, ComputationBackend, ComputationBackendExt};
 use binius_math::{
 	ArithExpr, CompositionPolyOS, EvaluationDomainFactory, IsomorphicEvaluationDomainFactory,
-	MLEEmbeddingAdapter, MultilinearExtension, MultilinearPoly, MultilinearQuery,
+	MLEDirectAdapter, MLEEmbeddingAdapter, MultilinearExtension, MultilinearPoly, MultilinearQuery,
+	MultilinearQueryRef,
 };
 use binius_maybe_rayon::{current_num_threads, prelude::*};
 use binius_utils::checked_arithmetics::log2_ceil_usize;
@@ -30,19 +38,21 @@ use super::{
 	front_loaded::BatchVerifier as FrontLoadedBatchVerifier,
 	prove::{
 		batch_prove, front_loaded::BatchProver as FrontLoadedBatchProver, RegularSumcheckProver,
+		RoundEvaluation,
 	},
 	verify::batch_verify,
-	BatchSumcheckOutput, SumcheckClaim,
+	BatchSumcheckOutput, Error, SumcheckClaim,
 };
 use crate::{
 	composition::index_composition,
 	fiat_shamir::{CanSample, HasherChallenger},
-	polynomial::{IdentityCompositionPoly, MultilinearComposite},
+	polynomial::{ArithCircuitPoly, IdentityCompositionPoly, MultilinearComposite},
 	protocols::{
 		sumcheck::prove::SumcheckProver,
 		test_utils::{AddOneComposition, TestProductComposition},
 	},
-	transcript::ProverTranscript,
+	transcript::{ProverTranscript, VerifierTranscript},
+	witness::MultilinearWitness,
 };
 
 #[derive(Debug, Clone)]
@@ -131,6 +141,26 @@ fn test_prove_verify_product_helper<U, F, FDomain, FExt>(
 	FDomain: BinaryField,
 	FExt: TowerField + ExtensionField<F> + ExtensionField<FDomain>,
 	BinaryField128b: From<FExt> + Into<FExt>,
+{
+	test_prove_verify_product_helper_with_backend::<U, F, FDomain, FExt>(
+		n_vars,
+		n_multilinears,
+		switchover_rd,
+		&make_portable_backend(),
+	)
+}
+
+fn test_prove_verify_product_helper_with_backend<U, F, FDomain, FExt>(
+	n_vars: usize,
+	n_multilinears: usize,
+	switchover_rd: usize,
+	backend: &impl ComputationBackend,
+) where
+	U: UnderlierType + PackScalar<F> + PackScalar<FDomain> + PackScalar<FExt>,
+	F: Field,
+	FDomain: BinaryField,
+	FExt: TowerField + ExtensionField<F> + ExtensionField<FDomain>,
+	BinaryField128b: From<FExt> + Into<FExt>,
 {
 	let mut rng = StdRng::seed_from_u64(0);
 
@@ -153,7 +183,6 @@ fn test_prove_verify_product_helper<U, F, FDomain, FExt>(
 	)
 	.unwrap();
 
-	let backend = make_portable_backend();
 	let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
 	let prover = RegularSumcheckProver::<FDomain, _, _, _, _>::new(
 		multilins.iter().collect(),
@@ -163,7 +192,7 @@ fn test_prove_verify_product_helper<U, F, FDomain, FExt>(
 		}],
 		domain_factory,
 		move |_| switchover_rd,
-		&backend,
+		backend,
 	)
 	.unwrap();
 
@@ -250,6 +279,344 @@ fn test_sumcheck_prove_verify_with_nontrivial_packing() {
 	>(n_vars, n_multilinears, switchover_rd);
 }
 
+#[test]
+fn test_sumcheck_prove_verify_byte_sliced() {
+	let n_vars = 10;
+	let n_multilinears = 3;
+	let switchover_rd = 3;
+
+	// The byte-sliced underlier packs 32 128-bit elements, so the packed AES subfields are
+	// byte-sliced as well.
+	test_prove_verify_product_helper::<
+		<ByteSlicedAES32x128b as WithUnderlier>::Underlier,
+		AESTowerField32b,
+		AESTowerField8b,
+		AESTowerField128b,
+	>(n_vars, n_multilinears, switchover_rd);
+}
+
+#[test]
+fn test_sumcheck_prove_verify_numa_backend() {
+	// Two artificial nodes exercise the partitioning of the subcubes between thread pools, and
+	// the small tile size forces the round evaluation to use small subcubes.
+	let topology = NumaTopology::new([NumaNode::new(0, vec![0]), NumaNode::new(1, vec![1])]);
+	let backend = NumaCpuBackend::new(topology)
+		.unwrap()
+		.with_tile_bytes(1 << 8);
+
+	for n_vars in 2..8 {
+		for n_multilinears in 1..4 {
+			test_prove_verify_product_helper_with_backend::<
+				OptimalUnderlier128b,
+				BinaryField32b,
+				BinaryField8b,
+				BinaryField128b,
+			>(n_vars, n_multilinears, n_vars / 2, &backend);
+		}
+	}
+}
+
+fn prove_with_round_evaluation(
+	multilins: &[MultilinearExtension<PackedBinaryField1x128b>],
+	compositions: &[ArithCircuitPoly<BinaryField128b>],
+	switchover_rd: usize,
+	round_evaluation: RoundEvaluation,
+	backend: &impl ComputationBackend,
+) -> (BatchSumcheckOutput<BinaryField128b>, Vec<u8>) {
+	let multilins = multilins
+		.iter()
+		.map(|multilin| MLEDirectAdapter::from(multilin.to_ref()))
+		.collect::<Vec<_>>();
+	let claims = compositions
+		.iter()
+		.map(|composition| CompositeSumClaim {
+			composition,
+			sum: compute_composite_sum(&multilins, composition),
+		})
+		.collect::<Vec<_>>();
+
+	let domain_factory = IsomorphicEvaluationDomainFactory::<BinaryField8b>::default();
+	let prover = RegularSumcheckProver::<BinaryField8b, _, _, _, _>::new(
+		multilins.clone(),
+		claims.clone(),
+		domain_factory,
+		move |_| switchover_rd,
+		backend,
+	)
+	.unwrap()
+	.with_round_evaluation(round_evaluation);
+
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let output = batch_prove(vec![prover], &mut prover_transcript).unwrap();
+
+	let claim = SumcheckClaim::new(multilins[0].n_vars(), multilins.len(), claims).unwrap();
+	let proof = prover_transcript.finalize();
+	let mut verifier_transcript =
+		VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof.clone());
+	let verifier_output = batch_verify(&[claim], &mut verifier_transcript).unwrap();
+	verifier_transcript.finalize().unwrap();
+	assert_eq!(verifier_output, output);
+
+	(output, proof)
+}
+
+/// A backend relying on the default product round evaluation of [`ComputationBackend`].
+#[derive(Debug, Default)]
+struct CompositionRoundsBackend(CpuBackend);
+
+impl ComputationBackend for CompositionRoundsBackend {
+	type Vec<P: Send + Sync + Debug + 'static> = Vec<P>;
+
+	fn to_hal_slice<P: Debug + Send + Sync + 'static>(v: Vec<P>) -> Self::Vec<P> {
+		v
+	}
+
+	fn tensor_product_full_query<P: PackedField>(
+		&self,
+		query: &[P::Scalar],
+	) -> Result<Self::Vec<P>, binius_hal::Error> {
+		self.0.tensor_product_full_query(query)
+	}
+
+	fn sumcheck_compute_round_evals<FDomain, P, M, Evaluator, Composition>(
+		&self,
+		n_vars: usize,
+		tensor_query: Option<MultilinearQueryRef<P>>,
+		multilinears: &[SumcheckMultilinear<P, M>],
+		evaluators: &[Evaluator],
+		evaluation_points: &[FDomain],
+	) -> Result<Vec<RoundEvals<P::Scalar>>, binius_hal::Error>
+	where
+		FDomain: Field,
+		P: PackedField<Scalar: ExtensionField<FDomain>> + PackedExtension<FDomain>,
+		M: MultilinearPoly<P> + Send + Sync,
+		Evaluator: SumcheckEvaluator<P, Composition> + Sync,
+		Composition: CompositionPolyOS<P>,
+	{
+		self.0.sumcheck_compute_round_evals(
+			n_vars,
+			tensor_query,
+			multilinears,
+			evaluators,
+			evaluation_points,
+		)
+	}
+}
+
+#[test]
+fn test_sumcheck_product_of_linears_matches_composition() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let numa_backend = NumaCpuBackend::new(NumaTopology::new([
+		NumaNode::new(0, vec![0]),
+		NumaNode::new(1, vec![1]),
+	]))
+	.unwrap()
+	.with_tile_bytes(1 << 8);
+
+	for n_vars in 1..7 {
+		for n_multilinears in 1..6 {
+			let multilins = generate_random_multilinears(&mut rng, n_vars, n_multilinears);
+
+			// The products are evaluated from their factors, the sum with a constant is not a product.
+			let product = (0..n_multilinears)
+				.map(ArithExpr::Var)
+				.product::<ArithExpr<_>>();
+			let compositions = [
+				product.clone(),
+				product + ArithExpr::one(),
+				ArithExpr::Var(0).pow(3) * ArithExpr::Var(n_multilinears - 1),
+			]
+			.map(ArithCircuitPoly::new);
+
+			let switchover_rd = n_vars / 2;
+			let expected = prove_with_round_evaluation(
+				&multilins,
+				&compositions,
+				switchover_rd,
+				RoundEvaluation::Composition,
+				&make_portable_backend(),
+			);
+			assert_eq!(
+				prove_with_round_evaluation(
+					&multilins,
+					&compositions,
+					switchover_rd,
+					RoundEvaluation::ProductOfLinears,
+					&make_portable_backend(),
+				),
+				expected
+			);
+			assert_eq!(
+				prove_with_round_evaluation(
+					&multilins,
+					&compositions,
+					switchover_rd,
+					RoundEvaluation::ProductOfLinears,
+					&numa_backend,
+				),
+				expected
+			);
+			assert_eq!(
+				prove_with_round_evaluation(
+					&multilins,
+					&compositions,
+					switchover_rd,
+					RoundEvaluation::ProductOfLinears,
+					&CompositionRoundsBackend::default(),
+				),
+				expected
+			);
+		}
+	}
+}
+
+fn prove_with_small_field_rounds(
+	multilins: &[MultilinearWitness<'static, PackedBinaryField1x128b>],
+	compositions: &[ArithCircuitPoly<BinaryField128b>],
+	n_small_field_rounds: usize,
+) -> (BatchSumcheckOutput<BinaryField128b>, Vec<u8>) {
+	let claims = compositions
+		.iter()
+		.map(|composition| CompositeSumClaim {
+			composition,
+			sum: compute_composite_sum(multilins, composition),
+		})
+		.collect::<Vec<_>>();
+
+	let backend = make_portable_backend();
+	let domain_factory = IsomorphicEvaluationDomainFactory::<BinaryField8b>::default();
+	let prover = RegularSumcheckProver::<BinaryField8b, _, _, _, _>::new(
+		multilins.to_vec(),
+		claims.clone(),
+		domain_factory,
+		|_| 1,
+		&backend,
+	)
+	.unwrap()
+	.with_small_field_rounds(n_small_field_rounds)
+	.unwrap();
+
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let output = batch_prove(vec![prover], &mut prover_transcript).unwrap();
+
+	let claim = SumcheckClaim::new(multilins[0].n_vars(), multilins.len(), claims).unwrap();
+	let proof = prover_transcript.finalize();
+	let mut verifier_transcript =
+		VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof.clone());
+	let verifier_output = batch_verify(&[claim], &mut verifier_transcript).unwrap();
+	verifier_transcript.finalize().unwrap();
+	assert_eq!(verifier_output, output);
+
+	(output, proof)
+}
+
+#[test]
+fn test_sumcheck_small_field_rounds_match_regular() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let compositions = [
+		ArithExpr::Var(0) * ArithExpr::Var(1) - ArithExpr::Var(2),
+		ArithExpr::Var(0) * ArithExpr::Var(2) * ArithExpr::Var(3)
+			+ ArithExpr::Var(1)
+			+ ArithExpr::Const(BinaryField128b::new(0x42)),
+		ArithExpr::Var(3),
+	]
+	.map(|expr| ArithCircuitPoly::with_n_vars(4, expr).unwrap());
+
+	for n_vars in 1..8 {
+		// 1-bit and 8-bit multilinears both embed into the 8-bit domain field.
+		let multilins = iter::empty()
+			.chain(
+				generate_random_multilinears::<PackedBinaryField128x1b>(&mut rng, n_vars, 2)
+					.into_iter()
+					.map(|multilin| multilin.specialize_arc_dyn()),
+			)
+			.chain(
+				generate_random_multilinears::<PackedBinaryField16x8b>(&mut rng, n_vars, 2)
+					.into_iter()
+					.map(|multilin| multilin.specialize_arc_dyn()),
+			)
+			.collect::<Vec<_>>();
+
+		let expected = prove_with_small_field_rounds(&multilins, &compositions, 0);
+		for n_small_field_rounds in 1..=n_vars + 1 {
+			assert_eq!(
+				prove_with_small_field_rounds(&multilins, &compositions, n_small_field_rounds),
+				expected
+			);
+		}
+	}
+}
+
+#[test]
+fn test_sumcheck_small_field_rounds_require_small_field() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let backend = make_portable_backend();
+	let domain_factory = IsomorphicEvaluationDomainFactory::<BinaryField8b>::default();
+
+	let make_prover = |multilins: Vec<MultilinearWitness<'static, PackedBinaryField1x128b>>,
+	                   composition: ArithExpr<BinaryField128b>| {
+		RegularSumcheckProver::<BinaryField8b, _, _, _, _>::new(
+			multilins,
+			[CompositeSumClaim {
+				composition: ArithCircuitPoly::new(composition),
+				sum: BinaryField128b::ZERO,
+			}],
+			domain_factory.clone(),
+			|_| 1,
+			&backend,
+		)
+		.unwrap()
+		.with_small_field_rounds(2)
+	};
+
+	let small_multilins = generate_random_multilinears::<PackedBinaryField16x8b>(&mut rng, 4, 2)
+		.into_iter()
+		.map(|multilin| multilin.specialize_arc_dyn())
+		.collect::<Vec<_>>();
+	let large_multilins = generate_random_multilinears::<PackedBinaryField4x32b>(&mut rng, 4, 2)
+		.into_iter()
+		.map(|multilin| multilin.specialize_arc_dyn())
+		.collect::<Vec<_>>();
+	let product = ArithExpr::Var(0) * ArithExpr::Var(1);
+
+	assert!(make_prover(small_multilins.clone(), product.clone()).is_ok());
+	assert!(matches!(
+		make_prover(large_multilins, product.clone()),
+		Err(Error::MultilinearEvalsCannotBeEmbeddedInBaseField)
+	));
+	assert!(matches!(
+		make_prover(small_multilins, product + ArithExpr::Const(BinaryField128b::new(1 << 100))),
+		Err(Error::CircuitFieldDowncastFailed)
+	));
+}
+
+#[test]
+fn test_sumcheck_prove_reuses_scratch_buffers() {
+	let backend = make_portable_backend();
+	let prove = || {
+		test_prove_verify_product_helper_with_backend::<
+			OptimalUnderlier128b,
+			BinaryField32b,
+			BinaryField8b,
+			BinaryField128b,
+		>(8, 3, 2, &backend)
+	};
+
+	// Folding halves the multilinears in each round, so the buffers released in a round are
+	// reused by the following ones.
+	prove();
+	let first_stats = backend.scratch_stats();
+	assert!(first_stats.reuses > 0);
+
+	// The buffers of the first proof have been released, so a second identical proof is mostly
+	// served from the arena.
+	prove();
+	let second_stats = backend.scratch_stats() - first_stats;
+	assert!(second_stats.allocations < first_stats.allocations);
+	assert!(second_stats.reuses > first_stats.reuses);
+}
+
 #[derive(Clone)]
 struct TestSumcheckClaimShape {
 	n_vars: usize,
@@ -434,6 +434,64 @@ mod tests {
 		}
 	}
 
+	fn test_compare_small_field_rounds_with_reference(
+		n_vars: usize,
+		n_multilinears: usize,
+		n_small_field_rounds: usize,
+	) {
+		type P = PackedBinaryField1x128b;
+		type FDomain = BinaryField8b;
+		let mut rng = StdRng::seed_from_u64(0);
+
+		let multilins = generate_zero_product_multilinears::<PackedSubfield<P, FDomain>, P>(
+			&mut rng,
+			n_vars,
+			n_multilinears,
+		);
+
+		let backend = make_portable_backend();
+		let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+		let composition = TestProductComposition::new(n_multilinears);
+
+		let prove = |n_small_field_rounds| {
+			let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+			let challenges: Vec<BinaryField128b> = transcript.sample_vec(n_vars);
+			let prover = UnivariateZerocheck::<FDomain, FDomain, P, _, _, _, _>::new(
+				multilins.clone(),
+				[("test_product".into(), composition.clone(), composition.clone())],
+				&challenges,
+				domain_factory.clone(),
+				|_| 1,
+				&backend,
+			)
+			.unwrap()
+			.into_regular_zerocheck()
+			.unwrap()
+			.with_small_field_rounds(n_small_field_rounds)
+			.unwrap();
+
+			let output = batch_prove(vec![prover], &mut transcript).unwrap();
+			(output, transcript.finalize())
+		};
+
+		assert_eq!(prove(n_small_field_rounds), prove(0));
+	}
+
+	#[test]
+	fn test_compare_small_field_rounds_to_regular_zerocheck() {
+		for n_vars in 1..8 {
+			for n_multilinears in 1..5 {
+				for n_small_field_rounds in 1..=n_vars {
+					test_compare_small_field_rounds_with_reference(
+						n_vars,
+						n_multilinears,
+						n_small_field_rounds,
+					);
+				}
+			}
+		}
+	}
+
 	#[test]
 	fn test_prove_verify_product_basic() {
 		for n_vars in 2..8 {
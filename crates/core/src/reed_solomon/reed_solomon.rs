@@ -13,12 +13,17 @@
 use std::marker::PhantomData;
 
 use binius_field::{BinaryField, ExtensionField, PackedField, RepackedExtension};
-use binius_maybe_rayon::prelude::*;
+use binius_hal::ComputationBackend;
 use binius_ntt::{AdditiveNTT, DynamicDispatchNTT, Error, NTTOptions, ThreadingSettings};
 use binius_utils::{bail, checked_arithmetics::checked_log_2};
 use getset::CopyGetters;
 use tracing::instrument;
 
+/// Base-2 logarithm of the smallest code length encoded with the cache-blocked NTT kernel.
+///
+/// Smaller codewords fit in cache, where the blocked kernel gives no speedup.
+const BLOCKED_NTT_MIN_LOG_LEN: usize = 24;
+
 #[derive(Debug, CopyGetters)]
 pub struct ReedSolomonCode<P>
 where
@@ -56,6 +61,14 @@ where
 				..ntt_options
 			},
 		)?;
+		// The blocked kernel is single-threaded, so it is only used when each NTT runs on one
+		// thread.
+		let ntt = if ntt_log_threads == 0 && log_dimension + log_inv_rate >= BLOCKED_NTT_MIN_LOG_LEN
+		{
+			ntt.blocked()?
+		} else {
+			ntt
+		};
 
 		let multithreaded =
 			!matches!(ntt_options.thread_settings, ThreadingSettings::SingleThreaded);
@@ -106,13 +119,18 @@ where
 	///
 	/// The message symbols are interleaved in the buffer, which improves the cache-efficiency of
 	/// the encoding procedure. The interleaved codeword is stored in the buffer when the method
-	/// completes.
+	/// completes. The encoding is performed by the given computation backend.
 	///
 	/// ## Throws
 	///
 	/// * If the `code` buffer does not have capacity for `len() << log_batch_size` field
 	///   elements.
-	fn encode_batch_inplace(&self, code: &mut [P], log_batch_size: usize) -> Result<(), Error> {
+	fn encode_batch_inplace(
+		&self,
+		backend: &impl ComputationBackend,
+		code: &mut [P],
+		log_batch_size: usize,
+	) -> Result<(), binius_hal::Error> {
 		let _scope = tracing::trace_span!(
 			"Reed–Solomon encode",
 			log_len = self.log_len(),
@@ -129,21 +147,14 @@ where
 			bail!(Error::PackingWidthMustDivideDimension);
 		}
 
-		let msgs_len = (self.dim() / P::WIDTH) << log_batch_size;
-		for i in 1..(1 << self.log_inv_rate) {
-			code.copy_within(0..msgs_len, i * msgs_len);
-		}
-
-		if self.multithreaded {
-			(0..(1 << self.log_inv_rate))
-				.into_par_iter()
-				.zip(code.par_chunks_exact_mut(msgs_len))
-				.try_for_each(|(i, data)| self.ntt.forward_transform(data, i, log_batch_size))
-		} else {
-			(0..(1 << self.log_inv_rate))
-				.zip(code.chunks_exact_mut(msgs_len))
-				.try_for_each(|(i, data)| self.ntt.forward_transform(data, i, log_batch_size))
-		}
+		backend.reed_solomon_encode_batch_inplace(
+			&self.ntt,
+			code,
+			self.log_dimension,
+			self.log_inv_rate,
+			log_batch_size,
+			self.multithreaded,
+		)
 	}
 
 	/// Encode a batch of interleaved messages of extension field elements in-place in a provided
@@ -162,14 +173,15 @@ where
 	#[instrument(skip_all, level = "debug")]
 	pub fn encode_ext_batch_inplace<PE>(
 		&self,
+		backend: &impl ComputationBackend,
 		code: &mut [PE],
 		log_batch_size: usize,
-	) -> Result<(), Error>
+	) -> Result<(), binius_hal::Error>
 	where
 		PE: RepackedExtension<P>,
 		PE::Scalar: ExtensionField<<P as PackedField>::Scalar>,
 	{
 		let log_degree = checked_log_2(PE::Scalar::DEGREE);
-		self.encode_batch_inplace(PE::cast_bases_mut(code), log_batch_size + log_degree)
+		self.encode_batch_inplace(backend, PE::cast_bases_mut(code), log_batch_size + log_degree)
 	}
 }
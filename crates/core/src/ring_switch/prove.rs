@@ -40,11 +40,10 @@ pub fn prove<F, P, M, Tower, Challenger_, Backend>(
 	backend: &Backend,
 ) -> Result<ReducedWitness<P>, Error>
 where
-	F: TowerField,
+	F: TowerField + PackedTop<Tower>,
 	P: PackedFieldIndexable<Scalar = F>,
 	M: MultilinearPoly<P> + Sync,
 	Tower: TowerFamily<B128 = F>,
-	F: PackedTop<Tower>,
 	Challenger_: Challenger,
 	Backend: ComputationBackend,
 {
@@ -205,9 +204,8 @@ fn compute_row_batched_sumcheck_evals<F, Tower>(
 	row_batch_coeffs: &[F],
 ) -> Vec<F>
 where
-	F: TowerField,
+	F: TowerField + PackedTop<Tower>,
 	Tower: TowerFamily<B128 = F>,
-	F: PackedTop<Tower>,
 {
 	tensor_elems
 		.into_par_iter()
@@ -223,10 +221,9 @@ fn make_ring_switch_eq_inds<F, P, Tower>(
 	mixing_coeffs: &[F],
 ) -> Result<Vec<MultilinearWitness<'static, P>>, Error>
 where
-	F: TowerField,
+	F: TowerField + PackedTop<Tower>,
 	P: PackedFieldIndexable<Scalar = F>,
 	Tower: TowerFamily<B128 = F>,
-	F: PackedTop<Tower>,
 {
 	sumcheck_claim_descs
 		.par_iter()
@@ -2,14 +2,23 @@
 
 //! Traits for working with field towers.
 
+use std::marker::PhantomData;
+
 use binius_field::{
+	aes_field::{AES_TO_BINARY_LINEAR_TRANSFORMATION, BINARY_TO_AES_LINEAR_TRANSFORMATION},
 	as_packed_field::PackScalar,
-	linear_transformation::{PackedTransformationFactory, Transformation},
-	polyval::{AES_TO_POLYVAL_TRANSFORMATION, BINARY_TO_POLYVAL_TRANSFORMATION},
+	linear_transformation::{
+		FieldLinearTransformation, PackedTransformationFactory, Transformation,
+	},
+	polyval::{
+		AES_TO_POLYVAL_TRANSFORMATION, BINARY_TO_POLYVAL_TRANSFORMATION,
+		POLYVAL_TO_AES_TRANSFORMARION, POLYVAL_TO_BINARY_TRANSFORMATION,
+	},
 	underlier::UnderlierType,
 	AESTowerField128b, AESTowerField16b, AESTowerField32b, AESTowerField64b, AESTowerField8b,
 	BinaryField128b, BinaryField128bPolyval, BinaryField16b, BinaryField1b, BinaryField32b,
-	BinaryField64b, BinaryField8b, ExtensionField, PackedExtension, PackedField, TowerField,
+	BinaryField64b, BinaryField8b, ExtensionField, Field, PackedExtension, PackedField,
+	PackedSubfield, TowerField,
 };
 use trait_set::trait_set;
 
@@ -44,6 +53,14 @@ pub trait ProverTowerFamily: TowerFamily {
 	where
 		Top: PackedTop<Self> + PackedTransformationFactory<FastTop>,
 		FastTop: PackedField<Scalar = Self::FastB128>;
+
+	/// Creates a packed transformation mapping `Self::FastB128` back onto `Self::B128`.
+	///
+	/// This is the inverse of [`Self::packed_transformation_to_fast`].
+	fn packed_transformation_from_fast<FastTop, Top>() -> impl Transformation<FastTop, Top>
+	where
+		FastTop: PackedField<Scalar = Self::FastB128> + PackedTransformationFactory<Top>,
+		Top: PackedTop<Self>;
 }
 
 /// The canonical Fan-Paar tower family.
@@ -69,6 +86,14 @@ impl ProverTowerFamily for CanonicalTowerFamily {
 	{
 		Top::make_packed_transformation(BINARY_TO_POLYVAL_TRANSFORMATION)
 	}
+
+	fn packed_transformation_from_fast<FastTop, Top>() -> impl Transformation<FastTop, Top>
+	where
+		FastTop: PackedField<Scalar = Self::FastB128> + PackedTransformationFactory<Top>,
+		Top: PackedTop<Self>,
+	{
+		FastTop::make_packed_transformation(POLYVAL_TO_BINARY_TRANSFORMATION)
+	}
 }
 
 /// The tower defined by Fan-Paar extensions built on top of the Rijndael field.
@@ -94,6 +119,79 @@ impl ProverTowerFamily for AESTowerFamily {
 	{
 		Top::make_packed_transformation(AES_TO_POLYVAL_TRANSFORMATION)
 	}
+
+	fn packed_transformation_from_fast<FastTop, Top>() -> impl Transformation<FastTop, Top>
+	where
+		FastTop: PackedField<Scalar = Self::FastB128> + PackedTransformationFactory<Top>,
+		Top: PackedTop<Self>,
+	{
+		FastTop::make_packed_transformation(POLYVAL_TO_AES_TRANSFORMARION)
+	}
+}
+
+/// An isomorphism from the tower family `Self` onto the tower family `To`.
+///
+/// Every tower family extends its 8-bit field with isomorphic generators, so an isomorphism
+/// between the `B8` fields extends byte-wise to all higher levels of the tower. `B1` is
+/// $\mathbb{F}_2$ in every family and maps by the identity. These are all the levels of a
+/// [`TowerFamily`]; the 2- and 4-bit subfields of the canonical tower have no counterpart in the
+/// AES tower and can't be mapped.
+pub trait TowerIsomorphism<To: TowerFamily>: TowerFamily {
+	/// The $\mathbb{F}_2$-linear map from `Self::B8` onto `To::B8`.
+	const B8_TRANSFORMATION: FieldLinearTransformation<To::B8>;
+
+	/// Creates a packed transformation mapping elements of any level of `Self` at or above `B8`
+	/// onto the same level of `To`.
+	///
+	/// Note that creation of this object is not cheap, so it is better to create it once and reuse.
+	fn packed_transformation<PFrom, PTo>() -> impl Transformation<PFrom, PTo>
+	where
+		PFrom: PackedExtension<Self::B8, Scalar: ExtensionField<Self::B8>>,
+		PTo: PackedExtension<To::B8, Scalar: ExtensionField<To::B8>>,
+		PackedSubfield<PFrom, Self::B8>: PackedTransformationFactory<PackedSubfield<PTo, To::B8>>,
+	{
+		ByteWiseTransformation::<Self::B8, To::B8, _>::new(
+			PackedSubfield::<PFrom, Self::B8>::make_packed_transformation(Self::B8_TRANSFORMATION),
+		)
+	}
+}
+
+impl TowerIsomorphism<AESTowerFamily> for CanonicalTowerFamily {
+	const B8_TRANSFORMATION: FieldLinearTransformation<AESTowerField8b> =
+		BINARY_TO_AES_LINEAR_TRANSFORMATION;
+}
+
+impl TowerIsomorphism<CanonicalTowerFamily> for AESTowerFamily {
+	const B8_TRANSFORMATION: FieldLinearTransformation<BinaryField8b> =
+		AES_TO_BINARY_LINEAR_TRANSFORMATION;
+}
+
+/// Applies a packed transformation between 8-bit fields to every byte of a packed extension.
+struct ByteWiseTransformation<FFrom, FTo, T> {
+	inner: T,
+	_marker: PhantomData<fn(FFrom) -> FTo>,
+}
+
+impl<FFrom, FTo, T> ByteWiseTransformation<FFrom, FTo, T> {
+	const fn new(inner: T) -> Self {
+		Self {
+			inner,
+			_marker: PhantomData,
+		}
+	}
+}
+
+impl<FFrom, FTo, PFrom, PTo, T> Transformation<PFrom, PTo> for ByteWiseTransformation<FFrom, FTo, T>
+where
+	FFrom: Field,
+	FTo: Field,
+	PFrom: PackedExtension<FFrom, Scalar: ExtensionField<FFrom>>,
+	PTo: PackedExtension<FTo, Scalar: ExtensionField<FTo>>,
+	T: Transformation<PackedSubfield<PFrom, FFrom>, PackedSubfield<PTo, FTo>>,
+{
+	fn transform(&self, data: &PFrom) -> PTo {
+		PTo::cast_ext(self.inner.transform(PFrom::cast_base_ref(data)))
+	}
 }
 
 trait_set! {
@@ -10,4 +10,12 @@ pub enum Error {
 	NotEnoughBytes,
 	#[error("Serialization error: {0}")]
 	Serialization(#[from] SerializationError),
+	#[error("Transcript diverged at labeled message {step}: verifier expected \"{expected}\", prover wrote \"{found}\"")]
+	LabelMismatch {
+		step: usize,
+		expected: String,
+		found: String,
+	},
+	#[error("Transcript diverged at labeled message {step}: verifier expected \"{expected}\", prover wrote no labeled message")]
+	UnlabeledMessage { step: usize, expected: String },
 }
@@ -11,6 +11,12 @@
 //! used for values that were previously committed to in the transcript tape.** For example, it is
 //! secure to write a Merkle tree root to the transcript tape, sample a random index, then provide
 //! the Merkle leaf opening at that index in the advice tape.
+//!
+//! Prover messages may optionally be labeled with [`ProverTranscript::message_labeled`]. A label
+//! absorbs a domain-separation tag into the Fiat-Shamir state. In debug mode the label is also
+//! written to the advice tape, like [`TranscriptWriter::write_debug`] messages, and a verifier that
+//! reads the messages in a different order fails with [`Error::LabelMismatch`] naming the divergent
+//! step, rather than with a generic deserialization error further down the proof.
 
 mod error;
 
@@ -32,6 +38,7 @@ use crate::fiat_shamir::{CanSample, CanSampleBits, Challenger};
 pub struct ProverTranscript<Challenger> {
 	combined: FiatShamirBuf<BytesMut, Challenger>,
 	debug_assertions: bool,
+	labels: Vec<String>,
 }
 
 /// Verifier transcript over some Challenger that reads from the internal tape and `CanSample<F: TowerField>`
@@ -42,6 +49,7 @@ pub struct ProverTranscript<Challenger> {
 pub struct VerifierTranscript<Challenger> {
 	combined: FiatShamirBuf<Bytes, Challenger>,
 	debug_assertions: bool,
+	labels: Vec<String>,
 }
 
 #[derive(Debug, Default)]
@@ -103,6 +111,7 @@ impl<Challenger_: Default + Challenger> ProverTranscript<Challenger_> {
 		Self {
 			combined: Default::default(),
 			debug_assertions: cfg!(debug_assertions),
+			labels: Vec::new(),
 		}
 	}
 
@@ -170,6 +179,32 @@ impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
 			debug_assertions: self.debug_assertions,
 		}
 	}
+
+	/// Returns a writeable buffer that observes the data written and writes it to the proof tape,
+	/// after absorbing a domain-separation tag for `label`.
+	///
+	/// In debug mode, the label is also written to the advice tape, so that the verifier can check
+	/// it, and recorded in the label trace returned by [`Self::labels`]. The label is not observed
+	/// there, so the sampled challenges are the same in debug and release mode. The verifier must
+	/// read the message with [`VerifierTranscript::message_labeled`] using the same label.
+	pub fn message_labeled<'a, 'b>(&'a mut self, label: &str) -> TranscriptWriter<'b, impl BufMut>
+	where
+		'a: 'b,
+	{
+		write_label_tag(self.combined.challenger.observer(), label);
+		if self.debug_assertions {
+			write_label_tag(&mut self.combined.buffer, label);
+			self.labels.push(label.to_string());
+		}
+		self.message()
+	}
+
+	/// Returns the labels of the labeled messages written so far.
+	///
+	/// Labels are only recorded in debug mode.
+	pub fn labels(&self) -> &[String] {
+		&self.labels
+	}
 }
 
 impl<Challenger_: Default + Challenger> VerifierTranscript<Challenger_> {
@@ -180,6 +215,7 @@ impl<Challenger_: Default + Challenger> VerifierTranscript<Challenger_> {
 				buffer: Bytes::from(vec),
 			},
 			debug_assertions: cfg!(debug_assertions),
+			labels: Vec::new(),
 		}
 	}
 }
@@ -234,6 +270,50 @@ impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
 			debug_assertions: self.debug_assertions,
 		}
 	}
+
+	/// Returns a readable buffer that observes the data read, after absorbing a domain-separation
+	/// tag for `label`.
+	///
+	/// This is the counterpart of [`ProverTranscript::message_labeled`]. In debug mode, the label
+	/// the prover wrote to the advice tape is compared against `label`, and an error naming the
+	/// divergent step is returned if they differ.
+	pub fn message_labeled<'a, 'b>(
+		&'a mut self,
+		label: &str,
+	) -> Result<TranscriptReader<'b, impl Buf>, Error>
+	where
+		'a: 'b,
+	{
+		write_label_tag(self.combined.challenger.observer(), label);
+		if self.debug_assertions {
+			let step = self.labels.len();
+			match read_label_tag(&mut self.combined.buffer) {
+				Some(found) if found == label => {}
+				Some(found) => {
+					return Err(Error::LabelMismatch {
+						step,
+						expected: label.to_string(),
+						found,
+					})
+				}
+				None => {
+					return Err(Error::UnlabeledMessage {
+						step,
+						expected: label.to_string(),
+					})
+				}
+			}
+			self.labels.push(label.to_string());
+		}
+		Ok(self.message())
+	}
+
+	/// Returns the labels of the labeled messages read so far.
+	///
+	/// Labels are only recorded in debug mode.
+	pub fn labels(&self) -> &[String] {
+		&self.labels
+	}
 }
 
 // Useful warnings to see if we are neglecting to read any advice or transcript entirely
@@ -402,7 +482,7 @@ where
 	}
 }
 
-fn sample_bits_reader<Reader: Buf>(mut reader: Reader, bits: usize) -> usize {
+pub(crate) fn sample_bits_reader<Reader: Buf>(mut reader: Reader, bits: usize) -> usize {
 	let bits = bits.min(usize::BITS as usize);
 
 	let bytes_to_sample = bits.div_ceil(8);
@@ -438,6 +518,28 @@ where
 	}
 }
 
+/// Writes the length-prefixed label bytes.
+fn write_label_tag(mut buffer: impl BufMut, label: &str) {
+	buffer.put_u64_le(label.len() as u64);
+	buffer.put_slice(label.as_bytes());
+}
+
+/// Reads a label written by [`write_label_tag`].
+///
+/// Returns `None` if the buffer does not start with a length-prefixed UTF-8 label.
+fn read_label_tag(buffer: &mut impl Buf) -> Option<String> {
+	if buffer.remaining() < std::mem::size_of::<u64>() {
+		return None;
+	}
+	let len = usize::try_from(buffer.get_u64_le()).ok()?;
+	if buffer.remaining() < len {
+		return None;
+	}
+	let mut label = vec![0; len];
+	buffer.copy_to_slice(&mut label);
+	String::from_utf8(label).ok()
+}
+
 /// Helper functions for serializing native types
 pub fn read_u64<B: Buf>(transcript: &mut TranscriptReader<B>) -> Result<u64, Error> {
 	let mut as_bytes = [0; size_of::<u64>()];
@@ -451,6 +553,7 @@ pub fn write_u64<B: BufMut>(transcript: &mut TranscriptWriter<B>, n: u64) {
 
 #[cfg(test)]
 mod tests {
+	use assert_matches::assert_matches;
 	use binius_field::{
 		AESTowerField128b, AESTowerField16b, AESTowerField32b, AESTowerField8b, BinaryField128b,
 		BinaryField128bPolyval, BinaryField32b, BinaryField64b, BinaryField8b,
@@ -623,6 +726,138 @@ mod tests {
 			.read_debug("test_transcript_debug");
 	}
 
+	#[test]
+	fn test_labeled_messages() {
+		let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		prover_transcript.set_debug(true);
+		prover_transcript
+			.message_labeled("first")
+			.write_scalar(BinaryField32b::new(0xDEADBEEF));
+		let sampled_1: BinaryField64b = prover_transcript.sample();
+		prover_transcript
+			.message_labeled("second")
+			.write_scalar(BinaryField8b::new(0x96));
+		let sampled_2: BinaryField64b = prover_transcript.sample();
+		assert_eq!(prover_transcript.labels(), ["first", "second"]);
+
+		let mut verifier_transcript = prover_transcript.into_verifier();
+		verifier_transcript.set_debug(true);
+		let fp_32: BinaryField32b = verifier_transcript
+			.message_labeled("first")
+			.unwrap()
+			.read_scalar()
+			.unwrap();
+		assert_eq!(fp_32.val(), 0xDEADBEEF);
+		assert_eq!(CanSample::<BinaryField64b>::sample(&mut verifier_transcript), sampled_1);
+		let fp_8: BinaryField8b = verifier_transcript
+			.message_labeled("second")
+			.unwrap()
+			.read_scalar()
+			.unwrap();
+		assert_eq!(fp_8.val(), 0x96);
+		assert_eq!(CanSample::<BinaryField64b>::sample(&mut verifier_transcript), sampled_2);
+		assert_eq!(verifier_transcript.labels(), ["first", "second"]);
+
+		verifier_transcript.finalize().unwrap();
+	}
+
+	#[test]
+	fn test_labels_are_domain_separated() {
+		let mut labeled = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let mut unlabeled = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let mut relabeled = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		for transcript in [&mut labeled, &mut unlabeled, &mut relabeled] {
+			transcript.set_debug(false);
+		}
+
+		labeled.message_labeled("a").write_bytes(b"data");
+		unlabeled.message().write_bytes(b"data");
+		relabeled.message_labeled("b").write_bytes(b"data");
+
+		let sampled_labeled: BinaryField64b = labeled.sample();
+		let sampled_unlabeled: BinaryField64b = unlabeled.sample();
+		let sampled_relabeled: BinaryField64b = relabeled.sample();
+		assert_ne!(sampled_labeled, sampled_unlabeled);
+		assert_ne!(sampled_labeled, sampled_relabeled);
+
+		// Labels are not written to the proof tape.
+		assert_eq!(labeled.finalize(), unlabeled.finalize());
+	}
+
+	#[test]
+	fn test_labeled_challenges_independent_of_debug() {
+		let samples = [false, true].map(|debug| {
+			let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+			transcript.set_debug(debug);
+			transcript.message_labeled("a").write_bytes(b"data");
+			CanSample::<BinaryField64b>::sample(&mut transcript)
+		});
+		assert_eq!(samples[0], samples[1]);
+	}
+
+	#[test]
+	fn test_labeled_message_mismatch_from_proof_bytes() {
+		let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		prover_transcript.set_debug(true);
+		prover_transcript
+			.message_labeled("first")
+			.write_scalar(BinaryField32b::new(0xDEADBEEF));
+		let proof = prover_transcript.finalize();
+
+		// The verifier only gets the proof bytes, as in constraint_system::verify.
+		let mut verifier_transcript =
+			VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+		verifier_transcript.set_debug(true);
+		assert_matches!(
+			verifier_transcript.message_labeled("second").err(),
+			Some(Error::LabelMismatch { step: 0, ref expected, ref found })
+				if expected == "second" && found == "first"
+		);
+	}
+
+	#[test]
+	fn test_labeled_message_mismatch() {
+		let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		prover_transcript.set_debug(true);
+		prover_transcript.message_labeled("first").write_bytes(b"1");
+		prover_transcript
+			.message_labeled("second")
+			.write_bytes(b"2");
+
+		let mut verifier_transcript = prover_transcript.into_verifier();
+		verifier_transcript.set_debug(true);
+		let mut byte = [0u8];
+		verifier_transcript
+			.message_labeled("first")
+			.unwrap()
+			.read_bytes(&mut byte)
+			.unwrap();
+		let err = verifier_transcript.message_labeled("third").err().unwrap();
+		assert_matches!(
+			err,
+			Error::LabelMismatch { step: 1, ref expected, ref found }
+				if expected == "third" && found == "second"
+		);
+		assert_eq!(
+			err.to_string(),
+			"Transcript diverged at labeled message 1: verifier expected \"third\", prover wrote \"second\""
+		);
+	}
+
+	#[test]
+	fn test_labeled_message_unlabeled() {
+		let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		prover_transcript.set_debug(true);
+		prover_transcript.message().write_bytes(b"1");
+
+		let mut verifier_transcript = prover_transcript.into_verifier();
+		verifier_transcript.set_debug(true);
+		assert_matches!(
+			verifier_transcript.message_labeled("first").err(),
+			Some(Error::UnlabeledMessage { step: 0, .. })
+		);
+	}
+
 	#[test]
 	#[should_panic]
 	fn test_transcript_debug_fail() {
@@ -5,6 +5,7 @@ pub mod disjoint_product;
 pub mod eq_ind;
 pub mod multilinear_extension;
 pub mod powers;
+pub mod row_index;
 pub mod select_row;
 pub mod shift_ind;
 pub mod step_down;
@@ -0,0 +1,160 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use binius_field::{BinaryField32b, ExtensionField, PackedField, TowerField};
+use binius_math::MultilinearExtension;
+use binius_utils::bail;
+
+use crate::polynomial::{Error, MultivariatePoly};
+
+/// Represents a multilinear polynomial whose evaluation at every hypercube index is that index,
+/// with a fixed prefix in the higher bits, as an element of [`BinaryField32b`].
+///
+/// ```txt
+///     (1 << n_vars)
+/// <-------------------->
+/// p|0, p|1, .. p|(1 << n_vars) - 1
+/// ```
+///
+/// where `p = prefix << n_vars` and integers are mapped to field elements with the same bit
+/// representation. Because every bit of the index is a hypercube coordinate, the polynomial is
+/// affine.
+///
+/// This is useful for labelling the rows of several columns with distinct field elements.
+#[derive(Debug, Clone)]
+pub struct RowIndex {
+	n_vars: usize,
+	prefix: usize,
+}
+
+impl RowIndex {
+	pub fn new(n_vars: usize, prefix: usize) -> Result<Self, Error> {
+		let max_n_vars = 1 << BinaryField32b::TOWER_LEVEL;
+		if n_vars > max_n_vars {
+			bail!(Error::ArgumentRangeError {
+				arg: "n_vars".into(),
+				range: 0..max_n_vars + 1,
+			})
+		}
+		let prefix_bits = max_n_vars - n_vars;
+		if prefix_bits < usize::BITS as usize && prefix >= 1 << prefix_bits {
+			bail!(Error::ArgumentRangeError {
+				arg: "prefix".into(),
+				range: 0..1 << prefix_bits,
+			})
+		}
+		Ok(Self { n_vars, prefix })
+	}
+
+	pub const fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	/// The value of the polynomial at the hypercube index `index`.
+	pub const fn value(&self, index: usize) -> BinaryField32b {
+		let prefix = if self.n_vars < usize::BITS as usize {
+			self.prefix << self.n_vars
+		} else {
+			0
+		};
+		BinaryField32b::new((prefix | index) as u32)
+	}
+
+	pub fn multilinear_extension<P: PackedField<Scalar = BinaryField32b>>(
+		&self,
+	) -> Result<MultilinearExtension<P>, Error> {
+		if self.n_vars < P::LOG_WIDTH {
+			bail!(Error::PackedFieldNotFilled {
+				length: 1 << self.n_vars,
+				packed_width: 1 << P::LOG_WIDTH,
+			});
+		}
+		let mut data = vec![P::zero(); 1 << (self.n_vars - P::LOG_WIDTH)];
+		self.populate(&mut data);
+		Ok(MultilinearExtension::from_values(data)?)
+	}
+
+	pub fn populate<P: PackedField<Scalar = BinaryField32b>>(&self, data: &mut [P]) {
+		for (i, packed) in data.iter_mut().enumerate() {
+			*packed = P::from_fn(|j| {
+				let index = (i << P::LOG_WIDTH) | j;
+				if index >> self.n_vars == 0 {
+					self.value(index)
+				} else {
+					BinaryField32b::default()
+				}
+			});
+		}
+	}
+}
+
+impl<F: TowerField + ExtensionField<BinaryField32b>> MultivariatePoly<F> for RowIndex {
+	fn degree(&self) -> usize {
+		1
+	}
+
+	fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	fn evaluate(&self, query: &[F]) -> Result<F, Error> {
+		let n_vars = MultivariatePoly::<F>::n_vars(self);
+		if query.len() != n_vars {
+			bail!(Error::IncorrectQuerySize { expected: n_vars });
+		}
+		let result = query
+			.iter()
+			.enumerate()
+			.map(|(i, &q)| q * self.value(1 << i) - q * self.value(0))
+			.sum::<F>();
+		Ok(result + self.value(0))
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		BinaryField32b::TOWER_LEVEL
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField128b, BinaryField32b, PackedBinaryField4x32b, PackedField};
+
+	use super::RowIndex;
+	use crate::polynomial::test_utils::hypercube_evals_from_oracle;
+
+	#[test]
+	fn test_row_index_values() {
+		let row_index = RowIndex::new(3, 5).unwrap();
+		let evals = row_index
+			.multilinear_extension::<BinaryField32b>()
+			.unwrap()
+			.evals()
+			.to_vec();
+		assert_eq!(evals, (40..48).map(BinaryField32b::new).collect::<Vec<_>>());
+	}
+
+	#[test]
+	fn test_row_index_argument_ranges() {
+		assert!(RowIndex::new(32, 0).is_ok());
+		assert!(RowIndex::new(33, 0).is_err());
+		assert!(RowIndex::new(30, 3).is_ok());
+		assert!(RowIndex::new(30, 4).is_err());
+	}
+
+	#[test]
+	fn test_consistency_between_multilinear_extension_and_multilinear_poly_oracle() {
+		for n_vars in 2..6 {
+			for prefix in [0, 1, 7] {
+				let row_index = RowIndex::new(n_vars, prefix).unwrap();
+				let evals = row_index
+					.multilinear_extension::<PackedBinaryField4x32b>()
+					.unwrap()
+					.evals()
+					.iter()
+					.flat_map(|packed| packed.iter())
+					.map(BinaryField128b::from)
+					.collect::<Vec<_>>();
+				assert_eq!(hypercube_evals_from_oracle::<BinaryField128b>(&row_index), evals);
+			}
+		}
+	}
+}
@@ -4,13 +4,22 @@ use std::{fmt::Debug, sync::Arc};
 
 use binius_field::{
 	as_packed_field::{PackScalar, PackedType},
-	underlier::UnderlierType,
-	ExtensionField, Field, PackedExtension, TowerField,
+	linear_transformation::{PackedTransformationFactory, Transformation},
+	underlier::{UnderlierType, WithUnderlier},
+	BinaryField1b, ExtensionField, Field, PackedExtension, TowerField,
 };
 use binius_math::{MultilinearExtension, MultilinearExtensionBorrowed, MultilinearPoly};
+use binius_maybe_rayon::prelude::*;
 use binius_utils::bail;
 
-use crate::{oracle::OracleId, polynomial::Error as PolynomialError};
+use crate::{
+	oracle::OracleId,
+	polynomial::Error as PolynomialError,
+	tower::{
+		PackedTop, ProverTowerFamily, ProverTowerUnderlier, TowerFamily, TowerIsomorphism,
+		TowerUnderlier,
+	},
+};
 
 pub type MultilinearWitness<'a, P> = Arc<dyn MultilinearPoly<P> + Send + Sync + 'a>;
 
@@ -41,6 +50,13 @@ pub enum Error {
 		field_log_extension_degree: usize,
 		entry_log_extension_degree: usize,
 	},
+	#[error("witness for oracle id {oracle_id} has log extension degree {log_extension_degree}, which does not correspond to a level of the tower family")]
+	UnsupportedTowerLevel {
+		oracle_id: OracleId,
+		log_extension_degree: usize,
+	},
+	#[error("witness for oracle id {oracle_id} is over the {n_bits}-bit subfield, which tower families don't represent")]
+	SubByteTowerLevel { oracle_id: OracleId, n_bits: usize },
 	#[error("polynomial error: {0}")]
 	Polynomial(#[from] PolynomialError),
 	#[error("HAL error: {0}")]
@@ -122,3 +138,364 @@ where
 		Ok(MultilinearExtension::from_values_slice(evals)?)
 	}
 }
+
+impl<'a, U, FW> MultilinearExtensionIndex<'a, U, FW>
+where
+	U: UnderlierType + PackScalar<FW>,
+	FW: TowerField,
+{
+	/// Maps every witness onto the isomorphic tower family `Dst`.
+	///
+	/// Each witness is converted at the tower level it is stored in, so that the resulting index
+	/// can be proven with the same constraint system instantiated over `Dst`. All witnesses must
+	/// have an explicit backing multilinear extension and be stored at a level of [`TowerFamily`].
+	/// In particular, witnesses over the 2- and 4-bit subfields of the canonical tower are rejected
+	/// with [`Error::SubByteTowerLevel`], as the AES tower has no representation of these
+	/// subfields.
+	pub fn convert_tower<Src, Dst>(
+		&self,
+	) -> Result<MultilinearExtensionIndex<'a, U, Dst::B128>, Error>
+	where
+		Src: TowerIsomorphism<Dst, B128 = FW>,
+		Dst: TowerFamily,
+		U: TowerUnderlier<Src> + TowerUnderlier<Dst>,
+		PackedType<U, Src::B8>: PackedTransformationFactory<PackedType<U, Dst::B8>>,
+	{
+		let transformation =
+			Src::packed_transformation::<PackedType<U, Src::B8>, PackedType<U, Dst::B8>>();
+
+		let entries = self
+			.entries
+			.iter()
+			.enumerate()
+			.map(|(oracle_id, entry)| {
+				entry
+					.as_ref()
+					.map(|witness| {
+						convert_witness_tower::<Src, Dst, U>(oracle_id, witness, &transformation)
+					})
+					.transpose()
+			})
+			.collect::<Result<_, _>>()?;
+
+		Ok(MultilinearExtensionIndex { entries })
+	}
+
+	/// Maps every witness onto the POLYVAL field `Tower::FastB128`.
+	///
+	/// POLYVAL is a single 128-bit field rather than a tower, so only the witnesses over `B1`,
+	/// which are copied as is, and over `B128` can be mapped. Witnesses stored at any other level
+	/// are rejected with [`Error::UnsupportedTowerLevel`].
+	pub fn convert_to_fast<Tower>(
+		&self,
+	) -> Result<MultilinearExtensionIndex<'a, U, Tower::FastB128>, Error>
+	where
+		Tower: ProverTowerFamily<B128 = FW>,
+		Tower::FastB128: ExtensionField<BinaryField1b>,
+		FW: ExtensionField<BinaryField1b>,
+		U: ProverTowerUnderlier<Tower> + PackScalar<BinaryField1b>,
+		PackedType<U, FW>:
+			PackedTop<Tower> + PackedTransformationFactory<PackedType<U, Tower::FastB128>>,
+	{
+		let transformation = Tower::packed_transformation_to_fast();
+		self.convert_polyval(&transformation)
+	}
+
+	/// Maps every witness over the POLYVAL field back onto `Tower::B128`.
+	///
+	/// This is the inverse of [`Self::convert_to_fast`].
+	pub fn convert_from_fast<Tower>(
+		&self,
+	) -> Result<MultilinearExtensionIndex<'a, U, Tower::B128>, Error>
+	where
+		Tower: ProverTowerFamily<FastB128 = FW>,
+		Tower::B128: ExtensionField<BinaryField1b>,
+		FW: ExtensionField<BinaryField1b>,
+		U: ProverTowerUnderlier<Tower> + PackScalar<BinaryField1b>,
+		PackedType<U, FW>: PackedTransformationFactory<PackedType<U, Tower::B128>>,
+		PackedType<U, Tower::B128>: PackedTop<Tower>,
+	{
+		let transformation = Tower::packed_transformation_from_fast();
+		self.convert_polyval(&transformation)
+	}
+
+	fn convert_polyval<FTo>(
+		&self,
+		transformation: &impl Transformation<PackedType<U, FW>, PackedType<U, FTo>>,
+	) -> Result<MultilinearExtensionIndex<'a, U, FTo>, Error>
+	where
+		FW: ExtensionField<BinaryField1b>,
+		FTo: TowerField + ExtensionField<BinaryField1b>,
+		U: PackScalar<FTo> + PackScalar<BinaryField1b>,
+	{
+		let entries = self
+			.entries
+			.iter()
+			.enumerate()
+			.map(|(oracle_id, entry)| {
+				entry
+					.as_ref()
+					.map(|witness| {
+						convert_witness_polyval::<U, FW, FTo>(oracle_id, witness, transformation)
+					})
+					.transpose()
+			})
+			.collect::<Result<_, _>>()?;
+
+		Ok(MultilinearExtensionIndex { entries })
+	}
+}
+
+fn convert_witness_polyval<'a, U, FFrom, FTo>(
+	oracle_id: OracleId,
+	witness: &MultilinearWitness<'a, PackedType<U, FFrom>>,
+	transformation: &impl Transformation<PackedType<U, FFrom>, PackedType<U, FTo>>,
+) -> Result<MultilinearWitness<'a, PackedType<U, FTo>>, Error>
+where
+	U: PackScalar<FFrom> + PackScalar<FTo> + PackScalar<BinaryField1b>,
+	FFrom: TowerField + ExtensionField<BinaryField1b>,
+	FTo: TowerField + ExtensionField<BinaryField1b>,
+{
+	let n_vars = witness.n_vars();
+	let log_extension_degree = witness.log_extension_degree();
+	let evals = witness
+		.packed_evals()
+		.ok_or(Error::NoExplicitBackingMultilinearExtension { id: oracle_id })?;
+
+	if log_extension_degree == 0 {
+		let underliers = evals
+			.par_iter()
+			.map(|packed| transformation.transform(packed).to_underlier())
+			.collect();
+		witness_from_underliers::<U, FTo, FTo>(n_vars, underliers)
+	} else if log_extension_degree == FFrom::TOWER_LEVEL {
+		// B1 is shared by both fields, so bit-packed witnesses are copied as is.
+		let underliers = PackedType::<U, FFrom>::to_underliers_ref(evals).to_vec();
+		witness_from_underliers::<U, BinaryField1b, FTo>(n_vars, underliers)
+	} else {
+		Err(Error::UnsupportedTowerLevel {
+			oracle_id,
+			log_extension_degree,
+		})
+	}
+}
+
+fn convert_witness_tower<'a, Src, Dst, U>(
+	oracle_id: OracleId,
+	witness: &MultilinearWitness<'a, PackedType<U, Src::B128>>,
+	transformation: &impl Transformation<PackedType<U, Src::B8>, PackedType<U, Dst::B8>>,
+) -> Result<MultilinearWitness<'a, PackedType<U, Dst::B128>>, Error>
+where
+	Src: TowerFamily,
+	Dst: TowerFamily,
+	U: TowerUnderlier<Src> + TowerUnderlier<Dst>,
+{
+	let n_vars = witness.n_vars();
+	let log_extension_degree = witness.log_extension_degree();
+	let tower_level = Src::B128::TOWER_LEVEL
+		.checked_sub(log_extension_degree)
+		.ok_or(Error::UnsupportedTowerLevel {
+			oracle_id,
+			log_extension_degree,
+		})?;
+	if matches!(tower_level, 1 | 2) {
+		bail!(Error::SubByteTowerLevel {
+			oracle_id,
+			n_bits: 1 << tower_level,
+		});
+	}
+	let evals = witness
+		.packed_evals()
+		.ok_or(Error::NoExplicitBackingMultilinearExtension { id: oracle_id })?;
+
+	// B1 is the same field in every tower family, so bit-packed witnesses are copied as is.
+	let underliers = if tower_level == 0 {
+		PackedType::<U, Src::B128>::to_underliers_ref(evals).to_vec()
+	} else {
+		<PackedType<U, Src::B128> as PackedExtension<Src::B8>>::cast_bases(evals)
+			.par_iter()
+			.map(|packed| transformation.transform(packed).to_underlier())
+			.collect()
+	};
+
+	match tower_level {
+		0 => witness_from_underliers::<U, Dst::B1, Dst::B128>(n_vars, underliers),
+		3 => witness_from_underliers::<U, Dst::B8, Dst::B128>(n_vars, underliers),
+		4 => witness_from_underliers::<U, Dst::B16, Dst::B128>(n_vars, underliers),
+		5 => witness_from_underliers::<U, Dst::B32, Dst::B128>(n_vars, underliers),
+		6 => witness_from_underliers::<U, Dst::B64, Dst::B128>(n_vars, underliers),
+		7 => witness_from_underliers::<U, Dst::B128, Dst::B128>(n_vars, underliers),
+		_ => Err(Error::UnsupportedTowerLevel {
+			oracle_id,
+			log_extension_degree,
+		}),
+	}
+}
+
+fn witness_from_underliers<'a, U, FS, FW>(
+	n_vars: usize,
+	underliers: Vec<U>,
+) -> Result<MultilinearWitness<'a, PackedType<U, FW>>, Error>
+where
+	U: PackScalar<FS> + PackScalar<FW>,
+	FS: Field,
+	FW: ExtensionField<FS>,
+{
+	let evals = underliers
+		.into_iter()
+		.map(PackedType::<U, FS>::from_underlier)
+		.collect::<Vec<_>>();
+	Ok(MultilinearExtension::new(n_vars, evals)?.specialize_arc_dyn())
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{
+		arch::OptimalUnderlier, AESTowerField128b, BinaryField128b, BinaryField128bPolyval,
+		BinaryField1b, BinaryField32b, BinaryField4b, BinaryField8b, PackedField,
+	};
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::tower::{AESTowerFamily, CanonicalTowerFamily};
+
+	fn random_witness<'a, FS>(
+		mut rng: impl rand::RngCore,
+		n_vars: usize,
+	) -> MultilinearWitness<'a, PackedType<OptimalUnderlier, BinaryField128b>>
+	where
+		FS: TowerField,
+		BinaryField128b: ExtensionField<FS>,
+		OptimalUnderlier: PackScalar<FS>,
+	{
+		let evals = (0..1 << n_vars.saturating_sub(PackedType::<OptimalUnderlier, FS>::LOG_WIDTH))
+			.map(|_| PackedType::<OptimalUnderlier, FS>::random(&mut rng))
+			.collect::<Vec<_>>();
+		MultilinearExtension::new(n_vars, evals)
+			.unwrap()
+			.specialize_arc_dyn()
+	}
+
+	#[test]
+	fn test_convert_tower_round_trip() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let n_vars = 10;
+
+		let mut witness = MultilinearExtensionIndex::<OptimalUnderlier, BinaryField128b>::new();
+		witness
+			.update_multilin_poly([
+				(0, random_witness::<BinaryField1b>(&mut rng, n_vars)),
+				(1, random_witness::<BinaryField8b>(&mut rng, n_vars)),
+				(3, random_witness::<BinaryField32b>(&mut rng, n_vars)),
+				(4, random_witness::<BinaryField128b>(&mut rng, n_vars)),
+			])
+			.unwrap();
+
+		let aes_witness = witness
+			.convert_tower::<CanonicalTowerFamily, AESTowerFamily>()
+			.unwrap();
+		assert!(!aes_witness.has(2));
+
+		for id in [0, 1, 3, 4] {
+			let canonical = witness.get_multilin_poly(id).unwrap();
+			let aes = aes_witness.get_multilin_poly(id).unwrap();
+			assert_eq!(aes.n_vars(), n_vars);
+			assert_eq!(aes.log_extension_degree(), canonical.log_extension_degree());
+			for i in 0..1 << n_vars {
+				assert_eq!(
+					aes.evaluate_on_hypercube(i).unwrap(),
+					AESTowerField128b::from(canonical.evaluate_on_hypercube(i).unwrap())
+				);
+			}
+		}
+
+		let round_trip = aes_witness
+			.convert_tower::<AESTowerFamily, CanonicalTowerFamily>()
+			.unwrap();
+		for id in [0, 1, 3, 4] {
+			assert_eq!(
+				round_trip.get_multilin_poly(id).unwrap().packed_evals(),
+				witness.get_multilin_poly(id).unwrap().packed_evals()
+			);
+		}
+	}
+
+	#[test]
+	fn test_convert_to_fast_round_trip() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let n_vars = 10;
+
+		let mut witness = MultilinearExtensionIndex::<OptimalUnderlier, BinaryField128b>::new();
+		witness
+			.update_multilin_poly([
+				(0, random_witness::<BinaryField1b>(&mut rng, n_vars)),
+				(2, random_witness::<BinaryField128b>(&mut rng, n_vars)),
+			])
+			.unwrap();
+
+		let fast_witness = witness.convert_to_fast::<CanonicalTowerFamily>().unwrap();
+		assert!(!fast_witness.has(1));
+
+		for id in [0, 2] {
+			let canonical = witness.get_multilin_poly(id).unwrap();
+			let fast = fast_witness.get_multilin_poly(id).unwrap();
+			assert_eq!(fast.n_vars(), n_vars);
+			assert_eq!(fast.log_extension_degree(), canonical.log_extension_degree());
+			for i in 0..1 << n_vars {
+				assert_eq!(
+					fast.evaluate_on_hypercube(i).unwrap(),
+					BinaryField128bPolyval::from(canonical.evaluate_on_hypercube(i).unwrap())
+				);
+			}
+		}
+
+		let round_trip = fast_witness
+			.convert_from_fast::<CanonicalTowerFamily>()
+			.unwrap();
+		for id in [0, 2] {
+			assert_eq!(
+				round_trip.get_multilin_poly(id).unwrap().packed_evals(),
+				witness.get_multilin_poly(id).unwrap().packed_evals()
+			);
+		}
+	}
+
+	#[test]
+	fn test_convert_to_fast_rejects_intermediate_levels() {
+		let mut rng = StdRng::seed_from_u64(0);
+
+		let mut witness = MultilinearExtensionIndex::<OptimalUnderlier, BinaryField128b>::new();
+		witness
+			.update_multilin_poly([(0, random_witness::<BinaryField32b>(&mut rng, 8))])
+			.unwrap();
+
+		assert!(matches!(
+			witness.convert_to_fast::<CanonicalTowerFamily>(),
+			Err(Error::UnsupportedTowerLevel {
+				oracle_id: 0,
+				log_extension_degree: 2,
+			})
+		));
+	}
+
+	#[test]
+	fn test_convert_tower_rejects_sub_byte_levels() {
+		let mut rng = StdRng::seed_from_u64(0);
+
+		let mut witness = MultilinearExtensionIndex::<OptimalUnderlier, BinaryField128b>::new();
+		witness
+			.update_multilin_poly([
+				(0, random_witness::<BinaryField8b>(&mut rng, 8)),
+				(1, random_witness::<BinaryField4b>(&mut rng, 8)),
+			])
+			.unwrap();
+
+		assert!(matches!(
+			witness.convert_tower::<CanonicalTowerFamily, AESTowerFamily>(),
+			Err(Error::SubByteTowerLevel {
+				oracle_id: 1,
+				n_bits: 4,
+			})
+		));
+	}
+}
@@ -0,0 +1,60 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::iter::repeat_with;
+
+use binius_field::{
+	util::{batch_invert, batch_invert_or_zero},
+	AESTowerField8b, BinaryField128b, BinaryField128bPolyval, Field, PackedAESBinaryField32x8b,
+	PackedBinaryField2x128b, PackedBinaryField8x32b, PackedBinaryPolyval2x128b, PackedField,
+};
+use criterion::{
+	criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion, Throughput,
+};
+use rand::thread_rng;
+
+fn bench_batch_invert<P: PackedField>(group: &mut BenchmarkGroup<WallTime>, name: &str) {
+	let mut rng = thread_rng();
+	for log_len in [10, 16] {
+		// Non-zero values so that `batch_invert` succeeds on every iteration. Inverting in place
+		// keeps them non-zero.
+		let mut buffer = repeat_with(|| {
+			P::from_fn(|_| {
+				repeat_with(|| <P::Scalar as Field>::random(&mut rng))
+					.find(|scalar| *scalar != P::Scalar::ZERO)
+					.expect("iterator is infinite")
+			})
+		})
+		.take(1 << log_len)
+		.collect::<Vec<_>>();
+		group.throughput(Throughput::Elements(((1 << log_len) * P::WIDTH) as u64));
+
+		group.bench_function(format!("{name}/log_len={log_len}/elementwise"), |bench| {
+			bench.iter(|| {
+				for value in &mut buffer {
+					*value = value.invert_or_zero();
+				}
+			})
+		});
+		group.bench_function(format!("{name}/log_len={log_len}/batch_invert_or_zero"), |bench| {
+			bench.iter(|| batch_invert_or_zero(&mut buffer))
+		});
+		group.bench_function(format!("{name}/log_len={log_len}/batch_invert"), |bench| {
+			bench.iter(|| batch_invert(&mut buffer))
+		});
+	}
+}
+
+fn batch_invert_packed(c: &mut Criterion) {
+	let mut group = c.benchmark_group("batch_invert");
+	bench_batch_invert::<PackedBinaryField8x32b>(&mut group, "8x32b");
+	bench_batch_invert::<PackedAESBinaryField32x8b>(&mut group, "aes_32x8b");
+	bench_batch_invert::<PackedBinaryField2x128b>(&mut group, "2x128b");
+	bench_batch_invert::<PackedBinaryPolyval2x128b>(&mut group, "polyval_2x128b");
+	bench_batch_invert::<AESTowerField8b>(&mut group, "aes_8b");
+	bench_batch_invert::<BinaryField128b>(&mut group, "128b");
+	bench_batch_invert::<BinaryField128bPolyval>(&mut group, "polyval_128b");
+	group.finish();
+}
+
+criterion_group!(packed_batch_invert, batch_invert_packed);
+criterion_main!(packed_batch_invert);
@@ -279,6 +279,14 @@ macro_rules! benchmark_packed_operation {
 				ByteSlicedAES32x32b
 				ByteSlicedAES32x64b
 				ByteSlicedAES32x128b
+				ByteSlicedAES16x128b
+				ByteSlicedAES64x128b
+
+				// Byte sliced AES fields with stacked scalars
+				ByteSlicedAES32x2x64b
+				ByteSlicedAES32x4x32b
+				ByteSlicedAES32x8x16b
+				ByteSlicedAES32x16x8b
 			]);
 	};
 }
@@ -14,7 +14,10 @@ use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 use super::{
 	arithmetic_traits::InvertOrZero,
-	binary_field::{binary_field, impl_field_extension, BinaryField, BinaryField1b},
+	binary_field::{
+		binary_field, impl_field_extension, serialize_deserialize_non_canonical, BinaryField,
+		BinaryField1b,
+	},
 	binary_field_arithmetic::TowerFieldArithmetic,
 	mul_by_binary_field_1b, BinaryField8b, Error, PackedExtension, PackedSubfield,
 };
@@ -75,6 +78,12 @@ impl_arithmetic_using_packed!(AESTowerField32b);
 impl_arithmetic_using_packed!(AESTowerField64b);
 impl_arithmetic_using_packed!(AESTowerField128b);
 
+serialize_deserialize_non_canonical!(AESTowerField8b, canonical = BinaryField8b);
+serialize_deserialize_non_canonical!(AESTowerField16b, canonical = BinaryField16b);
+serialize_deserialize_non_canonical!(AESTowerField32b, canonical = BinaryField32b);
+serialize_deserialize_non_canonical!(AESTowerField64b, canonical = BinaryField64b);
+serialize_deserialize_non_canonical!(AESTowerField128b, canonical = BinaryField128b);
+
 impl TowerField for AESTowerField8b {
 	type Canonical = BinaryField8b;
 
@@ -288,10 +297,11 @@ mod tests {
 
 	use super::*;
 	use crate::{
-		binary_field::tests::is_binary_field_valid_generator, deserialize_canonical,
-		serialize_canonical, underlier::WithUnderlier, PackedAESBinaryField16x32b,
-		PackedAESBinaryField4x32b, PackedAESBinaryField8x32b, PackedBinaryField16x32b,
-		PackedBinaryField4x32b, PackedBinaryField8x32b,
+		binary_field::tests::{check_reference_vectors, is_binary_field_valid_generator},
+		deserialize_canonical, serialize_canonical,
+		underlier::WithUnderlier,
+		PackedAESBinaryField16x32b, PackedAESBinaryField4x32b, PackedAESBinaryField8x32b,
+		PackedBinaryField16x32b, PackedBinaryField4x32b, PackedBinaryField8x32b,
 	};
 
 	fn check_square(f: impl Field) {
@@ -334,9 +344,68 @@ mod tests {
 		}
 	}
 
-	fn check_isomorphism_preserves_ops<F1: Field, F2: Field + From<F1>>(a: F1, b: F1) {
+	#[test]
+	fn test_aes_tower_reference_vectors() {
+		check_reference_vectors::<AESTowerField8b, _>(&[
+			(0x38, 0xc3, 0xd5, 0x2e, 1, 0xac, 0x70),
+			(0xf0, 0x7e, 0x35, 0x58, 0, 0x46, 0x8c),
+		]);
+		check_reference_vectors::<AESTowerField16b, _>(&[
+			(0x3bab, 0x587f, 0xb2ce, 0xd292, 0, 0x3c77, 0x3b1a),
+			(0xad45, 0x3802, 0xbed7, 0x2229, 1, 0xca98, 0xc2cc),
+		]);
+		check_reference_vectors::<AESTowerField32b, _>(&[
+			(0x75a89294, 0xf3c64af7, 0x834b2b30, 0x7880e48b, 1, 0xfa19daa2, 0x4a2f20aa),
+			(0xed2f89d9, 0x5805975, 0x8dee0bed, 0x6665b7c7, 1, 0x2c70c86f, 0x6a8ac4ba),
+		]);
+		check_reference_vectors::<AESTowerField64b, _>(&[
+			(
+				0xea90a8f0d66b829e,
+				0xec148cb48e73ca47,
+				0xf293c7579a57bca0,
+				0xc9bd0953681bec33,
+				0,
+				0x5ea0ca95aae0e529,
+				0x19999e3fa46d6752,
+			),
+			(
+				0xa11d459a2f978d87,
+				0xb94067edfe175330,
+				0x9bb7f1bb696d3b90,
+				0x5e9e87a49889fc66,
+				1,
+				0xa962c755784f9ab2,
+				0x4be03db0dc2574bc,
+			),
+		]);
+		check_reference_vectors::<AESTowerField128b, _>(&[
+			(
+				0xe5446dd4552b82f6be3edc0a1ef2a4f0,
+				0x803468b6b610a9f7f9270f4eb8b333a8,
+				0x15d41902a340dab96c6fa5e3e9699ee9,
+				0xc410422ceeb1ed46671c9ff8e5754ed4,
+				1,
+				0x66df9a804ed316fee66920b2b9601b5a,
+				0x81f9c1f66c0f3459f79b17aeefba91fc,
+			),
+			(
+				0x3099fdf5ab99254ae901e35cd47d380d,
+				0xf9341c68966baea148beab134da98f1d,
+				0xc36ef8caf79d7e94ef6e6ea99777b590,
+				0x80ce950268db98fd547ab228fbc0aee,
+				1,
+				0x893e997f296d8a497a444e05f49f7405,
+				0xf0dfb4a5d8a064df7fd63116e1ea24c4,
+			),
+		]);
+	}
+
+	fn check_isomorphism_preserves_ops<F1: BinaryField, F2: BinaryField + From<F1>>(a: F1, b: F1) {
 		assert_eq!(F2::from(a * b), F2::from(a) * F2::from(b));
 		assert_eq!(F2::from(a + b), F2::from(a) + F2::from(b));
+		assert_eq!(F2::from(a.sqrt()), F2::from(a).sqrt());
+		assert_eq!(F2::from(a).trace(), a.trace());
+		assert_eq!(F2::from(a).solve_quadratic().is_some(), a.solve_quadratic().is_some());
 	}
 
 	proptest! {
@@ -4,8 +4,10 @@ mod invert;
 mod multiply;
 mod packed_byte_sliced;
 mod square;
+mod underlier;
 
 pub use packed_byte_sliced::*;
+pub use underlier::*;
 
 #[cfg(test)]
 pub mod tests {
@@ -13,88 +15,93 @@ pub mod tests {
 		($module_name:ident, $name:ident, $scalar_type:ty) => {
 			mod $module_name{
 				use proptest::prelude::*;
-				use crate::{$scalar_type, underlier::WithUnderlier, packed::PackedField, arch::byte_sliced::$name};
+				use rand::{rngs::StdRng, SeedableRng};
 
-				fn scalar_array_strategy() -> impl Strategy<Value = [$scalar_type; 32]> {
-					any::<[<$scalar_type as WithUnderlier>::Underlier; 32]>().prop_map(|arr| arr.map(<$scalar_type>::from_underlier))
+				use crate::{$scalar_type, packed::PackedField, arch::byte_sliced::$name, ExtensionField, Field};
+
+				fn scalar_array_strategy() -> impl Strategy<Value = Vec<$scalar_type>> {
+					any::<u64>().prop_map(|seed| {
+						let mut rng = StdRng::seed_from_u64(seed);
+						(0..<$name>::WIDTH).map(|_| <$scalar_type as Field>::random(&mut rng)).collect()
+					})
 				}
 
 				proptest! {
 					#[test]
 					fn check_add(scalar_elems_a in scalar_array_strategy(), scalar_elems_b in scalar_array_strategy()) {
-						let bytesliced_a = <$name>::from_scalars(scalar_elems_a);
-						let bytesliced_b = <$name>::from_scalars(scalar_elems_b);
+						let bytesliced_a = <$name>::from_scalars(scalar_elems_a.clone());
+						let bytesliced_b = <$name>::from_scalars(scalar_elems_b.clone());
 
 						let bytesliced_result = bytesliced_a + bytesliced_b;
 
-						for i in 0..32 {
+						for i in 0..<$name>::WIDTH {
 							assert_eq!(scalar_elems_a[i] + scalar_elems_b[i], bytesliced_result.get(i));
 						}
 					}
 
 					#[test]
 					fn check_add_assign(scalar_elems_a in scalar_array_strategy(), scalar_elems_b in scalar_array_strategy()) {
-						let mut bytesliced_a = <$name>::from_scalars(scalar_elems_a);
-						let bytesliced_b = <$name>::from_scalars(scalar_elems_b);
+						let mut bytesliced_a = <$name>::from_scalars(scalar_elems_a.clone());
+						let bytesliced_b = <$name>::from_scalars(scalar_elems_b.clone());
 
 						bytesliced_a += bytesliced_b;
 
-						for i in 0..32 {
+						for i in 0..<$name>::WIDTH {
 							assert_eq!(scalar_elems_a[i] + scalar_elems_b[i], bytesliced_a.get(i));
 						}
 					}
 
 					#[test]
 					fn check_sub(scalar_elems_a in scalar_array_strategy(), scalar_elems_b in scalar_array_strategy()) {
-						let bytesliced_a = <$name>::from_scalars(scalar_elems_a);
-						let bytesliced_b = <$name>::from_scalars(scalar_elems_b);
+						let bytesliced_a = <$name>::from_scalars(scalar_elems_a.clone());
+						let bytesliced_b = <$name>::from_scalars(scalar_elems_b.clone());
 
 						let bytesliced_result = bytesliced_a - bytesliced_b;
 
-						for i in 0..32 {
+						for i in 0..<$name>::WIDTH {
 							assert_eq!(scalar_elems_a[i] - scalar_elems_b[i], bytesliced_result.get(i));
 						}
 					}
 
 					#[test]
 					fn check_sub_assign(scalar_elems_a in scalar_array_strategy(), scalar_elems_b in scalar_array_strategy()) {
-						let mut bytesliced_a = <$name>::from_scalars(scalar_elems_a);
-						let bytesliced_b = <$name>::from_scalars(scalar_elems_b);
+						let mut bytesliced_a = <$name>::from_scalars(scalar_elems_a.clone());
+						let bytesliced_b = <$name>::from_scalars(scalar_elems_b.clone());
 
 						bytesliced_a -= bytesliced_b;
 
-						for i in 0..32 {
+						for i in 0..<$name>::WIDTH {
 							assert_eq!(scalar_elems_a[i] - scalar_elems_b[i], bytesliced_a.get(i));
 						}
 					}
 
 					#[test]
 					fn check_mul(scalar_elems_a in scalar_array_strategy(), scalar_elems_b in scalar_array_strategy()) {
-						let bytesliced_a = <$name>::from_scalars(scalar_elems_a);
-						let bytesliced_b = <$name>::from_scalars(scalar_elems_b);
+						let bytesliced_a = <$name>::from_scalars(scalar_elems_a.clone());
+						let bytesliced_b = <$name>::from_scalars(scalar_elems_b.clone());
 
 						let bytesliced_result = bytesliced_a * bytesliced_b;
 
-						for i in 0..32 {
+						for i in 0..<$name>::WIDTH {
 							assert_eq!(scalar_elems_a[i] * scalar_elems_b[i], bytesliced_result.get(i));
 						}
 					}
 
 					#[test]
 					fn check_mul_assign(scalar_elems_a in scalar_array_strategy(), scalar_elems_b in scalar_array_strategy()) {
-						let mut bytesliced_a = <$name>::from_scalars(scalar_elems_a);
-						let bytesliced_b = <$name>::from_scalars(scalar_elems_b);
+						let mut bytesliced_a = <$name>::from_scalars(scalar_elems_a.clone());
+						let bytesliced_b = <$name>::from_scalars(scalar_elems_b.clone());
 
 						bytesliced_a *= bytesliced_b;
 
-						for i in 0..32 {
+						for i in 0..<$name>::WIDTH {
 							assert_eq!(scalar_elems_a[i] * scalar_elems_b[i], bytesliced_a.get(i));
 						}
 					}
 
 					#[test]
 					fn check_inv(scalar_elems in scalar_array_strategy()) {
-						let bytesliced = <$name>::from_scalars(scalar_elems);
+						let bytesliced = <$name>::from_scalars(scalar_elems.clone());
 
 						let bytesliced_result = bytesliced.invert_or_zero();
 
@@ -105,7 +112,7 @@ pub mod tests {
 
 					#[test]
 					fn check_square(scalar_elems in scalar_array_strategy()) {
-						let bytesliced = <$name>::from_scalars(scalar_elems);
+						let bytesliced = <$name>::from_scalars(scalar_elems.clone());
 
 						let bytesliced_result = bytesliced.square();
 
@@ -113,14 +120,138 @@ pub mod tests {
 							assert_eq!(scalar_elem.square(), bytesliced_result.get(i));
 						}
 					}
+
+					#[test]
+					fn check_interleave(scalar_elems_a in scalar_array_strategy(), scalar_elems_b in scalar_array_strategy()) {
+						let bytesliced_a = <$name>::from_scalars(scalar_elems_a.clone());
+						let bytesliced_b = <$name>::from_scalars(scalar_elems_b.clone());
+
+						for log_block_len in 0..<$name>::LOG_WIDTH {
+							let (result_a, result_b) = bytesliced_a.interleave(bytesliced_b, log_block_len);
+
+							for i in 0..<$name>::WIDTH {
+								let block_len = 1 << log_block_len;
+								let (expected_a, expected_b) = if i & block_len == 0 {
+									(scalar_elems_a[i], scalar_elems_a[i | block_len])
+								} else {
+									(scalar_elems_b[i ^ block_len], scalar_elems_b[i])
+								};
+								assert_eq!(result_a.get(i), expected_a);
+								assert_eq!(result_b.get(i), expected_b);
+							}
+						}
+					}
+
+					#[test]
+					fn check_bytes(scalar_elems in scalar_array_strategy()) {
+						let bytesliced = <$name>::from_scalars(scalar_elems.clone());
+
+						let bits = scalar_elems
+							.iter()
+							.flat_map(|scalar| <$scalar_type as ExtensionField<crate::BinaryField1b>>::iter_bases(scalar).collect::<Vec<_>>())
+							.collect::<Vec<_>>();
+						for (i, byte_bits) in bits.chunks(8).enumerate() {
+							let expected_byte = byte_bits
+								.iter()
+								.enumerate()
+								.fold(0u8, |byte, (j, &bit)| byte | u8::from(bit) << j);
+							assert_eq!(unsafe { bytesliced.get_byte_unchecked(i) }, expected_byte);
+						}
+					}
+				}
+			}
+		};
+	}
+
+	macro_rules! define_byte_sliced_cast_test {
+		($module_name:ident, $underlier:ty) => {
+			mod $module_name {
+				use proptest::prelude::*;
+				use rand::{rngs::StdRng, SeedableRng};
+
+				use crate::{
+					arch::byte_sliced::*,
+					as_packed_field::{PackScalar, PackedType},
+					packed::PackedField,
+					underlier::WithUnderlier,
+					AESTowerField128b, AESTowerField16b, AESTowerField32b, AESTowerField64b,
+					AESTowerField8b, BinaryField1b, ExtensionField, Field, PackedExtension,
+				};
+
+				type U = $underlier;
+
+				fn check_cast<FS>(packed: PackedType<U, AESTowerField128b>)
+				where
+					FS: Field,
+					AESTowerField128b: ExtensionField<FS>,
+					U: PackScalar<FS>,
+				{
+					let subfield =
+						<PackedType<U, AESTowerField128b> as PackedExtension<FS>>::cast_base(
+							packed,
+						);
+					let expected = packed
+						.iter()
+						.flat_map(|scalar| {
+							<AESTowerField128b as ExtensionField<FS>>::iter_bases(&scalar)
+								.collect::<Vec<_>>()
+						})
+						.collect::<Vec<_>>();
+					assert_eq!(subfield.iter().collect::<Vec<_>>(), expected);
+				}
+
+				proptest! {
+					#[test]
+					fn check_cast_bases(seed in any::<u64>()) {
+						let packed = PackedType::<U, AESTowerField128b>::random(StdRng::seed_from_u64(seed));
+
+						check_cast::<BinaryField1b>(packed);
+						check_cast::<AESTowerField8b>(packed);
+						check_cast::<AESTowerField16b>(packed);
+						check_cast::<AESTowerField32b>(packed);
+						check_cast::<AESTowerField64b>(packed);
+						check_cast::<AESTowerField128b>(packed);
+					}
 				}
 			}
 		};
 	}
 
+	define_byte_sliced_test!(tests_16x128, ByteSlicedAES16x128b, AESTowerField128b);
+	define_byte_sliced_test!(tests_16x2x64, ByteSlicedAES16x2x64b, AESTowerField64b);
+	define_byte_sliced_test!(tests_16x4x32, ByteSlicedAES16x4x32b, AESTowerField32b);
+	define_byte_sliced_test!(tests_16x8x16, ByteSlicedAES16x8x16b, AESTowerField16b);
+	define_byte_sliced_test!(tests_16x16x8, ByteSlicedAES16x16x8b, AESTowerField8b);
+	define_byte_sliced_test!(tests_16x128x1, ByteSliced16x128x1b, BinaryField1b);
+
 	define_byte_sliced_test!(tests_32x128, ByteSlicedAES32x128b, AESTowerField128b);
 	define_byte_sliced_test!(tests_32x64, ByteSlicedAES32x64b, AESTowerField64b);
 	define_byte_sliced_test!(tests_32x32, ByteSlicedAES32x32b, AESTowerField32b);
 	define_byte_sliced_test!(tests_32x16, ByteSlicedAES32x16b, AESTowerField16b);
 	define_byte_sliced_test!(tests_32x8, ByteSlicedAES32x8b, AESTowerField8b);
+	define_byte_sliced_test!(tests_32x2x64, ByteSlicedAES32x2x64b, AESTowerField64b);
+	define_byte_sliced_test!(tests_32x4x32, ByteSlicedAES32x4x32b, AESTowerField32b);
+	define_byte_sliced_test!(tests_32x8x16, ByteSlicedAES32x8x16b, AESTowerField16b);
+	define_byte_sliced_test!(tests_32x16x8, ByteSlicedAES32x16x8b, AESTowerField8b);
+	define_byte_sliced_test!(tests_32x128x1, ByteSliced32x128x1b, BinaryField1b);
+
+	define_byte_sliced_test!(tests_64x128, ByteSlicedAES64x128b, AESTowerField128b);
+	define_byte_sliced_test!(tests_64x2x64, ByteSlicedAES64x2x64b, AESTowerField64b);
+	define_byte_sliced_test!(tests_64x4x32, ByteSlicedAES64x4x32b, AESTowerField32b);
+	define_byte_sliced_test!(tests_64x8x16, ByteSlicedAES64x8x16b, AESTowerField16b);
+	define_byte_sliced_test!(tests_64x16x8, ByteSlicedAES64x16x8b, AESTowerField8b);
+	define_byte_sliced_test!(tests_64x128x1, ByteSliced64x128x1b, BinaryField1b);
+
+	define_byte_sliced_cast_test!(
+		cast_tests_16,
+		<ByteSlicedAES16x128b as WithUnderlier>::Underlier
+	);
+	define_byte_sliced_cast_test!(
+		cast_tests_32,
+		<ByteSlicedAES32x128b as WithUnderlier>::Underlier
+	);
+	define_byte_sliced_cast_test!(
+		cast_tests_64,
+		<ByteSlicedAES64x128b as WithUnderlier>::Underlier
+	);
 }
@@ -7,33 +7,233 @@ use std::{
 	ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
 };
 
-use bytemuck::Zeroable;
+use binius_utils::checked_arithmetics::checked_log_2;
+use bytemuck::{TransparentWrapper, Zeroable};
 
-use super::{invert::invert_or_zero, multiply::mul, square::square};
+use super::{invert::invert_or_zero, multiply::mul, square::square, ByteSlicedUnderlier};
 use crate::{
-	packed_aes_field::PackedAESBinaryField32x8b,
+	as_packed_field::PackScalar,
+	packed_aes_field::{
+		PackedAESBinaryField16x8b, PackedAESBinaryField32x8b, PackedAESBinaryField64x8b,
+	},
 	tower_levels::*,
 	underlier::{UnderlierWithBitOps, WithUnderlier},
 	AESTowerField128b, AESTowerField16b, AESTowerField32b, AESTowerField64b, AESTowerField8b,
+	BinaryField1b, PackedBinaryField128x1b, PackedBinaryField256x1b, PackedBinaryField512x1b,
 	PackedField,
 };
 
-/// Represents 32 AES Tower Field elements in byte-sliced form backed by Packed 32x8b AES fields.
+/// Implements the parts shared by all byte-sliced packed fields: the underlier conversions,
+/// serialization and the arithmetic operations that are expressed through addition,
+/// multiplication and broadcast.
+macro_rules! impl_byte_sliced_common {
+	($name:ident, $scalar_type:ty, $packed_storage:ty, $storage_tower_level:ty) => {
+		unsafe impl
+			TransparentWrapper<
+				ByteSlicedUnderlier<
+					<$packed_storage as WithUnderlier>::Underlier,
+					{ <$storage_tower_level as TowerLevel<$packed_storage>>::WIDTH },
+				>,
+			> for $name
+		{
+		}
+
+		unsafe impl WithUnderlier for $name {
+			type Underlier = ByteSlicedUnderlier<
+				<$packed_storage as WithUnderlier>::Underlier,
+				{ <$storage_tower_level as TowerLevel<$packed_storage>>::WIDTH },
+			>;
+
+			fn to_underlier(self) -> Self::Underlier {
+				TransparentWrapper::peel(self)
+			}
+
+			fn to_underlier_ref(&self) -> &Self::Underlier {
+				TransparentWrapper::peel_ref(self)
+			}
+
+			fn to_underlier_ref_mut(&mut self) -> &mut Self::Underlier {
+				TransparentWrapper::peel_mut(self)
+			}
+
+			fn to_underliers_ref(val: &[Self]) -> &[Self::Underlier] {
+				TransparentWrapper::peel_slice(val)
+			}
+
+			fn to_underliers_ref_mut(val: &mut [Self]) -> &mut [Self::Underlier] {
+				TransparentWrapper::peel_slice_mut(val)
+			}
+
+			fn from_underlier(val: Self::Underlier) -> Self {
+				TransparentWrapper::wrap(val)
+			}
+
+			fn from_underlier_ref(val: &Self::Underlier) -> &Self {
+				TransparentWrapper::wrap_ref(val)
+			}
+
+			fn from_underlier_ref_mut(val: &mut Self::Underlier) -> &mut Self {
+				TransparentWrapper::wrap_mut(val)
+			}
+
+			fn from_underliers_ref(val: &[Self::Underlier]) -> &[Self] {
+				TransparentWrapper::wrap_slice(val)
+			}
+
+			fn from_underliers_ref_mut(val: &mut [Self::Underlier]) -> &mut [Self] {
+				TransparentWrapper::wrap_slice_mut(val)
+			}
+		}
+
+		#[cfg(feature = "serde")]
+		impl serde::Serialize for $name {
+			fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+				crate::serde_impl::serialize_packed(self, serializer)
+			}
+		}
+
+		#[cfg(feature = "serde")]
+		impl<'de> serde::Deserialize<'de> for $name {
+			fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+				crate::serde_impl::deserialize_packed(deserializer)
+			}
+		}
+
+		impl Add<$scalar_type> for $name {
+			type Output = Self;
+
+			fn add(self, rhs: $scalar_type) -> $name {
+				self + Self::broadcast(rhs)
+			}
+		}
+
+		impl AddAssign<$scalar_type> for $name {
+			fn add_assign(&mut self, rhs: $scalar_type) {
+				*self += Self::broadcast(rhs)
+			}
+		}
+
+		impl Sub for $name {
+			type Output = Self;
+
+			fn sub(self, rhs: Self) -> Self {
+				self.add(rhs)
+			}
+		}
+
+		impl Sub<$scalar_type> for $name {
+			type Output = Self;
+
+			fn sub(self, rhs: $scalar_type) -> $name {
+				self.add(rhs)
+			}
+		}
+
+		impl SubAssign for $name {
+			fn sub_assign(&mut self, rhs: Self) {
+				self.add_assign(rhs);
+			}
+		}
+
+		impl SubAssign<$scalar_type> for $name {
+			fn sub_assign(&mut self, rhs: $scalar_type) {
+				self.add_assign(rhs)
+			}
+		}
+
+		impl Mul<$scalar_type> for $name {
+			type Output = Self;
+
+			fn mul(self, rhs: $scalar_type) -> $name {
+				self * Self::broadcast(rhs)
+			}
+		}
+
+		impl MulAssign for $name {
+			fn mul_assign(&mut self, rhs: Self) {
+				*self = *self * rhs;
+			}
+		}
+
+		impl MulAssign<$scalar_type> for $name {
+			fn mul_assign(&mut self, rhs: $scalar_type) {
+				*self *= Self::broadcast(rhs);
+			}
+		}
+
+		impl Product for $name {
+			fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+				let mut result = Self::one();
+
+				let mut is_first_item = true;
+				for item in iter {
+					if is_first_item {
+						result = item;
+					} else {
+						result *= item;
+					}
+
+					is_first_item = false;
+				}
+
+				result
+			}
+		}
+
+		impl Sum for $name {
+			fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+				let mut result = Self::zero();
+
+				for item in iter {
+					result += item;
+				}
+
+				result
+			}
+		}
+	};
+}
+
+/// Represents AES Tower Field elements in byte-sliced form backed by packed 8-bit AES fields.
+///
+/// This allows us to multiply many 128b values in parallel using an efficient tower
+/// multiplication circuit on GFNI machines, since multiplication of two packed 8-bit field
+/// elements is handled in one instruction.
+///
+/// The data consists of `$storage_tower_level::WIDTH` byte planes. Scalars that are narrower than
+/// the storage are stacked: the planes are split into groups of `$scalar_tower_level::WIDTH`
+/// consecutive planes, and the scalar with index `i` is stored in the group `i % HEIGHT` at the
+/// lane `i / HEIGHT`. This is exactly the layout of the byte decomposition of the widest scalar,
+/// so all the types with the same storage share an underlier and can be cast into each other.
 ///
-/// This allows us to multiply 32 128b values in parallel using an efficient tower
-/// multiplication circuit on GFNI machines, since multiplication of two 32x8b field elements is
-/// handled in one instruction.
-macro_rules! define_byte_sliced {
-	($name:ident, $scalar_type:ty, $tower_level: ty) => {
+/// Storage layout note: `ByteSlicedAES32x128b`, `ByteSlicedAES32x64b`, `ByteSlicedAES32x32b`,
+/// `ByteSlicedAES32x16b` and `ByteSlicedAES32x8b` used to be plain arrays of
+/// `PackedAESBinaryField32x8b` without an underlier. They are now `#[repr(transparent)]` over a
+/// [`ByteSlicedUnderlier`] with as many planes as their scalar has bytes, which is not the
+/// underlier of the 256-bit [`PackScalar`] family for the narrower scalars. For example, a
+/// `ByteSlicedAES32x32b` holds 32 scalars in 4 planes, while the `PackScalar<AESTowerField32b>`
+/// type of the 16-plane underlier is `ByteSlicedAES32x4x32b`, which holds 128 scalars. Code that
+/// stored these values or reinterpreted their bytes must not assume either layout carries over.
+macro_rules! define_byte_sliced_3d {
+	($name:ident, $scalar_type:ty, $packed_storage:ty, $scalar_tower_level:ty, $storage_tower_level:ty) => {
 		#[derive(Default, Clone, Debug, Copy, PartialEq, Eq, Zeroable)]
+		#[repr(transparent)]
 		pub struct $name {
-			pub(super) data: [PackedAESBinaryField32x8b;
-				<$tower_level as TowerLevel<PackedAESBinaryField32x8b>>::WIDTH],
+			pub(super) data: [[$packed_storage;
+				<$scalar_tower_level as TowerLevel<$packed_storage>>::WIDTH];
+				<$storage_tower_level as TowerLevel<$packed_storage>>::WIDTH
+					/ <$scalar_tower_level as TowerLevel<$packed_storage>>::WIDTH],
 		}
 
 		impl $name {
-			pub const BYTES: usize = PackedAESBinaryField32x8b::WIDTH
-				* <$tower_level as TowerLevel<PackedAESBinaryField32x8b>>::WIDTH;
+			pub const BYTES: usize = <$packed_storage>::WIDTH
+				* <$storage_tower_level as TowerLevel<$packed_storage>>::WIDTH;
+
+			const SCALAR_BYTES: usize = <$scalar_tower_level as TowerLevel<$packed_storage>>::WIDTH;
+			const HEIGHT_BYTES: usize =
+				<$storage_tower_level as TowerLevel<$packed_storage>>::WIDTH;
+			const HEIGHT: usize = Self::HEIGHT_BYTES / Self::SCALAR_BYTES;
+			const LOG_HEIGHT: usize = checked_log_2(Self::HEIGHT);
 
 			/// Get the byte at the given index.
 			///
@@ -41,58 +241,67 @@ macro_rules! define_byte_sliced {
 			/// The caller must ensure that `byte_index` is less than `BYTES`.
 			#[allow(clippy::modulo_one)]
 			pub unsafe fn get_byte_unchecked(&self, byte_index: usize) -> u8 {
-				self.data
-					[byte_index % <$tower_level as TowerLevel<PackedAESBinaryField32x8b>>::WIDTH]
-					.get(
-						byte_index / <$tower_level as TowerLevel<PackedAESBinaryField32x8b>>::WIDTH,
-					)
+				let plane = byte_index % Self::HEIGHT_BYTES;
+				self.data[plane / Self::SCALAR_BYTES][plane % Self::SCALAR_BYTES]
+					.get_unchecked(byte_index / Self::HEIGHT_BYTES)
 					.to_underlier()
 			}
 		}
 
+		impl_byte_sliced_common!($name, $scalar_type, $packed_storage, $storage_tower_level);
+
 		impl PackedField for $name {
 			type Scalar = $scalar_type;
 
-			const LOG_WIDTH: usize = 5;
+			const LOG_WIDTH: usize = <$packed_storage>::LOG_WIDTH + Self::LOG_HEIGHT;
 
+			#[allow(clippy::modulo_one)]
 			unsafe fn get_unchecked(&self, i: usize) -> Self::Scalar {
+				let element_rows = &self.data[i % Self::HEIGHT];
+				let lane = i / Self::HEIGHT;
+
 				let mut result_underlier = 0;
-				for (byte_index, val) in self.data.iter().enumerate() {
+				for (byte_index, val) in element_rows.iter().enumerate() {
 					// Safety:
-					// - `byte_index` is less than 16
-					// - `i` must be less than 32 due to safety conditions of this method
+					// - `byte_index` is less than the number of bytes in the scalar
+					// - `lane` is less than the width of the storage due to safety conditions of
+					//   this method
 					unsafe {
 						result_underlier
-							.set_subvalue(byte_index, val.get_unchecked(i).to_underlier())
+							.set_subvalue(byte_index, val.get_unchecked(lane).to_underlier())
 					}
 				}
 
 				Self::Scalar::from_underlier(result_underlier)
 			}
 
+			#[allow(clippy::modulo_one)]
 			unsafe fn set_unchecked(&mut self, i: usize, scalar: Self::Scalar) {
+				let element_rows = &mut self.data[i % Self::HEIGHT];
+				let lane = i / Self::HEIGHT;
 				let underlier = scalar.to_underlier();
 
-				for byte_index in 0..<$tower_level as TowerLevel<PackedAESBinaryField32x8b>>::WIDTH
-				{
-					self.data[byte_index].set_unchecked(
-						i,
+				for (byte_index, val) in element_rows.iter_mut().enumerate() {
+					val.set_unchecked(
+						lane,
 						AESTowerField8b::from_underlier(underlier.get_subvalue(byte_index)),
 					);
 				}
 			}
 
-			fn random(rng: impl rand::RngCore) -> Self {
-				Self::from_scalars([Self::Scalar::random(rng); 32])
+			fn random(mut rng: impl rand::RngCore) -> Self {
+				Self::from_fn(|_| Self::Scalar::random(&mut rng))
 			}
 
 			fn broadcast(scalar: Self::Scalar) -> Self {
+				let element_rows = array::from_fn(|byte_index| {
+					<$packed_storage>::broadcast(AESTowerField8b::from_underlier(unsafe {
+						scalar.to_underlier().get_subvalue(byte_index)
+					}))
+				});
+
 				Self {
-					data: array::from_fn(|byte_index| {
-						PackedAESBinaryField32x8b::broadcast(AESTowerField8b::from_underlier(
-							unsafe { scalar.to_underlier().get_subvalue(byte_index) },
-						))
-					}),
+					data: [element_rows; Self::HEIGHT],
 				}
 			}
 
@@ -110,14 +319,20 @@ macro_rules! define_byte_sliced {
 			fn square(self) -> Self {
 				let mut result = Self::default();
 
-				square::<$tower_level>(&self.data, &mut result.data);
+				for (rows, result_rows) in zip(&self.data, &mut result.data) {
+					square::<$packed_storage, $scalar_tower_level>(rows, result_rows);
+				}
 
 				result
 			}
 
 			fn invert_or_zero(self) -> Self {
 				let mut result = Self::default();
-				invert_or_zero::<$tower_level>(&self.data, &mut result.data);
+
+				for (rows, result_rows) in zip(&self.data, &mut result.data) {
+					invert_or_zero::<$packed_storage, $scalar_tower_level>(rows, result_rows);
+				}
+
 				result
 			}
 
@@ -125,12 +340,28 @@ macro_rules! define_byte_sliced {
 				let mut result1 = Self::default();
 				let mut result2 = Self::default();
 
-				for byte_num in 0..<$tower_level as TowerLevel<PackedAESBinaryField32x8b>>::WIDTH {
-					let (this_byte_result1, this_byte_result2) =
-						self.data[byte_num].interleave(other.data[byte_num], log_block_len);
-
-					result1.data[byte_num] = this_byte_result1;
-					result2.data[byte_num] = this_byte_result2;
+				if log_block_len < Self::LOG_HEIGHT {
+					// The blocks are stacked within the same lanes, so only the groups of planes
+					// are permuted.
+					let block_len = 1 << log_block_len;
+					for i in (0..Self::HEIGHT).filter(|i| i & block_len == 0) {
+						result1.data[i] = self.data[i];
+						result1.data[i | block_len] = other.data[i];
+						result2.data[i] = self.data[i | block_len];
+						result2.data[i | block_len] = other.data[i | block_len];
+					}
+				} else {
+					for (((this, other), result1), result2) in self
+						.data
+						.as_flattened()
+						.iter()
+						.zip(other.data.as_flattened())
+						.zip(result1.data.as_flattened_mut())
+						.zip(result2.data.as_flattened_mut())
+					{
+						(*result1, *result2) =
+							this.interleave(*other, log_block_len - Self::LOG_HEIGHT);
+					}
 				}
 
 				(result1, result2)
@@ -140,135 +371,385 @@ macro_rules! define_byte_sliced {
 		impl Add for $name {
 			type Output = Self;
 
-			fn add(self, rhs: Self) -> Self {
-				Self {
-					data: array::from_fn(|byte_number| {
-						self.data[byte_number] + rhs.data[byte_number]
-					}),
-				}
-			}
-		}
-
-		impl Add<$scalar_type> for $name {
-			type Output = Self;
-
-			fn add(self, rhs: $scalar_type) -> $name {
-				self + Self::broadcast(rhs)
+			fn add(mut self, rhs: Self) -> Self {
+				self += rhs;
+				self
 			}
 		}
 
 		impl AddAssign for $name {
 			fn add_assign(&mut self, rhs: Self) {
-				for (data, rhs) in zip(&mut self.data, &rhs.data) {
+				for (data, rhs) in zip(self.data.as_flattened_mut(), rhs.data.as_flattened()) {
 					*data += *rhs
 				}
 			}
 		}
 
-		impl AddAssign<$scalar_type> for $name {
-			fn add_assign(&mut self, rhs: $scalar_type) {
-				*self += Self::broadcast(rhs)
-			}
-		}
-
-		impl Sub for $name {
+		impl Mul for $name {
 			type Output = Self;
 
-			fn sub(self, rhs: Self) -> Self {
-				self.add(rhs)
-			}
-		}
+			fn mul(self, rhs: Self) -> Self {
+				let mut result = Self::default();
 
-		impl Sub<$scalar_type> for $name {
-			type Output = Self;
+				for ((lhs_rows, rhs_rows), result_rows) in
+					self.data.iter().zip(&rhs.data).zip(&mut result.data)
+				{
+					mul::<$packed_storage, $scalar_tower_level>(lhs_rows, rhs_rows, result_rows);
+				}
 
-			fn sub(self, rhs: $scalar_type) -> $name {
-				self.add(rhs)
+				result
 			}
 		}
+	};
+}
 
-		impl SubAssign for $name {
-			fn sub_assign(&mut self, rhs: Self) {
-				self.add_assign(rhs);
-			}
+/// Represents binary field elements in the byte-sliced layout.
+///
+/// This is the 1-bit counterpart of the types defined with `define_byte_sliced_3d`: the bit `j` of
+/// the 128-bit lane `i` is the `(i * 128 + j)`-th scalar, and it is stored in the bit `j % 8` of the
+/// lane `i` of the byte plane `j / 8`. The type shares the underlier with the byte-sliced AES
+/// fields of the same storage, so it can be used as the packed subfield of those types.
+macro_rules! define_byte_sliced_3d_1b {
+	($name:ident, $packed_storage:ty, $packed_storage_8b:ty, $storage_tower_level:ty) => {
+		#[derive(Default, Clone, Debug, Copy, PartialEq, Eq, Zeroable)]
+		#[repr(transparent)]
+		pub struct $name {
+			pub(super) data:
+				[$packed_storage; <$storage_tower_level as TowerLevel<$packed_storage>>::WIDTH],
 		}
 
-		impl SubAssign<$scalar_type> for $name {
-			fn sub_assign(&mut self, rhs: $scalar_type) {
-				self.add_assign(rhs)
+		impl $name {
+			pub const BYTES: usize = <$packed_storage_8b>::WIDTH
+				* <$storage_tower_level as TowerLevel<$packed_storage>>::WIDTH;
+
+			const HEIGHT_BYTES: usize =
+				<$storage_tower_level as TowerLevel<$packed_storage>>::WIDTH;
+			const LOG_HEIGHT: usize = checked_log_2(Self::HEIGHT_BYTES) + 3;
+
+			/// Get the byte at the given index.
+			///
+			/// # Safety
+			/// The caller must ensure that `byte_index` is less than `BYTES`.
+			pub unsafe fn get_byte_unchecked(&self, byte_index: usize) -> u8 {
+				<$packed_storage_8b>::from_underlier(
+					self.data[byte_index % Self::HEIGHT_BYTES].to_underlier(),
+				)
+				.get_unchecked(byte_index / Self::HEIGHT_BYTES)
+				.to_underlier()
+			}
+
+			#[inline(always)]
+			const fn bit_position(i: usize) -> (usize, usize) {
+				let lane = i >> Self::LOG_HEIGHT;
+				let bit = i % (1 << Self::LOG_HEIGHT);
+				(bit / 8, lane * 8 + bit % 8)
 			}
 		}
 
-		impl Mul for $name {
-			type Output = Self;
+		impl_byte_sliced_common!($name, BinaryField1b, $packed_storage, $storage_tower_level);
 
-			fn mul(self, rhs: Self) -> Self {
-				let mut result = $name {
-					data: [PackedAESBinaryField32x8b::default();
-						<$tower_level as TowerLevel<PackedAESBinaryField32x8b>>::WIDTH],
-				};
+		impl PackedField for $name {
+			type Scalar = BinaryField1b;
 
-				mul::<$tower_level>(&self.data, &rhs.data, &mut result.data);
+			const LOG_WIDTH: usize =
+				<$packed_storage>::LOG_WIDTH + checked_log_2(Self::HEIGHT_BYTES);
 
-				result
+			unsafe fn get_unchecked(&self, i: usize) -> Self::Scalar {
+				let (plane, index) = Self::bit_position(i);
+				self.data[plane].get_unchecked(index)
 			}
-		}
 
-		impl Mul<$scalar_type> for $name {
-			type Output = Self;
+			unsafe fn set_unchecked(&mut self, i: usize, scalar: Self::Scalar) {
+				let (plane, index) = Self::bit_position(i);
+				self.data[plane].set_unchecked(index, scalar);
+			}
 
-			fn mul(self, rhs: $scalar_type) -> $name {
-				self * Self::broadcast(rhs)
+			fn random(mut rng: impl rand::RngCore) -> Self {
+				Self {
+					data: array::from_fn(|_| <$packed_storage>::random(&mut rng)),
+				}
 			}
-		}
 
-		impl MulAssign for $name {
-			fn mul_assign(&mut self, rhs: Self) {
-				*self = *self * rhs;
+			fn broadcast(scalar: Self::Scalar) -> Self {
+				Self {
+					data: [<$packed_storage>::broadcast(scalar); Self::HEIGHT_BYTES],
+				}
 			}
-		}
 
-		impl MulAssign<$scalar_type> for $name {
-			fn mul_assign(&mut self, rhs: $scalar_type) {
-				*self *= Self::broadcast(rhs);
+			fn from_fn(mut f: impl FnMut(usize) -> Self::Scalar) -> Self {
+				let mut result = Self::default();
+
+				for i in 0..Self::WIDTH {
+					//SAFETY: i doesn't exceed Self::WIDTH
+					unsafe { result.set_unchecked(i, f(i)) };
+				}
+
+				result
 			}
-		}
 
-		impl Product for $name {
-			fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
-				let mut result = Self::one();
+			fn square(self) -> Self {
+				self
+			}
 
-				let mut is_first_item = true;
-				for item in iter {
-					if is_first_item {
-						result = item;
-					} else {
-						result *= item;
+			fn invert_or_zero(self) -> Self {
+				self
+			}
+
+			fn interleave(self, other: Self, log_block_len: usize) -> (Self, Self) {
+				let mut result1 = Self::default();
+				let mut result2 = Self::default();
+
+				if (3..Self::LOG_HEIGHT).contains(&log_block_len) {
+					// The blocks consist of whole bytes within the same lanes, so only the planes
+					// are permuted.
+					let block_len = 1 << (log_block_len - 3);
+					for i in (0..Self::HEIGHT_BYTES).filter(|i| i & block_len == 0) {
+						result1.data[i] = self.data[i];
+						result1.data[i | block_len] = other.data[i];
+						result2.data[i] = self.data[i | block_len];
+						result2.data[i | block_len] = other.data[i | block_len];
 					}
+				} else {
+					let log_block_len = if log_block_len < 3 {
+						log_block_len
+					} else {
+						log_block_len - checked_log_2(Self::HEIGHT_BYTES)
+					};
 
-					is_first_item = false;
+					for i in 0..Self::HEIGHT_BYTES {
+						(result1.data[i], result2.data[i]) =
+							self.data[i].interleave(other.data[i], log_block_len);
+					}
 				}
 
-				result
+				(result1, result2)
 			}
 		}
 
-		impl Sum for $name {
-			fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-				let mut result = Self::zero();
+		impl Add for $name {
+			type Output = Self;
 
-				for item in iter {
-					result += item;
+			fn add(mut self, rhs: Self) -> Self {
+				self += rhs;
+				self
+			}
+		}
+
+		impl AddAssign for $name {
+			fn add_assign(&mut self, rhs: Self) {
+				for (data, rhs) in zip(&mut self.data, &rhs.data) {
+					*data += *rhs
 				}
+			}
+		}
 
-				result
+		impl Mul for $name {
+			type Output = Self;
+
+			fn mul(self, rhs: Self) -> Self {
+				Self {
+					data: array::from_fn(|i| self.data[i] * rhs.data[i]),
+				}
 			}
 		}
 	};
 }
 
-define_byte_sliced!(ByteSlicedAES32x128b, AESTowerField128b, TowerLevel16);
-define_byte_sliced!(ByteSlicedAES32x64b, AESTowerField64b, TowerLevel8);
-define_byte_sliced!(ByteSlicedAES32x32b, AESTowerField32b, TowerLevel4);
-define_byte_sliced!(ByteSlicedAES32x16b, AESTowerField16b, TowerLevel2);
-define_byte_sliced!(ByteSlicedAES32x8b, AESTowerField8b, TowerLevel1);
+/// Makes the byte-sliced packed fields with 16 byte planes available via [`PackScalar`].
+macro_rules! impl_pack_scalar_byte_sliced {
+	($packed_storage:ty => $($scalar_type:ty: $name:ident),* $(,)?) => {
+		$(
+			impl PackScalar<$scalar_type>
+				for ByteSlicedUnderlier<<$packed_storage as WithUnderlier>::Underlier, 16>
+			{
+				type Packed = $name;
+			}
+		)*
+	};
+}
+
+// 128 bit storage, uses GFNI for the byte multiplication when available on x86_64
+define_byte_sliced_3d!(
+	ByteSlicedAES16x128b,
+	AESTowerField128b,
+	PackedAESBinaryField16x8b,
+	TowerLevel16,
+	TowerLevel16
+);
+define_byte_sliced_3d!(
+	ByteSlicedAES16x2x64b,
+	AESTowerField64b,
+	PackedAESBinaryField16x8b,
+	TowerLevel8,
+	TowerLevel16
+);
+define_byte_sliced_3d!(
+	ByteSlicedAES16x4x32b,
+	AESTowerField32b,
+	PackedAESBinaryField16x8b,
+	TowerLevel4,
+	TowerLevel16
+);
+define_byte_sliced_3d!(
+	ByteSlicedAES16x8x16b,
+	AESTowerField16b,
+	PackedAESBinaryField16x8b,
+	TowerLevel2,
+	TowerLevel16
+);
+define_byte_sliced_3d!(
+	ByteSlicedAES16x16x8b,
+	AESTowerField8b,
+	PackedAESBinaryField16x8b,
+	TowerLevel1,
+	TowerLevel16
+);
+define_byte_sliced_3d_1b!(
+	ByteSliced16x128x1b,
+	PackedBinaryField128x1b,
+	PackedAESBinaryField16x8b,
+	TowerLevel16
+);
+
+impl_pack_scalar_byte_sliced!(PackedAESBinaryField16x8b =>
+	BinaryField1b: ByteSliced16x128x1b,
+	AESTowerField8b: ByteSlicedAES16x16x8b,
+	AESTowerField16b: ByteSlicedAES16x8x16b,
+	AESTowerField32b: ByteSlicedAES16x4x32b,
+	AESTowerField64b: ByteSlicedAES16x2x64b,
+	AESTowerField128b: ByteSlicedAES16x128b,
+);
+
+// 256 bit storage
+define_byte_sliced_3d!(
+	ByteSlicedAES32x128b,
+	AESTowerField128b,
+	PackedAESBinaryField32x8b,
+	TowerLevel16,
+	TowerLevel16
+);
+define_byte_sliced_3d!(
+	ByteSlicedAES32x2x64b,
+	AESTowerField64b,
+	PackedAESBinaryField32x8b,
+	TowerLevel8,
+	TowerLevel16
+);
+define_byte_sliced_3d!(
+	ByteSlicedAES32x4x32b,
+	AESTowerField32b,
+	PackedAESBinaryField32x8b,
+	TowerLevel4,
+	TowerLevel16
+);
+define_byte_sliced_3d!(
+	ByteSlicedAES32x8x16b,
+	AESTowerField16b,
+	PackedAESBinaryField32x8b,
+	TowerLevel2,
+	TowerLevel16
+);
+define_byte_sliced_3d!(
+	ByteSlicedAES32x16x8b,
+	AESTowerField8b,
+	PackedAESBinaryField32x8b,
+	TowerLevel1,
+	TowerLevel16
+);
+define_byte_sliced_3d_1b!(
+	ByteSliced32x128x1b,
+	PackedBinaryField256x1b,
+	PackedAESBinaryField32x8b,
+	TowerLevel16
+);
+
+impl_pack_scalar_byte_sliced!(PackedAESBinaryField32x8b =>
+	BinaryField1b: ByteSliced32x128x1b,
+	AESTowerField8b: ByteSlicedAES32x16x8b,
+	AESTowerField16b: ByteSlicedAES32x8x16b,
+	AESTowerField32b: ByteSlicedAES32x4x32b,
+	AESTowerField64b: ByteSlicedAES32x2x64b,
+	AESTowerField128b: ByteSlicedAES32x128b,
+);
+
+// Byte-sliced fields with a single scalar per lane and partial storage
+define_byte_sliced_3d!(
+	ByteSlicedAES32x64b,
+	AESTowerField64b,
+	PackedAESBinaryField32x8b,
+	TowerLevel8,
+	TowerLevel8
+);
+define_byte_sliced_3d!(
+	ByteSlicedAES32x32b,
+	AESTowerField32b,
+	PackedAESBinaryField32x8b,
+	TowerLevel4,
+	TowerLevel4
+);
+define_byte_sliced_3d!(
+	ByteSlicedAES32x16b,
+	AESTowerField16b,
+	PackedAESBinaryField32x8b,
+	TowerLevel2,
+	TowerLevel2
+);
+define_byte_sliced_3d!(
+	ByteSlicedAES32x8b,
+	AESTowerField8b,
+	PackedAESBinaryField32x8b,
+	TowerLevel1,
+	TowerLevel1
+);
+
+// 512 bit storage
+define_byte_sliced_3d!(
+	ByteSlicedAES64x128b,
+	AESTowerField128b,
+	PackedAESBinaryField64x8b,
+	TowerLevel16,
+	TowerLevel16
+);
+define_byte_sliced_3d!(
+	ByteSlicedAES64x2x64b,
+	AESTowerField64b,
+	PackedAESBinaryField64x8b,
+	TowerLevel8,
+	TowerLevel16
+);
+define_byte_sliced_3d!(
+	ByteSlicedAES64x4x32b,
+	AESTowerField32b,
+	PackedAESBinaryField64x8b,
+	TowerLevel4,
+	TowerLevel16
+);
+define_byte_sliced_3d!(
+	ByteSlicedAES64x8x16b,
+	AESTowerField16b,
+	PackedAESBinaryField64x8b,
+	TowerLevel2,
+	TowerLevel16
+);
+define_byte_sliced_3d!(
+	ByteSlicedAES64x16x8b,
+	AESTowerField8b,
+	PackedAESBinaryField64x8b,
+	TowerLevel1,
+	TowerLevel16
+);
+define_byte_sliced_3d_1b!(
+	ByteSliced64x128x1b,
+	PackedBinaryField512x1b,
+	PackedAESBinaryField64x8b,
+	TowerLevel16
+);
+
+impl_pack_scalar_byte_sliced!(PackedAESBinaryField64x8b =>
+	BinaryField1b: ByteSliced64x128x1b,
+	AESTowerField8b: ByteSlicedAES64x16x8b,
+	AESTowerField16b: ByteSlicedAES64x8x16b,
+	AESTowerField32b: ByteSlicedAES64x4x32b,
+	AESTowerField64b: ByteSlicedAES64x2x64b,
+	AESTowerField128b: ByteSlicedAES64x128b,
+);
@@ -0,0 +1,46 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use std::array;
+
+use binius_utils::checked_arithmetics::checked_log_2;
+use bytemuck::{Pod, Zeroable};
+use rand::RngCore;
+use subtle::{Choice, ConstantTimeEq};
+
+use crate::underlier::{Random, UnderlierType};
+
+/// Underlier of the byte-sliced packed fields.
+///
+/// Stores `N` byte planes, each of them being the underlier of a packed 8-bit field. All the
+/// byte-sliced packed fields with the same plane underlier and the same number of planes share
+/// this underlier regardless of the tower level of their scalars, which makes it possible to cast
+/// between them without moving any data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct ByteSlicedUnderlier<U, const N: usize>(pub [U; N]);
+
+impl<U: Default, const N: usize> Default for ByteSlicedUnderlier<U, N> {
+	fn default() -> Self {
+		Self(array::from_fn(|_| U::default()))
+	}
+}
+
+impl<U: Random, const N: usize> Random for ByteSlicedUnderlier<U, N> {
+	fn random(mut rng: impl RngCore) -> Self {
+		Self(array::from_fn(|_| U::random(&mut rng)))
+	}
+}
+
+impl<U: ConstantTimeEq, const N: usize> ConstantTimeEq for ByteSlicedUnderlier<U, N> {
+	fn ct_eq(&self, other: &Self) -> Choice {
+		self.0.ct_eq(&other.0)
+	}
+}
+
+unsafe impl<U: Zeroable, const N: usize> Zeroable for ByteSlicedUnderlier<U, N> {}
+
+unsafe impl<U: Pod, const N: usize> Pod for ByteSlicedUnderlier<U, N> {}
+
+impl<U: UnderlierType + Pod, const N: usize> UnderlierType for ByteSlicedUnderlier<U, N> {
+	const LOG_BITS: usize = U::LOG_BITS + checked_log_2(N);
+}
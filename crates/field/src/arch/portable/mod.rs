@@ -32,6 +32,7 @@ pub(super) mod packed_scaled;
 pub(super) mod hybrid_recursive_arithmetics;
 pub(super) mod packed_arithmetic;
 pub(super) mod pairwise_arithmetic;
+pub(super) mod pairwise_constant_time_arithmetic;
 pub(super) mod pairwise_recursive_arithmetic;
 pub(super) mod pairwise_table_arithmetic;
 pub(super) mod reuse_multiply_arithmetic;
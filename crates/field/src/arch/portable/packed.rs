@@ -134,6 +134,30 @@ where
 	}
 }
 
+#[cfg(feature = "serde")]
+impl<U, Scalar> serde::Serialize for PackedPrimitiveType<U, Scalar>
+where
+	U: UnderlierType,
+	Scalar: BinaryField + serde::Serialize,
+	Self: PackedField<Scalar = Scalar>,
+{
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		crate::serde_impl::serialize_packed(self, serializer)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de, U, Scalar> serde::Deserialize<'de> for PackedPrimitiveType<U, Scalar>
+where
+	U: UnderlierType,
+	Scalar: BinaryField + serde::Deserialize<'de>,
+	Self: PackedField<Scalar = Scalar>,
+{
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		crate::serde_impl::deserialize_packed(deserializer)
+	}
+}
+
 impl<U: UnderlierType, Scalar: BinaryField> From<U> for PackedPrimitiveType<U, Scalar> {
 	#[inline]
 	fn from(val: U) -> Self {
@@ -5,7 +5,9 @@ use super::{
 	packed_arithmetic::{alphas, impl_tower_constants},
 };
 use crate::{
-	arch::{PackedStrategy, PairwiseRecursiveStrategy, PairwiseStrategy, PairwiseTableStrategy},
+	arch::{
+		PackedStrategy, PairwiseRecursiveStrategy, PairwiseSmallFieldStrategy, PairwiseStrategy,
+	},
 	arithmetic_traits::{
 		impl_invert_with, impl_mul_alpha_with, impl_mul_with, impl_square_with,
 		impl_transformation_with_strategy,
@@ -39,7 +41,7 @@ impl_tower_constants!(BinaryField8b, u16, { alphas!(u16, 3) });
 // Define multiplication
 impl_mul_with!(PackedBinaryField8x2b @ PackedStrategy);
 impl_mul_with!(PackedBinaryField4x4b @ PackedStrategy);
-impl_mul_with!(PackedBinaryField2x8b @ PairwiseTableStrategy);
+impl_mul_with!(PackedBinaryField2x8b @ PairwiseSmallFieldStrategy);
 impl_mul_with!(PackedBinaryField1x16b @ PairwiseRecursiveStrategy);
 
 // Define square
@@ -51,7 +53,7 @@ impl_square_with!(PackedBinaryField1x16b @ PairwiseRecursiveStrategy);
 // Define invert
 impl_invert_with!(PackedBinaryField8x2b @ PairwiseRecursiveStrategy);
 impl_invert_with!(PackedBinaryField4x4b @ PairwiseRecursiveStrategy);
-impl_invert_with!(PackedBinaryField2x8b @ PairwiseTableStrategy);
+impl_invert_with!(PackedBinaryField2x8b @ PairwiseSmallFieldStrategy);
 impl_invert_with!(PackedBinaryField1x16b @ PairwiseRecursiveStrategy);
 
 // Define multiply by alpha
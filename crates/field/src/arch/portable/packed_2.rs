@@ -6,7 +6,7 @@ use super::{
 	reuse_multiply_arithmetic::Alpha,
 };
 use crate::{
-	arch::{PackedStrategy, PairwiseStrategy, PairwiseTableStrategy, ReuseMultiplyStrategy},
+	arch::{PackedStrategy, PairwiseSmallFieldStrategy, PairwiseStrategy, ReuseMultiplyStrategy},
 	arithmetic_traits::{
 		impl_invert_with, impl_mul_alpha_with, impl_mul_with, impl_square_with,
 		impl_transformation_with_strategy,
@@ -35,13 +35,13 @@ impl TowerConstants<U2> for BinaryField2b {
 }
 
 // Define multiplication
-impl_mul_with!(PackedBinaryField1x2b @ PairwiseTableStrategy);
+impl_mul_with!(PackedBinaryField1x2b @ PairwiseSmallFieldStrategy);
 
 // Define square
 impl_square_with!(PackedBinaryField1x2b @ ReuseMultiplyStrategy);
 
 // Define invert
-impl_invert_with!(PackedBinaryField1x2b @ PairwiseTableStrategy);
+impl_invert_with!(PackedBinaryField1x2b @ PairwiseSmallFieldStrategy);
 
 // Define multiply by alpha
 impl_mul_alpha_with!(PackedBinaryField1x2b @ ReuseMultiplyStrategy);
@@ -50,9 +50,9 @@ cfg_if! {
 		impl_mul_with!(PackedBinaryField2x16b => crate::PackedBinaryField8x16b);
 		impl_mul_with!(PackedBinaryField1x32b => crate::PackedBinaryField4x32b);
 	} else {
-		use crate::arch::PairwiseTableStrategy;
+		use crate::arch::PairwiseSmallFieldStrategy;
 
-		impl_mul_with!(PackedBinaryField4x8b @ PairwiseTableStrategy);
+		impl_mul_with!(PackedBinaryField4x8b @ PairwiseSmallFieldStrategy);
 		impl_mul_with!(PackedBinaryField2x16b @ PairwiseRecursiveStrategy);
 		impl_mul_with!(PackedBinaryField1x32b @ PairwiseRecursiveStrategy);
 	}
@@ -74,7 +74,7 @@ cfg_if! {
 		impl_invert_with!(PackedBinaryField2x16b => crate::PackedBinaryField8x16b);
 		impl_invert_with!(PackedBinaryField1x32b => crate::PackedBinaryField4x32b);
 	} else {
-		impl_invert_with!(PackedBinaryField4x8b @ PairwiseTableStrategy);
+		impl_invert_with!(PackedBinaryField4x8b @ PairwiseSmallFieldStrategy);
 		impl_invert_with!(PackedBinaryField2x16b @ PairwiseStrategy);
 		impl_invert_with!(PackedBinaryField1x32b @ PairwiseRecursiveStrategy);
 	}
@@ -5,7 +5,9 @@ use super::{
 	packed_arithmetic::{alphas, impl_tower_constants},
 };
 use crate::{
-	arch::{PackedStrategy, PairwiseRecursiveStrategy, PairwiseStrategy, PairwiseTableStrategy},
+	arch::{
+		PackedStrategy, PairwiseRecursiveStrategy, PairwiseSmallFieldStrategy, PairwiseStrategy,
+	},
 	arithmetic_traits::{
 		impl_invert_with, impl_mul_alpha_with, impl_mul_with, impl_square_with,
 		impl_transformation_with_strategy,
@@ -36,22 +38,22 @@ impl_tower_constants!(BinaryField4b, u8, { alphas!(u8, 2) });
 // Define multiplication
 impl_mul_with!(PackedBinaryField4x2b @ PackedStrategy);
 impl_mul_with!(PackedBinaryField2x4b @ PackedStrategy);
-impl_mul_with!(PackedBinaryField1x8b @ PairwiseTableStrategy);
+impl_mul_with!(PackedBinaryField1x8b @ PairwiseSmallFieldStrategy);
 
 // Define square
 impl_square_with!(PackedBinaryField4x2b @ PackedStrategy);
 impl_square_with!(PackedBinaryField2x4b @ PackedStrategy);
-impl_square_with!(PackedBinaryField1x8b @ PairwiseTableStrategy);
+impl_square_with!(PackedBinaryField1x8b @ PairwiseSmallFieldStrategy);
 
 // Define invert
 impl_invert_with!(PackedBinaryField4x2b @ PairwiseRecursiveStrategy);
 impl_invert_with!(PackedBinaryField2x4b @ PairwiseRecursiveStrategy);
-impl_invert_with!(PackedBinaryField1x8b @ PairwiseTableStrategy);
+impl_invert_with!(PackedBinaryField1x8b @ PairwiseSmallFieldStrategy);
 
 // Define multiply by alpha
 impl_mul_alpha_with!(PackedBinaryField4x2b @ PackedStrategy);
 impl_mul_alpha_with!(PackedBinaryField2x4b @ PackedStrategy);
-impl_mul_alpha_with!(PackedBinaryField1x8b @ PairwiseTableStrategy);
+impl_mul_alpha_with!(PackedBinaryField1x8b @ PairwiseSmallFieldStrategy);
 
 // Define linear transformations
 impl_transformation_with_strategy!(PackedBinaryField8x1b, PackedStrategy);
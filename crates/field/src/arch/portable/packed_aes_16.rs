@@ -5,7 +5,9 @@ use super::{
 	packed_arithmetic::impl_tower_constants,
 };
 use crate::{
-	arch::{PackedStrategy, PairwiseRecursiveStrategy, PairwiseStrategy, PairwiseTableStrategy},
+	arch::{
+		PackedStrategy, PairwiseRecursiveStrategy, PairwiseSmallFieldStrategy, PairwiseStrategy,
+	},
 	arithmetic_traits::{
 		impl_invert_with, impl_mul_alpha_with, impl_mul_with, impl_square_with,
 		impl_transformation_with_strategy,
@@ -25,19 +27,19 @@ impl_broadcast!(u16, AESTowerField16b);
 impl_tower_constants!(AESTowerField8b, u16, 0x00d3);
 
 // Define multiplication
-impl_mul_with!(PackedAESBinaryField2x8b @ PairwiseTableStrategy);
+impl_mul_with!(PackedAESBinaryField2x8b @ PairwiseSmallFieldStrategy);
 impl_mul_with!(PackedAESBinaryField1x16b @ PairwiseRecursiveStrategy);
 
 // Define square
-impl_square_with!(PackedAESBinaryField2x8b @ PairwiseTableStrategy);
+impl_square_with!(PackedAESBinaryField2x8b @ PairwiseSmallFieldStrategy);
 impl_square_with!(PackedAESBinaryField1x16b @ PairwiseRecursiveStrategy);
 
 // Define invert
-impl_invert_with!(PackedAESBinaryField2x8b @ PairwiseTableStrategy);
+impl_invert_with!(PackedAESBinaryField2x8b @ PairwiseSmallFieldStrategy);
 impl_invert_with!(PackedAESBinaryField1x16b @ PairwiseRecursiveStrategy);
 
 // Define multiply by alpha
-impl_mul_alpha_with!(PackedAESBinaryField2x8b @ PairwiseTableStrategy);
+impl_mul_alpha_with!(PackedAESBinaryField2x8b @ PairwiseSmallFieldStrategy);
 impl_mul_alpha_with!(PackedAESBinaryField1x16b @ PackedStrategy);
 
 // Define linear transformations
@@ -7,7 +7,9 @@ use super::{
 	packed_arithmetic::{alphas, impl_tower_constants},
 };
 use crate::{
-	arch::{PackedStrategy, PairwiseRecursiveStrategy, PairwiseStrategy, PairwiseTableStrategy},
+	arch::{
+		PackedStrategy, PairwiseRecursiveStrategy, PairwiseSmallFieldStrategy, PairwiseStrategy,
+	},
 	arithmetic_traits::{
 		impl_invert_with, impl_mul_alpha_with, impl_mul_with, impl_square_with,
 		impl_transformation_with_strategy,
@@ -36,14 +38,14 @@ cfg_if! {
 		impl_mul_with!(PackedAESBinaryField2x16b => crate::PackedAESBinaryField8x16b);
 		impl_mul_with!(PackedAESBinaryField1x32b => crate::PackedAESBinaryField4x32b);
 	} else {
-		impl_mul_with!(PackedAESBinaryField4x8b @ PairwiseTableStrategy);
+		impl_mul_with!(PackedAESBinaryField4x8b @ PairwiseSmallFieldStrategy);
 		impl_mul_with!(PackedAESBinaryField2x16b @ PairwiseRecursiveStrategy);
 		impl_mul_with!(PackedAESBinaryField1x32b @ PairwiseRecursiveStrategy);
 	}
 }
 
 // Define square
-impl_square_with!(PackedAESBinaryField4x8b @ PairwiseTableStrategy);
+impl_square_with!(PackedAESBinaryField4x8b @ PairwiseSmallFieldStrategy);
 impl_square_with!(PackedAESBinaryField2x16b @ PairwiseRecursiveStrategy);
 impl_square_with!(PackedAESBinaryField1x32b @ PairwiseRecursiveStrategy);
 
@@ -54,14 +56,14 @@ cfg_if! {
 		impl_invert_with!(PackedAESBinaryField2x16b => crate::PackedAESBinaryField8x16b);
 		impl_invert_with!(PackedAESBinaryField1x32b => crate::PackedAESBinaryField4x32b);
 	} else {
-		impl_invert_with!(PackedAESBinaryField4x8b @ PairwiseTableStrategy);
+		impl_invert_with!(PackedAESBinaryField4x8b @ PairwiseSmallFieldStrategy);
 		impl_invert_with!(PackedAESBinaryField2x16b @ PairwiseRecursiveStrategy);
 		impl_invert_with!(PackedAESBinaryField1x32b @ PairwiseRecursiveStrategy);
 	}
 }
 
 // Define multiply by alpha
-impl_mul_alpha_with!(PackedAESBinaryField4x8b @ PairwiseTableStrategy);
+impl_mul_alpha_with!(PackedAESBinaryField4x8b @ PairwiseSmallFieldStrategy);
 impl_mul_alpha_with!(PackedAESBinaryField2x16b @ PackedStrategy);
 impl_mul_alpha_with!(PackedAESBinaryField1x32b @ PairwiseRecursiveStrategy);
 
@@ -7,7 +7,9 @@ use super::{
 	packed_arithmetic::{alphas, impl_tower_constants},
 };
 use crate::{
-	arch::{PackedStrategy, PairwiseRecursiveStrategy, PairwiseStrategy, PairwiseTableStrategy},
+	arch::{
+		PackedStrategy, PairwiseRecursiveStrategy, PairwiseSmallFieldStrategy, PairwiseStrategy,
+	},
 	arithmetic_traits::{
 		impl_invert_with, impl_mul_alpha_with, impl_mul_with, impl_square_with,
 		impl_transformation_with_strategy,
@@ -40,7 +42,7 @@ cfg_if! {
 		impl_mul_with!(PackedAESBinaryField2x32b => crate::PackedAESBinaryField4x32b);
 		impl_mul_with!(PackedAESBinaryField1x64b => crate::PackedAESBinaryField2x64b);
 	} else {
-		impl_mul_with!(PackedAESBinaryField8x8b @ PairwiseTableStrategy);
+		impl_mul_with!(PackedAESBinaryField8x8b @ PairwiseSmallFieldStrategy);
 		impl_mul_with!(PackedAESBinaryField4x16b @ PairwiseRecursiveStrategy);
 		impl_mul_with!(PackedAESBinaryField2x32b @ PairwiseRecursiveStrategy);
 		impl_mul_with!(PackedAESBinaryField1x64b @ PairwiseRecursiveStrategy);
@@ -55,7 +57,7 @@ cfg_if! {
 		impl_square_with!(PackedAESBinaryField2x32b => crate::PackedAESBinaryField4x32b);
 		impl_square_with!(PackedAESBinaryField1x64b => crate::PackedAESBinaryField2x64b);
 	} else {
-		impl_square_with!(PackedAESBinaryField8x8b @ PairwiseTableStrategy);
+		impl_square_with!(PackedAESBinaryField8x8b @ PairwiseSmallFieldStrategy);
 		impl_square_with!(PackedAESBinaryField4x16b @ PairwiseRecursiveStrategy);
 		impl_square_with!(PackedAESBinaryField2x32b @ PairwiseRecursiveStrategy);
 		impl_square_with!(PackedAESBinaryField1x64b @ PairwiseRecursiveStrategy);
@@ -70,7 +72,7 @@ cfg_if! {
 		impl_invert_with!(PackedAESBinaryField2x32b => crate::PackedAESBinaryField4x32b);
 		impl_invert_with!(PackedAESBinaryField1x64b => crate::PackedAESBinaryField2x64b);
 	} else {
-		impl_invert_with!(PackedAESBinaryField8x8b @ PairwiseTableStrategy);
+		impl_invert_with!(PackedAESBinaryField8x8b @ PairwiseSmallFieldStrategy);
 		impl_invert_with!(PackedAESBinaryField4x16b @ PairwiseRecursiveStrategy);
 		impl_invert_with!(PackedAESBinaryField2x32b @ PairwiseRecursiveStrategy);
 		impl_invert_with!(PackedAESBinaryField1x64b @ PairwiseRecursiveStrategy);
@@ -78,7 +80,7 @@ cfg_if! {
 }
 
 // Define multiply by alpha
-impl_mul_alpha_with!(PackedAESBinaryField8x8b @ PairwiseTableStrategy);
+impl_mul_alpha_with!(PackedAESBinaryField8x8b @ PairwiseSmallFieldStrategy);
 impl_mul_alpha_with!(PackedAESBinaryField4x16b @ PackedStrategy);
 impl_mul_alpha_with!(PackedAESBinaryField2x32b @ PackedStrategy);
 impl_mul_alpha_with!(PackedAESBinaryField1x64b @ PairwiseRecursiveStrategy);
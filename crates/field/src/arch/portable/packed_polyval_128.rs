@@ -8,10 +8,7 @@
 //!
 //! <https://bearssl.org/gitweb/?p=BearSSL;a=blob;f=src/hash/ghash_ctmul64.c;hb=4b6046412>
 
-use std::{
-	num::Wrapping,
-	ops::{BitXor, Mul},
-};
+use std::{num::Wrapping, ops::Mul};
 
 use super::packed::{impl_broadcast, PackedPrimitiveType};
 use crate::{
@@ -166,6 +163,7 @@ const fn rev64(mut x: u64) -> u64 {
 }
 
 /// Calculates `value^(2^(2^n))`
+#[cfg(not(feature = "constant_time"))]
 fn pow_2_2_n(value: BinaryField128bPolyval, n: usize) -> BinaryField128bPolyval {
 	match n {
 		// value^(2^(2^0)) = value
@@ -181,7 +179,7 @@ fn pow_2_2_n(value: BinaryField128bPolyval, n: usize) -> BinaryField128bPolyval
 
 					POLYVAL_NIBBLE_POW_2_N_TABLE[n - 1][nibble_index][nibble_value as usize]
 				})
-				.fold(0, BitXor::bitxor);
+				.fold(0, std::ops::BitXor::bitxor);
 
 			BinaryField128bPolyval(result)
 		}
@@ -189,7 +187,15 @@ fn pow_2_2_n(value: BinaryField128bPolyval, n: usize) -> BinaryField128bPolyval
 	}
 }
 
+/// Calculates `value^(2^(2^n))` by repeated squaring.
+/// Unlike the table based version, the memory access pattern doesn't depend on `value`.
+#[cfg(feature = "constant_time")]
+fn pow_2_2_n(value: BinaryField128bPolyval, n: usize) -> BinaryField128bPolyval {
+	(0..1 << n).fold(value, |acc, _| acc.square())
+}
+
 /// Table where value[i][k][j] = BinaryField128bPolyval(j << 4 * k) ^ (2^(i+1))
+#[cfg(not(feature = "constant_time"))]
 static POLYVAL_NIBBLE_POW_2_N_TABLE: [[[u128; 16]; 32]; 6] = [
 	[
 		[
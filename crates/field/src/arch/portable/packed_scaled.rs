@@ -65,6 +65,26 @@ where
 	}
 }
 
+#[cfg(feature = "serde")]
+impl<PT, const N: usize> serde::Serialize for ScaledPackedField<PT, N>
+where
+	Self: PackedField<Scalar: serde::Serialize>,
+{
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		crate::serde_impl::serialize_packed(self, serializer)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de, PT, const N: usize> serde::Deserialize<'de> for ScaledPackedField<PT, N>
+where
+	Self: PackedField<Scalar: serde::Deserialize<'de>>,
+{
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		crate::serde_impl::deserialize_packed(deserializer)
+	}
+}
+
 unsafe impl<PT: Zeroable, const N: usize> Zeroable for ScaledPackedField<PT, N> {}
 
 unsafe impl<PT: Pod, const N: usize> Pod for ScaledPackedField<PT, N> {}
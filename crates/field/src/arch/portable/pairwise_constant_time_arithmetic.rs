@@ -0,0 +1,234 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+//! Table-free and branch-free arithmetic for the small binary fields.
+//!
+//! The operations here only use shifts, masks and XORs whose sequence doesn't depend on the
+//! values of the operands, so unlike [`PairwiseTableStrategy`](crate::arch::PairwiseTableStrategy)
+//! they don't leak the operands through the cache access pattern or branch prediction.
+
+use super::packed::PackedPrimitiveType;
+use crate::{
+	arch::PairwiseConstantTimeStrategy,
+	arithmetic_traits::{TaggedInvertOrZero, TaggedMul, TaggedMulAlpha, TaggedSquare},
+	packed::PackedField,
+	underlier::UnderlierType,
+	AESTowerField8b, BinaryField2b, BinaryField4b, BinaryField8b,
+};
+
+#[inline(always)]
+const fn mul_tower_1b(a: u8, b: u8) -> u8 {
+	a & b
+}
+
+#[inline(always)]
+const fn mul_alpha_tower_1b(a: u8) -> u8 {
+	a
+}
+
+/// Define multiplication and multiplication by the generator for a binary tower field with
+/// `$bits` bits via the Karatsuba formula over the subfield halves.
+macro_rules! define_tower_level {
+	($mul:ident, $mul_alpha:ident, $bits:literal, $sub_mul:ident, $sub_mul_alpha:ident) => {
+		#[inline(always)]
+		const fn $mul(a: u8, b: u8) -> u8 {
+			const HALF: u32 = $bits / 2;
+			const MASK: u8 = (1 << HALF) - 1;
+
+			let (a0, a1) = (a & MASK, a >> HALF);
+			let (b0, b1) = (b & MASK, b >> HALF);
+
+			let z0 = $sub_mul(a0, b0);
+			let z2 = $sub_mul(a1, b1);
+			let z1 = $sub_mul(a0 ^ a1, b0 ^ b1) ^ z0 ^ z2;
+
+			(z0 ^ z2) | (z1 ^ $sub_mul_alpha(z2)) << HALF
+		}
+
+		#[inline(always)]
+		const fn $mul_alpha(a: u8) -> u8 {
+			const HALF: u32 = $bits / 2;
+			const MASK: u8 = (1 << HALF) - 1;
+
+			let (a0, a1) = (a & MASK, a >> HALF);
+
+			a1 | (a0 ^ $sub_mul_alpha(a1)) << HALF
+		}
+	};
+}
+
+define_tower_level!(mul_tower_2b, mul_alpha_tower_2b, 2, mul_tower_1b, mul_alpha_tower_1b);
+define_tower_level!(mul_tower_4b, mul_alpha_tower_4b, 4, mul_tower_2b, mul_alpha_tower_2b);
+define_tower_level!(mul_tower_8b, mul_alpha_tower_8b, 8, mul_tower_4b, mul_alpha_tower_4b);
+
+/// Multiply two elements of the AES field by shift-and-add with a masked reduction.
+#[inline(always)]
+const fn mul_aes(mut a: u8, b: u8) -> u8 {
+	let mut result = 0;
+	let mut i = 0;
+	while i < 8 {
+		result ^= a & ((b >> i) & 1).wrapping_neg();
+		a = (a << 1) ^ (0x1b & (a >> 7).wrapping_neg());
+		i += 1;
+	}
+	result
+}
+
+/// The image of the binary tower generator of `BinaryField8b` in the AES field.
+const AES_ALPHA: u8 = 0xd3;
+
+/// Raise `a` to the power `2^bits - 2` with a fixed square-and-multiply schedule.
+/// This is the inverse of `a` for non-zero values and zero otherwise.
+#[inline(always)]
+fn invert_or_zero_with(a: u8, bits: u32, mul: impl Fn(u8, u8) -> u8) -> u8 {
+	// The exponent is `bits - 1` ones followed by a single zero.
+	let mut result = a;
+	for _ in 2..bits {
+		result = mul(mul(result, result), a);
+	}
+	mul(result, result)
+}
+
+#[inline(always)]
+fn binary_op<PT>(lhs: PT, rhs: PT, op: impl Fn(u8, u8) -> u8) -> PT
+where
+	PT: PackedField,
+	PT::Scalar: From<u8>,
+	u8: From<PT::Scalar>,
+{
+	PT::from_fn(|i| op(lhs.get(i).into(), rhs.get(i).into()).into())
+}
+
+#[inline(always)]
+fn unary_op<PT>(val: PT, op: impl Fn(u8) -> u8) -> PT
+where
+	PT: PackedField,
+	PT::Scalar: From<u8>,
+	u8: From<PT::Scalar>,
+{
+	PT::from_fn(|i| op(val.get(i).into()).into())
+}
+
+/// Implement all the operations for the binary tower field with the given number of bits
+macro_rules! impl_tower_ops {
+	($field:ty, $bits:literal, $mul:ident, $mul_alpha:ident) => {
+		impl<U: UnderlierType> TaggedMul<PairwiseConstantTimeStrategy>
+			for PackedPrimitiveType<U, $field>
+		where
+			Self: PackedField<Scalar = $field>,
+		{
+			#[inline]
+			fn mul(self, rhs: Self) -> Self {
+				binary_op(self, rhs, $mul)
+			}
+		}
+
+		impl<U: UnderlierType> TaggedSquare<PairwiseConstantTimeStrategy>
+			for PackedPrimitiveType<U, $field>
+		where
+			Self: PackedField<Scalar = $field>,
+		{
+			#[inline]
+			fn square(self) -> Self {
+				unary_op(self, |a| $mul(a, a))
+			}
+		}
+
+		impl<U: UnderlierType> TaggedMulAlpha<PairwiseConstantTimeStrategy>
+			for PackedPrimitiveType<U, $field>
+		where
+			Self: PackedField<Scalar = $field>,
+		{
+			#[inline]
+			fn mul_alpha(self) -> Self {
+				unary_op(self, $mul_alpha)
+			}
+		}
+
+		impl<U: UnderlierType> TaggedInvertOrZero<PairwiseConstantTimeStrategy>
+			for PackedPrimitiveType<U, $field>
+		where
+			Self: PackedField<Scalar = $field>,
+		{
+			#[inline]
+			fn invert_or_zero(self) -> Self {
+				unary_op(self, |a| invert_or_zero_with(a, $bits, $mul))
+			}
+		}
+	};
+}
+
+impl_tower_ops!(BinaryField2b, 2, mul_tower_2b, mul_alpha_tower_2b);
+impl_tower_ops!(BinaryField4b, 4, mul_tower_4b, mul_alpha_tower_4b);
+impl_tower_ops!(BinaryField8b, 8, mul_tower_8b, mul_alpha_tower_8b);
+
+impl<U: UnderlierType> TaggedMul<PairwiseConstantTimeStrategy>
+	for PackedPrimitiveType<U, AESTowerField8b>
+where
+	Self: PackedField<Scalar = AESTowerField8b>,
+{
+	#[inline]
+	fn mul(self, rhs: Self) -> Self {
+		binary_op(self, rhs, mul_aes)
+	}
+}
+
+impl<U: UnderlierType> TaggedSquare<PairwiseConstantTimeStrategy>
+	for PackedPrimitiveType<U, AESTowerField8b>
+where
+	Self: PackedField<Scalar = AESTowerField8b>,
+{
+	#[inline]
+	fn square(self) -> Self {
+		unary_op(self, |a| mul_aes(a, a))
+	}
+}
+
+impl<U: UnderlierType> TaggedMulAlpha<PairwiseConstantTimeStrategy>
+	for PackedPrimitiveType<U, AESTowerField8b>
+where
+	Self: PackedField<Scalar = AESTowerField8b>,
+{
+	#[inline]
+	fn mul_alpha(self) -> Self {
+		unary_op(self, |a| mul_aes(a, AES_ALPHA))
+	}
+}
+
+impl<U: UnderlierType> TaggedInvertOrZero<PairwiseConstantTimeStrategy>
+	for PackedPrimitiveType<U, AESTowerField8b>
+where
+	Self: PackedField<Scalar = AESTowerField8b>,
+{
+	#[inline]
+	fn invert_or_zero(self) -> Self {
+		unary_op(self, |a| invert_or_zero_with(a, 8, mul_aes))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_utils::{
+		define_invert_tests, define_mul_alpha_tests, define_multiply_tests, define_square_tests,
+	};
+
+	define_multiply_tests!(
+		TaggedMul<PairwiseConstantTimeStrategy>::mul,
+		TaggedMul<PairwiseConstantTimeStrategy>
+	);
+
+	define_square_tests!(
+		TaggedSquare<PairwiseConstantTimeStrategy>::square,
+		TaggedSquare<PairwiseConstantTimeStrategy>
+	);
+
+	define_invert_tests!(
+		TaggedInvertOrZero<PairwiseConstantTimeStrategy>::invert_or_zero,
+		TaggedInvertOrZero<PairwiseConstantTimeStrategy>
+	);
+
+	define_mul_alpha_tests!(
+		TaggedMulAlpha<PairwiseConstantTimeStrategy>::mul_alpha,
+		TaggedMulAlpha<PairwiseConstantTimeStrategy>
+	);
+}
@@ -11,6 +11,9 @@ pub struct PairwiseRecursiveStrategy;
 pub struct PairwiseStrategy;
 /// Get result of operation from the table for each sub-element
 pub struct PairwiseTableStrategy;
+/// Pairwise strategy for the small binary fields that uses neither lookup tables nor data-dependent
+/// branches, so that the running time doesn't depend on the values of the operands.
+pub struct PairwiseConstantTimeStrategy;
 /// Similar to `PackedStrategy`, but uses SIMD operations supported by the platform.
 pub struct SimdStrategy;
 /// Applicable only for multiply by alpha and square operations.
@@ -27,3 +30,13 @@ pub struct GfniSpecializedStrategy512b;
 /// Performs conversion to the packed isomorphic AES field, applies the operation and
 /// converts the result back to the canonical tower field.
 pub struct AESIsomorphicStrategy;
+
+/// Strategy used for the 8-bit and smaller fields when no specialized instructions are available.
+///
+/// Table lookups are the fastest option in this case, but the accessed memory locations depend on
+/// the operand values. The `constant_time` feature replaces them with the data-independent
+/// [`PairwiseConstantTimeStrategy`].
+#[cfg(not(feature = "constant_time"))]
+pub type PairwiseSmallFieldStrategy = PairwiseTableStrategy;
+#[cfg(feature = "constant_time")]
+pub type PairwiseSmallFieldStrategy = PairwiseConstantTimeStrategy;
@@ -26,7 +26,7 @@ cfg_if! {
 	if #[cfg(target_feature = "gfni")] {
 		impl_mul_with!(PackedAESBinaryField32x8b @ crate::arch::GfniStrategy);
 	} else {
-		impl_mul_with!(PackedAESBinaryField32x8b @ crate::arch::PairwiseTableStrategy);
+		impl_mul_with!(PackedAESBinaryField32x8b @ crate::arch::PairwiseSmallFieldStrategy);
 	}
 }
 impl_mul_with!(PackedAESBinaryField16x16b @ SimdStrategy);
@@ -39,7 +39,7 @@ cfg_if! {
 	if #[cfg(target_feature = "gfni")] {
 		impl_square_with!(PackedAESBinaryField32x8b @ crate::arch::ReuseMultiplyStrategy);
 	} else {
-		impl_square_with!(PackedAESBinaryField32x8b @ crate::arch::PairwiseTableStrategy);
+		impl_square_with!(PackedAESBinaryField32x8b @ crate::arch::PairwiseSmallFieldStrategy);
 	}
 }
 impl_square_with!(PackedAESBinaryField16x16b @ SimdStrategy);
@@ -52,7 +52,7 @@ cfg_if! {
 	if #[cfg(target_feature = "gfni")] {
 		impl_invert_with!(PackedAESBinaryField32x8b @ crate::arch::GfniStrategy);
 	} else {
-		impl_invert_with!(PackedAESBinaryField32x8b @ crate::arch::PairwiseTableStrategy);
+		impl_invert_with!(PackedAESBinaryField32x8b @ crate::arch::PairwiseSmallFieldStrategy);
 	}
 }
 impl_invert_with!(PackedAESBinaryField16x16b @ SimdStrategy);
@@ -65,7 +65,7 @@ cfg_if! {
 	if #[cfg(target_feature = "gfni")] {
 		impl_mul_alpha_with!(PackedAESBinaryField32x8b @ crate::arch::ReuseMultiplyStrategy);
 	} else {
-		impl_mul_alpha_with!(PackedAESBinaryField32x8b @ crate::arch::PairwiseTableStrategy);
+		impl_mul_alpha_with!(PackedAESBinaryField32x8b @ crate::arch::PairwiseSmallFieldStrategy);
 	}
 }
 impl_mul_alpha_with!(PackedAESBinaryField16x16b @ SimdStrategy);
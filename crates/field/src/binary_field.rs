@@ -1,10 +1,12 @@
 // Copyright 2023-2025 Irreducible Inc.
 
 use std::{
-	any::TypeId,
+	any::{Any, TypeId},
+	collections::HashMap,
 	fmt::{Debug, Display, Formatter},
 	iter::{Product, Sum},
 	ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+	sync::{OnceLock, RwLock},
 };
 
 use binius_utils::serialization::{DeserializeBytes, Error as SerializationError, SerializeBytes};
@@ -25,6 +27,99 @@ use crate::{
 pub trait BinaryField: ExtensionField<BinaryField1b> {
 	const N_BITS: usize = Self::DEGREE;
 	const MULTIPLICATIVE_GENERATOR: Self;
+
+	/// Returns the unique square root of the element.
+	///
+	/// Squaring is the Frobenius automorphism of a binary field, so the square root is its inverse
+	/// $x \mapsto x^{2^{n-1}}$.
+	fn sqrt(self) -> Self {
+		(1..Self::N_BITS).fold(self, |x, _| x.square())
+	}
+
+	/// Returns the absolute trace $\mathrm{Tr}(x) = \sum_{i=0}^{n-1} x^{2^i}$ of the element.
+	fn trace(self) -> BinaryField1b {
+		let trace = iter_frobenius(self).sum::<Self>();
+		debug_assert!(trace == Self::ZERO || trace == Self::ONE);
+		BinaryField1b::from(u8::from(trace == Self::ONE))
+	}
+
+	/// Returns a solution $x$ of the equation $x^2 + x = c$, where $c$ is the element.
+	///
+	/// A solution exists if and only if $\mathrm{Tr}(c) = 0$, in which case the other solution is
+	/// $x + 1$. Returns `None` if there is no solution.
+	fn solve_quadratic(self) -> Option<Self> {
+		(self.trace() == BinaryField1b::ZERO)
+			.then(|| solve_quadratic_unchecked(self, quadratic_solution_coeffs::<Self>()))
+	}
+}
+
+/// Iterates over the Frobenius conjugates $x, x^2, \ldots, x^{2^{n-1}}$ of a field element.
+fn iter_frobenius<F: BinaryField>(x: F) -> impl Iterator<Item = F> {
+	std::iter::successors(Some(x), |x| Some(x.square())).take(F::N_BITS)
+}
+
+/// Returns an element of absolute trace one.
+///
+/// The trace is a non-zero $\mathbb{F}_2$-linear functional, so at least one element of the
+/// $\mathbb{F}_2$ basis has trace one.
+fn trace_one_element<F: BinaryField>() -> F {
+	(0..F::N_BITS)
+		.map(|i| <F as ExtensionField<BinaryField1b>>::basis(i).expect("i < N_BITS"))
+		.find(|&basis| basis.trace() == BinaryField1b::ONE)
+		.expect("the trace is a non-zero linear functional")
+}
+
+/// Returns the coefficients of [`solve_quadratic_unchecked`] for the field `F`.
+///
+/// The coefficients are computed by [`compute_quadratic_solution_coeffs`] on the first call for
+/// each field and cached for the lifetime of the process.
+pub(crate) fn quadratic_solution_coeffs<F: BinaryField>() -> &'static [F] {
+	type Cache = RwLock<HashMap<TypeId, &'static (dyn Any + Send + Sync)>>;
+	static CACHE: OnceLock<Cache> = OnceLock::new();
+
+	let cache = CACHE.get_or_init(Default::default);
+	let cached = cache
+		.read()
+		.expect("lock is not poisoned")
+		.get(&TypeId::of::<F>())
+		.copied();
+	let coeffs = cached.unwrap_or_else(|| {
+		*cache
+			.write()
+			.expect("lock is not poisoned")
+			.entry(TypeId::of::<F>())
+			.or_insert_with(|| {
+				Box::leak(Box::new(compute_quadratic_solution_coeffs::<F>().into_boxed_slice()))
+			})
+	});
+	coeffs
+		.downcast_ref::<Box<[F]>>()
+		.expect("coefficients are stored with the TypeId of their field")
+}
+
+/// Returns the coefficients $d_i = \sum_{j=i+1}^{n-1} \delta^{2^j}$, for $0 \le i < n$, of
+/// [`solve_quadratic_unchecked`], where $\delta$ is an element of absolute trace one.
+fn compute_quadratic_solution_coeffs<F: BinaryField>() -> Vec<F> {
+	let mut coeffs = iter_frobenius(trace_one_element::<F>()).collect::<Vec<_>>();
+	let mut suffix_sum = F::ZERO;
+	for coeff in coeffs.iter_mut().rev() {
+		let delta_conjugate = *coeff;
+		*coeff = suffix_sum;
+		suffix_sum += delta_conjugate;
+	}
+	coeffs
+}
+
+/// Returns $x = \sum_{i=0}^{n-1} c^{2^i} d_i$ for the coefficients $d_i$ of
+/// [`compute_quadratic_solution_coeffs`].
+///
+/// As $\mathrm{Tr}(\delta) = 1$, this satisfies $x^2 + x = c + \mathrm{Tr}(c)$, so $x$ solves
+/// $x^2 + x = c$ whenever $\mathrm{Tr}(c) = 0$. The result is $\mathbb{F}_2$-linear in $c$.
+pub(crate) fn solve_quadratic_unchecked<F: BinaryField>(c: F, coeffs: &[F]) -> F {
+	iter_frobenius(c)
+		.zip(coeffs)
+		.map(|(c_conjugate, &coeff)| c_conjugate * coeff)
+		.sum()
 }
 
 /// A binary field *isomorphic* to a binary tower field.
@@ -70,6 +165,17 @@ where
 	fn mul_primitive(self, iota: usize) -> Result<Self, Error> {
 		Ok(self * <Self as ExtensionField<BinaryField1b>>::basis(1 << iota)?)
 	}
+
+	/// Returns the smallest tower level $\iota$ such that the element lies in the subfield
+	/// isomorphic to $T_{\iota}$.
+	fn min_tower_level(self) -> usize {
+		(0..Self::TOWER_LEVEL)
+			.find(|&iota| {
+				// The subfield is the set of elements fixed by the Frobenius map $x \mapsto x^{2^{2^\iota}}$
+				(0..1 << iota).fold(self, |acc, _| acc.square()) == self
+			})
+			.unwrap_or(Self::TOWER_LEVEL)
+	}
 }
 
 pub(super) trait TowerExtensionField:
@@ -757,6 +863,35 @@ macro_rules! serialize_deserialize {
 	};
 }
 
+/// Implements [`SerializeBytes`] and [`DeserializeBytes`] for a field isomorphic to a canonical
+/// tower field by encoding its image in the canonical field.
+macro_rules! serialize_deserialize_non_canonical {
+	($field:ty, canonical=$canonical:ty) => {
+		impl binius_utils::serialization::SerializeBytes for $field {
+			fn serialize(
+				&self,
+				write_buf: impl bytes::BufMut,
+			) -> Result<(), binius_utils::serialization::Error> {
+				binius_utils::serialization::SerializeBytes::serialize(
+					&<$canonical>::from(*self),
+					write_buf,
+				)
+			}
+		}
+
+		impl binius_utils::serialization::DeserializeBytes for $field {
+			fn deserialize(
+				read_buf: impl bytes::Buf,
+			) -> Result<Self, binius_utils::serialization::Error> {
+				<$canonical as binius_utils::serialization::DeserializeBytes>::deserialize(read_buf)
+					.map(Self::from)
+			}
+		}
+	};
+}
+
+pub(crate) use serialize_deserialize_non_canonical;
+
 serialize_deserialize!(BinaryField1b, SmallU<1>);
 serialize_deserialize!(BinaryField2b, SmallU<2>);
 serialize_deserialize!(BinaryField4b, SmallU<4>);
@@ -1300,4 +1435,139 @@ pub(crate) mod tests {
 		let result = std::panic::catch_unwind(|| unsafe { BF4::new_unchecked(16) });
 		assert!(result.is_err(), "Expected a panic for value > 15, but no panic occurred");
 	}
+
+	#[test]
+	fn test_min_tower_level() {
+		assert_eq!(BinaryField128b::ZERO.min_tower_level(), 0);
+		assert_eq!(BinaryField128b::ONE.min_tower_level(), 0);
+		assert_eq!(BinaryField128b::from(BF8::new(2)).min_tower_level(), 1);
+		assert_eq!(BinaryField128b::from(BF8::new(0x10)).min_tower_level(), 3);
+		assert_eq!(BinaryField128b::from(BF16::new(0x100)).min_tower_level(), 4);
+		assert_eq!(BinaryField128b::from(BF64::new(1 << 40)).min_tower_level(), 6);
+		assert_eq!(BinaryField128b::new(1 << 64).min_tower_level(), 7);
+		for x in 0..16 {
+			assert_eq!(BF8::from(BF4::from(x)).min_tower_level(), BF4::from(x).min_tower_level());
+		}
+	}
+
+	fn check_sqrt_trace_quadratic<F: BinaryField>(x: F, y: F) {
+		assert_eq!(x.sqrt().square(), x);
+		assert_eq!(x.square().sqrt(), x);
+
+		assert_eq!((x + y).trace(), x.trace() + y.trace());
+		assert_eq!(x.square().trace(), x.trace());
+
+		let c = x.square() + x;
+		assert_eq!(c.trace(), BF1::ZERO);
+		let root = c.solve_quadratic().unwrap();
+		assert!(root == x || root == x + F::ONE);
+
+		match y.solve_quadratic() {
+			Some(root) => assert_eq!(root.square() + root, y),
+			None => assert_eq!(y.trace(), BF1::ONE),
+		}
+	}
+
+	/// Checks reference vectors `(x, y, x * y, sqrt(x), Tr(x), c, root)`, where `root` is the
+	/// solution of $z^2 + z = c$ with the lowest bit cleared.
+	///
+	/// The vectors are generated by `testgen/field/binary_field_ops.sage`.
+	pub(crate) fn check_reference_vectors<F, U>(vectors: &[(U, U, U, U, u8, U, U)])
+	where
+		F: BinaryField + From<U>,
+		U: Copy,
+	{
+		for &(x, y, xy, sqrt_x, trace_x, c, root) in vectors {
+			let (x, y, c, root) = (F::from(x), F::from(y), F::from(c), F::from(root));
+			assert_eq!(x * y, F::from(xy));
+			assert_eq!(x.sqrt(), F::from(sqrt_x));
+			assert_eq!(x.trace(), BF1::from(trace_x));
+			let solution = c.solve_quadratic().unwrap();
+			assert!(solution == root || solution == root + F::ONE);
+		}
+	}
+
+	#[test]
+	fn test_canonical_tower_reference_vectors() {
+		check_reference_vectors::<BinaryField8b, _>(&[
+			(0x22, 0x91, 0x3c, 0x74, 1, 0x60, 0xd8),
+			(0xcd, 0xc3, 0x55, 0x2c, 1, 0x51, 0x10),
+		]);
+		check_reference_vectors::<BinaryField16b, _>(&[
+			(0x414c, 0x1e2f, 0x2e64, 0x9987, 0, 0x9c44, 0x7ed4),
+			(0xc2ce, 0x7311, 0x42fc, 0x7d53, 0, 0x3afc, 0x78e4),
+		]);
+		check_reference_vectors::<BinaryField32b, _>(&[
+			(0xa6cecc1b, 0x612e7696, 0xd07569dc, 0x57bf7204, 0, 0x39d6066a, 0xc9e9c616),
+			(0x35bf992d, 0x18072e8c, 0xc6429e1f, 0xcc956444, 1, 0x7036520a, 0x7ce42c82),
+		]);
+		check_reference_vectors::<BinaryField64b, _>(&[
+			(
+				0xe4b06ce60741c7a8,
+				0x63ca828dd5f4b3b2,
+				0x38b1457d1569a0a3,
+				0x6ba4700bf20c1219,
+				0,
+				0x6bea0c0785defe91,
+				0x9b810e766ec9d286,
+			),
+			(
+				0xc4647159c324c985,
+				0xb2221a58008a05a6,
+				0xab8f9eb757de46e5,
+				0xa743e492b154511e,
+				1,
+				0xc1bee529ab6c26bd,
+				0x442e3d437204e52c,
+			),
+		]);
+		check_reference_vectors::<BinaryField128b, _>(&[
+			(
+				0x9755d4c13a902931cd447e35b8b6d8fe,
+				0x51431193e6c3f3391a2b8f1ff1fd42a2,
+				0x6709ad67443cd0a8e773170742e6007,
+				0x915b3c2fc2f23b5b158479d926832ff2,
+				1,
+				0x25323c7edca8aaf05f7ded2aaf0dc15f,
+				0xa648a7dd06839eb905b6e6e307d4bedc,
+			),
+			(
+				0xe1988ad9f06c144a025b413f8a9a021e,
+				0xf8130c4237730edfafbd67f9619699cf,
+				0x20cadce5eba12920d770f89af4a96e32,
+				0x47309e133678aac88e05fc12b635f356,
+				0,
+				0xf74f950bea773892fd09cbf845f1568d,
+				0x8712b8bc076f3787b9d179e06c0fd4f4,
+			),
+		]);
+	}
+
+	#[test]
+	fn test_sqrt_trace_quadratic_small_fields() {
+		for x in 0..2 {
+			for y in 0..2 {
+				check_sqrt_trace_quadratic(BF1::from(x), BF1::from(y));
+			}
+		}
+		for x in 0..16 {
+			for y in 0..16 {
+				check_sqrt_trace_quadratic(BF2::from(x % 4), BF2::from(y % 4));
+				check_sqrt_trace_quadratic(BF4::from(x), BF4::from(y));
+			}
+		}
+		for x in 0..=255 {
+			check_sqrt_trace_quadratic(BF8::new(x), BF8::new(x.wrapping_mul(37)));
+		}
+	}
+
+	proptest! {
+		#[test]
+		fn test_sqrt_trace_quadratic(x in any::<u128>(), y in any::<u128>()) {
+			check_sqrt_trace_quadratic(BF16::new(x as u16), BF16::new(y as u16));
+			check_sqrt_trace_quadratic(BinaryField32b::new(x as u32), BinaryField32b::new(y as u32));
+			check_sqrt_trace_quadratic(BF64::new(x as u64), BF64::new(y as u64));
+			check_sqrt_trace_quadratic(BinaryField128b::new(x), BinaryField128b::new(y));
+		}
+	}
 }
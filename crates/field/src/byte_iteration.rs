@@ -7,9 +7,13 @@ use bytemuck::Pod;
 use crate::{
 	packed::get_packed_slice, AESTowerField128b, AESTowerField16b, AESTowerField32b,
 	AESTowerField64b, AESTowerField8b, BinaryField128b, BinaryField128bPolyval, BinaryField16b,
-	BinaryField32b, BinaryField64b, BinaryField8b, ByteSlicedAES32x128b, ByteSlicedAES32x16b,
-	ByteSlicedAES32x32b, ByteSlicedAES32x64b, ByteSlicedAES32x8b, Field,
-	PackedAESBinaryField16x16b, PackedAESBinaryField16x32b, PackedAESBinaryField16x8b,
+	BinaryField32b, BinaryField64b, BinaryField8b, ByteSliced16x128x1b, ByteSliced32x128x1b,
+	ByteSliced64x128x1b, ByteSlicedAES16x128b, ByteSlicedAES16x16x8b, ByteSlicedAES16x2x64b,
+	ByteSlicedAES16x4x32b, ByteSlicedAES16x8x16b, ByteSlicedAES32x128b, ByteSlicedAES32x16b,
+	ByteSlicedAES32x16x8b, ByteSlicedAES32x2x64b, ByteSlicedAES32x32b, ByteSlicedAES32x4x32b,
+	ByteSlicedAES32x64b, ByteSlicedAES32x8b, ByteSlicedAES32x8x16b, ByteSlicedAES64x128b,
+	ByteSlicedAES64x16x8b, ByteSlicedAES64x2x64b, ByteSlicedAES64x4x32b, ByteSlicedAES64x8x16b,
+	Field, PackedAESBinaryField16x16b, PackedAESBinaryField16x32b, PackedAESBinaryField16x8b,
 	PackedAESBinaryField1x128b, PackedAESBinaryField1x16b, PackedAESBinaryField1x32b,
 	PackedAESBinaryField1x64b, PackedAESBinaryField1x8b, PackedAESBinaryField2x128b,
 	PackedAESBinaryField2x16b, PackedAESBinaryField2x32b, PackedAESBinaryField2x64b,
@@ -167,6 +171,38 @@ pub fn is_sequential_bytes<T>() -> bool {
 	cloned[0].0
 }
 
+/// Calls the given macro with the list of all byte-sliced packed field types.
+/// Note: add more byte sliced types here as soon as they are added
+macro_rules! for_each_byte_sliced_type {
+	($macro:ident!($($args:tt)*)) => {
+		$macro!(
+			$($args)*
+			ByteSlicedAES16x128b,
+			ByteSlicedAES16x2x64b,
+			ByteSlicedAES16x4x32b,
+			ByteSlicedAES16x8x16b,
+			ByteSlicedAES16x16x8b,
+			ByteSliced16x128x1b,
+			ByteSlicedAES32x128b,
+			ByteSlicedAES32x64b,
+			ByteSlicedAES32x32b,
+			ByteSlicedAES32x16b,
+			ByteSlicedAES32x8b,
+			ByteSlicedAES32x2x64b,
+			ByteSlicedAES32x4x32b,
+			ByteSlicedAES32x8x16b,
+			ByteSlicedAES32x16x8b,
+			ByteSliced32x128x1b,
+			ByteSlicedAES64x128b,
+			ByteSlicedAES64x2x64b,
+			ByteSlicedAES64x4x32b,
+			ByteSlicedAES64x8x16b,
+			ByteSlicedAES64x16x8b,
+			ByteSliced64x128x1b
+		)
+	};
+}
+
 /// Returns if we can iterate over bytes, each representing 8 1-bit values.
 pub fn can_iterate_bytes<P: PackedField>() -> bool {
 	// Packed fields with sequential byte order
@@ -175,15 +211,13 @@ pub fn can_iterate_bytes<P: PackedField>() -> bool {
 	}
 
 	// Byte-sliced fields
-	// Note: add more byte sliced types here as soon as they are added
-	match TypeId::of::<P>() {
-		x if x == TypeId::of::<ByteSlicedAES32x128b>() => true,
-		x if x == TypeId::of::<ByteSlicedAES32x64b>() => true,
-		x if x == TypeId::of::<ByteSlicedAES32x32b>() => true,
-		x if x == TypeId::of::<ByteSlicedAES32x16b>() => true,
-		x if x == TypeId::of::<ByteSlicedAES32x8b>() => true,
-		_ => false,
+	macro_rules! is_byte_sliced {
+		($($packed_type:ty),*) => {
+			[$(TypeId::of::<$packed_type>()),*].contains(&TypeId::of::<P>())
+		};
 	}
+
+	for_each_byte_sliced_type!(is_byte_sliced!())
 }
 
 /// Helper macro to generate the iteration over bytes for byte-sliced types.
@@ -222,25 +256,20 @@ pub fn iterate_bytes<P: PackedField>(data: &[P], callback: &mut impl ByteIterato
 		};
 		callback.call(bytes.iter().copied());
 	} else {
-		// Note: add more byte sliced types here as soon as they are added
-		match TypeId::of::<P>() {
-			x if x == TypeId::of::<ByteSlicedAES32x128b>() => {
-				iterate_byte_sliced!(ByteSlicedAES32x128b, data, callback);
-			}
-			x if x == TypeId::of::<ByteSlicedAES32x64b>() => {
-				iterate_byte_sliced!(ByteSlicedAES32x64b, data, callback);
-			}
-			x if x == TypeId::of::<ByteSlicedAES32x32b>() => {
-				iterate_byte_sliced!(ByteSlicedAES32x32b, data, callback);
-			}
-			x if x == TypeId::of::<ByteSlicedAES32x16b>() => {
-				iterate_byte_sliced!(ByteSlicedAES32x16b, data, callback);
-			}
-			x if x == TypeId::of::<ByteSlicedAES32x8b>() => {
-				iterate_byte_sliced!(ByteSlicedAES32x8b, data, callback);
-			}
-			_ => unreachable!("packed field doesn't support byte iteration"),
+		macro_rules! iterate_byte_sliced_types {
+			($data:ident, $callback:ident, $($packed_type:ty),*) => {
+				match TypeId::of::<P>() {
+					$(
+						x if x == TypeId::of::<$packed_type>() => {
+							iterate_byte_sliced!($packed_type, $data, $callback);
+						}
+					)*
+					_ => unreachable!("packed field doesn't support byte iteration"),
+				}
+			};
 		}
+
+		for_each_byte_sliced_type!(iterate_byte_sliced_types!(data, callback,));
 	}
 }
 
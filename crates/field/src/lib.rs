@@ -8,6 +8,23 @@
 //! $T_{\iota}$.
 //!
 //! [DP23]: https://eprint.iacr.org/2023/1784
+//!
+//! # Constant-time arithmetic
+//!
+//! The multiplication and inversion of most packed fields are computed with bitwise operations,
+//! carry-less multiplication or GFNI instructions, whose timing doesn't depend on the operands.
+//! The exceptions are the 8-bit and smaller fields on targets without GFNI and the portable
+//! POLYVAL inversion, which use lookup tables indexed by the field values. Enabling the
+//! `constant_time` feature replaces these with data-independent implementations, see
+//! [`arch::PairwiseSmallFieldStrategy`]. On aarch64 the small field lookups are performed with the
+//! NEON `tbl` instruction on registers, which is data-independent.
+//!
+//! The `constant_time` integration test runs a statistical timing check over the packed fields
+//! of every underlier:
+//!
+//! ```text
+//! cargo test --release -p binius_field --features constant_time --test constant_time -- --ignored
+//! ```
 
 #![cfg_attr(
 	all(target_arch = "x86_64", not(feature = "stable_only")),
@@ -33,6 +50,8 @@ pub mod packed_extension;
 pub mod packed_extension_ops;
 mod packed_polyval;
 pub mod polyval;
+#[cfg(feature = "serde")]
+mod serde_impl;
 #[cfg(test)]
 mod tests;
 pub mod tower_levels;
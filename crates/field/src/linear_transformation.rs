@@ -4,7 +4,12 @@ use std::ops::Deref;
 
 use rand::RngCore;
 
-use crate::{packed::PackedBinaryField, BinaryField, BinaryField1b, ExtensionField};
+use crate::{
+	binary_field::{quadratic_solution_coeffs, solve_quadratic_unchecked},
+	packed::PackedBinaryField,
+	underlier::{UnderlierWithBitOps, WithUnderlier},
+	BinaryField, BinaryField1b, ExtensionField, Field,
+};
 
 /// Generic transformation trait that is used both for scalars and packed fields
 pub trait Transformation<Input, Output>: Sync {
@@ -64,6 +69,48 @@ impl<OF: BinaryField> FieldLinearTransformation<OF, Vec<OF>> {
 	}
 }
 
+impl<OF> FieldLinearTransformation<OF, Vec<OF>>
+where
+	OF: BinaryField + WithUnderlier<Underlier: UnderlierWithBitOps>,
+{
+	/// Creates the transformation $x \mapsto \sqrt{x}$, see [`BinaryField::sqrt`].
+	pub fn sqrt() -> Self {
+		Self::from_basis_images(BinaryField::sqrt)
+	}
+
+	/// Creates the transformation mapping $x$ to its absolute trace embedded into `OF`, see
+	/// [`BinaryField::trace`].
+	pub fn trace() -> Self {
+		Self::from_basis_images(|basis| {
+			if basis.trace() == BinaryField1b::ONE {
+				OF::ONE
+			} else {
+				OF::ZERO
+			}
+		})
+	}
+
+	/// Creates the transformation mapping $c$ to a solution of $x^2 + x = c$, see
+	/// [`BinaryField::solve_quadratic`].
+	///
+	/// The result is only a solution for inputs with zero trace, which the caller must check
+	/// separately, e.g. with [`Self::trace`].
+	pub fn solve_quadratic() -> Self {
+		let coeffs = quadratic_solution_coeffs::<OF>();
+		Self::from_basis_images(|basis| solve_quadratic_unchecked(basis, coeffs))
+	}
+
+	/// Creates the transformation from the images of the elements whose underlier has a single
+	/// bit set, which is the basis [`Transformation::transform`] decomposes its input in.
+	fn from_basis_images(f: impl Fn(OF) -> OF) -> Self {
+		Self {
+			bases: (0..OF::DEGREE)
+				.map(|i| f(OF::from_underlier(OF::Underlier::ONE << i)))
+				.collect(),
+		}
+	}
+}
+
 /// This crates represents a type that creates a packed transformation from `Self` to a packed
 /// field based on the scalar field transformation.
 #[allow(private_bounds)]
@@ -17,7 +17,10 @@ use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 use super::{
 	aes_field::AESTowerField128b,
 	arithmetic_traits::InvertOrZero,
-	binary_field::{BinaryField, BinaryField128b, BinaryField1b, TowerField},
+	binary_field::{
+		serialize_deserialize_non_canonical, BinaryField, BinaryField128b, BinaryField1b,
+		TowerField,
+	},
 	error::Error,
 	extension::ExtensionField,
 	underlier::WithUnderlier,
@@ -454,6 +457,8 @@ impl BinaryField for BinaryField128bPolyval {
 	const MULTIPLICATIVE_GENERATOR: Self = Self(0x72bdf2504ce49c03105433c1c25a4a7);
 }
 
+serialize_deserialize_non_canonical!(BinaryField128bPolyval, canonical = BinaryField128b);
+
 impl TowerField for BinaryField128bPolyval {
 	type Canonical = BinaryField128b;
 
@@ -1079,6 +1084,55 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_sqrt_trace_quadratic() {
+		// Test vectors from testgen/field/binary_field_ops.sage
+		let a = BinaryField128bPolyval::new(0x2a9055e4e69a61f0b5cfd6f4161087ba);
+		let b = BinaryField128bPolyval::new(0x3843cf87fb7c84e18276983bed670337);
+
+		assert_eq!(a.sqrt(), BinaryField128bPolyval::new(0xa1ca7c8ff4dfbe607f808c50bfce537c));
+		assert_eq!(a.trace(), BinaryField1b::ONE);
+		assert_eq!(b.trace(), BinaryField1b::ZERO);
+
+		assert_eq!(a.solve_quadratic(), None);
+		let root = b.solve_quadratic().unwrap();
+		assert!(
+			root == BinaryField128bPolyval::new(0x313662774c2642c545464e4338d0e358)
+				|| root == BinaryField128bPolyval::new(0x313662774c2642c545464e4338d0e359)
+		);
+	}
+
+	#[test]
+	fn test_packed_sqrt_trace_quadratic() {
+		let mut rng = thread_rng();
+		let c = PackedBinaryPolyval4x128b::random(&mut rng);
+
+		let sqrt = <PackedBinaryPolyval4x128b as PackedTransformationFactory<
+			PackedBinaryPolyval4x128b,
+		>>::make_packed_transformation(FieldLinearTransformation::sqrt());
+		let trace = <PackedBinaryPolyval4x128b as PackedTransformationFactory<
+			PackedBinaryPolyval4x128b,
+		>>::make_packed_transformation(FieldLinearTransformation::trace());
+		let solve_quadratic = <PackedBinaryPolyval4x128b as PackedTransformationFactory<
+			PackedBinaryPolyval4x128b,
+		>>::make_packed_transformation(
+			FieldLinearTransformation::solve_quadratic()
+		);
+
+		let (sqrt, trace, root) =
+			(sqrt.transform(&c), trace.transform(&c), solve_quadratic.transform(&c));
+		for i in 0..PackedBinaryPolyval4x128b::WIDTH {
+			assert_eq!(sqrt.get(i), c.get(i).sqrt());
+			assert_eq!(
+				trace.get(i) == BinaryField128bPolyval::ONE,
+				c.get(i).trace() == BinaryField1b::ONE
+			);
+			if let Some(expected) = c.get(i).solve_quadratic() {
+				assert_eq!(root.get(i), expected);
+			}
+		}
+	}
+
 	#[test]
 	fn test_multiplicative_generator() {
 		assert!(is_binary_field_valid_generator::<BinaryField128bPolyval>());
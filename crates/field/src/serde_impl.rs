@@ -0,0 +1,297 @@
+// Copyright 2025 Irreducible Inc.
+
+//! [`serde`] support for tower field scalars and packed fields.
+//!
+//! Every tower field element is encoded through its image in the canonical tower basis, so
+//! isomorphic representations of the same element (e.g. [`AESTowerField128b`],
+//! [`BinaryField128b`] and [`BinaryField128bPolyval`]) serialize identically.
+//!
+//! * Human-readable formats (JSON, TOML, ...) use a `0x`-prefixed, zero-padded, big-endian hex
+//!   string of the canonical value, e.g. `"0x00ff"` for a [`BinaryField16b`] element.
+//! * Binary formats use the little-endian byte encoding of [`serialize_canonical`].
+//!
+//! Packed fields are encoded as a fixed-length sequence of their scalars.
+
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+	de::{self, SeqAccess, Visitor},
+	ser::SerializeTuple,
+	Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+	deserialize_canonical, serialize_canonical, AESTowerField128b, AESTowerField16b,
+	AESTowerField32b, AESTowerField64b, AESTowerField8b, BinaryField, BinaryField128b,
+	BinaryField128bPolyval, BinaryField16b, BinaryField1b, BinaryField2b, BinaryField32b,
+	BinaryField4b, BinaryField64b, BinaryField8b, PackedField, TowerField,
+};
+
+/// Number of bytes in the canonical byte encoding of a tower field element.
+const fn n_bytes<F: BinaryField>() -> usize {
+	F::N_BITS.div_ceil(8)
+}
+
+fn to_canonical_u128<F: TowerField>(elem: F) -> u128 {
+	let mut bytes = [0u8; 16];
+	serialize_canonical(elem, &mut bytes[..n_bytes::<F>()])
+		.expect("buffer is large enough for any tower field element");
+	u128::from_le_bytes(bytes)
+}
+
+fn from_canonical_u128<F: TowerField, E: de::Error>(value: u128) -> Result<F, E> {
+	if F::N_BITS < 128 && value >> F::N_BITS != 0 {
+		return Err(E::custom(format!("value {value:#x} does not fit in {} bits", F::N_BITS)));
+	}
+	let bytes = value.to_le_bytes();
+	deserialize_canonical(&bytes[..n_bytes::<F>()]).map_err(E::custom)
+}
+
+fn serialize_tower_field<F: TowerField, S: Serializer>(
+	elem: F,
+	serializer: S,
+) -> Result<S::Ok, S::Error> {
+	let value = to_canonical_u128(elem);
+	if serializer.is_human_readable() {
+		let width = 2 * n_bytes::<F>() + 2;
+		serializer.serialize_str(&format!("{value:#0width$x}"))
+	} else {
+		serializer.serialize_bytes(&value.to_le_bytes()[..n_bytes::<F>()])
+	}
+}
+
+fn deserialize_tower_field<'de, F: TowerField, D: Deserializer<'de>>(
+	deserializer: D,
+) -> Result<F, D::Error> {
+	if deserializer.is_human_readable() {
+		deserializer.deserialize_str(TowerFieldVisitor::<F>(PhantomData))
+	} else {
+		deserializer.deserialize_bytes(TowerFieldVisitor::<F>(PhantomData))
+	}
+}
+
+struct TowerFieldVisitor<F>(PhantomData<F>);
+
+impl<'de, F: TowerField> Visitor<'de> for TowerFieldVisitor<F> {
+	type Value = F;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			formatter,
+			"a hex string or {} little-endian bytes encoding a {}-bit tower field element",
+			n_bytes::<F>(),
+			F::N_BITS
+		)
+	}
+
+	fn visit_str<E: de::Error>(self, v: &str) -> Result<F, E> {
+		let digits = v.strip_prefix("0x").unwrap_or(v);
+		if digits.is_empty() || digits.len() > 2 * n_bytes::<F>() {
+			return Err(E::invalid_length(digits.len(), &self));
+		}
+		let value = u128::from_str_radix(digits, 16)
+			.map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))?;
+		from_canonical_u128(value)
+	}
+
+	fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<F, E> {
+		if v.len() != n_bytes::<F>() {
+			return Err(E::invalid_length(v.len(), &self));
+		}
+		let mut bytes = [0u8; 16];
+		bytes[..v.len()].copy_from_slice(v);
+		from_canonical_u128(u128::from_le_bytes(bytes))
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<F, A::Error> {
+		let mut bytes = [0u8; 16];
+		for (i, byte) in bytes.iter_mut().take(n_bytes::<F>()).enumerate() {
+			*byte = seq
+				.next_element()?
+				.ok_or_else(|| de::Error::invalid_length(i, &self))?;
+		}
+		if seq.next_element::<u8>()?.is_some() {
+			return Err(de::Error::invalid_length(n_bytes::<F>() + 1, &self));
+		}
+		from_canonical_u128(u128::from_le_bytes(bytes))
+	}
+}
+
+macro_rules! impl_serde_for_tower_field {
+	($($field:ty),* $(,)?) => {
+		$(
+			impl Serialize for $field {
+				fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+					serialize_tower_field(*self, serializer)
+				}
+			}
+
+			impl<'de> Deserialize<'de> for $field {
+				fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+					deserialize_tower_field(deserializer)
+				}
+			}
+		)*
+	};
+}
+
+impl_serde_for_tower_field!(
+	BinaryField1b,
+	BinaryField2b,
+	BinaryField4b,
+	BinaryField8b,
+	BinaryField16b,
+	BinaryField32b,
+	BinaryField64b,
+	BinaryField128b,
+	AESTowerField8b,
+	AESTowerField16b,
+	AESTowerField32b,
+	AESTowerField64b,
+	AESTowerField128b,
+	BinaryField128bPolyval,
+);
+
+/// Serializes a packed field as a fixed-length sequence of its scalars.
+pub(crate) fn serialize_packed<P, S>(packed: &P, serializer: S) -> Result<S::Ok, S::Error>
+where
+	P: PackedField<Scalar: Serialize>,
+	S: Serializer,
+{
+	let mut tuple = serializer.serialize_tuple(P::WIDTH)?;
+	for scalar in packed.iter() {
+		tuple.serialize_element(&scalar)?;
+	}
+	tuple.end()
+}
+
+/// Deserializes a packed field from a sequence of exactly `P::WIDTH` scalars.
+pub(crate) fn deserialize_packed<'de, P, D>(deserializer: D) -> Result<P, D::Error>
+where
+	P: PackedField<Scalar: Deserialize<'de>>,
+	D: Deserializer<'de>,
+{
+	deserializer.deserialize_tuple(P::WIDTH, PackedFieldVisitor::<P>(PhantomData))
+}
+
+struct PackedFieldVisitor<P>(PhantomData<P>);
+
+impl<'de, P> Visitor<'de> for PackedFieldVisitor<P>
+where
+	P: PackedField<Scalar: Deserialize<'de>>,
+{
+	type Value = P;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+		write!(formatter, "a sequence of {} field elements", P::WIDTH)
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<P, A::Error> {
+		let mut scalars = Vec::with_capacity(P::WIDTH);
+		for i in 0..P::WIDTH {
+			let scalar = seq
+				.next_element()?
+				.ok_or_else(|| de::Error::invalid_length(i, &self))?;
+			scalars.push(scalar);
+		}
+		if seq.next_element::<P::Scalar>()?.is_some() {
+			return Err(de::Error::invalid_length(P::WIDTH + 1, &self));
+		}
+		Ok(P::from_scalars(scalars))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use proptest::prelude::*;
+	use serde::de::DeserializeOwned;
+
+	use super::*;
+	use crate::{
+		ByteSlicedAES32x128b, Field, PackedAESBinaryField4x32b, PackedBinaryField128x1b,
+		PackedBinaryField16x8b, PackedBinaryField2x128b, PackedBinaryField4x32b,
+		PackedBinaryPolyval2x128b,
+	};
+
+	fn round_trip<T>(value: T) -> String
+	where
+		T: Serialize + DeserializeOwned + PartialEq + fmt::Debug,
+	{
+		let json = serde_json::to_string(&value).unwrap();
+		assert_eq!(serde_json::from_str::<T>(&json).unwrap(), value);
+		json
+	}
+
+	#[test]
+	fn test_hex_encoding() {
+		assert_eq!(round_trip(BinaryField1b::ONE), r#""0x01""#);
+		assert_eq!(round_trip(BinaryField4b::from(0xa)), r#""0x0a""#);
+		assert_eq!(round_trip(BinaryField16b::new(0x1234)), r#""0x1234""#);
+		assert_eq!(round_trip(BinaryField32b::new(0xff)), r#""0x000000ff""#);
+		assert_eq!(round_trip(BinaryField128b::new(1)), r#""0x00000000000000000000000000000001""#);
+	}
+
+	#[test]
+	fn test_deserialize_rejects_invalid_values() {
+		assert!(serde_json::from_str::<BinaryField1b>(r#""0x02""#).is_err());
+		assert!(serde_json::from_str::<BinaryField4b>(r#""0x10""#).is_err());
+		assert!(serde_json::from_str::<BinaryField8b>(r#""0x100""#).is_err());
+		assert!(serde_json::from_str::<BinaryField8b>(r#""0x""#).is_err());
+		assert!(serde_json::from_str::<BinaryField8b>(r#""0xzz""#).is_err());
+		assert!(serde_json::from_str::<BinaryField8b>("255").is_err());
+	}
+
+	#[test]
+	fn test_packed_length_mismatch() {
+		let json = serde_json::to_string(&[BinaryField32b::ONE; 3]).unwrap();
+		assert!(serde_json::from_str::<PackedBinaryField4x32b>(&json).is_err());
+		let json = serde_json::to_string(&[BinaryField32b::ONE; 5]).unwrap();
+		assert!(serde_json::from_str::<PackedBinaryField4x32b>(&json).is_err());
+	}
+
+	proptest! {
+		#[test]
+		fn test_isomorphic_encodings_agree_8b(val in any::<u8>()) {
+			let canonical = BinaryField8b::new(val);
+			let json = round_trip(canonical);
+			prop_assert_eq!(&round_trip(AESTowerField8b::from(canonical)), &json);
+			let aes: AESTowerField8b = serde_json::from_str(&json).unwrap();
+			prop_assert_eq!(BinaryField8b::from(aes), canonical);
+		}
+
+		#[test]
+		fn test_isomorphic_encodings_agree_32b(val in any::<u32>()) {
+			let canonical = BinaryField32b::new(val);
+			let json = round_trip(canonical);
+			prop_assert_eq!(round_trip(AESTowerField32b::from(canonical)), json);
+		}
+
+		#[test]
+		fn test_isomorphic_encodings_agree_128b(val in any::<u128>()) {
+			let canonical = BinaryField128b::new(val);
+			let json = round_trip(canonical);
+			prop_assert_eq!(&round_trip(AESTowerField128b::from(canonical)), &json);
+			prop_assert_eq!(&round_trip(BinaryField128bPolyval::from(canonical)), &json);
+
+			let aes: AESTowerField128b = serde_json::from_str(&json).unwrap();
+			let polyval: BinaryField128bPolyval = serde_json::from_str(&json).unwrap();
+			prop_assert_eq!(BinaryField128b::from(aes), canonical);
+			prop_assert_eq!(BinaryField128b::from(polyval), canonical);
+			prop_assert_eq!(BinaryField128bPolyval::from(aes), polyval);
+		}
+
+		#[test]
+		fn test_packed_round_trip(seed in any::<u64>()) {
+			let mut rng = <rand::rngs::StdRng as rand::SeedableRng>::seed_from_u64(seed);
+			round_trip(PackedBinaryField128x1b::random(&mut rng));
+			round_trip(PackedBinaryField16x8b::random(&mut rng));
+			round_trip(PackedBinaryField2x128b::random(&mut rng));
+			round_trip(PackedBinaryPolyval2x128b::random(&mut rng));
+			round_trip(ByteSlicedAES32x128b::random(&mut rng));
+
+			let canonical = PackedBinaryField4x32b::random(&mut rng);
+			let aes = PackedAESBinaryField4x32b::from_fn(|i| canonical.get(i).into());
+			prop_assert_eq!(round_trip(canonical), round_trip(aes));
+		}
+	}
+}
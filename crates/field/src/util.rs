@@ -5,7 +5,7 @@ use std::iter;
 use binius_maybe_rayon::prelude::*;
 use binius_utils::checked_arithmetics::checked_int_div;
 
-use crate::{packed::get_packed_slice_unchecked, ExtensionField, Field, PackedField};
+use crate::{packed::get_packed_slice_unchecked, Error, ExtensionField, Field, PackedField};
 
 /// Computes the inner product of two vectors without checking that the lengths are equal
 pub fn inner_product_unchecked<F, FE>(
@@ -79,3 +79,205 @@ pub fn eq<F: Field>(x: F, y: F) -> F {
 pub fn powers<F: Field>(val: F) -> impl Iterator<Item = F> {
 	iter::successors(Some(F::ONE), move |&power| Some(power * val))
 }
+
+/// Number of packed elements in a chunk that is batch inverted with a single field inversion.
+const BATCH_INVERT_CHUNK_SIZE: usize = 1 << 10;
+
+/// Inverts every scalar in a slice of packed field elements in place.
+///
+/// This uses Montgomery's trick, which replaces all but one inversion per chunk of
+/// [`BATCH_INVERT_CHUNK_SIZE`] packed elements with three multiplications, and processes chunks in
+/// parallel. This pays off when inversion is much more expensive than multiplication, as it is for
+/// [`BinaryField128bPolyval`](crate::BinaryField128bPolyval). Inversion in the canonical tower
+/// reduces to subfield inversions and is already cheap, so element-wise inversion can be faster
+/// there; see the `packed_field_batch_invert` benchmark.
+///
+/// ## Throws
+///
+/// * [`Error::ZeroInverse`] if any scalar is zero, in which case `values` is left unmodified.
+pub fn batch_invert<P: PackedField>(values: &mut [P]) -> Result<(), Error> {
+	let mut prefix_products = vec![P::zero(); values.len()];
+	let totals = values
+		.par_chunks(BATCH_INVERT_CHUNK_SIZE)
+		.zip(prefix_products.par_chunks_mut(BATCH_INVERT_CHUNK_SIZE))
+		.map(|(chunk, prefix_products)| exclusive_prefix_products(chunk, prefix_products))
+		.collect::<Vec<_>>();
+
+	if totals
+		.iter()
+		.any(|total| total.iter().any(|scalar| scalar == P::Scalar::ZERO))
+	{
+		return Err(Error::ZeroInverse);
+	}
+
+	values
+		.par_chunks_mut(BATCH_INVERT_CHUNK_SIZE)
+		.zip(prefix_products.par_chunks(BATCH_INVERT_CHUNK_SIZE))
+		.zip(totals)
+		.for_each(|((chunk, prefix_products), total)| {
+			invert_from_prefix_products(chunk, prefix_products, total);
+		});
+
+	Ok(())
+}
+
+/// Inverts every scalar in a slice of packed field elements in place, mapping zero to zero.
+///
+/// This is the batched equivalent of [`PackedField::invert_or_zero`]. It uses Montgomery's trick
+/// on parallel chunks like [`batch_invert`]. Zero scalars are temporarily replaced by one so that
+/// they do not zero out the running products of their lanes.
+pub fn batch_invert_or_zero<P: PackedField>(values: &mut [P]) {
+	values
+		.par_chunks_mut(BATCH_INVERT_CHUNK_SIZE)
+		.for_each(|chunk| {
+			let mut zero_masked = Vec::new();
+			for (i, value) in chunk.iter_mut().enumerate() {
+				if value.iter().any(|scalar| scalar == P::Scalar::ZERO) {
+					zero_masked.push((i, *value));
+					*value = P::from_fn(|j| {
+						let scalar = value.get(j);
+						if scalar == P::Scalar::ZERO {
+							P::Scalar::ONE
+						} else {
+							scalar
+						}
+					});
+				}
+			}
+
+			let mut prefix_products = vec![P::zero(); chunk.len()];
+			let total = exclusive_prefix_products(chunk, &mut prefix_products);
+			invert_from_prefix_products(chunk, &prefix_products, total);
+
+			for (i, original) in zero_masked {
+				let inverse = chunk[i];
+				chunk[i] = P::from_fn(|j| {
+					if original.get(j) == P::Scalar::ZERO {
+						P::Scalar::ZERO
+					} else {
+						inverse.get(j)
+					}
+				});
+			}
+		});
+}
+
+/// Writes the product of all elements preceding each element of `values` to `prefix_products`
+/// and returns the product of all elements.
+fn exclusive_prefix_products<P: PackedField>(values: &[P], prefix_products: &mut [P]) -> P {
+	let mut acc = P::one();
+	for (&value, prefix_product) in values.iter().zip(prefix_products) {
+		*prefix_product = acc;
+		acc *= value;
+	}
+	acc
+}
+
+fn invert_from_prefix_products<P: PackedField>(values: &mut [P], prefix_products: &[P], total: P) {
+	let mut inverse = total.invert_or_zero();
+	for (value, &prefix_product) in values.iter_mut().zip(prefix_products).rev() {
+		let original = *value;
+		*value = inverse * prefix_product;
+		inverse *= original;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::{
+		AESTowerField8b, BinaryField128b, BinaryField128bPolyval, BinaryField32b,
+		PackedAESBinaryField16x8b, PackedBinaryField4x32b, PackedBinaryPolyval1x128b,
+	};
+
+	fn random_values<P: PackedField>(len: usize, zero_probability: f64) -> Vec<P> {
+		let mut rng = StdRng::seed_from_u64(0);
+		repeat_with(|| {
+			P::from_fn(|_| {
+				if rand::Rng::gen_bool(&mut rng, zero_probability) {
+					P::Scalar::ZERO
+				} else {
+					repeat_with(|| <P::Scalar as Field>::random(&mut rng))
+						.find(|scalar| *scalar != P::Scalar::ZERO)
+						.expect("iterator is infinite")
+				}
+			})
+		})
+		.take(len)
+		.collect()
+	}
+
+	fn check_batch_invert_or_zero<P: PackedField>(len: usize) {
+		let values = random_values::<P>(len, 0.1);
+		let mut inverses = values.clone();
+		batch_invert_or_zero(&mut inverses);
+
+		let expected = values
+			.iter()
+			.map(|value| value.invert_or_zero())
+			.collect::<Vec<_>>();
+		assert_eq!(inverses, expected);
+	}
+
+	fn check_batch_invert<P: PackedField>(len: usize) {
+		let values = random_values::<P>(len, 0.0);
+		let mut inverses = values.clone();
+		batch_invert(&mut inverses).unwrap();
+
+		for (value, inverse) in values.iter().zip(&inverses) {
+			assert_eq!(*value * *inverse, P::one());
+		}
+	}
+
+	#[test]
+	fn test_batch_invert_or_zero() {
+		for len in [
+			0,
+			1,
+			7,
+			BATCH_INVERT_CHUNK_SIZE + 3,
+			3 * BATCH_INVERT_CHUNK_SIZE,
+		] {
+			check_batch_invert_or_zero::<BinaryField32b>(len);
+			check_batch_invert_or_zero::<BinaryField128b>(len);
+			check_batch_invert_or_zero::<PackedBinaryField4x32b>(len);
+			check_batch_invert_or_zero::<PackedAESBinaryField16x8b>(len);
+			check_batch_invert_or_zero::<PackedBinaryPolyval1x128b>(len);
+		}
+	}
+
+	#[test]
+	fn test_batch_invert() {
+		for len in [
+			0,
+			1,
+			7,
+			BATCH_INVERT_CHUNK_SIZE + 3,
+			3 * BATCH_INVERT_CHUNK_SIZE,
+		] {
+			check_batch_invert::<AESTowerField8b>(len);
+			check_batch_invert::<BinaryField128bPolyval>(len);
+			check_batch_invert::<PackedBinaryField4x32b>(len);
+		}
+	}
+
+	#[test]
+	fn test_batch_invert_zero_leaves_values_unmodified() {
+		let mut values = random_values::<PackedBinaryField4x32b>(2 * BATCH_INVERT_CHUNK_SIZE, 0.0);
+		values[BATCH_INVERT_CHUNK_SIZE + 5] = PackedBinaryField4x32b::from_fn(|i| {
+			if i == 2 {
+				BinaryField32b::ZERO
+			} else {
+				BinaryField32b::ONE
+			}
+		});
+		let original = values.clone();
+
+		assert!(matches!(batch_invert(&mut values), Err(Error::ZeroInverse)));
+		assert_eq!(values, original);
+	}
+}
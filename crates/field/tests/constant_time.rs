@@ -0,0 +1,266 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+//! Statistical timing tests for the packed field arithmetic in the spirit of
+//! [dudect](https://eprint.iacr.org/2016/1123).
+//!
+//! For every operation the execution time is measured on two classes of inputs: a fixed zero
+//! operand and uniformly random operands, interleaved in a random order. Welch's t-test is then
+//! applied to the two timing distributions, as well as to versions of them with the slowest
+//! measurements cropped at several percentiles. A statistic above [`T_THRESHOLD`] is a strong
+//! indication that the timing depends on the operand values.
+//!
+//! The tests are ignored by default because they are slow and only meaningful in release builds
+//! on an otherwise idle machine:
+//!
+//! ```text
+//! cargo test --release -p binius_field --features constant_time --test constant_time -- --ignored
+//! ```
+//!
+//! The number of measurements per operation can be changed with the
+//! `BINIUS_CONSTANT_TIME_SAMPLES` environment variable.
+
+#![cfg(feature = "constant_time")]
+
+use std::{any::type_name, hint::black_box, time::Instant};
+
+use binius_field::{
+	arch::{
+		byte_sliced::*, packed_1::*, packed_128::*, packed_16::*, packed_2::*, packed_256::*,
+		packed_32::*, packed_4::*, packed_512::*, packed_64::*, packed_8::*, packed_aes_128::*,
+		packed_aes_16::*, packed_aes_256::*, packed_aes_32::*, packed_aes_512::*, packed_aes_64::*,
+		packed_aes_8::*, packed_polyval_128::*, packed_polyval_256::*, packed_polyval_512::*,
+	},
+	PackedField,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// The t-statistic above which the operation is considered to leak timing information.
+/// This is the threshold used by dudect for a definite leak.
+const T_THRESHOLD: f64 = 10.0;
+
+const DEFAULT_SAMPLES: usize = 20_000;
+
+/// The number of operations timed together in a single measurement, so that the measured
+/// duration is well above the timer resolution.
+const BATCH_SIZE: usize = 16;
+
+/// The number of cropped versions of the measurements that are tested in addition to the
+/// uncropped one.
+const NUMBER_PERCENTILES: usize = 16;
+
+/// Welford's online algorithm for the mean and variance of the two input classes.
+#[derive(Default, Clone)]
+struct WelchTest {
+	count: [f64; 2],
+	mean: [f64; 2],
+	m2: [f64; 2],
+}
+
+impl WelchTest {
+	fn push(&mut self, class: usize, value: f64) {
+		self.count[class] += 1.0;
+		let delta = value - self.mean[class];
+		self.mean[class] += delta / self.count[class];
+		self.m2[class] += delta * (value - self.mean[class]);
+	}
+
+	fn t_statistic(&self) -> f64 {
+		let variance = |class: usize| self.m2[class] / (self.count[class] - 1.0);
+		let denominator = (variance(0) / self.count[0] + variance(1) / self.count[1]).sqrt();
+		if denominator == 0.0 {
+			return 0.0;
+		}
+		(self.mean[0] - self.mean[1]) / denominator
+	}
+}
+
+/// Runs the fixed-vs-random test for `op` and returns the largest absolute t-statistic over the
+/// uncropped and cropped measurements.
+fn max_t_statistic<P: PackedField>(mut op: impl FnMut(P, P) -> P) -> f64 {
+	let samples = std::env::var("BINIUS_CONSTANT_TIME_SAMPLES")
+		.ok()
+		.and_then(|value| value.parse().ok())
+		.unwrap_or(DEFAULT_SAMPLES);
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let mut measurements = Vec::with_capacity(samples);
+	for _ in 0..samples {
+		let class = rng.gen_range(0..2);
+		let lhs: [P; BATCH_SIZE] = std::array::from_fn(|_| match class {
+			0 => P::zero(),
+			_ => P::random(&mut rng),
+		});
+		let rhs: [P; BATCH_SIZE] = std::array::from_fn(|_| P::random(&mut rng));
+
+		let start = Instant::now();
+		for (&lhs, &rhs) in lhs.iter().zip(&rhs) {
+			black_box(op(black_box(lhs), black_box(rhs)));
+		}
+		let elapsed = start.elapsed().as_nanos() as f64;
+
+		measurements.push((class, elapsed));
+	}
+
+	// Skip the first measurements to warm up the caches and the branch predictor.
+	let measurements = &measurements[samples / 10..];
+
+	let mut sorted = measurements
+		.iter()
+		.map(|&(_, elapsed)| elapsed)
+		.collect::<Vec<_>>();
+	sorted.sort_by(f64::total_cmp);
+	let thresholds = (0..NUMBER_PERCENTILES)
+		.map(|i| {
+			let percentile = 1.0 - 0.5f64.powf(10.0 * (i + 1) as f64 / NUMBER_PERCENTILES as f64);
+			sorted[((sorted.len() - 1) as f64 * percentile) as usize]
+		})
+		.collect::<Vec<_>>();
+
+	let mut uncropped = WelchTest::default();
+	let mut cropped = vec![WelchTest::default(); NUMBER_PERCENTILES];
+	for &(class, elapsed) in measurements {
+		uncropped.push(class, elapsed);
+		for (test, &threshold) in cropped.iter_mut().zip(&thresholds) {
+			if elapsed < threshold {
+				test.push(class, elapsed);
+			}
+		}
+	}
+
+	std::iter::once(&uncropped)
+		.chain(&cropped)
+		.map(|test| test.t_statistic().abs())
+		.fold(0.0, f64::max)
+}
+
+fn check_constant_time<P: PackedField>() {
+	let mul_t = max_t_statistic::<P>(|lhs, rhs| lhs * rhs);
+	assert!(
+		mul_t < T_THRESHOLD,
+		"multiplication of {} is not constant time: |t| = {mul_t:.2}",
+		type_name::<P>()
+	);
+
+	let invert_t = max_t_statistic::<P>(|lhs, _| lhs.invert_or_zero());
+	assert!(
+		invert_t < T_THRESHOLD,
+		"inversion of {} is not constant time: |t| = {invert_t:.2}",
+		type_name::<P>()
+	);
+}
+
+macro_rules! define_constant_time_tests {
+	($($test_name:ident => [$($packed_field:ty),* $(,)?]),* $(,)?) => {
+		$(
+			#[test]
+			#[ignore = "statistical timing test, run explicitly in release mode"]
+			fn $test_name() {
+				$(
+					check_constant_time::<$packed_field>();
+				)*
+			}
+		)*
+	};
+}
+
+define_constant_time_tests! {
+	test_constant_time_u1 => [PackedBinaryField1x1b],
+	test_constant_time_u2 => [PackedBinaryField2x1b, PackedBinaryField1x2b],
+	test_constant_time_u4 => [PackedBinaryField4x1b, PackedBinaryField2x2b, PackedBinaryField1x4b],
+	test_constant_time_u8 => [
+		PackedBinaryField8x1b,
+		PackedBinaryField4x2b,
+		PackedBinaryField2x4b,
+		PackedBinaryField1x8b,
+		PackedAESBinaryField1x8b,
+	],
+	test_constant_time_u16 => [
+		PackedBinaryField16x1b,
+		PackedBinaryField8x2b,
+		PackedBinaryField4x4b,
+		PackedBinaryField2x8b,
+		PackedBinaryField1x16b,
+		PackedAESBinaryField2x8b,
+		PackedAESBinaryField1x16b,
+	],
+	test_constant_time_u32 => [
+		PackedBinaryField32x1b,
+		PackedBinaryField16x2b,
+		PackedBinaryField8x4b,
+		PackedBinaryField4x8b,
+		PackedBinaryField2x16b,
+		PackedBinaryField1x32b,
+		PackedAESBinaryField4x8b,
+		PackedAESBinaryField2x16b,
+		PackedAESBinaryField1x32b,
+	],
+	test_constant_time_u64 => [
+		PackedBinaryField64x1b,
+		PackedBinaryField32x2b,
+		PackedBinaryField16x4b,
+		PackedBinaryField8x8b,
+		PackedBinaryField4x16b,
+		PackedBinaryField2x32b,
+		PackedBinaryField1x64b,
+		PackedAESBinaryField8x8b,
+		PackedAESBinaryField4x16b,
+		PackedAESBinaryField2x32b,
+		PackedAESBinaryField1x64b,
+	],
+	test_constant_time_m128 => [
+		PackedBinaryField128x1b,
+		PackedBinaryField64x2b,
+		PackedBinaryField32x4b,
+		PackedBinaryField16x8b,
+		PackedBinaryField8x16b,
+		PackedBinaryField4x32b,
+		PackedBinaryField2x64b,
+		PackedBinaryField1x128b,
+		PackedAESBinaryField16x8b,
+		PackedAESBinaryField8x16b,
+		PackedAESBinaryField4x32b,
+		PackedAESBinaryField2x64b,
+		PackedAESBinaryField1x128b,
+		PackedBinaryPolyval1x128b,
+	],
+	test_constant_time_m256 => [
+		PackedBinaryField256x1b,
+		PackedBinaryField128x2b,
+		PackedBinaryField64x4b,
+		PackedBinaryField32x8b,
+		PackedBinaryField16x16b,
+		PackedBinaryField8x32b,
+		PackedBinaryField4x64b,
+		PackedBinaryField2x128b,
+		PackedAESBinaryField32x8b,
+		PackedAESBinaryField16x16b,
+		PackedAESBinaryField8x32b,
+		PackedAESBinaryField4x64b,
+		PackedAESBinaryField2x128b,
+		PackedBinaryPolyval2x128b,
+	],
+	test_constant_time_m512 => [
+		PackedBinaryField512x1b,
+		PackedBinaryField256x2b,
+		PackedBinaryField128x4b,
+		PackedBinaryField64x8b,
+		PackedBinaryField32x16b,
+		PackedBinaryField16x32b,
+		PackedBinaryField8x64b,
+		PackedBinaryField4x128b,
+		PackedAESBinaryField64x8b,
+		PackedAESBinaryField32x16b,
+		PackedAESBinaryField16x32b,
+		PackedAESBinaryField8x64b,
+		PackedAESBinaryField4x128b,
+		PackedBinaryPolyval4x128b,
+	],
+	test_constant_time_byte_sliced => [
+		ByteSlicedAES16x16x8b,
+		ByteSlicedAES16x128b,
+		ByteSlicedAES32x16x8b,
+		ByteSlicedAES32x128b,
+		ByteSlicedAES64x16x8b,
+		ByteSlicedAES64x128b,
+	],
+}
@@ -2,17 +2,29 @@
 
 use std::{
 	fmt::Debug,
+	mem::MaybeUninit,
 	ops::{Deref, DerefMut},
+	slice,
 };
 
-use binius_field::{ExtensionField, Field, PackedExtension, PackedField};
+use binius_field::{BinaryField, ExtensionField, Field, PackedExtension, PackedField, TowerField};
+use binius_hash::PseudoCompressionFunction;
 use binius_math::{
 	CompositionPolyOS, MultilinearExtension, MultilinearPoly, MultilinearQuery, MultilinearQueryRef,
 };
-use binius_maybe_rayon::iter::FromParallelIterator;
+use binius_maybe_rayon::{iter::FromParallelIterator, prelude::*};
+use binius_ntt::AdditiveNTT;
+use binius_utils::bail;
+use bytemuck::zeroed_vec;
+use digest::{core_api::BlockSizeUser, Digest, FixedOutputReset, Output};
 use tracing::instrument;
 
-use crate::{Error, RoundEvals, SumcheckEvaluator, SumcheckMultilinear};
+use crate::{
+	fri::{fold_chunk, fold_interleaved_chunk},
+	merkle::{compress_layer, hash_iterated},
+	sumcheck_round_calculator::ProductEvaluator,
+	Error, RoundEvals, ScratchStats, SumcheckEvaluator, SumcheckMultilinear,
+};
 
 /// HAL-managed memory containing the result of its operations.
 pub trait HalSlice<P: Debug + Send + Sync>:
@@ -30,12 +42,42 @@ pub trait HalSlice<P: Debug + Send + Sync>:
 impl<P: Send + Sync + Debug + 'static> HalSlice<P> for Vec<P> {}
 
 /// An abstraction to interface with acceleration hardware to perform computation intensive operations.
+///
+/// Besides the required sumcheck primitives, the trait covers the rest of the prover data path:
+/// multilinear folding, Reed–Solomon encoding, FRI folding and Merkle tree hashing. These methods
+/// have default implementations running on the CPU, so that a backend only needs to override the
+/// operations it accelerates.
+///
+/// Backends may keep a pool of scratch buffers, see [`crate::ScratchArena`]. Buffers obtained with
+/// [`Self::alloc_scratch`] or returned by the backend operations should be handed back with
+/// [`Self::release_scratch`] and [`Self::release_hal_slice`] once they are no longer needed, so
+/// that the following operations can reuse them.
 pub trait ComputationBackend: Send + Sync + Debug {
 	type Vec<P: Send + Sync + Debug + 'static>: HalSlice<P>;
 
 	/// Creates `Self::Vec<P>` from the given `Vec<P>`.
 	fn to_hal_slice<P: Debug + Send + Sync>(v: Vec<P>) -> Self::Vec<P>;
 
+	/// Returns a zero-initialized host buffer of `len` packed elements.
+	fn alloc_scratch<P: PackedField>(&self, len: usize) -> Vec<P> {
+		zeroed_vec(len)
+	}
+
+	/// Returns a buffer obtained from [`Self::alloc_scratch`] to the backend for reuse.
+	fn release_scratch<P: PackedField>(&self, buffer: Vec<P>) {
+		drop(buffer)
+	}
+
+	/// Returns a buffer created by the backend operations to the backend for reuse.
+	fn release_hal_slice<P: PackedField>(&self, slice: Self::Vec<P>) {
+		drop(slice)
+	}
+
+	/// Returns the counters of the scratch buffer requests served by the backend.
+	fn scratch_stats(&self) -> ScratchStats {
+		ScratchStats::default()
+	}
+
 	/// Computes tensor product expansion.
 	fn tensor_product_full_query<P: PackedField>(
 		&self,
@@ -58,12 +100,344 @@ pub trait ComputationBackend: Send + Sync + Debug {
 		Evaluator: SumcheckEvaluator<P, Composition> + Sync,
 		Composition: CompositionPolyOS<P>;
 
+	/// Calculate the accumulated evaluations for an arbitrary sumcheck round over sums of
+	/// products of multilinears.
+	///
+	/// Every entry of `products` lists the indices of the multilinears in the product. The round
+	/// polynomial of a product of degree $d$ is evaluated at `evaluation_points[1..=d]`.
+	///
+	/// The default implementation evaluates the products as compositions with
+	/// [`Self::sumcheck_compute_round_evals`].
+	fn sumcheck_compute_product_round_evals<FDomain, P, M>(
+		&self,
+		n_vars: usize,
+		tensor_query: Option<MultilinearQueryRef<P>>,
+		multilinears: &[SumcheckMultilinear<P, M>],
+		products: &[Vec<usize>],
+		evaluation_points: &[FDomain],
+	) -> Result<Vec<RoundEvals<P::Scalar>>, Error>
+	where
+		FDomain: Field,
+		P: PackedField<Scalar: ExtensionField<FDomain>> + PackedExtension<FDomain>,
+		M: MultilinearPoly<P> + Send + Sync,
+	{
+		let evaluators =
+			ProductEvaluator::new_batch(multilinears.len(), products, evaluation_points.len())?;
+		self.sumcheck_compute_round_evals(
+			n_vars,
+			tensor_query,
+			multilinears,
+			&evaluators,
+			evaluation_points,
+		)
+	}
+
 	/// Partially evaluate the polynomial with assignment to the high-indexed variables.
+	///
+	/// Multilinears with their evaluations available in memory are folded with
+	/// [`Self::fold_left`], others are evaluated by the polynomial itself.
+	#[instrument(skip_all, name = "ComputationBackend::evaluate_partial_high")]
 	fn evaluate_partial_high<P: PackedField>(
 		&self,
 		multilinear: &impl MultilinearPoly<P>,
 		query_expansion: MultilinearQueryRef<P>,
-	) -> Result<MultilinearExtension<P>, Error>;
+	) -> Result<MultilinearExtension<P>, Error> {
+		let n_vars = multilinear.n_vars();
+		match multilinear.packed_evals() {
+			Some(evals) if multilinear.log_extension_degree() == 0 => {
+				let new_n_vars = n_vars.saturating_sub(query_expansion.n_vars());
+				let result_len = 1 << new_n_vars.saturating_sub(P::LOG_WIDTH);
+				let mut result = self.alloc_scratch::<P>(result_len);
+				// SAFETY: `MaybeUninit<P>` has the same layout as `P`, and `fold_left` only writes
+				// initialized values into the output.
+				let out = unsafe {
+					slice::from_raw_parts_mut(
+						result.as_mut_ptr() as *mut MaybeUninit<P>,
+						result.len(),
+					)
+				};
+				self.fold_left(
+					evals,
+					n_vars,
+					query_expansion.expansion(),
+					query_expansion.n_vars(),
+					out,
+				)?;
+				Ok(MultilinearExtension::new(new_n_vars, result)?)
+			}
+			_ => Ok(multilinear.evaluate_partial_high(query_expansion)?),
+		}
+	}
+
+	/// Partially evaluate the polynomial with assignment to the low-indexed variables.
+	///
+	/// Multilinears with their evaluations available in memory are folded with
+	/// [`Self::fold_right`], others are evaluated by the polynomial itself.
+	fn evaluate_partial_low<P: PackedField>(
+		&self,
+		multilinear: &impl MultilinearPoly<P>,
+		query_expansion: MultilinearQueryRef<P>,
+	) -> Result<MultilinearExtension<P>, Error> {
+		let n_vars = multilinear.n_vars();
+		match multilinear.packed_evals() {
+			Some(evals) if multilinear.log_extension_degree() == 0 => {
+				if n_vars < query_expansion.n_vars() {
+					bail!(Error::IncorrectQuerySize { expected: n_vars });
+				}
+
+				let new_n_vars = n_vars - query_expansion.n_vars();
+				let mut result = self.alloc_scratch(1 << new_n_vars.saturating_sub(P::LOG_WIDTH));
+				self.fold_right(
+					evals,
+					n_vars,
+					query_expansion.expansion(),
+					query_expansion.n_vars(),
+					&mut result,
+				)?;
+				Ok(MultilinearExtension::new(new_n_vars, result)?)
+			}
+			_ => Ok(multilinear.evaluate_partial_low(query_expansion)?),
+		}
+	}
+
+	/// Executes the right fold operation, see [`binius_math::fold_right`].
+	fn fold_right<P, PE>(
+		&self,
+		evals: &[P],
+		log_evals_size: usize,
+		query: &[PE],
+		log_query_size: usize,
+		out: &mut [PE],
+	) -> Result<(), Error>
+	where
+		P: PackedField,
+		PE: PackedField<Scalar: ExtensionField<P::Scalar>>,
+	{
+		Ok(binius_math::fold_right(evals, log_evals_size, query, log_query_size, out)?)
+	}
+
+	/// Executes the left fold operation, see [`binius_math::fold_left`].
+	///
+	/// If the method returns `Ok(())`, then `out` can be safely interpreted as initialized.
+	fn fold_left<P, PE>(
+		&self,
+		evals: &[P],
+		log_evals_size: usize,
+		query: &[PE],
+		log_query_size: usize,
+		out: &mut [MaybeUninit<PE>],
+	) -> Result<(), Error>
+	where
+		P: PackedField,
+		PE: PackedField<Scalar: ExtensionField<P::Scalar>>,
+	{
+		Ok(binius_math::fold_left(evals, log_evals_size, query, log_query_size, out)?)
+	}
+
+	/// Encodes a batch of interleaved messages in-place with the Reed–Solomon code whose
+	/// evaluation domain is given by `ntt`.
+	///
+	/// The first `2^(log_dim + log_batch_size)` scalars of `code` hold the interleaved messages.
+	/// When the method completes, `code` holds the `2^log_inv_rate` cosets of the interleaved
+	/// codeword. The cosets are encoded in parallel only if `multithreaded` is set.
+	///
+	/// ## Preconditions
+	///
+	/// * `code` must have at least `2^(log_dim + log_inv_rate + log_batch_size)` scalars.
+	/// * `2^log_dim` must be a multiple of `P::WIDTH`.
+	fn reed_solomon_encode_batch_inplace<P, NTT>(
+		&self,
+		ntt: &NTT,
+		code: &mut [P],
+		log_dim: usize,
+		log_inv_rate: usize,
+		log_batch_size: usize,
+		multithreaded: bool,
+	) -> Result<(), Error>
+	where
+		P: PackedField<Scalar: BinaryField>,
+		NTT: AdditiveNTT<P> + Sync,
+	{
+		let msgs_len = (1 << (log_dim - P::LOG_WIDTH)) << log_batch_size;
+		for i in 1..(1 << log_inv_rate) {
+			code.copy_within(0..msgs_len, i * msgs_len);
+		}
+
+		if multithreaded {
+			(0..(1 << log_inv_rate))
+				.into_par_iter()
+				.zip(code.par_chunks_exact_mut(msgs_len))
+				.try_for_each(|(i, data)| ntt.forward_transform(data, i, log_batch_size))?;
+		} else {
+			(0..(1 << log_inv_rate))
+				.zip(code.chunks_exact_mut(msgs_len))
+				.try_for_each(|(i, data)| ntt.forward_transform(data, i, log_batch_size))?;
+		}
+		Ok(())
+	}
+
+	/// Folds a FRI codeword with the given folding challenges.
+	///
+	/// Every chunk of `2^folding_challenges.len()` consecutive symbols of the codeword is folded
+	/// into a single symbol, starting at the FRI fold round `start_round`.
+	///
+	/// ## Preconditions
+	///
+	/// * `folding_challenges` must not be empty.
+	/// * `codeword.len()` must be a multiple of `2^folding_challenges.len()`.
+	fn fri_fold_codeword<F, FS, NTT>(
+		&self,
+		ntt: &NTT,
+		codeword: &[F],
+		start_round: usize,
+		folding_challenges: &[F],
+	) -> Result<Vec<F>, Error>
+	where
+		F: BinaryField + ExtensionField<FS>,
+		FS: BinaryField,
+		NTT: AdditiveNTT<FS> + Sync,
+	{
+		let chunk_size = 1 << folding_challenges.len();
+		let folded = codeword
+			.par_chunks(chunk_size)
+			.enumerate()
+			.map_init(
+				|| vec![F::default(); chunk_size],
+				|scratch_buffer, (chunk_index, chunk)| {
+					fold_chunk(
+						ntt,
+						start_round,
+						chunk_index,
+						chunk,
+						folding_challenges,
+						scratch_buffer,
+					)
+				},
+			)
+			.collect();
+		Ok(folded)
+	}
+
+	/// Folds an interleaved FRI codeword into a single codeword.
+	///
+	/// The first `log_batch_size` challenges mix the interleaved codewords and the remaining ones
+	/// are used as FRI folding challenges, starting at the first fold round.
+	///
+	/// ## Preconditions
+	///
+	/// * `challenges.len()` must be at least `log_batch_size`.
+	/// * `codeword.len()` must be a multiple of `2^challenges.len()`.
+	fn fri_fold_interleaved<F, FS, NTT>(
+		&self,
+		ntt: &NTT,
+		codeword: &[F],
+		log_batch_size: usize,
+		challenges: &[F],
+	) -> Result<Vec<F>, Error>
+	where
+		F: BinaryField + ExtensionField<FS>,
+		FS: BinaryField,
+		NTT: AdditiveNTT<FS> + Sync,
+	{
+		let (interleave_challenges, fold_challenges) = challenges.split_at(log_batch_size);
+		let tensor = self.tensor_product_full_query::<F>(interleave_challenges)?;
+
+		let fold_chunk_size = 1 << fold_challenges.len();
+		let chunk_size = fold_chunk_size << log_batch_size;
+		let folded = codeword
+			.par_chunks(chunk_size)
+			.enumerate()
+			.map_init(
+				|| vec![F::default(); 2 * fold_chunk_size],
+				|scratch_buffer, (chunk_index, chunk)| {
+					fold_interleaved_chunk(
+						ntt,
+						log_batch_size,
+						chunk_index,
+						chunk,
+						&tensor,
+						fold_challenges,
+						scratch_buffer,
+					)
+				},
+			)
+			.collect();
+		Ok(folded)
+	}
+
+	/// Hashes the elements into the leaf digests of a Merkle tree.
+	///
+	/// The elements are split into as many consecutive batches of equal size as there are
+	/// digests, and each batch is hashed into the corresponding digest. If the method returns
+	/// `Ok(())`, then `digests` can be safely interpreted as initialized.
+	fn merkle_hash_leaves<F, H>(
+		&self,
+		elements: &[F],
+		digests: &mut [MaybeUninit<Output<H>>],
+	) -> Result<(), Error>
+	where
+		F: TowerField,
+		H: Digest + BlockSizeUser + FixedOutputReset,
+	{
+		if digests.is_empty() || elements.len() % digests.len() != 0 {
+			bail!(Error::IncorrectLeafBatchSize {
+				n_digests: digests.len(),
+			});
+		}
+
+		let batch_size = elements.len() / digests.len();
+		self.merkle_hash_iterated_leaves::<F, H, _>(
+			elements
+				.par_chunks(batch_size)
+				.map(|chunk| chunk.iter().copied()),
+			digests,
+		)
+	}
+
+	/// Hashes the chunks of elements yielded by a parallel iterator into the leaf digests of a
+	/// Merkle tree.
+	///
+	/// Each item of the iterator is hashed into the digest with the same index. If the method
+	/// returns `Ok(())`, then `digests` can be safely interpreted as initialized.
+	fn merkle_hash_iterated_leaves<F, H, ParIter>(
+		&self,
+		iterated_chunks: ParIter,
+		digests: &mut [MaybeUninit<Output<H>>],
+	) -> Result<(), Error>
+	where
+		F: TowerField,
+		H: Digest + BlockSizeUser + FixedOutputReset,
+		ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
+	{
+		if iterated_chunks.len() != digests.len() {
+			bail!(Error::IncorrectLeafCount {
+				expected: digests.len(),
+			});
+		}
+
+		hash_iterated::<F, H, _>(iterated_chunks, digests);
+		Ok(())
+	}
+
+	/// Compresses the pairs of adjacent digests of a Merkle tree layer into the digests of the
+	/// next layer.
+	///
+	/// ## Preconditions
+	///
+	/// * `prev_layer.len()` must be twice `next_layer.len()`.
+	fn merkle_compress_layer<D, C>(
+		&self,
+		compression: &C,
+		prev_layer: &[D],
+		next_layer: &mut [MaybeUninit<D>],
+	) -> Result<(), Error>
+	where
+		D: Clone + Send + Sync,
+		C: PseudoCompressionFunction<D, 2> + Sync,
+	{
+		compress_layer(compression, prev_layer, next_layer);
+		Ok(())
+	}
 }
 
 /// Makes it unnecessary to clone backends.
@@ -78,6 +452,22 @@ where
 		T::to_hal_slice(v)
 	}
 
+	fn alloc_scratch<P: PackedField>(&self, len: usize) -> Vec<P> {
+		T::alloc_scratch(self, len)
+	}
+
+	fn release_scratch<P: PackedField>(&self, buffer: Vec<P>) {
+		T::release_scratch(self, buffer)
+	}
+
+	fn release_hal_slice<P: PackedField>(&self, slice: Self::Vec<P>) {
+		T::release_hal_slice(self, slice)
+	}
+
+	fn scratch_stats(&self) -> ScratchStats {
+		T::scratch_stats(self)
+	}
+
 	fn tensor_product_full_query<P: PackedField>(
 		&self,
 		query: &[P::Scalar],
@@ -110,6 +500,29 @@ where
 		)
 	}
 
+	fn sumcheck_compute_product_round_evals<FDomain, P, M>(
+		&self,
+		n_vars: usize,
+		tensor_query: Option<MultilinearQueryRef<P>>,
+		multilinears: &[SumcheckMultilinear<P, M>],
+		products: &[Vec<usize>],
+		evaluation_points: &[FDomain],
+	) -> Result<Vec<RoundEvals<P::Scalar>>, Error>
+	where
+		FDomain: Field,
+		P: PackedField<Scalar: ExtensionField<FDomain>> + PackedExtension<FDomain>,
+		M: MultilinearPoly<P> + Send + Sync,
+	{
+		T::sumcheck_compute_product_round_evals(
+			self,
+			n_vars,
+			tensor_query,
+			multilinears,
+			products,
+			evaluation_points,
+		)
+	}
+
 	fn evaluate_partial_high<P: PackedField>(
 		&self,
 		multilinear: &impl MultilinearPoly<P>,
@@ -117,6 +530,136 @@ where
 	) -> Result<MultilinearExtension<P>, Error> {
 		T::evaluate_partial_high(self, multilinear, query_expansion)
 	}
+
+	fn evaluate_partial_low<P: PackedField>(
+		&self,
+		multilinear: &impl MultilinearPoly<P>,
+		query_expansion: MultilinearQueryRef<P>,
+	) -> Result<MultilinearExtension<P>, Error> {
+		T::evaluate_partial_low(self, multilinear, query_expansion)
+	}
+
+	fn fold_right<P, PE>(
+		&self,
+		evals: &[P],
+		log_evals_size: usize,
+		query: &[PE],
+		log_query_size: usize,
+		out: &mut [PE],
+	) -> Result<(), Error>
+	where
+		P: PackedField,
+		PE: PackedField<Scalar: ExtensionField<P::Scalar>>,
+	{
+		T::fold_right(self, evals, log_evals_size, query, log_query_size, out)
+	}
+
+	fn fold_left<P, PE>(
+		&self,
+		evals: &[P],
+		log_evals_size: usize,
+		query: &[PE],
+		log_query_size: usize,
+		out: &mut [MaybeUninit<PE>],
+	) -> Result<(), Error>
+	where
+		P: PackedField,
+		PE: PackedField<Scalar: ExtensionField<P::Scalar>>,
+	{
+		T::fold_left(self, evals, log_evals_size, query, log_query_size, out)
+	}
+
+	fn reed_solomon_encode_batch_inplace<P, NTT>(
+		&self,
+		ntt: &NTT,
+		code: &mut [P],
+		log_dim: usize,
+		log_inv_rate: usize,
+		log_batch_size: usize,
+		multithreaded: bool,
+	) -> Result<(), Error>
+	where
+		P: PackedField<Scalar: BinaryField>,
+		NTT: AdditiveNTT<P> + Sync,
+	{
+		T::reed_solomon_encode_batch_inplace(
+			self,
+			ntt,
+			code,
+			log_dim,
+			log_inv_rate,
+			log_batch_size,
+			multithreaded,
+		)
+	}
+
+	fn fri_fold_codeword<F, FS, NTT>(
+		&self,
+		ntt: &NTT,
+		codeword: &[F],
+		start_round: usize,
+		folding_challenges: &[F],
+	) -> Result<Vec<F>, Error>
+	where
+		F: BinaryField + ExtensionField<FS>,
+		FS: BinaryField,
+		NTT: AdditiveNTT<FS> + Sync,
+	{
+		T::fri_fold_codeword(self, ntt, codeword, start_round, folding_challenges)
+	}
+
+	fn fri_fold_interleaved<F, FS, NTT>(
+		&self,
+		ntt: &NTT,
+		codeword: &[F],
+		log_batch_size: usize,
+		challenges: &[F],
+	) -> Result<Vec<F>, Error>
+	where
+		F: BinaryField + ExtensionField<FS>,
+		FS: BinaryField,
+		NTT: AdditiveNTT<FS> + Sync,
+	{
+		T::fri_fold_interleaved(self, ntt, codeword, log_batch_size, challenges)
+	}
+
+	fn merkle_hash_leaves<F, H>(
+		&self,
+		elements: &[F],
+		digests: &mut [MaybeUninit<Output<H>>],
+	) -> Result<(), Error>
+	where
+		F: TowerField,
+		H: Digest + BlockSizeUser + FixedOutputReset,
+	{
+		T::merkle_hash_leaves::<F, H>(self, elements, digests)
+	}
+
+	fn merkle_hash_iterated_leaves<F, H, ParIter>(
+		&self,
+		iterated_chunks: ParIter,
+		digests: &mut [MaybeUninit<Output<H>>],
+	) -> Result<(), Error>
+	where
+		F: TowerField,
+		H: Digest + BlockSizeUser + FixedOutputReset,
+		ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
+	{
+		T::merkle_hash_iterated_leaves::<F, H, _>(self, iterated_chunks, digests)
+	}
+
+	fn merkle_compress_layer<D, C>(
+		&self,
+		compression: &C,
+		prev_layer: &[D],
+		next_layer: &mut [MaybeUninit<D>],
+	) -> Result<(), Error>
+	where
+		D: Clone + Send + Sync,
+		C: PseudoCompressionFunction<D, 2> + Sync,
+	{
+		T::merkle_compress_layer(self, compression, prev_layer, next_layer)
+	}
 }
 
 pub trait ComputationBackendExt: ComputationBackend {
@@ -3,23 +3,53 @@
 use std::fmt::Debug;
 
 use binius_field::{ExtensionField, Field, PackedExtension, PackedField};
-use binius_math::{
-	eq_ind_partial_eval, CompositionPolyOS, MultilinearExtension, MultilinearPoly,
-	MultilinearQueryRef,
-};
+use binius_math::{tensor_prod_eq_ind, CompositionPolyOS, MultilinearPoly, MultilinearQueryRef};
 use tracing::instrument;
 
 use crate::{
-	sumcheck_round_calculator::calculate_round_evals, ComputationBackend, Error, RoundEvals,
-	SumcheckEvaluator, SumcheckMultilinear,
+	sumcheck_round_calculator::{
+		calculate_product_round_evals_partitioned, calculate_round_evals, SinglePartition,
+		DEFAULT_MAX_SUBCUBE_VARS,
+	},
+	ComputationBackend, Error, RoundEvals, ScratchArena, ScratchStats, SumcheckEvaluator,
+	SumcheckMultilinear,
 };
 
 /// Implementation of ComputationBackend for the default Backend that uses the CPU for all computations.
-#[derive(Clone, Debug)]
-pub struct CpuBackend;
+///
+/// Scratch buffers released to the backend are pooled in a [`ScratchArena`] owned by the backend
+/// and reused by later allocations. Backends don't share their arenas, so the [`ScratchStats`] of
+/// a backend count only the buffers requested through it; a clone starts with an empty arena.
+#[derive(Debug, Default)]
+pub struct CpuBackend {
+	arena: ScratchArena,
+}
 
 pub const fn make_portable_backend() -> CpuBackend {
-	CpuBackend
+	CpuBackend {
+		arena: ScratchArena::new(),
+	}
+}
+
+impl CpuBackend {
+	/// Creates a backend whose arena retains at most `max_bytes` bytes of released buffers.
+	pub const fn with_scratch_max_bytes(max_bytes: usize) -> Self {
+		Self {
+			arena: ScratchArena::with_max_bytes(max_bytes),
+		}
+	}
+
+	/// Returns the scratch buffer pool of the backend, e.g. to [`trim`](ScratchArena::trim) it
+	/// between proofs.
+	pub const fn scratch_arena(&self) -> &ScratchArena {
+		&self.arena
+	}
+}
+
+impl Clone for CpuBackend {
+	fn clone(&self) -> Self {
+		Self::with_scratch_max_bytes(self.arena.max_bytes())
+	}
 }
 
 impl ComputationBackend for CpuBackend {
@@ -29,12 +59,31 @@ impl ComputationBackend for CpuBackend {
 		v
 	}
 
+	fn alloc_scratch<P: PackedField>(&self, len: usize) -> Vec<P> {
+		self.arena.allocate_zeroed(len)
+	}
+
+	fn release_scratch<P: PackedField>(&self, buffer: Vec<P>) {
+		self.arena.release(buffer)
+	}
+
+	fn release_hal_slice<P: PackedField>(&self, slice: Self::Vec<P>) {
+		self.arena.release(slice)
+	}
+
+	fn scratch_stats(&self) -> ScratchStats {
+		self.arena.stats()
+	}
+
 	#[instrument(skip_all, level = "trace")]
 	fn tensor_product_full_query<P: PackedField>(
 		&self,
 		query: &[P::Scalar],
 	) -> Result<Self::Vec<P>, Error> {
-		Ok(eq_ind_partial_eval(query))
+		let mut buffer = self.alloc_scratch::<P>(1 << query.len().saturating_sub(P::LOG_WIDTH));
+		buffer[0].set(0, P::Scalar::ONE);
+		tensor_prod_eq_ind(0, &mut buffer, query)?;
+		Ok(buffer)
 	}
 
 	fn sumcheck_compute_round_evals<FDomain, P, M, Evaluator, Composition>(
@@ -55,12 +104,27 @@ impl ComputationBackend for CpuBackend {
 		calculate_round_evals(n_vars, tensor_query, multilinears, evaluators, evaluation_points)
 	}
 
-	#[instrument(skip_all, name = "CpuBackend::evaluate_partial_high")]
-	fn evaluate_partial_high<P: PackedField>(
+	fn sumcheck_compute_product_round_evals<FDomain, P, M>(
 		&self,
-		multilinear: &impl MultilinearPoly<P>,
-		query_expansion: MultilinearQueryRef<P>,
-	) -> Result<MultilinearExtension<P>, Error> {
-		Ok(multilinear.evaluate_partial_high(query_expansion)?)
+		n_vars: usize,
+		tensor_query: Option<MultilinearQueryRef<P>>,
+		multilinears: &[SumcheckMultilinear<P, M>],
+		products: &[Vec<usize>],
+		evaluation_points: &[FDomain],
+	) -> Result<Vec<RoundEvals<P::Scalar>>, Error>
+	where
+		FDomain: Field,
+		P: PackedField<Scalar: ExtensionField<FDomain>> + PackedExtension<FDomain>,
+		M: MultilinearPoly<P> + Send + Sync,
+	{
+		calculate_product_round_evals_partitioned(
+			n_vars,
+			tensor_query,
+			multilinears,
+			products,
+			evaluation_points,
+			DEFAULT_MAX_SUBCUBE_VARS,
+			&SinglePartition,
+		)
 	}
 }
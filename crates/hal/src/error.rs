@@ -11,6 +11,19 @@ pub enum Error {
 	MathError(#[from] binius_math::Error),
 	#[error("the query must have size {expected}")]
 	IncorrectQuerySize { expected: usize },
+	#[error(
+		"the number of elements must be a multiple of the number of digests, which is {n_digests}"
+	)]
+	IncorrectLeafBatchSize { n_digests: usize },
+	#[error("the number of leaves must equal the number of digests, which is {expected}")]
+	IncorrectLeafCount { expected: usize },
+	#[error(
+		"every product must be non-empty, refer to existing multilinears and have fewer factors \
+		 than evaluation points"
+	)]
+	IncorrectProductIndices,
 	#[error("{0}")]
 	FieldError(#[from] binius_field::Error),
+	#[error("{0}")]
+	NttError(#[from] binius_ntt::Error),
 }
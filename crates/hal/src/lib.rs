@@ -6,11 +6,17 @@
 //! The HAL is consumed by the `binius_core` crate. The interfaces are currently designed around
 //! the architecture of [Irreducible's](https://www.irreducible.com) custom FPGA platform. The
 //! crate exposes a default, portable CPU backend that can be created with
-//! [`crate::make_portable_backend`].
+//! [`crate::make_portable_backend`], and a NUMA-aware CPU backend for multi-socket servers that
+//! can be created with [`crate::make_numa_cpu_backend`].
 
 mod backend;
 mod cpu;
 mod error;
+pub mod fri;
+pub mod merkle;
+pub mod numa;
+mod numa_cpu;
+mod scratch;
 mod sumcheck_evaluator;
 mod sumcheck_multilinear;
 mod sumcheck_round_calculator;
@@ -18,5 +24,7 @@ mod sumcheck_round_calculator;
 pub use backend::*;
 pub use cpu::*;
 pub use error::*;
+pub use numa_cpu::*;
+pub use scratch::*;
 pub use sumcheck_evaluator::*;
 pub use sumcheck_multilinear::*;
@@ -0,0 +1,54 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+//! Hashing operations for building binary Merkle trees.
+//!
+//! These are shared by the default implementations of the Merkle tree methods of
+//! [`crate::ComputationBackend`] and the Merkle tree prover.
+
+use std::{array, mem::MaybeUninit};
+
+use binius_field::{serialize_canonical, TowerField};
+use binius_hash::{HashBuffer, PseudoCompressionFunction};
+use binius_maybe_rayon::prelude::*;
+use digest::{core_api::BlockSizeUser, Digest, FixedOutputReset, Output};
+
+/// Hashes the chunks of elements yielded by a parallel iterator into digests.
+///
+/// Each item of the iterator is hashed into the digest with the same index.
+pub fn hash_iterated<F, H, ParIter>(
+	iterated_chunks: ParIter,
+	digests: &mut [MaybeUninit<Output<H>>],
+) where
+	F: TowerField,
+	H: Digest + BlockSizeUser + FixedOutputReset,
+	ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
+{
+	digests
+		.par_iter_mut()
+		.zip(iterated_chunks)
+		.for_each_init(H::new, |hasher, (digest, elems)| {
+			{
+				let mut hash_buffer = HashBuffer::new(hasher);
+				for elem in elems {
+					serialize_canonical(elem, &mut hash_buffer)
+						.expect("HashBuffer has infinite capacity");
+				}
+			}
+			digest.write(Digest::finalize_reset(hasher));
+		});
+}
+
+/// Compresses the pairs of adjacent digests of a Merkle tree layer into the digests of the next
+/// layer.
+pub fn compress_layer<D, C>(compression: &C, prev_layer: &[D], next_layer: &mut [MaybeUninit<D>])
+where
+	D: Clone + Send + Sync,
+	C: PseudoCompressionFunction<D, 2> + Sync,
+{
+	prev_layer
+		.par_chunks_exact(2)
+		.zip(next_layer.par_iter_mut())
+		.for_each(|(prev_pair, next_digest)| {
+			next_digest.write(compression.compress(array::from_fn(|i| prev_pair[i].clone())));
+		})
+}
@@ -0,0 +1,187 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Discovery of the NUMA topology of the host and pinning of threads to NUMA nodes.
+
+use std::{ops::Range, thread::available_parallelism};
+
+/// A NUMA node, given by the logical CPUs that are local to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumaNode {
+	id: usize,
+	cpus: Vec<usize>,
+}
+
+impl NumaNode {
+	pub const fn new(id: usize, cpus: Vec<usize>) -> Self {
+		Self { id, cpus }
+	}
+
+	/// The operating system identifier of the node.
+	pub const fn id(&self) -> usize {
+		self.id
+	}
+
+	/// The logical CPUs of the node.
+	pub fn cpus(&self) -> &[usize] {
+		&self.cpus
+	}
+}
+
+/// The NUMA nodes of the host that have CPUs attached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumaTopology {
+	nodes: Vec<NumaNode>,
+}
+
+impl NumaTopology {
+	/// Creates a topology from the given nodes.
+	///
+	/// Nodes without CPUs are dropped. If no node remains, the topology falls back to a single
+	/// node with all the available CPUs.
+	pub fn new(nodes: impl IntoIterator<Item = NumaNode>) -> Self {
+		let nodes = nodes
+			.into_iter()
+			.filter(|node| !node.cpus.is_empty())
+			.collect::<Vec<_>>();
+		if nodes.is_empty() {
+			Self::single_node()
+		} else {
+			Self { nodes }
+		}
+	}
+
+	/// A topology with one node holding all the available CPUs.
+	pub fn single_node() -> Self {
+		let n_cpus = available_parallelism().map_or(1, usize::from);
+		Self {
+			nodes: vec![NumaNode::new(0, (0..n_cpus).collect())],
+		}
+	}
+
+	/// Detects the NUMA topology of the host.
+	///
+	/// On Linux the nodes are read from sysfs. On other platforms, or if sysfs is not available,
+	/// this returns [`Self::single_node`].
+	pub fn detect() -> Self {
+		#[cfg(target_os = "linux")]
+		if let Some(nodes) = linux::read_sysfs_nodes() {
+			return Self::new(nodes);
+		}
+
+		Self::single_node()
+	}
+
+	pub fn nodes(&self) -> &[NumaNode] {
+		&self.nodes
+	}
+
+	/// Splits `0..len` into contiguous ranges, one per node, proportionally to the number of CPUs
+	/// of each node.
+	pub fn partition(&self, len: usize) -> Vec<Range<usize>> {
+		let total_cpus = self.nodes.iter().map(|node| node.cpus.len()).sum::<usize>();
+
+		let mut cpus_before = 0;
+		self.nodes
+			.iter()
+			.map(|node| {
+				let start = len * cpus_before / total_cpus;
+				cpus_before += node.cpus.len();
+				let end = len * cpus_before / total_cpus;
+				start..end
+			})
+			.collect()
+	}
+}
+
+/// Parses a CPU list in the format used by the Linux kernel, e.g. `0-3,8,10-11`.
+pub fn parse_cpu_list(cpu_list: &str) -> Option<Vec<usize>> {
+	let mut cpus = Vec::new();
+	for part in cpu_list.trim().split(',').filter(|part| !part.is_empty()) {
+		match part.split_once('-') {
+			Some((first, last)) => {
+				let first = first.parse::<usize>().ok()?;
+				let last = last.parse::<usize>().ok()?;
+				cpus.extend(first..=last);
+			}
+			None => cpus.push(part.parse().ok()?),
+		}
+	}
+	Some(cpus)
+}
+
+/// Restricts the current thread to run on the given CPUs.
+///
+/// This is a best-effort operation: it does nothing on platforms that don't support thread
+/// affinity, and failures are ignored because they only affect performance.
+pub fn pin_current_thread(cpus: &[usize]) {
+	#[cfg(target_os = "linux")]
+	linux::set_affinity(cpus);
+
+	#[cfg(not(target_os = "linux"))]
+	let _ = cpus;
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+	use std::fs;
+
+	use super::{parse_cpu_list, NumaNode};
+
+	const SYSFS_NODE_PATH: &str = "/sys/devices/system/node";
+
+	pub fn read_sysfs_nodes() -> Option<Vec<NumaNode>> {
+		let mut nodes = fs::read_dir(SYSFS_NODE_PATH)
+			.ok()?
+			.filter_map(|entry| {
+				let entry = entry.ok()?;
+				let id = entry
+					.file_name()
+					.to_str()?
+					.strip_prefix("node")?
+					.parse::<usize>()
+					.ok()?;
+				let cpu_list = fs::read_to_string(entry.path().join("cpulist")).ok()?;
+				Some(NumaNode::new(id, parse_cpu_list(&cpu_list)?))
+			})
+			.collect::<Vec<_>>();
+		nodes.sort_by_key(NumaNode::id);
+		Some(nodes)
+	}
+
+	pub fn set_affinity(cpus: &[usize]) {
+		// SAFETY: `cpu_set_t` is a plain bitmask for which all zeroes is a valid value, and the
+		// CPU indices are checked against its capacity before being set.
+		unsafe {
+			let mut cpu_set = std::mem::zeroed::<libc::cpu_set_t>();
+			let max_cpus = 8 * std::mem::size_of::<libc::cpu_set_t>();
+			for &cpu in cpus.iter().filter(|&&cpu| cpu < max_cpus) {
+				libc::CPU_SET(cpu, &mut cpu_set);
+			}
+			libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &cpu_set);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_cpu_list() {
+		assert_eq!(parse_cpu_list("0-3,8,10-11\n"), Some(vec![0, 1, 2, 3, 8, 10, 11]));
+		assert_eq!(parse_cpu_list(""), Some(vec![]));
+		assert_eq!(parse_cpu_list("1-x"), None);
+	}
+
+	#[test]
+	fn test_partition_covers_range() {
+		let topology = NumaTopology::new([
+			NumaNode::new(0, vec![0, 1, 2]),
+			NumaNode::new(1, vec![]),
+			NumaNode::new(2, vec![3]),
+		]);
+		assert_eq!(topology.nodes().len(), 2);
+		assert_eq!(topology.partition(8), vec![0..6, 6..8]);
+		assert_eq!(topology.partition(1), vec![0..0, 0..1]);
+	}
+}
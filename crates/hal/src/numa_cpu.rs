@@ -0,0 +1,237 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{fmt::Debug, mem, mem::MaybeUninit, ops::Range};
+
+use binius_field::{ExtensionField, Field, PackedExtension, PackedField};
+use binius_math::{tensor_prod_eq_ind, CompositionPolyOS, MultilinearPoly, MultilinearQueryRef};
+use binius_maybe_rayon::{prelude::*, scope, ThreadPool, ThreadPoolBuilder};
+use tracing::instrument;
+
+use crate::{
+	numa::{pin_current_thread, NumaTopology},
+	sumcheck_round_calculator::{
+		calculate_product_round_evals_partitioned, calculate_round_evals_partitioned,
+		SubcubePartitioner,
+	},
+	ComputationBackend, Error, RoundEvals, ScratchArena, ScratchStats, SumcheckEvaluator,
+	SumcheckMultilinear,
+};
+
+/// The default size in bytes of the scratch space of a round evaluation tile.
+///
+/// This is chosen to fit into the L2 cache of a core on common server CPUs.
+pub const DEFAULT_TILE_BYTES: usize = 1 << 18;
+
+/// Implementation of ComputationBackend that runs the computations on CPU thread pools pinned to
+/// the NUMA nodes of the host.
+///
+/// Work is split into contiguous ranges, proportionally to the number of CPUs of each node, and
+/// each range is processed by the thread pool of its node. Buffers returned by the backend are
+/// first touched by the node that later processes the corresponding range, so that with the
+/// default first-touch policy of the operating system their pages are local to that node.
+/// Released buffers are pooled in a [`ScratchArena`] and reused by later allocations.
+///
+/// Sumcheck round evaluation is tiled: the size of the subcubes is chosen so that the scratch
+/// evaluations of all the multilinears fit into a tile of [`Self::tile_bytes`] bytes.
+#[derive(Debug)]
+pub struct NumaCpuBackend {
+	topology: NumaTopology,
+	pools: Vec<ThreadPool>,
+	tile_bytes: usize,
+	arena: ScratchArena,
+}
+
+/// Creates a [`NumaCpuBackend`] for the detected NUMA topology of the host.
+pub fn make_numa_cpu_backend() -> Result<NumaCpuBackend, Error> {
+	NumaCpuBackend::new(NumaTopology::detect())
+}
+
+impl NumaCpuBackend {
+	/// Creates a backend with one thread pool per node of `topology`.
+	pub fn new(topology: NumaTopology) -> Result<Self, Error> {
+		let pools = topology
+			.nodes()
+			.iter()
+			.map(|node| {
+				let cpus = node.cpus().to_vec();
+				ThreadPoolBuilder::new()
+					.num_threads(cpus.len())
+					.start_handler(move |_| pin_current_thread(&cpus))
+					.build()
+					.map_err(|err| Error::BackendError(Box::new(err)))
+			})
+			.collect::<Result<_, _>>()?;
+
+		Ok(Self {
+			topology,
+			pools,
+			tile_bytes: DEFAULT_TILE_BYTES,
+			arena: ScratchArena::new(),
+		})
+	}
+
+	/// Sets the size in bytes of the scratch space of a round evaluation tile.
+	pub fn with_tile_bytes(self, tile_bytes: usize) -> Self {
+		Self { tile_bytes, ..self }
+	}
+
+	pub const fn topology(&self) -> &NumaTopology {
+		&self.topology
+	}
+
+	pub const fn tile_bytes(&self) -> usize {
+		self.tile_bytes
+	}
+
+	/// Returns the maximum number of variables of the subcubes processed at once, such that the
+	/// scratch space of a round evaluation fits into a tile.
+	fn max_subcube_vars<P: PackedField>(&self, n_multilinears: usize) -> usize {
+		// For a subcube with `v` variables, every multilinear needs `2^v` interleaved evaluations
+		// and `2^(v - 1)` evaluations at each of 0, 1 and the interpolated point.
+		let bytes_per_half_subcube = 5 * n_multilinears.max(1) * mem::size_of::<P::Scalar>();
+		let half_subcube_len = (self.tile_bytes / bytes_per_half_subcube).max(1);
+		(half_subcube_len.ilog2() as usize).max(P::LOG_WIDTH) + 1
+	}
+
+	/// Allocates a zero-initialized buffer, each range of which is written by the pool of the
+	/// node it is assigned to.
+	fn zeroed_vec<P: PackedField>(&self, len: usize) -> Vec<P> {
+		let mut buffer = Vec::<P>::with_capacity(len);
+
+		let mut remaining = &mut buffer.spare_capacity_mut()[..len];
+		let chunks = self
+			.topology
+			.partition(len)
+			.into_iter()
+			.map(|range| {
+				let (chunk, rest) = mem::take(&mut remaining).split_at_mut(range.len());
+				remaining = rest;
+				chunk
+			})
+			.collect::<Vec<_>>();
+
+		scope(|s| {
+			for (pool, chunk) in self.pools.iter().zip(chunks) {
+				s.spawn(move |_| {
+					pool.install(|| {
+						chunk.par_iter_mut().for_each(|elem: &mut MaybeUninit<P>| {
+							elem.write(P::zero());
+						})
+					})
+				});
+			}
+		});
+
+		// SAFETY: the partition covers `0..len`, so all the elements have been initialized.
+		unsafe { buffer.set_len(len) };
+		buffer
+	}
+}
+
+impl SubcubePartitioner for NumaCpuBackend {
+	fn map_partitions<R, F>(&self, n_subcubes: usize, f: F) -> Vec<R>
+	where
+		R: Send,
+		F: Fn(Range<usize>) -> R + Sync,
+	{
+		let ranges = self.topology.partition(n_subcubes);
+		let mut results = ranges.iter().map(|_| None).collect::<Vec<_>>();
+
+		let f = &f;
+		scope(|s| {
+			for ((pool, range), result) in self.pools.iter().zip(ranges).zip(&mut results) {
+				if !range.is_empty() {
+					s.spawn(move |_| *result = Some(pool.install(|| f(range))));
+				}
+			}
+		});
+
+		results.into_iter().flatten().collect()
+	}
+}
+
+impl ComputationBackend for NumaCpuBackend {
+	type Vec<P: Send + Sync + Debug + 'static> = Vec<P>;
+
+	fn to_hal_slice<P: Debug + Send + Sync + 'static>(v: Vec<P>) -> Self::Vec<P> {
+		v
+	}
+
+	fn alloc_scratch<P: PackedField>(&self, len: usize) -> Vec<P> {
+		self.arena
+			.allocate_zeroed_with(len, |len| self.zeroed_vec(len))
+	}
+
+	fn release_scratch<P: PackedField>(&self, buffer: Vec<P>) {
+		self.arena.release(buffer)
+	}
+
+	fn release_hal_slice<P: PackedField>(&self, slice: Self::Vec<P>) {
+		self.arena.release(slice)
+	}
+
+	fn scratch_stats(&self) -> ScratchStats {
+		self.arena.stats()
+	}
+
+	#[instrument(skip_all, level = "trace")]
+	fn tensor_product_full_query<P: PackedField>(
+		&self,
+		query: &[P::Scalar],
+	) -> Result<Self::Vec<P>, Error> {
+		let mut buffer = self.alloc_scratch::<P>(1 << query.len().saturating_sub(P::LOG_WIDTH));
+		buffer[0].set(0, P::Scalar::ONE);
+		tensor_prod_eq_ind(0, &mut buffer, query)?;
+		Ok(buffer)
+	}
+
+	fn sumcheck_compute_round_evals<FDomain, P, M, Evaluator, Composition>(
+		&self,
+		n_vars: usize,
+		tensor_query: Option<MultilinearQueryRef<P>>,
+		multilinears: &[SumcheckMultilinear<P, M>],
+		evaluators: &[Evaluator],
+		evaluation_points: &[FDomain],
+	) -> Result<Vec<RoundEvals<P::Scalar>>, Error>
+	where
+		FDomain: Field,
+		P: PackedField<Scalar: ExtensionField<FDomain>> + PackedExtension<FDomain>,
+		M: MultilinearPoly<P> + Send + Sync,
+		Evaluator: SumcheckEvaluator<P, Composition> + Sync,
+		Composition: CompositionPolyOS<P>,
+	{
+		calculate_round_evals_partitioned(
+			n_vars,
+			tensor_query,
+			multilinears,
+			evaluators,
+			evaluation_points,
+			self.max_subcube_vars::<P>(multilinears.len()),
+			self,
+		)
+	}
+
+	fn sumcheck_compute_product_round_evals<FDomain, P, M>(
+		&self,
+		n_vars: usize,
+		tensor_query: Option<MultilinearQueryRef<P>>,
+		multilinears: &[SumcheckMultilinear<P, M>],
+		products: &[Vec<usize>],
+		evaluation_points: &[FDomain],
+	) -> Result<Vec<RoundEvals<P::Scalar>>, Error>
+	where
+		FDomain: Field,
+		P: PackedField<Scalar: ExtensionField<FDomain>> + PackedExtension<FDomain>,
+		M: MultilinearPoly<P> + Send + Sync,
+	{
+		calculate_product_round_evals_partitioned(
+			n_vars,
+			tensor_query,
+			multilinears,
+			products,
+			evaluation_points,
+			self.max_subcube_vars::<P>(multilinears.len()),
+			self,
+		)
+	}
+}
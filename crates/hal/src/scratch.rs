@@ -0,0 +1,299 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use std::{
+	any::{Any, TypeId},
+	mem,
+	ops::Sub,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Mutex,
+	},
+};
+
+use binius_field::PackedField;
+use bytemuck::zeroed_vec;
+
+/// Counters of the buffer requests served by a [`ScratchArena`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ScratchStats {
+	/// The number of buffers that were freshly allocated on the heap.
+	pub allocations: usize,
+	/// The number of buffers that were served from previously released buffers.
+	pub reuses: usize,
+	/// The number of buffers returned to the arena.
+	pub releases: usize,
+}
+
+impl Sub for ScratchStats {
+	type Output = Self;
+
+	fn sub(self, rhs: Self) -> Self {
+		Self {
+			allocations: self.allocations - rhs.allocations,
+			reuses: self.reuses - rhs.reuses,
+			releases: self.releases - rhs.releases,
+		}
+	}
+}
+
+#[derive(Debug)]
+struct FreeBuffer {
+	type_id: TypeId,
+	capacity: usize,
+	bytes: usize,
+	buffer: Box<dyn Any + Send>,
+}
+
+/// The default maximum number of bytes retained by a [`ScratchArena`].
+pub const DEFAULT_SCRATCH_ARENA_MAX_BYTES: usize = 1 << 30;
+
+#[derive(Debug)]
+struct FreeBuffers {
+	buffers: Vec<FreeBuffer>,
+	bytes: usize,
+}
+
+impl FreeBuffers {
+	/// Frees the largest buffers until at most `max_bytes` bytes are retained.
+	fn trim(&mut self, max_bytes: usize) {
+		if self.bytes <= max_bytes {
+			return;
+		}
+
+		self.buffers.sort_unstable_by_key(|free| free.bytes);
+		while self.bytes > max_bytes {
+			let free = self
+				.buffers
+				.pop()
+				.expect("retained bytes are held by the buffers");
+			self.bytes -= free.bytes;
+		}
+	}
+}
+
+/// A pool of scratch buffers of packed field elements that can be reused across allocations.
+///
+/// Released buffers are kept in the arena and handed out again by later allocations of the same
+/// element type that fit into their capacity. This avoids the allocator churn of the sumcheck
+/// provers, which replace every folded multilinear with a freshly allocated one of half the size
+/// in each round.
+///
+/// The arena retains at most [`Self::max_bytes`] bytes of released buffers; buffers released
+/// beyond that are freed. [`Self::trim`] frees retained buffers on demand.
+#[derive(Debug)]
+pub struct ScratchArena {
+	free_buffers: Mutex<FreeBuffers>,
+	max_bytes: usize,
+	allocations: AtomicUsize,
+	reuses: AtomicUsize,
+	releases: AtomicUsize,
+}
+
+impl ScratchArena {
+	/// Creates an arena retaining at most [`DEFAULT_SCRATCH_ARENA_MAX_BYTES`] bytes.
+	pub const fn new() -> Self {
+		Self::with_max_bytes(DEFAULT_SCRATCH_ARENA_MAX_BYTES)
+	}
+
+	/// Creates an arena retaining at most `max_bytes` bytes of released buffers.
+	pub const fn with_max_bytes(max_bytes: usize) -> Self {
+		Self {
+			free_buffers: Mutex::new(FreeBuffers {
+				buffers: Vec::new(),
+				bytes: 0,
+			}),
+			max_bytes,
+			allocations: AtomicUsize::new(0),
+			reuses: AtomicUsize::new(0),
+			releases: AtomicUsize::new(0),
+		}
+	}
+
+	/// The maximum number of bytes of released buffers retained by the arena.
+	pub const fn max_bytes(&self) -> usize {
+		self.max_bytes
+	}
+
+	/// The number of bytes of released buffers currently retained by the arena.
+	pub fn retained_bytes(&self) -> usize {
+		self.free_buffers
+			.lock()
+			.expect("mutex is not poisoned")
+			.bytes
+	}
+
+	/// Returns a zero-initialized buffer of `len` elements.
+	pub fn allocate_zeroed<P: PackedField>(&self, len: usize) -> Vec<P> {
+		self.allocate_zeroed_with(len, zeroed_vec)
+	}
+
+	/// Returns a zero-initialized buffer of `len` elements, calling `alloc` to create it if no
+	/// released buffer can be reused.
+	///
+	/// Among the released buffers with enough capacity, the smallest one is reused.
+	pub fn allocate_zeroed_with<P: PackedField>(
+		&self,
+		len: usize,
+		alloc: impl FnOnce(usize) -> Vec<P>,
+	) -> Vec<P> {
+		let reused = {
+			let mut free_buffers = self.free_buffers.lock().expect("mutex is not poisoned");
+			let reused = free_buffers
+				.buffers
+				.iter()
+				.enumerate()
+				.filter(|(_, free)| free.type_id == TypeId::of::<P>() && free.capacity >= len)
+				.min_by_key(|(_, free)| free.capacity)
+				.map(|(index, _)| index)
+				.map(|index| free_buffers.buffers.swap_remove(index));
+			if let Some(free) = &reused {
+				free_buffers.bytes -= free.bytes;
+			}
+			reused
+		};
+
+		match reused {
+			Some(free) => {
+				self.reuses.fetch_add(1, Ordering::Relaxed);
+				let mut buffer = *free
+					.buffer
+					.downcast::<Vec<P>>()
+					.expect("buffers are stored with the TypeId of their element type");
+				buffer.clear();
+				buffer.resize(len, P::zero());
+				buffer
+			}
+			None => {
+				self.allocations.fetch_add(1, Ordering::Relaxed);
+				alloc(len)
+			}
+		}
+	}
+
+	/// Returns a buffer to the arena so that later allocations can reuse it.
+	///
+	/// The buffer is freed instead if retaining it would exceed [`Self::max_bytes`].
+	pub fn release<P: PackedField>(&self, buffer: Vec<P>) {
+		if buffer.capacity() == 0 {
+			return;
+		}
+
+		self.releases.fetch_add(1, Ordering::Relaxed);
+		let bytes = buffer.capacity() * mem::size_of::<P>();
+		let mut free_buffers = self.free_buffers.lock().expect("mutex is not poisoned");
+		if free_buffers.bytes + bytes > self.max_bytes {
+			return;
+		}
+
+		free_buffers.bytes += bytes;
+		free_buffers.buffers.push(FreeBuffer {
+			type_id: TypeId::of::<P>(),
+			capacity: buffer.capacity(),
+			bytes,
+			buffer: Box::new(buffer),
+		});
+	}
+
+	/// Frees the largest released buffers until at most `max_bytes` bytes are retained.
+	pub fn trim(&self, max_bytes: usize) {
+		self.free_buffers
+			.lock()
+			.expect("mutex is not poisoned")
+			.trim(max_bytes);
+	}
+
+	/// Frees all the released buffers held by the arena.
+	pub fn clear(&self) {
+		self.trim(0);
+	}
+
+	pub fn stats(&self) -> ScratchStats {
+		ScratchStats {
+			allocations: self.allocations.load(Ordering::Relaxed),
+			reuses: self.reuses.load(Ordering::Relaxed),
+			releases: self.releases.load(Ordering::Relaxed),
+		}
+	}
+}
+
+impl Default for ScratchArena {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{PackedBinaryField4x32b, PackedBinaryField8x16b};
+
+	use super::*;
+
+	#[test]
+	fn test_reuse_released_buffer() {
+		let arena = ScratchArena::new();
+
+		let mut buffer = arena.allocate_zeroed::<PackedBinaryField4x32b>(16);
+		buffer.fill(PackedBinaryField4x32b::one());
+		arena.release(buffer);
+
+		let buffer = arena.allocate_zeroed::<PackedBinaryField4x32b>(8);
+		assert_eq!(buffer, vec![PackedBinaryField4x32b::zero(); 8]);
+
+		assert_eq!(
+			arena.stats(),
+			ScratchStats {
+				allocations: 1,
+				reuses: 1,
+				releases: 1,
+			}
+		);
+	}
+
+	#[test]
+	fn test_release_beyond_max_bytes_frees_buffer() {
+		let buffer_bytes = 8 * mem::size_of::<PackedBinaryField4x32b>();
+		let arena = ScratchArena::with_max_bytes(buffer_bytes);
+
+		let first = arena.allocate_zeroed::<PackedBinaryField4x32b>(8);
+		let second = arena.allocate_zeroed::<PackedBinaryField4x32b>(8);
+		arena.release(first);
+		arena.release(second);
+		assert_eq!(arena.retained_bytes(), buffer_bytes);
+
+		let _ = arena.allocate_zeroed::<PackedBinaryField4x32b>(8);
+		let _ = arena.allocate_zeroed::<PackedBinaryField4x32b>(8);
+		assert_eq!(arena.retained_bytes(), 0);
+		assert_eq!(arena.stats().reuses, 1);
+	}
+
+	#[test]
+	fn test_trim_frees_largest_buffers() {
+		let arena = ScratchArena::new();
+		let small = arena.allocate_zeroed::<PackedBinaryField4x32b>(8);
+		let large = arena.allocate_zeroed::<PackedBinaryField4x32b>(64);
+		let small_bytes = small.capacity() * mem::size_of::<PackedBinaryField4x32b>();
+		arena.release(small);
+		arena.release(large);
+
+		arena.trim(small_bytes);
+		assert_eq!(arena.retained_bytes(), small_bytes);
+
+		let _ = arena.allocate_zeroed::<PackedBinaryField4x32b>(64);
+		assert_eq!(arena.stats().reuses, 0);
+
+		arena.clear();
+		assert_eq!(arena.retained_bytes(), 0);
+	}
+
+	#[test]
+	fn test_no_reuse_across_types_or_larger_sizes() {
+		let arena = ScratchArena::new();
+
+		arena.release(arena.allocate_zeroed::<PackedBinaryField4x32b>(8));
+		let _ = arena.allocate_zeroed::<PackedBinaryField8x16b>(8);
+		let _ = arena.allocate_zeroed::<PackedBinaryField4x32b>(16);
+
+		assert_eq!(arena.stats().allocations, 3);
+		assert_eq!(arena.stats().reuses, 0);
+	}
+}
@@ -4,12 +4,12 @@
 //!
 //! This is one of the core computational tasks in the sumcheck proving algorithm.
 
-use std::iter;
+use std::{iter, ops::Range};
 
 use binius_field::{ExtensionField, Field, PackedExtension, PackedField, PackedSubfield};
 use binius_math::{
-	deinterleave, extrapolate_lines, CompositionPolyOS, MultilinearPoly, MultilinearQuery,
-	MultilinearQueryRef,
+	deinterleave, extrapolate_lines, ArithExpr, CompositionPolyOS, MultilinearPoly,
+	MultilinearQuery, MultilinearQueryRef,
 };
 use binius_maybe_rayon::prelude::*;
 use bytemuck::zeroed_vec;
@@ -41,6 +41,33 @@ trait SumcheckMultilinearAccess<P: PackedField> {
 	) -> Result<(), Error>;
 }
 
+/// The default maximum number of variables of the subcubes that are processed at once.
+pub(crate) const DEFAULT_MAX_SUBCUBE_VARS: usize = 5;
+
+/// Strategy for distributing the subcubes of a round evaluation between groups of workers.
+pub(crate) trait SubcubePartitioner {
+	/// Splits the subcube indices `0..n_subcubes` into ranges, calls `f` on each of them and
+	/// returns the results.
+	fn map_partitions<R, F>(&self, n_subcubes: usize, f: F) -> Vec<R>
+	where
+		R: Send,
+		F: Fn(Range<usize>) -> R + Sync;
+}
+
+/// Processes all the subcubes as a single partition on the current thread pool.
+#[derive(Debug)]
+pub(crate) struct SinglePartition;
+
+impl SubcubePartitioner for SinglePartition {
+	fn map_partitions<R, F>(&self, n_subcubes: usize, f: F) -> Vec<R>
+	where
+		R: Send,
+		F: Fn(Range<usize>) -> R + Sync,
+	{
+		vec![f(0..n_subcubes)]
+	}
+}
+
 /// Calculate the accumulated evaluations for an arbitrary sumcheck round.
 ///
 /// See [`calculate_first_round_evals`] for an optimized version of this method
@@ -52,6 +79,36 @@ pub(crate) fn calculate_round_evals<FDomain, F, P, M, Evaluator, Composition>(
 	evaluators: &[Evaluator],
 	evaluation_points: &[FDomain],
 ) -> Result<Vec<RoundEvals<F>>, Error>
+where
+	FDomain: Field,
+	F: Field + ExtensionField<FDomain>,
+	P: PackedField<Scalar = F> + PackedExtension<FDomain>,
+	M: MultilinearPoly<P> + Send + Sync,
+	Evaluator: SumcheckEvaluator<P, Composition> + Sync,
+	Composition: CompositionPolyOS<P>,
+{
+	calculate_round_evals_partitioned(
+		n_vars,
+		tensor_query,
+		multilinears,
+		evaluators,
+		evaluation_points,
+		DEFAULT_MAX_SUBCUBE_VARS,
+		&SinglePartition,
+	)
+}
+
+/// Calculate the accumulated evaluations for an arbitrary sumcheck round, processing subcubes
+/// with at most `max_subcube_vars` variables and distributing them with `partitioner`.
+pub(crate) fn calculate_round_evals_partitioned<FDomain, F, P, M, Evaluator, Composition>(
+	n_vars: usize,
+	tensor_query: Option<MultilinearQueryRef<P>>,
+	multilinears: &[SumcheckMultilinear<P, M>],
+	evaluators: &[Evaluator],
+	evaluation_points: &[FDomain],
+	max_subcube_vars: usize,
+	partitioner: &impl SubcubePartitioner,
+) -> Result<Vec<RoundEvals<F>>, Error>
 where
 	FDomain: Field,
 	F: Field + ExtensionField<FDomain>,
@@ -71,7 +128,14 @@ where
 		})
 		.collect_vec();
 
-	calculate_round_evals_with_access(n_vars, &later_rounds_accesses, evaluators, evaluation_points)
+	calculate_round_evals_with_access(
+		n_vars,
+		&later_rounds_accesses,
+		evaluators,
+		evaluation_points,
+		max_subcube_vars,
+		partitioner,
+	)
 }
 
 fn calculate_round_evals_with_access<FDomain, F, P, Evaluator, Access, Composition>(
@@ -79,6 +143,8 @@ fn calculate_round_evals_with_access<FDomain, F, P, Evaluator, Access, Compositi
 	multilinears: &[Access],
 	evaluators: &[Evaluator],
 	evaluation_points: &[FDomain],
+	max_subcube_vars: usize,
+	partitioner: &impl SubcubePartitioner,
 ) -> Result<Vec<RoundEvals<F>>, Error>
 where
 	FDomain: Field,
@@ -93,9 +159,10 @@ where
 		.iter()
 		.map(|evaluator| evaluator.eval_point_indices().len());
 
-	/// Process batches of vertices in parallel, accumulating the round evaluations.
-	const MAX_SUBCUBE_VARS: usize = 5;
-	let subcube_vars = MAX_SUBCUBE_VARS.min(n_vars) - 1;
+	// Process batches of vertices in parallel, accumulating the round evaluations.
+	// Subcubes span at least one packed element, so that wide packed fields, such as the
+	// byte-sliced ones, deinterleave whole packed elements instead of falling back to scalars.
+	let subcube_vars = max_subcube_vars.max(P::LOG_WIDTH + 1).clamp(1, n_vars) - 1;
 
 	// Compute the union of all evaluation point index ranges.
 	let eval_point_indices = evaluators
@@ -104,113 +171,294 @@ where
 		.reduce(|range1, range2| range1.start.min(range2.start)..range1.end.max(range2.end))
 		.unwrap_or(0..0);
 
-	let packed_accumulators = (0..(1 << (n_vars - 1 - subcube_vars)))
-		.into_par_iter()
-		.fold(
-			|| ParFoldStates::new(n_multilinears, n_round_evals.clone(), subcube_vars),
-			|mut par_fold_states, subcube_index| {
-				let ParFoldStates {
-					multilinear_evals,
-					interleaved_evals,
-					round_evals,
-				} = &mut par_fold_states;
-
-				for (multilinear, evals) in iter::zip(multilinears, multilinear_evals.iter_mut()) {
-					multilinear
-						.subcube_evaluations(
-							subcube_vars + 1,
-							subcube_index,
-							interleaved_evals.as_mut_slice(),
-						)
-						.expect("indices are in range");
-
-					// Returned slice has interleaved 0/1 evals due to round variable
-					// being the lowermost one. Deinterleave into two slices.
-					deinterleave(subcube_vars, interleaved_evals.as_slice()).for_each(
-						|(i, even, odd)| {
-							evals.evals_0[i] = even;
-							evals.evals_1[i] = odd;
-						},
-					);
+	let zero_accumulators = || -> Vec<Vec<P>> {
+		evaluators
+			.iter()
+			.map(|evaluator| vec![P::zero(); evaluator.eval_point_indices().len()])
+			.collect()
+	};
+
+	// Simply sum up the fold partitions.
+	let add_accumulators = |lhs: Vec<Vec<P>>, rhs: Vec<Vec<P>>| -> Vec<Vec<P>> {
+		iter::zip(lhs, rhs)
+			.map(|(mut lhs_vals, rhs_vals)| {
+				for (lhs_val, rhs_val) in lhs_vals.iter_mut().zip(rhs_vals) {
+					*lhs_val += rhs_val;
 				}
+				lhs_vals
+			})
+			.collect()
+	};
+
+	let n_subcubes = 1 << (n_vars - 1 - subcube_vars);
+	let process_subcubes = |subcube_indices: Range<usize>| {
+		subcube_indices
+			.into_par_iter()
+			.fold(
+				|| ParFoldStates::new(n_multilinears, n_round_evals.clone(), subcube_vars),
+				|mut par_fold_states, subcube_index| {
+					let ParFoldStates {
+						multilinear_evals,
+						interleaved_evals,
+						round_evals,
+					} = &mut par_fold_states;
+
+					for (multilinear, evals) in
+						iter::zip(multilinears, multilinear_evals.iter_mut())
+					{
+						multilinear
+							.subcube_evaluations(
+								subcube_vars + 1,
+								subcube_index,
+								interleaved_evals.as_mut_slice(),
+							)
+							.expect("indices are in range");
+
+						// Returned slice has interleaved 0/1 evals due to round variable
+						// being the lowermost one. Deinterleave into two slices.
+						deinterleave(subcube_vars, interleaved_evals.as_slice()).for_each(
+							|(i, even, odd)| {
+								evals.evals_0[i] = even;
+								evals.evals_1[i] = odd;
+							},
+						);
+					}
 
-				// Proceed by evaluation point first to share interpolation work between evaluators.
-				for eval_point_index in eval_point_indices.clone() {
-					let eval_point = evaluation_points[eval_point_index];
-					let eval_point_broadcast = <PackedSubfield<P, FDomain>>::broadcast(eval_point);
-
-					// Only points with indices two and above need to be interpolated.
-					if eval_point_index >= 2 {
-						for evals in multilinear_evals.iter_mut() {
-							for (&eval_0, &eval_1, eval_z) in izip!(
-								evals.evals_0.as_slice(),
-								evals.evals_1.as_slice(),
-								evals.evals_z.as_mut_slice(),
-							) {
-								// This is logically the same as calling
-								// `binius_math::univariate::extrapolate_line`, except that we do
-								// not repeat the broadcast of the subfield element to a packed
-								// subfield.
-								*eval_z = P::cast_ext(extrapolate_lines(
-									P::cast_base(eval_0),
-									P::cast_base(eval_1),
-									eval_point_broadcast,
-								));
+					// Proceed by evaluation point first to share interpolation work between evaluators.
+					for eval_point_index in eval_point_indices.clone() {
+						let eval_point = evaluation_points[eval_point_index];
+						let eval_point_broadcast =
+							<PackedSubfield<P, FDomain>>::broadcast(eval_point);
+
+						// Only points with indices two and above need to be interpolated.
+						if eval_point_index >= 2 {
+							for evals in multilinear_evals.iter_mut() {
+								for (&eval_0, &eval_1, eval_z) in izip!(
+									evals.evals_0.as_slice(),
+									evals.evals_1.as_slice(),
+									evals.evals_z.as_mut_slice(),
+								) {
+									// This is logically the same as calling
+									// `binius_math::univariate::extrapolate_line`, except that we do
+									// not repeat the broadcast of the subfield element to a packed
+									// subfield.
+									*eval_z = P::cast_ext(extrapolate_lines(
+										P::cast_base(eval_0),
+										P::cast_base(eval_1),
+										eval_point_broadcast,
+									));
+								}
 							}
 						}
-					}
 
-					let evals_z_iter =
-						multilinear_evals
-							.iter()
-							.map(|evals| match eval_point_index {
-								0 => evals.evals_0.as_slice(),
-								1 => evals.evals_1.as_slice(),
-								_ => evals.evals_z.as_slice(),
-							});
-
-					stackalloc_with_iter(n_multilinears, evals_z_iter, |evals_z| {
-						for (evaluator, round_evals) in
-							iter::zip(evaluators, round_evals.iter_mut())
-						{
-							let eval_point_indices = evaluator.eval_point_indices();
-							if !eval_point_indices.contains(&eval_point_index) {
-								continue;
+						let evals_z_iter =
+							multilinear_evals
+								.iter()
+								.map(|evals| match eval_point_index {
+									0 => evals.evals_0.as_slice(),
+									1 => evals.evals_1.as_slice(),
+									_ => evals.evals_z.as_slice(),
+								});
+
+						stackalloc_with_iter(n_multilinears, evals_z_iter, |evals_z| {
+							for (evaluator, round_evals) in
+								iter::zip(evaluators, round_evals.iter_mut())
+							{
+								let eval_point_indices = evaluator.eval_point_indices();
+								if !eval_point_indices.contains(&eval_point_index) {
+									continue;
+								}
+
+								round_evals[eval_point_index - eval_point_indices.start] +=
+									evaluator.process_subcube_at_eval_point(
+										subcube_vars,
+										subcube_index,
+										evals_z,
+									);
 							}
+						});
+					}
 
-							round_evals[eval_point_index - eval_point_indices.start] += evaluator
-								.process_subcube_at_eval_point(
-									subcube_vars,
-									subcube_index,
-									evals_z,
-								);
-						}
-					});
+					par_fold_states
+				},
+			)
+			.map(|states| states.round_evals)
+			.reduce(zero_accumulators, add_accumulators)
+	};
+
+	let packed_accumulators = partitioner
+		.map_partitions(n_subcubes, process_subcubes)
+		.into_iter()
+		.reduce(add_accumulators)
+		.unwrap_or_else(zero_accumulators);
+
+	let evals = packed_accumulators
+		.into_iter()
+		.map(|vals| {
+			RoundEvals(
+				vals.into_iter()
+					.map(|packed_val| packed_val.iter().take(1 << subcube_vars).sum())
+					.collect(),
+			)
+		})
+		.collect();
+
+	Ok(evals)
+}
+
+/// Calculate the accumulated round evaluations for sums of products of multilinears.
+///
+/// Every entry of `products` lists the indices of the multilinears whose product is summed over
+/// the hypercube, with repetitions for higher powers. The round polynomial of a product of degree
+/// $d$ is evaluated at the evaluation points with indices $1, \ldots, d$, the evaluation at the
+/// zeroth point being recoverable from the claimed sum.
+///
+/// Restricted to the round variable, the multilinears are linear polynomials, and the products
+/// are evaluated from their linear factors directly instead of through a composition. For
+/// products of degree four and higher, pairs of linear factors are first multiplied out into
+/// quadratics with the Karatsuba identity
+///
+/// $$(a_0 + b_0 X)(a_1 + b_1 X) = a_0 a_1 + ((a_0 + b_0)(a_1 + b_1) - a_0 a_1 - b_0 b_1) X + b_0 b_1 X^2,$$
+///
+/// which takes three multiplications, whereas the quadratics are evaluated at the evaluation
+/// points with cheap multiplications by subfield elements. This roughly halves the number of
+/// extension field multiplications compared to evaluating every factor at every point.
+pub(crate) fn calculate_product_round_evals_partitioned<FDomain, F, P, M>(
+	n_vars: usize,
+	tensor_query: Option<MultilinearQueryRef<P>>,
+	multilinears: &[SumcheckMultilinear<P, M>],
+	products: &[Vec<usize>],
+	evaluation_points: &[FDomain],
+	max_subcube_vars: usize,
+	partitioner: &impl SubcubePartitioner,
+) -> Result<Vec<RoundEvals<F>>, Error>
+where
+	FDomain: Field,
+	F: Field + ExtensionField<FDomain>,
+	P: PackedField<Scalar = F> + PackedExtension<FDomain>,
+	M: MultilinearPoly<P> + Send + Sync,
+{
+	for product in products {
+		if product.is_empty()
+			|| product.len() >= evaluation_points.len()
+			|| product.iter().any(|&index| index >= multilinears.len())
+		{
+			return Err(Error::IncorrectProductIndices);
+		}
+	}
+
+	let empty_query = MultilinearQuery::with_capacity(0);
+	let tensor_query = tensor_query.unwrap_or_else(|| empty_query.to_ref());
+
+	let accesses = multilinears
+		.iter()
+		.map(|multilinear| LargeFieldAccess {
+			multilinear,
+			tensor_query,
+		})
+		.collect_vec();
+
+	let n_multilinears = multilinears.len();
+	let n_round_evals = products.iter().map(|product| product.len());
+	let max_degree = products
+		.iter()
+		.map(|product| product.len())
+		.max()
+		.unwrap_or(0);
+
+	// See `calculate_round_evals_with_access` for why subcubes span at least one packed element.
+	let subcube_vars = max_subcube_vars.max(P::LOG_WIDTH + 1).clamp(1, n_vars) - 1;
+	let row_len = 1 << subcube_vars.saturating_sub(P::LOG_WIDTH);
+
+	let zero_accumulators = || -> Vec<Vec<P>> {
+		n_round_evals
+			.clone()
+			.map(|n_round_evals| vec![P::zero(); n_round_evals])
+			.collect()
+	};
+
+	let add_accumulators = |lhs: Vec<Vec<P>>, rhs: Vec<Vec<P>>| -> Vec<Vec<P>> {
+		iter::zip(lhs, rhs)
+			.map(|(mut lhs_vals, rhs_vals)| {
+				for (lhs_val, rhs_val) in lhs_vals.iter_mut().zip(rhs_vals) {
+					*lhs_val += rhs_val;
 				}
+				lhs_vals
+			})
+			.collect()
+	};
+
+	let n_subcubes = 1 << (n_vars - 1 - subcube_vars);
+	let process_subcubes = |subcube_indices: Range<usize>| {
+		subcube_indices
+			.into_par_iter()
+			.fold(
+				|| {
+					let par_fold_states = ParFoldStates::<P, P>::new(
+						n_multilinears,
+						n_round_evals.clone(),
+						subcube_vars,
+					);
+					let scratch = ProductScratch {
+						product_evals: zeroed_vec(max_degree * row_len),
+						quadratic: zeroed_vec(3 * row_len),
+					};
+					(par_fold_states, scratch)
+				},
+				|(mut par_fold_states, mut scratch), subcube_index| {
+					let ParFoldStates {
+						multilinear_evals,
+						interleaved_evals,
+						round_evals,
+					} = &mut par_fold_states;
+
+					for (access, evals) in iter::zip(&accesses, multilinear_evals.iter_mut()) {
+						access
+							.subcube_evaluations(
+								subcube_vars + 1,
+								subcube_index,
+								interleaved_evals.as_mut_slice(),
+							)
+							.expect("indices are in range");
+
+						deinterleave(subcube_vars, interleaved_evals.as_slice()).for_each(
+							|(i, even, odd)| {
+								evals.evals_0[i] = even;
+								evals.evals_1[i] = odd;
+							},
+						);
+					}
 
-				par_fold_states
-			},
-		)
-		.map(|states| states.round_evals)
-		// Simply sum up the fold partitions.
-		.reduce(
-			|| {
-				evaluators
-					.iter()
-					.map(|evaluator| vec![P::zero(); evaluator.eval_point_indices().len()])
-					.collect()
-			},
-			|lhs, rhs| {
-				iter::zip(lhs, rhs)
-					.map(|(mut lhs_vals, rhs_vals)| {
-						for (lhs_val, rhs_val) in lhs_vals.iter_mut().zip(rhs_vals) {
-							*lhs_val += rhs_val;
+					for (product, round_evals) in iter::zip(products, round_evals.iter_mut()) {
+						let degree = product.len();
+						let product_evals = &mut scratch.product_evals[..degree * row_len];
+						evaluate_product(
+							product.iter().map(|&index| &multilinear_evals[index]),
+							degree,
+							&evaluation_points[1..=degree],
+							product_evals,
+							&mut scratch.quadratic,
+						);
+
+						for (round_eval, evals) in
+							iter::zip(round_evals.iter_mut(), product_evals.chunks_exact(row_len))
+						{
+							*round_eval += evals.iter().copied().sum::<P>();
 						}
-						lhs_vals
-					})
-					.collect()
-			},
-		);
+					}
+
+					(par_fold_states, scratch)
+				},
+			)
+			.map(|(states, _)| states.round_evals)
+			.reduce(zero_accumulators, add_accumulators)
+	};
+
+	let packed_accumulators = partitioner
+		.map_partitions(n_subcubes, process_subcubes)
+		.into_iter()
+		.reduce(add_accumulators)
+		.unwrap_or_else(zero_accumulators);
 
 	let evals = packed_accumulators
 		.into_iter()
@@ -226,6 +474,97 @@ where
 	Ok(evals)
 }
 
+/// Scratch space for [`evaluate_product`].
+#[derive(Debug)]
+struct ProductScratch<P: PackedField> {
+	// Evaluations of the product at the evaluation points, one row per point.
+	product_evals: Vec<P>,
+	// Monomial coefficients of a quadratic factor, one row per coefficient.
+	quadratic: Vec<P>,
+}
+
+/// Evaluates a product of linear polynomials over a subcube at the given evaluation points.
+///
+/// Every factor is given by its evaluations at zero and one, and `product_evals` is filled with
+/// one row of evaluations per evaluation point.
+fn evaluate_product<'a, P, FDomain>(
+	mut factors: impl Iterator<Item = &'a MultilinearEvals<P>>,
+	degree: usize,
+	eval_points: &[FDomain],
+	product_evals: &mut [P],
+	quadratic: &mut [P],
+) where
+	P: PackedField<Scalar: ExtensionField<FDomain>> + PackedExtension<FDomain>,
+	FDomain: Field,
+{
+	let row_len = product_evals.len() / eval_points.len();
+	let mul_by_eval_point = |value: P, eval_point: PackedSubfield<P, FDomain>| {
+		P::cast_ext(P::cast_base(value) * eval_point)
+	};
+
+	// Karatsuba only pays off when there are more evaluation points than its multiplications.
+	let use_karatsuba = degree > 3;
+
+	let mut is_first = true;
+	while let Some(factor_0) = factors.next() {
+		let factor_1 = if use_karatsuba { factors.next() } else { None };
+
+		match factor_1 {
+			Some(factor_1) => {
+				let (c_0, rest) = quadratic.split_at_mut(row_len);
+				let (c_1, c_2) = rest.split_at_mut(row_len);
+				for (i, (c_0, c_1, c_2)) in izip!(c_0, c_1, c_2).enumerate() {
+					let (a_0, b_0) =
+						(factor_0.evals_0[i], factor_0.evals_1[i] - factor_0.evals_0[i]);
+					let (a_1, b_1) =
+						(factor_1.evals_0[i], factor_1.evals_1[i] - factor_1.evals_0[i]);
+					*c_0 = a_0 * a_1;
+					*c_2 = b_0 * b_1;
+					*c_1 = (a_0 + b_0) * (a_1 + b_1) - *c_0 - *c_2;
+				}
+
+				for (&eval_point, evals) in
+					iter::zip(eval_points, product_evals.chunks_exact_mut(row_len))
+				{
+					let is_one = eval_point == FDomain::ONE;
+					let eval_point = <PackedSubfield<P, FDomain>>::broadcast(eval_point);
+					for (i, eval) in evals.iter_mut().enumerate() {
+						let (c_0, c_1, c_2) =
+							(quadratic[i], quadratic[row_len + i], quadratic[2 * row_len + i]);
+						let value = if is_one {
+							c_0 + c_1 + c_2
+						} else {
+							c_0 + mul_by_eval_point(
+								c_1 + mul_by_eval_point(c_2, eval_point),
+								eval_point,
+							)
+						};
+						*eval = if is_first { value } else { *eval * value };
+					}
+				}
+			}
+			None => {
+				for (&eval_point, evals) in
+					iter::zip(eval_points, product_evals.chunks_exact_mut(row_len))
+				{
+					let is_one = eval_point == FDomain::ONE;
+					let eval_point = <PackedSubfield<P, FDomain>>::broadcast(eval_point);
+					for (i, eval) in evals.iter_mut().enumerate() {
+						let (eval_0, eval_1) = (factor_0.evals_0[i], factor_0.evals_1[i]);
+						let value = if is_one {
+							eval_1
+						} else {
+							eval_0 + mul_by_eval_point(eval_1 - eval_0, eval_point)
+						};
+						*eval = if is_first { value } else { *eval * value };
+					}
+				}
+			}
+		}
+		is_first = false;
+	}
+}
+
 // Evals of a single multilinear over a subcube, at 0/1 and some interpolated point.
 #[derive(Debug)]
 struct MultilinearEvals<P: PackedField> {
@@ -332,3 +671,112 @@ where
 		Ok(())
 	}
 }
+
+/// The product of some of the multilinears, as a composition over all of them.
+///
+/// Used by the default [`crate::ComputationBackend::sumcheck_compute_product_round_evals`] to
+/// evaluate the products with the generic composition round evaluation.
+#[derive(Debug)]
+pub(crate) struct ProductComposition {
+	n_vars: usize,
+	factors: Vec<usize>,
+}
+
+impl<P: PackedField> CompositionPolyOS<P> for ProductComposition {
+	fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	fn degree(&self) -> usize {
+		self.factors.len()
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		0
+	}
+
+	fn expression(&self) -> ArithExpr<P::Scalar> {
+		self.factors
+			.iter()
+			.map(|&index| ArithExpr::Var(index))
+			.product()
+	}
+
+	fn evaluate(&self, query: &[P]) -> Result<P, binius_math::Error> {
+		if query.len() != self.n_vars {
+			return Err(binius_math::Error::IncorrectQuerySize {
+				expected: self.n_vars,
+			});
+		}
+
+		Ok(self.factors.iter().map(|&index| query[index]).product())
+	}
+}
+
+/// Evaluator of the round polynomial of a [`ProductComposition`] at the evaluation points with
+/// indices $1, \ldots, d$.
+#[derive(Debug)]
+pub(crate) struct ProductEvaluator {
+	composition: ProductComposition,
+}
+
+impl ProductEvaluator {
+	/// Creates the evaluators of the `products` of `n_multilinears` multilinears, validating the
+	/// indices the same way as [`calculate_product_round_evals_partitioned`].
+	pub(crate) fn new_batch(
+		n_multilinears: usize,
+		products: &[Vec<usize>],
+		n_evaluation_points: usize,
+	) -> Result<Vec<Self>, Error> {
+		products
+			.iter()
+			.map(|product| {
+				if product.is_empty()
+					|| product.len() >= n_evaluation_points
+					|| product.iter().any(|&index| index >= n_multilinears)
+				{
+					return Err(Error::IncorrectProductIndices);
+				}
+
+				Ok(Self {
+					composition: ProductComposition {
+						n_vars: n_multilinears,
+						factors: product.clone(),
+					},
+				})
+			})
+			.collect()
+	}
+}
+
+impl<P: PackedField> SumcheckEvaluator<P, ProductComposition> for ProductEvaluator {
+	fn eval_point_indices(&self) -> Range<usize> {
+		1..self.composition.factors.len() + 1
+	}
+
+	fn process_subcube_at_eval_point(
+		&self,
+		_subcube_vars: usize,
+		_subcube_index: usize,
+		batch_query: &[&[P]],
+	) -> P {
+		let row_len = batch_query.first().map_or(0, |row| row.len());
+		(0..row_len)
+			.map(|i| {
+				self.composition
+					.factors
+					.iter()
+					.map(|&index| batch_query[index][i])
+					.product::<P>()
+			})
+			.sum()
+	}
+
+	fn composition(&self) -> &ProductComposition {
+		&self.composition
+	}
+
+	fn eq_ind_partial_eval(&self) -> Option<&[P]> {
+		None
+	}
+}
@@ -26,9 +26,11 @@ mod serialization;
 pub mod sha2;
 mod vision;
 mod vision_constants;
+mod vision_digest;
 
 pub use compression::*;
 pub use groestl::*;
 pub use hasher::*;
 pub use serialization::*;
 pub use vision::*;
+pub use vision_digest::*;
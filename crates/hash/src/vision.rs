@@ -77,7 +77,7 @@ lazy_static! {
 			arr
 		});
 
-	static ref PERMUTATION: Vision32bPermutation = Vision32bPermutation::default();
+	pub(crate) static ref PERMUTATION: Vision32bPermutation = Vision32bPermutation::default();
 }
 
 #[inline]
@@ -133,7 +133,7 @@ impl Vision32MDSTransform {
 
 /// This is the complete permutation function for the Vision hash which implements `Permutation`
 /// and `CryptographicPermutation` traits over `PackedAESBinary8x32b` as well as `BinaryField32b`
-#[derive(Clone, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct Vision32bPermutation {
 	mds: Vision32MDSTransform,
 }
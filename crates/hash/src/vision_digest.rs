@@ -0,0 +1,269 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Merkle hashing adapters for the [Vision Mark-32] permutation.
+//!
+//! [`Vision32bCompression`] compresses digests of `BinaryField32b` elements directly. The Merkle
+//! tree and Fiat-Shamir code in `binius_core` are generic over RustCrypto's [`Digest`] interface,
+//! so the byte-oriented types in this module expose the
+//! [`Vision32bPermutation`](crate::Vision32bPermutation) sponge and the compression through that
+//! interface so that Vision can be used as a drop-in replacement for Grøstl, while keeping all
+//! of the internal state in `BinaryField32b` elements. This makes the resulting commitments cheap
+//! to verify inside a Binius constraint system.
+//!
+//! [Vision Mark-32]: <https://eprint.iacr.org/2024/633>
+
+use binius_field::{AESTowerField32b, BinaryField32b, PackedAESBinaryField8x32b, PackedField};
+use digest::{
+	consts::{U32, U64},
+	core_api::BlockSizeUser,
+	FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update,
+};
+
+use crate::{
+	permutation::Permutation, vision::PERMUTATION, CompressionFunction, PseudoCompressionFunction,
+};
+
+/// Number of state elements overwritten in every absorption.
+const RATE_AS_U32: usize = 16;
+/// Number of bytes in the rate portion of the state.
+const RATE_AS_U8: usize = RATE_AS_U32 * size_of::<u32>();
+
+/// Converts 32 bytes into 8 field elements, reading each element as a little-endian `u32` in the
+/// canonical tower basis.
+fn bytes_to_elems(bytes: &[u8]) -> [BinaryField32b; 8] {
+	debug_assert_eq!(bytes.len(), 32);
+	std::array::from_fn(|i| {
+		BinaryField32b::new(u32::from_le_bytes(
+			bytes[i * 4..i * 4 + 4]
+				.try_into()
+				.expect("chunk is 4 bytes"),
+		))
+	})
+}
+
+/// Converts 32 bytes into 8 field elements of the Vision state, see [`bytes_to_elems`].
+fn bytes_to_packed(bytes: &[u8]) -> PackedAESBinaryField8x32b {
+	let elems = bytes_to_elems(bytes);
+	PackedAESBinaryField8x32b::from_fn(|i| AESTowerField32b::from(elems[i]))
+}
+
+/// Inverse of [`bytes_to_packed`].
+fn packed_to_bytes(packed: PackedAESBinaryField8x32b, out: &mut [u8]) {
+	debug_assert_eq!(out.len(), 32);
+	for (i, chunk) in out.chunks_exact_mut(4).enumerate() {
+		chunk.copy_from_slice(&BinaryField32b::from(packed.get(i)).val().to_le_bytes());
+	}
+}
+
+/// A variable-length byte hash built on the [`Vision32bPermutation`](crate::Vision32bPermutation) sponge.
+///
+/// The sponge has a rate of 16 `BinaryField32b` elements (64 bytes) and a capacity of 8 elements.
+/// Input bytes are read in little-endian 4-byte chunks, each interpreted as a `BinaryField32b`
+/// element, and absorbed in overwrite mode. The message is padded with a single `0x01` byte
+/// followed by zeros up to the next block boundary, so every message absorbs at least one block.
+/// The digest is the first 8 state elements after the final permutation.
+#[derive(Debug, Clone)]
+pub struct Vision32bByteHasher {
+	state: [PackedAESBinaryField8x32b; 3],
+	buffer: [u8; RATE_AS_U8],
+	/// Invariant: `index` is always strictly less than `RATE_AS_U8`.
+	index: usize,
+}
+
+impl Default for Vision32bByteHasher {
+	fn default() -> Self {
+		Self {
+			state: [PackedAESBinaryField8x32b::zero(); 3],
+			buffer: [0; RATE_AS_U8],
+			index: 0,
+		}
+	}
+}
+
+impl Vision32bByteHasher {
+	fn absorb_block(&mut self) {
+		self.state[0] = bytes_to_packed(&self.buffer[..32]);
+		self.state[1] = bytes_to_packed(&self.buffer[32..]);
+		PERMUTATION.permute_mut(&mut self.state);
+		self.index = 0;
+	}
+
+	fn finalize_into_reset_inner(&mut self, out: &mut Output<Self>) {
+		self.buffer[self.index] = 0x01;
+		self.buffer[self.index + 1..].fill(0);
+		self.absorb_block();
+		packed_to_bytes(self.state[0], out.as_mut_slice());
+		Reset::reset(self);
+	}
+}
+
+impl HashMarker for Vision32bByteHasher {}
+
+impl BlockSizeUser for Vision32bByteHasher {
+	type BlockSize = U64;
+}
+
+impl OutputSizeUser for Vision32bByteHasher {
+	type OutputSize = U32;
+}
+
+impl Update for Vision32bByteHasher {
+	fn update(&mut self, mut data: &[u8]) {
+		while !data.is_empty() {
+			let n = (RATE_AS_U8 - self.index).min(data.len());
+			self.buffer[self.index..self.index + n].copy_from_slice(&data[..n]);
+			self.index += n;
+			data = &data[n..];
+			if self.index == RATE_AS_U8 {
+				self.absorb_block();
+			}
+		}
+	}
+}
+
+impl FixedOutput for Vision32bByteHasher {
+	fn finalize_into(mut self, out: &mut Output<Self>) {
+		self.finalize_into_reset_inner(out);
+	}
+}
+
+impl Reset for Vision32bByteHasher {
+	fn reset(&mut self) {
+		*self = Self::default();
+	}
+}
+
+impl FixedOutputReset for Vision32bByteHasher {
+	fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+		self.finalize_into_reset_inner(out);
+	}
+}
+
+/// Two-to-one compression function over digests of 8 `BinaryField32b` elements.
+///
+/// The two digests fill the rate portion of the state, the capacity is zero, and the output is
+/// the first 8 elements of the permuted state added to the first input (a truncated permutation
+/// with feed-forward).
+#[derive(Debug, Clone, Default)]
+pub struct Vision32bCompression;
+
+impl PseudoCompressionFunction<[BinaryField32b; 8], 2> for Vision32bCompression {
+	fn compress(&self, [left, right]: [[BinaryField32b; 8]; 2]) -> [BinaryField32b; 8] {
+		let left = PackedAESBinaryField8x32b::from_fn(|i| AESTowerField32b::from(left[i]));
+		let right = PackedAESBinaryField8x32b::from_fn(|i| AESTowerField32b::from(right[i]));
+		let mut state = [left, right, PackedAESBinaryField8x32b::zero()];
+		PERMUTATION.permute_mut(&mut state);
+
+		let out = state[0] + left;
+		std::array::from_fn(|i| BinaryField32b::from(out.get(i)))
+	}
+}
+
+impl CompressionFunction<[BinaryField32b; 8], 2> for Vision32bCompression {}
+
+/// [`Vision32bCompression`] of [`Vision32bByteHasher`] digests.
+///
+/// Each digest is read as 8 `BinaryField32b` elements, as in [`Vision32bByteHasher`].
+#[derive(Debug, Clone, Default)]
+pub struct Vision32bByteCompression;
+
+impl PseudoCompressionFunction<Output<Vision32bByteHasher>, 2> for Vision32bByteCompression {
+	fn compress(&self, input: [Output<Vision32bByteHasher>; 2]) -> Output<Vision32bByteHasher> {
+		let out = Vision32bCompression.compress(input.map(|digest| bytes_to_elems(&digest)));
+
+		let mut digest = Output::<Vision32bByteHasher>::default();
+		for (chunk, elem) in digest.chunks_exact_mut(4).zip(out) {
+			chunk.copy_from_slice(&elem.val().to_le_bytes());
+		}
+		digest
+	}
+}
+
+impl CompressionFunction<Output<Vision32bByteHasher>, 2> for Vision32bByteCompression {}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::Field;
+	use digest::Digest;
+	use rand::{thread_rng, RngCore};
+
+	use super::*;
+	use crate::Vision32bPermutation;
+
+	#[test]
+	fn test_multi_update_matches_single_update() {
+		let mut rng = thread_rng();
+		for len in [0, 1, 31, 63, 64, 65, 128, 200] {
+			let mut data = vec![0u8; len];
+			rng.fill_bytes(&mut data);
+
+			let expected = Vision32bByteHasher::digest(&data);
+			for split in [0, len / 3, len / 2, len] {
+				let mut hasher = Vision32bByteHasher::new();
+				Digest::update(&mut hasher, &data[..split]);
+				Digest::update(&mut hasher, &data[split..]);
+				assert_eq!(hasher.finalize(), expected);
+			}
+		}
+	}
+
+	#[test]
+	fn test_padding_distinguishes_trailing_zeros() {
+		let a = Vision32bByteHasher::digest([]);
+		let b = Vision32bByteHasher::digest([0u8]);
+		let c = Vision32bByteHasher::digest([0u8; RATE_AS_U8]);
+		assert_ne!(a, b);
+		assert_ne!(a, c);
+		assert_ne!(b, c);
+	}
+
+	#[test]
+	fn test_finalize_reset() {
+		let mut hasher = Vision32bByteHasher::new();
+		Digest::update(&mut hasher, b"some data");
+		let first = hasher.finalize_reset();
+		Digest::update(&mut hasher, b"some data");
+		assert_eq!(hasher.finalize(), first);
+	}
+
+	#[test]
+	fn test_compression_matches_permutation() {
+		let mut rng = thread_rng();
+		let mut input = [Output::<Vision32bByteHasher>::default(); 2];
+		for digest in &mut input {
+			rng.fill_bytes(digest.as_mut_slice());
+		}
+
+		let mut state = [BinaryField32b::ZERO; 24];
+		for (i, chunk) in input.concat().chunks_exact(4).enumerate() {
+			state[i] = BinaryField32b::new(u32::from_le_bytes(chunk.try_into().unwrap()));
+		}
+		let left = state;
+		Vision32bPermutation::default().permute_mut(&mut state);
+
+		let expected = (0..8)
+			.flat_map(|i| (state[i] + left[i]).val().to_le_bytes())
+			.collect::<Vec<_>>();
+		assert_eq!(Vision32bByteCompression.compress(input).as_slice(), &expected[..]);
+	}
+
+	#[test]
+	fn test_field_compression_matches_byte_compression() {
+		let mut rng = thread_rng();
+		let input: [[BinaryField32b; 8]; 2] = std::array::from_fn(|_| {
+			std::array::from_fn(|_| <BinaryField32b as Field>::random(&mut rng))
+		});
+
+		let byte_input = input.map(|digest| {
+			let mut bytes = Output::<Vision32bByteHasher>::default();
+			for (chunk, elem) in bytes.chunks_exact_mut(4).zip(digest) {
+				chunk.copy_from_slice(&elem.val().to_le_bytes());
+			}
+			bytes
+		});
+		assert_eq!(
+			bytes_to_elems(&Vision32bByteCompression.compress(byte_input)),
+			Vision32bCompression.compress(input)
+		);
+	}
+}
@@ -3,7 +3,7 @@
 use quote::{quote, ToTokens};
 use syn::{bracketed, parse::Parse, parse_quote, spanned::Spanned, Token};
 
-use crate::composition_poly::CompositionPolyItem;
+use crate::composition_poly::{pow_exponent, CompositionPolyItem};
 
 #[derive(Debug)]
 pub(crate) struct ArithCircuitPolyItem {
@@ -68,11 +68,11 @@ impl Parse for ArithCircuitPolyItem {
 		};
 		input.parse::<Token![=]>()?;
 		let poly_packed = input.parse::<syn::Expr>()?;
-		let poly = flatten_expr(&poly_packed, &vars)?;
 
 		input.parse::<Token![,]>()?;
 
 		let field_name = input.parse()?;
+		let poly = flatten_expr(&poly_packed, &vars, &field_name)?;
 		// Here we assume that the `composition_poly` shares the expression syntax with the `arithmetic_circuit_poly`.
 		let composition_poly = CompositionPolyItem::parse(&original_tokens)?;
 
@@ -84,14 +84,18 @@ impl Parse for ArithCircuitPolyItem {
 	}
 }
 
-fn flatten_expr(expr: &syn::Expr, vars: &[syn::Ident]) -> Result<syn::Expr, syn::Error> {
+fn flatten_expr(
+	expr: &syn::Expr,
+	vars: &[syn::Ident],
+	field_name: &syn::Ident,
+) -> Result<syn::Expr, syn::Error> {
 	match expr.clone() {
 		syn::Expr::Lit(exprlit) => {
 			if let syn::Lit::Int(int) = &exprlit.lit {
 				match &*int.to_string() {
 					"0" => Ok(parse_quote!(Expr::Const(Field::ZERO))),
 					"1" => Ok(parse_quote!(Expr::Const(Field::ONE))),
-					_ => Err(syn::Error::new(expr.span(), "Unsupported integer")),
+					_ => Ok(parse_quote!(Expr::Const(binius_field::#field_name::new(#int)))),
 				}
 			} else {
 				Err(syn::Error::new(expr.span(), "Unsupported literal"))
@@ -105,10 +109,15 @@ fn flatten_expr(expr: &syn::Expr, vars: &[syn::Ident]) -> Result<syn::Expr, syn:
 			}
 			Err(syn::Error::new(expr.span(), "Unknown variable"))
 		}
-		syn::Expr::Paren(paren) => flatten_expr(&paren.expr, vars),
+		syn::Expr::Paren(paren) => flatten_expr(&paren.expr, vars, field_name),
+		syn::Expr::MethodCall(method_call) => {
+			let base = flatten_expr(&method_call.receiver, vars, field_name)?;
+			let exp = pow_exponent(&method_call)?;
+			Ok(parse_quote!(#base.pow(#exp)))
+		}
 		syn::Expr::Binary(binary) => {
-			let left = flatten_expr(&binary.left, vars)?;
-			let right = flatten_expr(&binary.right, vars)?;
+			let left = flatten_expr(&binary.left, vars, field_name)?;
+			let right = flatten_expr(&binary.right, vars, field_name)?;
 			match binary.op {
 				syn::BinOp::Add(_) | syn::BinOp::Sub(_) => Ok(parse_quote!((#left + #right))),
 				syn::BinOp::Mul(_) => Ok(parse_quote!((#left * #right))),
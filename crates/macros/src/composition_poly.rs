@@ -30,6 +30,7 @@ impl ToTokens for CompositionPolyItem {
 		let mut eval_single = poly_packed.clone();
 		subst_vars(&mut eval_single, vars, &|i| parse_quote!(unsafe {*query.get_unchecked(#i)}))
 			.expect("Failed to substitute vars");
+		rewrite_packed_pow(&mut eval_single);
 
 		let mut eval_batch = poly_packed.clone();
 		subst_vars(
@@ -38,6 +39,7 @@ impl ToTokens for CompositionPolyItem {
 			&|i| parse_quote!(unsafe {*batch_query.get_unchecked(#i).get_unchecked(row)}),
 		)
 		.expect("Failed to substitute vars");
+		rewrite_packed_pow(&mut eval_batch);
 
 		let result = quote! {
 			#[derive(Debug, Clone, Copy)]
@@ -53,7 +55,7 @@ impl ToTokens for CompositionPolyItem {
 				}
 
 				fn binary_tower_level(&self) -> usize {
-					0
+					binius_math::ArithExpr::<#scalar_type>::binary_tower_level(&(#expr))
 				}
 
 				fn expression<FE: binius_field::ExtensionField<#scalar_type>>(&self) -> binius_math::ArithExpr<FE> {
@@ -151,13 +153,7 @@ impl Parse for CompositionPolyItem {
 		let mut poly_packed = input.parse::<syn::Expr>()?;
 		let mut expr = poly_packed.clone();
 
-		let degree = poly_degree(&poly_packed)?;
-		rewrite_literals(&mut poly_packed, &replace_packed_literals)?;
-
-		subst_vars(&mut expr, &vars, &|i| parse_quote!(binius_math::ArithExpr::Var(#i)))?;
-		rewrite_literals(&mut expr, &replace_expr_literals)?;
-
-		let scalar_type = if input.is_empty() {
+		let scalar_type: syn::Type = if input.is_empty() {
 			parse_quote!(binius_field::BinaryField1b)
 		} else {
 			input.parse::<Token![,]>()?;
@@ -165,6 +161,14 @@ impl Parse for CompositionPolyItem {
 			input.parse()?
 		};
 
+		let degree = poly_degree(&poly_packed)?;
+		rewrite_literals(&mut poly_packed, &|literal| {
+			replace_packed_literals(literal, &scalar_type)
+		})?;
+
+		subst_vars(&mut expr, &vars, &|i| parse_quote!(binius_math::ArithExpr::Var(#i)))?;
+		rewrite_literals(&mut expr, &|literal| replace_expr_literals(literal, &scalar_type))?;
+
 		Ok(Self {
 			is_anonymous,
 			name,
@@ -184,6 +188,9 @@ fn poly_degree(expr: &syn::Expr) -> Result<usize, syn::Error> {
 		syn::Expr::Lit(_) => 0,
 		syn::Expr::Path(_) => 1,
 		syn::Expr::Paren(paren) => poly_degree(&paren.expr)?,
+		syn::Expr::MethodCall(method_call) => {
+			poly_degree(&method_call.receiver)? * pow_exponent(&method_call)? as usize
+		}
 		syn::Expr::Binary(binary) => {
 			let op = binary.op;
 			let left = poly_degree(&binary.left)?;
@@ -200,24 +207,66 @@ fn poly_degree(expr: &syn::Expr) -> Result<usize, syn::Error> {
 	})
 }
 
-/// Replace literals to P::zero() and P::one() to be used in `evaluate` and `batch_evaluate`.
-fn replace_packed_literals(literal: &syn::LitInt) -> Result<syn::Expr, syn::Error> {
+/// Returns the exponent of a `base.pow(exp)` method call, the only method call supported in
+/// expressions.
+pub(crate) fn pow_exponent(method_call: &syn::ExprMethodCall) -> Result<u64, syn::Error> {
+	if method_call.method != "pow" || method_call.args.len() != 1 {
+		return Err(syn::Error::new(method_call.span(), "Unsupported method call"));
+	}
+	match &method_call.args[0] {
+		syn::Expr::Lit(syn::ExprLit {
+			lit: syn::Lit::Int(exp),
+			..
+		}) => exp.base10_parse(),
+		arg => Err(syn::Error::new(arg.span(), "Exponent must be an integer literal")),
+	}
+}
+
+/// Replace literals to P::zero(), P::one() and broadcast constants of the scalar type to be used
+/// in `evaluate` and `batch_evaluate`.
+fn replace_packed_literals(
+	literal: &syn::LitInt,
+	scalar_type: &syn::Type,
+) -> Result<syn::Expr, syn::Error> {
 	Ok(match &*literal.to_string() {
 		"0" => parse_quote!(P::zero()),
 		"1" => parse_quote!(P::one()),
-		_ => return Err(syn::Error::new(literal.span(), "Unsupported integer")),
+		_ => parse_quote!(P::broadcast(<#scalar_type>::new(#literal).into())),
 	})
 }
 
-/// Replace literals to Expr::zero() and Expr::one() to be used in `expression` method.
-fn replace_expr_literals(literal: &syn::LitInt) -> Result<syn::Expr, syn::Error> {
+/// Replace literals to Expr::zero(), Expr::one() and constants of the scalar type to be used in
+/// `expression` method.
+fn replace_expr_literals(
+	literal: &syn::LitInt,
+	scalar_type: &syn::Type,
+) -> Result<syn::Expr, syn::Error> {
 	Ok(match &*literal.to_string() {
 		"0" => parse_quote!(binius_math::ArithExpr::zero()),
 		"1" => parse_quote!(binius_math::ArithExpr::one()),
-		_ => return Err(syn::Error::new(literal.span(), "Unsupported integer")),
+		_ => parse_quote!(binius_math::ArithExpr::Const(<#scalar_type>::new(#literal))),
 	})
 }
 
+/// Replace `base.pow(exp)` method calls with calls to `PackedField::pow`, so that the generated
+/// code doesn't depend on the trait being in scope.
+fn rewrite_packed_pow(expr: &mut syn::Expr) {
+	match expr {
+		syn::Expr::Paren(paren) => rewrite_packed_pow(&mut paren.expr),
+		syn::Expr::Binary(binary) => {
+			rewrite_packed_pow(&mut binary.left);
+			rewrite_packed_pow(&mut binary.right);
+		}
+		syn::Expr::MethodCall(method_call) => {
+			rewrite_packed_pow(&mut method_call.receiver);
+			let receiver = &method_call.receiver;
+			let args = &method_call.args;
+			*expr = parse_quote!(binius_field::PackedField::pow(#receiver, #args));
+		}
+		_ => {}
+	}
+}
+
 /// Replace literals in an expression
 fn rewrite_literals(
 	expr: &mut syn::Expr,
@@ -232,6 +281,9 @@ fn rewrite_literals(
 		syn::Expr::Paren(paren) => {
 			rewrite_literals(&mut paren.expr, f)?;
 		}
+		syn::Expr::MethodCall(method_call) => {
+			rewrite_literals(&mut method_call.receiver, f)?;
+		}
 		syn::Expr::Binary(binary) => {
 			rewrite_literals(&mut binary.left, f)?;
 			rewrite_literals(&mut binary.right, f)?;
@@ -258,6 +310,7 @@ fn subst_vars(
 			Err(syn::Error::new(p.span(), "unknown variable"))
 		}
 		syn::Expr::Paren(paren) => subst_vars(&mut paren.expr, vars, f),
+		syn::Expr::MethodCall(method_call) => subst_vars(&mut method_call.receiver, vars, f),
 		syn::Expr::Binary(binary) => {
 			subst_vars(&mut binary.left, vars, f)?;
 			subst_vars(&mut binary.right, vars, f)
@@ -17,7 +17,11 @@ use crate::{
 };
 
 /// Useful for concisely creating structs that implement CompositionPolyOS.
-/// This currently only supports creating composition polynomials of tower level 0.
+///
+/// The expression is expanded into straight-line code that is monomorphized for every packed field
+/// type. It may use `+`, `-`, `*`, powers with constant exponents written as `x.pow(3)` and integer
+/// constants, which are interpreted as elements of the optional scalar type given after the
+/// expression (`BinaryField1b` by default).
 ///
 /// ```
 /// use binius_macros::composition_poly;
@@ -37,6 +41,12 @@ use crate::{
 ///         .evaluate(&[F::ONE, F::ONE, F::ONE]).unwrap(),
 ///     F::ZERO
 /// );
+///
+/// // Constants other than 0 and 1 require a scalar type of a large enough tower level
+/// use binius_field::BinaryField8b;
+/// composition_poly!(Cubic[x, y] = x.pow(3) + 3 * y, BinaryField8b);
+/// let (x, y) = (BinaryField8b::new(2), BinaryField8b::new(5));
+/// assert_eq!(Cubic.evaluate(&[x, y]).unwrap(), x * x * x + BinaryField8b::new(3) * y);
 /// ```
 #[proc_macro]
 pub fn composition_poly(input: TokenStream) -> TokenStream {
@@ -69,6 +79,24 @@ pub fn arith_expr(input: TokenStream) -> TokenStream {
 		.into()
 }
 
+/// Creates an `ArithCircuitPoly` together with compiled kernels for all the packed field types
+/// over the given scalar field, wrapped into a `CachedPoly`.
+///
+/// The expression syntax is the same as for [`composition_poly!`]. The kernels can be attached to
+/// the constraints of a circuit, so that the prover doesn't interpret the expression.
+///
+/// ```
+/// use binius_macros::arith_circuit_poly;
+/// use binius_field::{BinaryField8b, PackedBinaryField16x8b as P, PackedField};
+/// use binius_math::CompositionPolyOS;
+///
+/// let composition = arith_circuit_poly!([x, y] = x.pow(3) * y + 5, BinaryField8b);
+/// let (x, y) = (P::broadcast(BinaryField8b::new(2)), P::one());
+/// assert_eq!(
+///     CompositionPolyOS::<P>::evaluate(&composition, &[x, y]).unwrap(),
+///     x.pow(3) * y + P::broadcast(BinaryField8b::new(5))
+/// );
+/// ```
 #[proc_macro]
 pub fn arith_circuit_poly(input: TokenStream) -> TokenStream {
 	parse_macro_input!(input as ArithCircuitPolyItem)
@@ -74,3 +74,37 @@ test_arithmetic_poly!(
 );
 
 test_arithmetic_poly!(PackedBinaryPolyval1x128b, BinaryField1b, BinaryField128bPolyval,);
+
+#[test]
+fn test_constants_and_powers() {
+	type P = PackedBinaryField8x16b;
+
+	let circuit =
+		arith_circuit_poly!([x0, x1] = x0.pow(3) * x1 + 3 * (x0 + x1).pow(2) + 0x35, BinaryField8b);
+	assert_eq!(CompositionPolyOS::<P>::degree(&circuit), 4);
+	assert_eq!(CompositionPolyOS::<P>::binary_tower_level(&circuit), 3);
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let query = [
+		std::array::from_fn::<_, BATCH_SIZE, _>(|_| P::random(&mut rng)),
+		std::array::from_fn::<_, BATCH_SIZE, _>(|_| P::random(&mut rng)),
+	];
+	let query_data = query.iter().map(|q| &q[..]).collect::<Vec<_>>();
+	let expected = std::array::from_fn::<_, BATCH_SIZE, _>(|i| {
+		let x0 = query[0][i];
+		let x1 = query[1][i];
+		x0.pow(3) * x1
+			+ P::broadcast(BinaryField8b::new(3).into()) * (x0 + x1).square()
+			+ P::broadcast(BinaryField8b::new(0x35).into())
+	});
+
+	for (i, &expected) in expected.iter().enumerate() {
+		assert_eq!(circuit.evaluate(&[query[0][i], query[1][i]]).unwrap(), expected);
+	}
+
+	let mut batch_result = vec![P::zero(); BATCH_SIZE];
+	circuit
+		.batch_evaluate(&query_data, &mut batch_result)
+		.unwrap();
+	assert_eq!(&batch_result, &expected);
+}
@@ -3,11 +3,11 @@
 use std::{
 	cmp::max,
 	fmt::{self, Display},
-	iter::{Product, Sum},
+	iter::{self, Product, Sum},
 	ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
 };
 
-use binius_field::{Field, PackedField};
+use binius_field::{Field, PackedField, TowerField};
 
 use super::error::Error;
 
@@ -16,7 +16,7 @@ use super::error::Error;
 /// Arithmetic expressions are trees, where the leaves are either constants or variables, and the
 /// non-leaf nodes are arithmetic operations, such as addition, multiplication, etc. They are
 /// specific representations of multivariate polynomials.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ArithExpr<F: Field> {
 	Const(F),
 	Var(usize),
@@ -59,6 +59,21 @@ impl<F: Field> ArithExpr<F> {
 		}
 	}
 
+	/// The smallest tower level of a subfield containing all the constants of the expression.
+	pub fn binary_tower_level(&self) -> usize
+	where
+		F: TowerField,
+	{
+		match self {
+			Self::Const(value) => value.min_tower_level(),
+			Self::Var(_) => 0,
+			Self::Add(left, right) | Self::Mul(left, right) => {
+				max(left.binary_tower_level(), right.binary_tower_level())
+			}
+			Self::Pow(base, _) => base.binary_tower_level(),
+		}
+	}
+
 	pub fn pow(self, exp: u64) -> Self {
 		Self::Pow(Box::new(self), exp)
 	}
@@ -163,9 +178,39 @@ impl<F: Field> ArithExpr<F> {
 		}
 	}
 
+	/// Returns the variables of the expression as a product of variables.
+	///
+	/// If the expression is a product of variables and powers of products of variables, returns
+	/// the indices of the factors, with a variable repeated as many times as its exponent. Returns
+	/// `None` otherwise, including for expressions with constants other than one.
+	pub fn product_factors(&self) -> Option<Vec<usize>> {
+		fn collect<F: Field>(expr: &ArithExpr<F>, exp: usize, factors: &mut Vec<usize>) -> bool {
+			match expr {
+				ArithExpr::Var(index) => {
+					factors.extend(iter::repeat_n(*index, exp));
+					true
+				}
+				ArithExpr::Mul(left, right) => {
+					collect(left, exp, factors) && collect(right, exp, factors)
+				}
+				ArithExpr::Pow(base, base_exp) => usize::try_from(*base_exp)
+					.ok()
+					.and_then(|base_exp| base_exp.checked_mul(exp))
+					.is_some_and(|exp| collect(base, exp, factors)),
+				ArithExpr::Const(value) => *value == F::ONE,
+				ArithExpr::Add(_, _) => false,
+			}
+		}
+
+		let mut factors = Vec::new();
+		(collect(self, 1, &mut factors) && !factors.is_empty()).then_some(factors)
+	}
+
 	/// Creates a new optimized expression.
 	///
-	/// Recursively rewrites expression for better evaluation performance.
+	/// Recursively rewrites expression for better evaluation performance. Apart from constant
+	/// folding, this applies the identities that hold in binary fields, such as $x + x = 0$, and
+	/// merges products of the same sub-expression into powers, eg. $x^2 \cdot x = x^3$.
 	pub fn optimize(&self) -> Self {
 		match self {
 			Self::Const(_) | Self::Var(_) => self.clone(),
@@ -174,6 +219,11 @@ impl<F: Field> ArithExpr<F> {
 				let right = right.optimize();
 				match (left, right) {
 					(Self::Const(left), Self::Const(right)) => Self::Const(left + right),
+					(Self::Const(zero), other) | (other, Self::Const(zero)) if zero == F::ZERO => {
+						other
+					}
+					// Addition is idempotent in characteristic 2
+					(left, right) if left == right => Self::zero(),
 					(left, right) => Self::Add(Box::new(left), Box::new(right)),
 				}
 			}
@@ -182,19 +232,192 @@ impl<F: Field> ArithExpr<F> {
 				let right = right.optimize();
 				match (left, right) {
 					(Self::Const(left), Self::Const(right)) => Self::Const(left * right),
-					(left, right) => Self::Mul(Box::new(left), Box::new(right)),
+					(Self::Const(zero), _) | (_, Self::Const(zero)) if zero == F::ZERO => {
+						Self::zero()
+					}
+					(Self::Const(one), other) | (other, Self::Const(one)) if one == F::ONE => other,
+					(left, right) => {
+						let (left_base, left_exp) = left.into_base_and_exp();
+						let (right_base, right_exp) = right.into_base_and_exp();
+						if left_base == right_base {
+							Self::Pow(Box::new(left_base), left_exp + right_exp)
+						} else {
+							Self::Mul(
+								Box::new(left_base.with_exp(left_exp)),
+								Box::new(right_base.with_exp(right_exp)),
+							)
+						}
+					}
 				}
 			}
 			Self::Pow(id, exp) => {
 				let id = id.optimize();
-				match id {
-					Self::Const(value) => Self::Const(PackedField::pow(value, *exp)),
-					Self::Pow(id_inner, exp_inner) => Self::Pow(id_inner, *exp * exp_inner),
-					id => Self::Pow(Box::new(id), *exp),
+				match (id, *exp) {
+					(_, 0) => Self::one(),
+					(id, 1) => id,
+					(Self::Const(value), exp) => Self::Const(PackedField::pow(value, exp)),
+					(Self::Pow(id_inner, exp_inner), exp) => Self::Pow(id_inner, exp * exp_inner),
+					(id, exp) => Self::Pow(Box::new(id), exp),
 				}
 			}
 		}
 	}
+
+	/// Reduces the exponents of variables that take values in subfields.
+	///
+	/// A variable with values in the binary tower subfield of order $q = 2^{2^\iota}$, where
+	/// $\iota$ is the tower level, satisfies $x^q = x$. In particular $x^2 = x$ for variables taking
+	/// values in $\mathbb{F}_2$. `var_tower_levels[i]` is the tower level of the values of the
+	/// variable with index `i`, variables without an entry are left untouched.
+	///
+	/// The expression is optimized before and after the reduction, so that products of the same
+	/// variable are combined into a single power.
+	pub fn reduce_subfield_powers(&self, var_tower_levels: &[usize]) -> Self {
+		fn reduce<F: Field>(expr: ArithExpr<F>, var_tower_levels: &[usize]) -> ArithExpr<F> {
+			match expr {
+				ArithExpr::Const(_) | ArithExpr::Var(_) => expr,
+				ArithExpr::Add(left, right) => {
+					reduce(*left, var_tower_levels) + reduce(*right, var_tower_levels)
+				}
+				ArithExpr::Mul(left, right) => {
+					reduce(*left, var_tower_levels) * reduce(*right, var_tower_levels)
+				}
+				ArithExpr::Pow(base, exp) => match *base {
+					ArithExpr::Var(index) => {
+						// The multiplicative group of the subfield has order 2^{2^level} - 1, which
+						// only fits into the exponent type for small tower levels.
+						let group_order = var_tower_levels
+							.get(index)
+							.and_then(|&level| 1u32.checked_shl(level as u32))
+							.and_then(|bits| 1u64.checked_shl(bits))
+							.map(|order| order - 1);
+						let exp = match group_order {
+							Some(group_order) if exp > 0 => (exp - 1) % group_order + 1,
+							_ => exp,
+						};
+						ArithExpr::Var(index).pow(exp)
+					}
+					base => reduce(base, var_tower_levels).pow(exp),
+				},
+			}
+		}
+
+		reduce(self.optimize(), var_tower_levels).optimize()
+	}
+
+	/// Rewrites the expression so that its degree doesn't exceed `max_degree` by introducing
+	/// intermediate variables.
+	///
+	/// Returns the rewritten expression and the definitions of the intermediate variables. The
+	/// intermediate variable with index `first_new_var + i` stands for the `i`-th definition, which
+	/// may refer to the original variables and to the preceding intermediate variables. The degree
+	/// of every definition doesn't exceed `max_degree` either.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::ArgumentRangeError`] if `max_degree` is less than 2, or if `first_new_var` is
+	///   less than the number of variables of the expression
+	pub fn reduce_degree(
+		&self,
+		max_degree: usize,
+		first_new_var: usize,
+	) -> Result<(Self, Vec<Self>), Error> {
+		if max_degree < 2 {
+			return Err(Error::ArgumentRangeError {
+				arg: "max_degree".to_string(),
+				range: 2..usize::MAX,
+			});
+		}
+		if first_new_var < self.n_vars() {
+			return Err(Error::ArgumentRangeError {
+				arg: "first_new_var".to_string(),
+				range: self.n_vars()..usize::MAX,
+			});
+		}
+
+		let mut definitions = Vec::new();
+		let expr = self
+			.optimize()
+			.reduce_degree_inner(max_degree, first_new_var, &mut definitions);
+		Ok((expr, definitions))
+	}
+
+	fn reduce_degree_inner(
+		self,
+		max_degree: usize,
+		first_new_var: usize,
+		definitions: &mut Vec<Self>,
+	) -> Self {
+		let define = |definition: Self, definitions: &mut Vec<Self>| {
+			debug_assert!(definition.degree() <= max_degree);
+			definitions.push(definition);
+			Self::Var(first_new_var + definitions.len() - 1)
+		};
+
+		match self {
+			Self::Const(_) | Self::Var(_) => self,
+			Self::Add(left, right) => {
+				let left = left.reduce_degree_inner(max_degree, first_new_var, definitions);
+				let right = right.reduce_degree_inner(max_degree, first_new_var, definitions);
+				left + right
+			}
+			Self::Mul(left, right) => {
+				let mut left = left.reduce_degree_inner(max_degree, first_new_var, definitions);
+				let mut right = right.reduce_degree_inner(max_degree, first_new_var, definitions);
+				// Replace the operand of the higher degree with a variable until the product is
+				// within the bound. Both operands have degree at most `max_degree` here, so this
+				// terminates after at most two replacements.
+				while left.degree() + right.degree() > max_degree {
+					if left.degree() >= right.degree() {
+						left = define(left, definitions);
+					} else {
+						right = define(right, definitions);
+					}
+				}
+				left * right
+			}
+			Self::Pow(base, exp) => {
+				let mut base = base.reduce_degree_inner(max_degree, first_new_var, definitions);
+				let pow_degree = |base: &Self| base.degree().saturating_mul(exp as usize);
+				if pow_degree(&base) > max_degree && base.degree() > 1 {
+					base = define(base, definitions);
+				}
+				if pow_degree(&base) <= max_degree {
+					return base.pow(exp);
+				}
+
+				// The base is linear here, so x^exp = (x^max_degree)^(exp / max_degree) *
+				// x^(exp % max_degree) reduces the degree.
+				let max_exp = max_degree as u64;
+				let high = define(base.clone().pow(max_exp), definitions)
+					.pow(exp / max_exp)
+					.reduce_degree_inner(max_degree, first_new_var, definitions);
+				match exp % max_exp {
+					0 => high,
+					low_exp => (high * base.pow(low_exp)).reduce_degree_inner(
+						max_degree,
+						first_new_var,
+						definitions,
+					),
+				}
+			}
+		}
+	}
+
+	/// Splits the expression into a base and an exponent, such that `base^exp` is the expression.
+	fn into_base_and_exp(self) -> (Self, u64) {
+		match self {
+			Self::Pow(base, exp) => (*base, exp),
+			expr => (expr, 1),
+		}
+	}
+
+	fn with_exp(self, exp: u64) -> Self {
+		match exp {
+			1 => self,
+			_ => self.pow(exp),
+		}
+	}
 }
 
 impl<F> Default for ArithExpr<F>
@@ -282,9 +505,92 @@ impl<F: Field> Product for ArithExpr<F> {
 mod tests {
 	use assert_matches::assert_matches;
 	use binius_field::{BinaryField128b, BinaryField1b, BinaryField8b};
+	use rand::{rngs::StdRng, SeedableRng};
 
 	use super::*;
 
+	fn evaluate<F: Field>(expr: &ArithExpr<F>, vars: &[F]) -> F {
+		match expr {
+			ArithExpr::Const(value) => *value,
+			ArithExpr::Var(index) => vars[*index],
+			ArithExpr::Add(left, right) => evaluate(left, vars) + evaluate(right, vars),
+			ArithExpr::Mul(left, right) => evaluate(left, vars) * evaluate(right, vars),
+			ArithExpr::Pow(base, exp) => PackedField::pow(evaluate(base, vars), *exp),
+		}
+	}
+
+	#[test]
+	fn test_optimize_binary_field_identities() {
+		type F = BinaryField8b;
+		let x = || ArithExpr::<F>::Var(0);
+		let y = || ArithExpr::<F>::Var(1);
+
+		assert_eq!((x() + x()).optimize(), ArithExpr::zero());
+		assert_eq!((x() * y() + x() * y()).optimize(), ArithExpr::zero());
+		assert_eq!((x() + ArithExpr::zero()).optimize(), x());
+		assert_eq!((ArithExpr::one() * x()).optimize(), x());
+		assert_eq!((x() * ArithExpr::zero() + y()).optimize(), y());
+		assert_eq!((x().pow(2) * x()).optimize(), x().pow(3));
+		assert_eq!((x() * x() * y()).optimize(), x().pow(2) * y());
+		assert_eq!(x().pow(1).optimize(), x());
+		assert_eq!(x().pow(0).optimize(), ArithExpr::one());
+	}
+
+	#[test]
+	fn test_reduce_subfield_powers() {
+		type F = BinaryField128b;
+		let x = || ArithExpr::<F>::Var(0);
+		let y = || ArithExpr::<F>::Var(1);
+		let z = || ArithExpr::<F>::Var(2);
+
+		// x is boolean, y is in the 8-bit subfield and z is unrestricted
+		let levels = [0, 3];
+		assert_eq!((x() * x() * y()).reduce_subfield_powers(&levels), x() * y());
+		assert_eq!((x().pow(5) + y().pow(256)).reduce_subfield_powers(&levels), x() + y());
+		assert_eq!(y().pow(255).reduce_subfield_powers(&levels), y().pow(255));
+		assert_eq!(z().pow(2).reduce_subfield_powers(&levels), z().pow(2));
+		assert_eq!(z().pow(2).reduce_subfield_powers(&[0, 3, 7]), z().pow(2));
+
+		// The boolean check constraint is trivially satisfied for boolean variables
+		assert_eq!((x() * x() - x()).reduce_subfield_powers(&levels), ArithExpr::zero());
+		assert_eq!((x().pow(0)).reduce_subfield_powers(&levels), ArithExpr::one());
+	}
+
+	#[test]
+	fn test_reduce_degree() {
+		type F = BinaryField128b;
+		let var = ArithExpr::<F>::Var;
+
+		let expr = var(0).pow(7) * var(1) * var(2) * (var(3) + ArithExpr::Const(F::new(3)))
+			+ (var(0) * var(1)).pow(3)
+			+ var(2) * var(3);
+		let n_vars = expr.n_vars();
+
+		let mut rng = StdRng::seed_from_u64(0);
+		for max_degree in 2..=5 {
+			let (reduced, definitions) = expr.reduce_degree(max_degree, n_vars).unwrap();
+			assert!(reduced.degree() <= max_degree);
+			assert!(definitions.iter().all(|def| def.degree() <= max_degree));
+
+			let mut vars = (0..n_vars)
+				.map(|_| <F as Field>::random(&mut rng))
+				.collect::<Vec<_>>();
+			for definition in &definitions {
+				let value = evaluate(definition, &vars);
+				vars.push(value);
+			}
+			assert_eq!(evaluate(&reduced, &vars), evaluate(&expr, &vars[..n_vars]));
+		}
+
+		// Expressions within the bound are left untouched
+		let (reduced, definitions) = expr.reduce_degree(10, n_vars).unwrap();
+		assert_eq!(reduced, expr.optimize());
+		assert!(definitions.is_empty());
+
+		assert_matches!(expr.reduce_degree(1, n_vars), Err(Error::ArgumentRangeError { .. }));
+		assert_matches!(expr.reduce_degree(2, 2), Err(Error::ArgumentRangeError { .. }));
+	}
+
 	#[test]
 	fn test_degree_with_pow() {
 		let expr = ArithExpr::Const(BinaryField8b::new(6)).pow(7);
@@ -297,6 +603,34 @@ mod tests {
 		assert_eq!(expr.degree(), 14);
 	}
 
+	#[test]
+	fn test_binary_tower_level() {
+		type F = BinaryField128b;
+
+		let expr = ArithExpr::<F>::Var(0) * ArithExpr::Var(1) + ArithExpr::one();
+		assert_eq!(expr.binary_tower_level(), 0);
+
+		let expr = (ArithExpr::Var(0) + ArithExpr::Const(F::from(BinaryField8b::new(0x10))))
+			* ArithExpr::Var(1);
+		assert_eq!(expr.binary_tower_level(), 3);
+
+		let expr = ArithExpr::<F>::Var(0).pow(2) * ArithExpr::Const(F::new(1 << 64));
+		assert_eq!(expr.binary_tower_level(), 7);
+	}
+
+	#[test]
+	fn test_product_factors() {
+		type F = BinaryField8b;
+
+		let expr = ArithExpr::<F>::Var(0) * (ArithExpr::Var(1) * ArithExpr::Var(2)).pow(2);
+		assert_eq!(expr.product_factors(), Some(vec![0, 1, 1, 2, 2]));
+		assert_eq!(ArithExpr::<F>::Var(3).product_factors(), Some(vec![3]));
+		assert_eq!((ArithExpr::<F>::Var(0) + ArithExpr::Var(1)).product_factors(), None);
+		assert_eq!((ArithExpr::Const(F::new(3)) * ArithExpr::Var(1)).product_factors(), None);
+		assert_eq!((ArithExpr::Const(F::ONE) * ArithExpr::Var(1)).product_factors(), Some(vec![1]));
+		assert_eq!(ArithExpr::<F>::Var(0).pow(0).product_factors(), None);
+	}
+
 	#[test]
 	fn test_remap_vars_with_too_few_vars() {
 		type F = BinaryField8b;
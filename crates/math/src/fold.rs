@@ -269,17 +269,17 @@ where
 				fn call(&mut self, iterator: impl Iterator<Item = u8>) {
 					let log_tables_count = LOG_QUERY_SIZE - 3;
 					let tables_count = 1 << log_tables_count;
-					for (current_index, byte) in iterator.enumerate() {
+					for (byte_index, byte) in iterator.enumerate() {
 						self.current_value +=
 							self.cached_tables[(self.current_table << 8) + byte as usize];
 						self.current_table += 1;
 
 						if self.current_table == tables_count {
-							// Safety: `i` is less than `chunk_size`
+							// Safety: `byte_index >> log_tables_count` is less than `chunk_size`
 							unsafe {
 								set_packed_slice_unchecked(
 									self.chunk,
-									current_index,
+									byte_index >> log_tables_count,
 									self.current_value,
 								);
 							}
@@ -701,6 +701,27 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_1b_128b_optimal() {
+		const LOG_EVALS_SIZE: usize = 14;
+		let mut rng = StdRng::seed_from_u64(0);
+		let evals = repeat_with(|| PackedBinaryField128x1b::random(&mut rng))
+			.take(1 << LOG_EVALS_SIZE)
+			.collect::<Vec<_>>();
+		let query = repeat_with(|| B128bOptimal::random(&mut rng))
+			.take(1 << (10 - B128bOptimal::LOG_WIDTH))
+			.collect::<Vec<_>>();
+
+		for log_query_size in 0..10 {
+			check_fold_right(
+				&evals,
+				LOG_EVALS_SIZE + PackedBinaryField128x1b::LOG_WIDTH,
+				&query,
+				log_query_size,
+			);
+		}
+	}
+
 	#[test]
 	fn test_8b_small_poly() {
 		const LOG_EVALS_SIZE: usize = 5;
@@ -300,6 +300,10 @@ where
 		Arc::new(self)
 	}
 
+	pub fn into_inner(self) -> MultilinearExtension<P, Data> {
+		self.0
+	}
+
 	/// Given a ($mu$-variate) multilinear function $f$ and an element $r$,
 	/// return the multilinear function $f(r, X_1, ..., X_{\mu - 1})$.
 	pub fn evaluate_zeroth_variable(&self, r: P::Scalar) -> Result<MultilinearExtension<P>, Error> {
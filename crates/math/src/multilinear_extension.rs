@@ -119,6 +119,10 @@ impl<P: PackedField, Data: Deref<Target = [P]>> MultilinearExtension<P, Data> {
 		&self.evals
 	}
 
+	pub fn into_evals(self) -> Data {
+		self.evals
+	}
+
 	pub fn to_ref(&self) -> MultilinearExtension<P, &[P]> {
 		MultilinearExtension {
 			mu: self.mu,
@@ -71,11 +71,14 @@ impl<I: Iterator, R, F: Fn(I::Item) -> R> IndexedParallelIteratorInner for std::
 impl<T> IndexedParallelIteratorInner for std::vec::IntoIter<T> {}
 impl<T, const N: usize> IndexedParallelIteratorInner for std::array::IntoIter<T, N> {}
 
-#[allow(private_bounds)]
+#[allow(private_bounds, clippy::len_without_is_empty)]
 pub trait IndexedParallelIterator: ParallelIterator {
 	type Inner: IndexedParallelIteratorInner<Item = Self::Item>;
 	fn into_inner(self) -> <Self as IndexedParallelIterator>::Inner;
 
+	/// Returns the exact number of items the iterator yields.
+	fn len(&self) -> usize;
+
 	#[inline(always)]
 	fn with_min_len(self, min: usize) -> impl IndexedParallelIterator<Item = Self::Item>
 	where
@@ -140,11 +143,19 @@ impl<I: IndexedParallelIteratorInner> IndexedParallelIterator for ParallelWrappe
 	fn into_inner(self) -> I {
 		self.0
 	}
+
+	#[inline]
+	fn len(&self) -> usize {
+		let (lower, upper) = self.0.size_hint();
+		debug_assert_eq!(Some(lower), upper, "indexed iterators must have an exact length");
+		lower
+	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::slice::ParallelSlice;
 
 	#[test]
 	fn check_zip() {
@@ -171,6 +182,14 @@ mod tests {
 		assert_eq!(result, vec![1, 3, 5, 7, 9]);
 	}
 
+	#[test]
+	fn check_len() {
+		let a = &[1, 2, 3, 4, 5, 6, 7];
+
+		assert_eq!(a.par_chunks(2).map(|chunk| chunk.len()).len(), 4);
+		assert_eq!((0..10).into_par_iter().step_by(3).len(), 4);
+	}
+
 	#[test]
 	fn check_map() {
 		let a = &[1, 2, 3];
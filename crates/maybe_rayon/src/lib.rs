@@ -38,6 +38,14 @@ cfg_if::cfg_if! {
 			pub const fn num_threads(self, _num_threads: usize) -> Self {
 				Self()
 			}
+
+			#[inline(always)]
+			pub fn start_handler<H>(self, _start_handler: H) -> Self
+			where
+				H: Fn(usize) + Send + Sync + 'static,
+			{
+				Self()
+			}
 		}
 
 		#[derive(Debug)]
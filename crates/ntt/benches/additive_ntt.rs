@@ -61,6 +61,17 @@ fn bench_helper<P, BT: BenchTransformationFunc>(
 		.precompute_twiddles()
 		.multithreaded();
 	BT::run_bench(group, &ntt, &mut data, "multithread/precompute", &params, log_batch_size);
+
+	let ntt = SingleThreadedNTT::<P::Scalar>::new(log_n)
+		.unwrap()
+		.blocked();
+	BT::run_bench(group, &ntt, &mut data, "blocked/on-the-fly", &params, log_batch_size);
+
+	let ntt = SingleThreadedNTT::<P::Scalar>::new(log_n)
+		.unwrap()
+		.precompute_twiddles()
+		.blocked();
+	BT::run_bench(group, &ntt, &mut data, "blocked/precompute", &params, log_batch_size);
 }
 
 fn run_benchmarks_on_packed_fields<BT: BenchTransformationFunc>(c: &mut Criterion, name: &str) {
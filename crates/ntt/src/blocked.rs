@@ -0,0 +1,341 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::mem;
+
+use binius_field::{BinaryField, PackedField};
+
+use super::{
+	error::Error,
+	single_threaded::{self, check_batch_transform_inputs, NTTParams},
+	twiddle::{PrecomputedTwiddleAccess, TwiddleAccess},
+	AdditiveNTT, SingleThreadedNTT,
+};
+
+/// Default base-2 logarithm of the working set size in bytes, sized to fit in a typical L2 cache.
+pub const DEFAULT_LOG_BLOCK_BYTES: usize = 18;
+
+/// Default base-2 logarithm of the number of butterfly layers fused into one pass over memory.
+pub const DEFAULT_LOG_RADIX: usize = 6;
+
+/// Implementation of `AdditiveNTT` that processes several butterfly layers per pass over memory.
+///
+/// [`SingleThreadedNTT`] streams the whole buffer through the cache once per layer, which makes
+/// transforms over buffers much larger than the cache memory-bound. This implementation is a
+/// recursive four-step (radix-$2^r$) variant: viewing the buffer as a matrix with $2^r$ rows, the
+/// top $r$ layers only mix elements within the same column, so they are applied to column tiles
+/// that fit in cache, one tile at a time. The remaining layers are independent NTTs on each row,
+/// which are transformed recursively until they fit in a cache block and can be handed to the
+/// single-threaded kernel.
+#[derive(Debug)]
+pub struct BlockedNTT<F: BinaryField, TA: TwiddleAccess<F> = PrecomputedTwiddleAccess<F>> {
+	single_threaded: SingleThreadedNTT<F, TA>,
+	log_block_bytes: usize,
+	log_radix: usize,
+}
+
+impl<F: BinaryField, TA: TwiddleAccess<F>> BlockedNTT<F, TA> {
+	/// Base-2 logarithm of the size of the NTT domain.
+	pub fn log_domain_size(&self) -> usize {
+		self.single_threaded.log_domain_size()
+	}
+
+	/// Get the normalized subspace polynomial evaluation $\hat{W}_i(\beta_j)$.
+	///
+	/// ## Preconditions
+	///
+	/// * `i` must be less than `self.log_domain_size()`
+	/// * `j` must be less than `self.log_domain_size() - i`
+	pub fn get_subspace_eval(&self, i: usize, j: usize) -> F {
+		self.single_threaded.get_subspace_eval(i, j)
+	}
+
+	/// Base-2 logarithm of the number of packed elements of type `P` in a cache block.
+	const fn log_block_len<P>(&self) -> usize {
+		self.log_block_bytes
+			.saturating_sub(mem::size_of::<P>().ilog2() as usize)
+	}
+}
+
+impl<F: BinaryField, TA: TwiddleAccess<F>> SingleThreadedNTT<F, TA> {
+	/// Returns a cache-blocked NTT implementation with the default block size and radix.
+	pub const fn blocked(self) -> BlockedNTT<F, TA> {
+		self.blocked_with_params(DEFAULT_LOG_BLOCK_BYTES, DEFAULT_LOG_RADIX)
+	}
+
+	/// Returns a cache-blocked NTT implementation that works on blocks of `1 << log_block_bytes`
+	/// bytes and fuses up to `log_radix` layers per pass over memory.
+	///
+	/// ## Preconditions
+	///
+	/// * `log_radix` must be greater than zero
+	pub const fn blocked_with_params(
+		self,
+		log_block_bytes: usize,
+		log_radix: usize,
+	) -> BlockedNTT<F, TA> {
+		assert!(log_radix > 0, "log_radix must be greater than zero");
+		BlockedNTT {
+			single_threaded: self,
+			log_block_bytes,
+			log_radix,
+		}
+	}
+}
+
+impl<F, TA, P> AdditiveNTT<P> for BlockedNTT<F, TA>
+where
+	F: BinaryField,
+	TA: TwiddleAccess<F>,
+	P: PackedField<Scalar = F>,
+{
+	fn log_domain_size(&self) -> usize {
+		self.log_domain_size()
+	}
+
+	fn get_subspace_eval(&self, i: usize, j: usize) -> F {
+		self.get_subspace_eval(i, j)
+	}
+
+	fn forward_transform(
+		&self,
+		data: &mut [P],
+		coset: u32,
+		log_batch_size: usize,
+	) -> Result<(), Error> {
+		let log_block_len = self.log_block_len::<P>();
+		if data.len() <= 1 << log_block_len {
+			return self
+				.single_threaded
+				.forward_transform(data, coset, log_batch_size);
+		}
+
+		let NTTParams { log_n, .. } =
+			check_batch_transform_inputs(self.log_domain_size(), data, coset, log_batch_size)?;
+		forward_transform(
+			self.log_domain_size(),
+			self.single_threaded.twiddles(),
+			data,
+			coset,
+			log_batch_size,
+			log_n,
+			log_block_len,
+			self.log_radix,
+		)
+	}
+
+	fn inverse_transform(
+		&self,
+		data: &mut [P],
+		coset: u32,
+		log_batch_size: usize,
+	) -> Result<(), Error> {
+		let log_block_len = self.log_block_len::<P>();
+		if data.len() <= 1 << log_block_len {
+			return self
+				.single_threaded
+				.inverse_transform(data, coset, log_batch_size);
+		}
+
+		let NTTParams { log_n, .. } =
+			check_batch_transform_inputs(self.log_domain_size(), data, coset, log_batch_size)?;
+		inverse_transform(
+			self.log_domain_size(),
+			self.single_threaded.twiddles(),
+			data,
+			coset,
+			log_batch_size,
+			log_n,
+			log_block_len,
+			self.log_radix,
+		)
+	}
+}
+
+/// Parameters of one pass over memory that applies the top layers of a transform.
+struct BlockedPass<P> {
+	/// Base-2 logarithm of the number of matrix rows, equal to the number of fused layers.
+	log_rows: usize,
+	/// Base-2 logarithm of the number of packed elements in a row.
+	log_width: usize,
+	/// Base-2 logarithm of the number of packed elements in a row of a cache-resident tile.
+	log_tile_width: usize,
+	/// Twiddles of the fused layers, from the top layer down. Layer `i` occupies
+	/// `1 << (log_rows - 1 - i)` entries starting at offset `(1 << (log_rows - 1 - i)) - 1`.
+	twiddles: Vec<P>,
+}
+
+impl<P: PackedField<Scalar: BinaryField>> BlockedPass<P> {
+	/// Plans the pass and precomputes its twiddles, or returns `None` if the transform fits in a
+	/// cache block or has no layers that act across packed elements.
+	#[allow(clippy::too_many_arguments)]
+	fn new(
+		log_domain_size: usize,
+		s_evals: &[impl TwiddleAccess<P::Scalar>],
+		data_len: usize,
+		coset: u32,
+		log_batch_size: usize,
+		log_n: usize,
+		log_block_len: usize,
+		log_radix: usize,
+	) -> Option<Self> {
+		let cutoff = P::LOG_WIDTH.saturating_sub(log_batch_size);
+		if data_len <= 1 << log_block_len || log_n <= cutoff {
+			return None;
+		}
+
+		let log_rows = log_radix.min(log_n - cutoff);
+		let log_width = data_len.ilog2() as usize - log_rows;
+		let log_tile_width = log_block_len.saturating_sub(log_rows).min(log_width);
+
+		let mut twiddles = Vec::with_capacity((1 << log_rows) - 1);
+		for i in (0..log_rows).rev() {
+			let coset_twiddle =
+				s_evals[log_n - log_rows + i].coset(log_domain_size - log_n, coset as usize);
+			twiddles
+				.extend((0..1 << (log_rows - 1 - i)).map(|j| P::broadcast(coset_twiddle.get(j))));
+		}
+
+		Some(Self {
+			log_rows,
+			log_width,
+			log_tile_width,
+			twiddles,
+		})
+	}
+
+	fn layer_twiddles(&self, i: usize) -> &[P] {
+		let len = 1 << (self.log_rows - 1 - i);
+		&self.twiddles[len - 1..2 * len - 1]
+	}
+
+	/// Applies `butterfly` to all pairs of rows in every layer, tile by tile. Layers are visited
+	/// in the order given by `layers`.
+	fn apply(
+		&self,
+		data: &mut [P],
+		layers: impl Iterator<Item = usize> + Clone,
+		butterfly: impl Fn(&mut P, &mut P, P),
+	) {
+		let log_width = self.log_width;
+		for tile_start in (0..1 << log_width).step_by(1 << self.log_tile_width) {
+			let tile = tile_start..tile_start + (1 << self.log_tile_width);
+			for i in layers.clone() {
+				for (j, &twiddle) in self.layer_twiddles(i).iter().enumerate() {
+					for k in 0..1 << i {
+						let row0 = (j << (i + 1) | k) << log_width;
+						let row1 = row0 | 1 << (i + log_width);
+						for l in tile.clone() {
+							let (mut u, mut v) = (data[row0 | l], data[row1 | l]);
+							butterfly(&mut u, &mut v, twiddle);
+							(data[row0 | l], data[row1 | l]) = (u, v);
+						}
+					}
+				}
+			}
+		}
+	}
+}
+
+#[allow(clippy::too_many_arguments)]
+fn forward_transform<F: BinaryField, P: PackedField<Scalar = F>>(
+	log_domain_size: usize,
+	s_evals: &[impl TwiddleAccess<F>],
+	data: &mut [P],
+	coset: u32,
+	log_batch_size: usize,
+	log_n: usize,
+	log_block_len: usize,
+	log_radix: usize,
+) -> Result<(), Error> {
+	let Some(pass) = BlockedPass::new(
+		log_domain_size,
+		s_evals,
+		data.len(),
+		coset,
+		log_batch_size,
+		log_n,
+		log_block_len,
+		log_radix,
+	) else {
+		return single_threaded::forward_transform(
+			log_domain_size,
+			s_evals,
+			data,
+			coset,
+			log_batch_size,
+			log_n,
+		);
+	};
+
+	pass.apply(data, (0..pass.log_rows).rev(), |u, v, twiddle| {
+		*u += *v * twiddle;
+		*v += *u;
+	});
+
+	for (inner_coset, row) in data.chunks_exact_mut(1 << pass.log_width).enumerate() {
+		forward_transform(
+			log_domain_size,
+			s_evals,
+			row,
+			coset << pass.log_rows | inner_coset as u32,
+			log_batch_size,
+			log_n - pass.log_rows,
+			log_block_len,
+			log_radix,
+		)?;
+	}
+
+	Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn inverse_transform<F: BinaryField, P: PackedField<Scalar = F>>(
+	log_domain_size: usize,
+	s_evals: &[impl TwiddleAccess<F>],
+	data: &mut [P],
+	coset: u32,
+	log_batch_size: usize,
+	log_n: usize,
+	log_block_len: usize,
+	log_radix: usize,
+) -> Result<(), Error> {
+	let Some(pass) = BlockedPass::new(
+		log_domain_size,
+		s_evals,
+		data.len(),
+		coset,
+		log_batch_size,
+		log_n,
+		log_block_len,
+		log_radix,
+	) else {
+		return single_threaded::inverse_transform(
+			log_domain_size,
+			s_evals,
+			data,
+			coset,
+			log_batch_size,
+			log_n,
+		);
+	};
+
+	for (inner_coset, row) in data.chunks_exact_mut(1 << pass.log_width).enumerate() {
+		inverse_transform(
+			log_domain_size,
+			s_evals,
+			row,
+			coset << pass.log_rows | inner_coset as u32,
+			log_batch_size,
+			log_n - pass.log_rows,
+			log_block_len,
+			log_radix,
+		)?;
+	}
+
+	pass.apply(data, 0..pass.log_rows, |u, v, twiddle| {
+		*v += *u;
+		*u += *v * twiddle;
+	});
+
+	Ok(())
+}
@@ -3,7 +3,10 @@
 use binius_field::{BinaryField, PackedField};
 use binius_utils::rayon::get_log_max_threads;
 
-use crate::{twiddle::PrecomputedTwiddleAccess, AdditiveNTT, MultithreadedNTT, SingleThreadedNTT};
+use crate::{
+	twiddle::{OnTheFlyTwiddleAccess, PrecomputedTwiddleAccess},
+	AdditiveNTT, BlockedNTT, MultithreadedNTT, SingleThreadedNTT,
+};
 
 /// How many threads to use (threads number is a power of 2).
 #[derive(Default, Debug, Clone, Copy)]
@@ -50,6 +53,8 @@ pub enum DynamicDispatchNTT<F: BinaryField> {
 	SingleThreadedPrecompute(SingleThreadedNTT<F, PrecomputedTwiddleAccess<F>>),
 	MultiThreaded(MultithreadedNTT<F>),
 	MultiThreadedPrecompute(MultithreadedNTT<F, PrecomputedTwiddleAccess<F>>),
+	Blocked(BlockedNTT<F, OnTheFlyTwiddleAccess<F>>),
+	BlockedPrecompute(BlockedNTT<F, PrecomputedTwiddleAccess<F>>),
 }
 
 impl<F: BinaryField> DynamicDispatchNTT<F> {
@@ -74,6 +79,23 @@ impl<F: BinaryField> DynamicDispatchNTT<F> {
 
 		Ok(result)
 	}
+
+	/// Switches a single-threaded NTT to the cache-blocked [`BlockedNTT`] kernel, with the default
+	/// block size and radix.
+	///
+	/// The blocked kernel is single-threaded, so this returns
+	/// [`Error::BlockedMultithreaded`](crate::error::Error::BlockedMultithreaded) for a
+	/// multithreaded NTT.
+	pub fn blocked(self) -> Result<Self, crate::error::Error> {
+		match self {
+			Self::SingleThreaded(ntt) => Ok(Self::Blocked(ntt.blocked())),
+			Self::SingleThreadedPrecompute(ntt) => Ok(Self::BlockedPrecompute(ntt.blocked())),
+			Self::Blocked(_) | Self::BlockedPrecompute(_) => Ok(self),
+			Self::MultiThreaded(_) | Self::MultiThreadedPrecompute(_) => {
+				Err(crate::error::Error::BlockedMultithreaded)
+			}
+		}
+	}
 }
 
 impl<F, P> AdditiveNTT<P> for DynamicDispatchNTT<F>
@@ -87,6 +109,8 @@ where
 			Self::SingleThreadedPrecompute(ntt) => ntt.log_domain_size(),
 			Self::MultiThreaded(ntt) => ntt.log_domain_size(),
 			Self::MultiThreadedPrecompute(ntt) => ntt.log_domain_size(),
+			Self::Blocked(ntt) => ntt.log_domain_size(),
+			Self::BlockedPrecompute(ntt) => ntt.log_domain_size(),
 		}
 	}
 
@@ -96,6 +120,8 @@ where
 			Self::SingleThreadedPrecompute(ntt) => ntt.get_subspace_eval(i, j),
 			Self::MultiThreaded(ntt) => ntt.get_subspace_eval(i, j),
 			Self::MultiThreadedPrecompute(ntt) => ntt.get_subspace_eval(i, j),
+			Self::Blocked(ntt) => ntt.get_subspace_eval(i, j),
+			Self::BlockedPrecompute(ntt) => ntt.get_subspace_eval(i, j),
 		}
 	}
 
@@ -114,6 +140,8 @@ where
 			Self::MultiThreadedPrecompute(ntt) => {
 				ntt.forward_transform(data, coset, log_batch_size)
 			}
+			Self::Blocked(ntt) => ntt.forward_transform(data, coset, log_batch_size),
+			Self::BlockedPrecompute(ntt) => ntt.forward_transform(data, coset, log_batch_size),
 		}
 	}
 
@@ -132,6 +160,8 @@ where
 			Self::MultiThreadedPrecompute(ntt) => {
 				ntt.inverse_transform(data, coset, log_batch_size)
 			}
+			Self::Blocked(ntt) => ntt.inverse_transform(data, coset, log_batch_size),
+			Self::BlockedPrecompute(ntt) => ntt.inverse_transform(data, coset, log_batch_size),
 		}
 	}
 }
@@ -186,6 +216,7 @@ mod tests {
 			thread_settings: ThreadingSettings::ExplicitThreadsCount { log_threads: 2 },
 		});
 		assert!(matches!(ntt, DynamicDispatchNTT::MultiThreaded(_)));
+		assert!(matches!(ntt.blocked(), Err(crate::error::Error::BlockedMultithreaded)));
 
 		let ntt = make_ntt(NTTOptions {
 			precompute_twiddles: true,
@@ -198,5 +229,21 @@ mod tests {
 			thread_settings: ThreadingSettings::ExplicitThreadsCount { log_threads: 0 },
 		});
 		assert!(matches!(ntt, DynamicDispatchNTT::SingleThreaded(_)));
+
+		let ntt = make_ntt(NTTOptions {
+			precompute_twiddles: false,
+			thread_settings: ThreadingSettings::SingleThreaded,
+		})
+		.blocked()
+		.unwrap();
+		assert!(matches!(ntt, DynamicDispatchNTT::Blocked(_)));
+
+		let ntt = make_ntt(NTTOptions {
+			precompute_twiddles: true,
+			thread_settings: ThreadingSettings::ExplicitThreadsCount { log_threads: 0 },
+		})
+		.blocked()
+		.unwrap();
+		assert!(matches!(ntt, DynamicDispatchNTT::BlockedPrecompute(_)));
 	}
 }
@@ -18,6 +18,8 @@ pub enum Error {
 	StrideGreaterThanPackedWidth,
 	#[error("the batch size is greater than the number of elements")]
 	BatchTooLarge,
+	#[error("the blocked NTT kernel does not support multithreading")]
+	BlockedMultithreaded,
 	#[error("odd interpolation length mismatch, expected to be exactly {expected_len}")]
 	OddInterpolateIncorrectLength { expected_len: usize },
 	#[error("math error: {0}")]
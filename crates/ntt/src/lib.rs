@@ -8,10 +8,12 @@
 //! [DP24]: <https://eprint.iacr.org/2024/504>
 
 mod additive_ntt;
+mod blocked;
 mod dynamic_dispatch;
 mod error;
 mod multithreaded;
 mod odd_interpolate;
+pub mod poly;
 mod single_threaded;
 mod strided_array;
 #[cfg(test)]
@@ -19,6 +21,7 @@ mod tests;
 pub mod twiddle;
 
 pub use additive_ntt::AdditiveNTT;
+pub use blocked::{BlockedNTT, DEFAULT_LOG_BLOCK_BYTES, DEFAULT_LOG_RADIX};
 pub use dynamic_dispatch::{DynamicDispatchNTT, NTTOptions, ThreadingSettings};
 pub use error::Error;
 pub use multithreaded::MultithreadedNTT;
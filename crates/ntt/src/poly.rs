@@ -0,0 +1,423 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Univariate polynomial arithmetic over binary fields built on the additive NTT.
+//!
+//! The additive NTT of [LCH14] maps between evaluations on an $\mathbb{F}_2$-linear subspace and
+//! coefficients in the *novel polynomial basis* $X_0, X_1, \ldots$, where
+//! $X_i(X) = \prod_{k : i_k = 1} \hat{W}_k(X)$ is the product of the normalized subspace polynomials
+//! selected by the bits of $i$. [`PolynomialNTT`] wraps an [`AdditiveNTT`] instance and adds the
+//! conversions between the novel basis and the standard monomial basis, which is enough to offer
+//! multipoint evaluation, interpolation and multiplication of polynomials in the monomial basis.
+//!
+//! The domain points of the NTT are indexed in binary counting order with respect to its subspace
+//! basis $\beta_0, \ldots, \beta_{d-1}$: the point with index $m$ is $\sum_k m_k \beta_k$. See
+//! [`PolynomialNTT::domain_point`].
+//!
+//! [LCH14]: <https://arxiv.org/abs/1404.3458>
+
+use binius_field::BinaryField;
+use binius_utils::{bail, checked_arithmetics::log2_ceil_usize};
+
+use crate::{additive_ntt::AdditiveNTT, error::Error};
+
+/// Polynomial evaluation, interpolation and multiplication using an additive NTT.
+///
+/// All polynomials passed to and returned from the methods of this type are coefficient vectors in
+/// the monomial basis, lowest degree first, unless the method name says otherwise.
+#[derive(Debug)]
+pub struct PolynomialNTT<F: BinaryField, NTT: AdditiveNTT<F>> {
+	ntt: NTT,
+	/// `subspace_polys[k][t]` is the coefficient of $X^{2^t}$ in $\hat{W}_k(X)$.
+	///
+	/// The subspace polynomials are linearized, so these are their only non-zero coefficients.
+	subspace_polys: Vec<Vec<F>>,
+}
+
+impl<F: BinaryField, NTT: AdditiveNTT<F>> PolynomialNTT<F, NTT> {
+	/// Wraps an NTT, precomputing the monomial coefficients of its normalized subspace
+	/// polynomials.
+	///
+	/// Time complexity is $\mathcal{O}(d^2)$, where $d$ is the log domain size of the NTT.
+	pub fn new(ntt: NTT) -> Self {
+		let log_domain_size = ntt.log_domain_size();
+		let mut subspace_polys = Vec::with_capacity(log_domain_size);
+		if log_domain_size > 0 {
+			// $\hat{W}_0(X) = X$ because $\beta_0 = 1$.
+			subspace_polys.push(vec![F::ONE]);
+		}
+		for k in 1..log_domain_size {
+			// $\hat{W}_k(X) = \hat{W}_{k-1}(X) (\hat{W}_{k-1}(X) + 1) / (c^2 + c)$, where
+			// $c = \hat{W}_{k-1}(\beta_k)$. The numerator vanishes on $U_k$ and has degree $2^k$.
+			let prev = subspace_polys.last().expect("pushed before the loop");
+			let c = ntt.get_subspace_eval(k - 1, 1);
+			let norm_inv = (c.square() + c)
+				.invert()
+				.expect("subspace polynomials do not vanish outside their subspace");
+			let poly = (0..=k)
+				.map(|t| {
+					let squared = if t > 0 { prev[t - 1].square() } else { F::ZERO };
+					let linear = prev.get(t).copied().unwrap_or(F::ZERO);
+					(squared + linear) * norm_inv
+				})
+				.collect();
+			subspace_polys.push(poly);
+		}
+
+		Self {
+			ntt,
+			subspace_polys,
+		}
+	}
+
+	/// The wrapped NTT.
+	pub const fn ntt(&self) -> &NTT {
+		&self.ntt
+	}
+
+	/// Base-2 logarithm of the size of the NTT domain.
+	pub fn log_domain_size(&self) -> usize {
+		self.ntt.log_domain_size()
+	}
+
+	/// Returns the domain point with the given index, $\sum_k index_k \beta_k$.
+	///
+	/// ## Preconditions
+	///
+	/// * `index` must be less than `2^self.log_domain_size()`
+	pub fn domain_point(&self, index: usize) -> F {
+		assert!(index >> self.log_domain_size() == 0, "index is out of the NTT domain");
+		let low = if index & 1 == 1 { F::ONE } else { F::ZERO };
+		if self.log_domain_size() > 1 {
+			// $\hat{W}_0$ is the identity map, so its twiddles are the domain points themselves.
+			low + self.ntt.get_subspace_eval(0, index >> 1)
+		} else {
+			low
+		}
+	}
+
+	/// Converts a polynomial in place from the monomial basis to the novel basis.
+	///
+	/// The length of `coeffs` must be a power of two no greater than the domain size. Time
+	/// complexity is $\mathcal{O}(n \log^2 n)$.
+	pub fn monomial_to_novel(&self, coeffs: &mut [F]) -> Result<(), Error> {
+		let log_n = self.check_basis_conversion_len(coeffs.len())?;
+
+		// Going from the top level down, divide each block by the subspace polynomial for that
+		// level: $P = R + Q \hat{W}_k$, where $R$ stays in the lower half of the block and $Q$ is
+		// written to the upper half. Since $X_{i + 2^k} = X_i \hat{W}_k$ for $i < 2^k$, recursing
+		// into both halves yields the novel basis coefficients.
+		for k in (0..log_n).rev() {
+			let w = &self.subspace_polys[k];
+			let lc_inv = w[k].invert().expect("subspace polynomials are non-zero");
+			let half = 1 << k;
+			for block in coeffs.chunks_exact_mut(2 * half) {
+				for d in (half..2 * half).rev() {
+					let q = block[d] * lc_inv;
+					block[d] = q;
+					for (t, &w_t) in w[..k].iter().enumerate() {
+						block[d - half + (1 << t)] += q * w_t;
+					}
+				}
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Converts a polynomial in place from the novel basis to the monomial basis.
+	///
+	/// This is the inverse of [`Self::monomial_to_novel`], with the same length requirements and
+	/// time complexity.
+	pub fn novel_to_monomial(&self, coeffs: &mut [F]) -> Result<(), Error> {
+		let log_n = self.check_basis_conversion_len(coeffs.len())?;
+
+		// Undo the divisions of `monomial_to_novel` in reverse order.
+		for k in 0..log_n {
+			let w = &self.subspace_polys[k];
+			let half = 1 << k;
+			for block in coeffs.chunks_exact_mut(2 * half) {
+				for d in half..2 * half {
+					let q = block[d];
+					block[d] = q * w[k];
+					for (t, &w_t) in w[..k].iter().enumerate() {
+						block[d - half + (1 << t)] += q * w_t;
+					}
+				}
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Evaluates a polynomial on a coset of a subspace of the NTT domain.
+	///
+	/// Returns the evaluations on the `2^log_size` domain points with indices
+	/// `coset << log_size | i`. The polynomial may have any degree, as long as the smallest
+	/// subspace containing both the coset and `coeffs.len()` points fits in the domain.
+	pub fn evaluate(&self, coeffs: &[F], log_size: usize, coset: u32) -> Result<Vec<F>, Error> {
+		// Evaluate on a larger coset if the polynomial does not fit in the requested one.
+		let log_n = log_size.max(log2_ceil_usize(coeffs.len()));
+		let mut evals = self.monomial_to_padded_novel(coeffs, log_n)?;
+		let log_ratio = log_n - log_size;
+		self.ntt
+			.forward_transform(&mut evals, coset >> log_ratio, 0)?;
+
+		let offset = ((coset as usize) & ((1 << log_ratio) - 1)) << log_size;
+		evals.truncate(offset + (1 << log_size));
+		evals.drain(..offset);
+		Ok(evals)
+	}
+
+	/// Interpolates the unique polynomial of degree less than `evals.len()` that takes the given
+	/// values on the first `evals.len()` domain points.
+	///
+	/// The number of evaluations does not need to be a power of two. Time complexity is
+	/// $\mathcal{O}(n \log^2 n)$.
+	pub fn interpolate(&self, evals: &[F]) -> Result<Vec<F>, Error> {
+		let n = evals.len();
+		let log_n = log2_ceil_usize(n);
+		if log_n > self.log_domain_size() {
+			bail!(Error::DomainTooSmall {
+				log_required_domain_size: log_n
+			});
+		}
+
+		let mut coeffs = Vec::with_capacity(1 << log_n);
+		coeffs.extend_from_slice(evals);
+		self.interpolate_novel(&mut coeffs, 0)?;
+		coeffs.resize(1 << log_n, F::ZERO);
+		self.novel_to_monomial(&mut coeffs)?;
+		coeffs.truncate(n);
+		Ok(coeffs)
+	}
+
+	/// Multiplies two polynomials.
+	///
+	/// The degree of the product must be less than the domain size. Time complexity is
+	/// $\mathcal{O}(n \log^2 n)$, where $n$ is the length of the product.
+	pub fn multiply(&self, lhs: &[F], rhs: &[F]) -> Result<Vec<F>, Error> {
+		if lhs.is_empty() || rhs.is_empty() {
+			return Ok(Vec::new());
+		}
+
+		let product_len = lhs.len() + rhs.len() - 1;
+		let log_n = log2_ceil_usize(product_len);
+
+		let mut lhs_evals = self.monomial_to_padded_novel(lhs, log_n)?;
+		let mut rhs_evals = self.monomial_to_padded_novel(rhs, log_n)?;
+		self.ntt.forward_transform(&mut lhs_evals, 0, 0)?;
+		self.ntt.forward_transform(&mut rhs_evals, 0, 0)?;
+
+		for (lhs_i, rhs_i) in lhs_evals.iter_mut().zip(rhs_evals) {
+			*lhs_i *= rhs_i;
+		}
+
+		self.ntt.inverse_transform(&mut lhs_evals, 0, 0)?;
+		self.novel_to_monomial(&mut lhs_evals)?;
+		lhs_evals.truncate(product_len);
+		Ok(lhs_evals)
+	}
+
+	/// Interpolates in place into the novel basis from evaluations on the points with indices
+	/// `offset | i` for `i < data.len()`.
+	///
+	/// `offset` must be a multiple of the next power of two after `data.len()`.
+	fn interpolate_novel(&self, data: &mut [F], offset: usize) -> Result<(), Error> {
+		let n = data.len();
+		if n == 0 {
+			return Ok(());
+		}
+
+		let log_n = log2_ceil_usize(n);
+		if n.is_power_of_two() {
+			return self
+				.ntt
+				.inverse_transform(data, (offset >> log_n) as u32, 0);
+		}
+
+		// Split the points into the full coset of size $2^k$ containing the first ones and the
+		// remaining points, which lie in the next coset. $\hat{W}_k$ is linear and vanishes on
+		// $U_k$, so it takes a constant value $c$ on the first coset and $c + 1$ on the next one.
+		// Write $P = A + \hat{W}_k B$ with $\deg A, \deg B < 2^k$. The interpolant $L$ of the first
+		// coset is $A + c B$, hence $P - L = B$ on the next coset, and $A = L - c B$.
+		let k = log_n - 1;
+		let (lo, hi) = data.split_at_mut(1 << k);
+		let lo_coset = offset >> k;
+		self.ntt.inverse_transform(lo, lo_coset as u32, 0)?;
+
+		let mut lo_evals = lo.to_vec();
+		self.ntt
+			.forward_transform(&mut lo_evals, (lo_coset | 1) as u32, 0)?;
+		for (hi_i, lo_eval_i) in hi.iter_mut().zip(lo_evals) {
+			*hi_i -= lo_eval_i;
+		}
+
+		// Since $X_{i + 2^k} = X_i \hat{W}_k$, the novel coefficients of $B$ are the upper
+		// coefficients of $P$.
+		self.interpolate_novel(hi, offset | 1 << k)?;
+
+		let c = self.ntt.get_subspace_eval(k, offset >> (k + 1));
+		for (lo_i, &hi_i) in lo.iter_mut().zip(hi.iter()) {
+			*lo_i -= c * hi_i;
+		}
+
+		Ok(())
+	}
+
+	fn monomial_to_padded_novel(&self, coeffs: &[F], log_n: usize) -> Result<Vec<F>, Error> {
+		let mut novel = vec![F::ZERO; 1 << log_n];
+		novel[..coeffs.len()].copy_from_slice(coeffs);
+		self.monomial_to_novel(&mut novel)?;
+		Ok(novel)
+	}
+
+	fn check_basis_conversion_len(&self, len: usize) -> Result<usize, Error> {
+		if !len.is_power_of_two() {
+			bail!(Error::PowerOfTwoLengthRequired);
+		}
+		let log_n = len.ilog2() as usize;
+		if log_n > self.log_domain_size() {
+			bail!(Error::DomainTooSmall {
+				log_required_domain_size: log_n
+			});
+		}
+		Ok(log_n)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use assert_matches::assert_matches;
+	use binius_field::{BinaryField32b, Field};
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::single_threaded::SingleThreadedNTT;
+
+	type F = BinaryField32b;
+
+	fn evaluate_naive(coeffs: &[F], x: F) -> F {
+		coeffs.iter().rev().fold(F::ZERO, |acc, &c| acc * x + c)
+	}
+
+	fn random_poly(rng: &mut StdRng, len: usize) -> Vec<F> {
+		repeat_with(|| F::random(&mut *rng)).take(len).collect()
+	}
+
+	#[test]
+	fn test_domain_points_match_canonical_basis() {
+		let poly_ntt = PolynomialNTT::<F, _>::new(SingleThreadedNTT::<F>::new(10).unwrap());
+		for i in 0..1 << 10 {
+			assert_eq!(poly_ntt.domain_point(i), F::new(i as u32));
+		}
+	}
+
+	#[test]
+	fn test_basis_conversion_round_trip() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let poly_ntt = PolynomialNTT::<F, _>::new(SingleThreadedNTT::<F>::new(8).unwrap());
+
+		for log_n in 0..=8 {
+			let monomial = random_poly(&mut rng, 1 << log_n);
+
+			let mut novel = monomial.clone();
+			poly_ntt.monomial_to_novel(&mut novel).unwrap();
+
+			let mut evals = novel.clone();
+			poly_ntt.ntt().forward_transform(&mut evals, 0, 0).unwrap();
+			for (i, &eval) in evals.iter().enumerate() {
+				assert_eq!(eval, evaluate_naive(&monomial, poly_ntt.domain_point(i)));
+			}
+
+			poly_ntt.novel_to_monomial(&mut novel).unwrap();
+			assert_eq!(novel, monomial);
+		}
+	}
+
+	#[test]
+	fn test_evaluate_on_cosets() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let poly_ntt = PolynomialNTT::<F, _>::new(SingleThreadedNTT::<F>::new(8).unwrap());
+
+		for len in [0, 1, 3, 8, 13, 32] {
+			let coeffs = random_poly(&mut rng, len);
+			for log_size in 0..4 {
+				for coset in [0, 1, 5, 14] {
+					let evals = poly_ntt.evaluate(&coeffs, log_size, coset).unwrap();
+					let expected = (0..1 << log_size)
+						.map(|i| {
+							let point = poly_ntt.domain_point((coset as usize) << log_size | i);
+							evaluate_naive(&coeffs, point)
+						})
+						.collect::<Vec<_>>();
+					assert_eq!(evals, expected);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_interpolate_arbitrary_sizes() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let poly_ntt = PolynomialNTT::<F, _>::new(SingleThreadedNTT::<F>::new(7).unwrap());
+
+		for n in 0..=100 {
+			let evals = random_poly(&mut rng, n);
+			let coeffs = poly_ntt.interpolate(&evals).unwrap();
+			assert_eq!(coeffs.len(), n);
+			for (i, &eval) in evals.iter().enumerate() {
+				assert_eq!(evaluate_naive(&coeffs, poly_ntt.domain_point(i)), eval);
+			}
+		}
+	}
+
+	#[test]
+	fn test_multiply_matches_schoolbook() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let poly_ntt = PolynomialNTT::<F, _>::new(SingleThreadedNTT::<F>::new(8).unwrap());
+
+		for (lhs_len, rhs_len) in [(0, 5), (1, 1), (1, 7), (5, 9), (17, 40), (128, 129)] {
+			let lhs = random_poly(&mut rng, lhs_len);
+			let rhs = random_poly(&mut rng, rhs_len);
+
+			let product_len = if lhs_len == 0 || rhs_len == 0 {
+				0
+			} else {
+				lhs_len + rhs_len - 1
+			};
+			let mut expected = vec![F::ZERO; product_len];
+			for (i, &lhs_i) in lhs.iter().enumerate() {
+				for (j, &rhs_j) in rhs.iter().enumerate() {
+					expected[i + j] += lhs_i * rhs_j;
+				}
+			}
+
+			assert_eq!(poly_ntt.multiply(&lhs, &rhs).unwrap(), expected);
+		}
+	}
+
+	#[test]
+	fn test_domain_too_small() {
+		let poly_ntt = PolynomialNTT::<F, _>::new(SingleThreadedNTT::<F>::new(4).unwrap());
+		let coeffs = vec![F::ONE; 9];
+
+		assert_matches!(
+			poly_ntt.multiply(&coeffs, &coeffs),
+			Err(Error::DomainTooSmall {
+				log_required_domain_size: 5
+			})
+		);
+		assert_matches!(
+			poly_ntt.interpolate(&[F::ONE; 17]),
+			Err(Error::DomainTooSmall {
+				log_required_domain_size: 5
+			})
+		);
+		assert_matches!(
+			poly_ntt.monomial_to_novel(&mut [F::ONE; 3]),
+			Err(Error::PowerOfTwoLengthRequired)
+		);
+	}
+}
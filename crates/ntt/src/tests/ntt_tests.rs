@@ -70,6 +70,13 @@ fn check_roundtrip_all_ntts<P>(
 		.unwrap()
 		.precompute_twiddles()
 		.multithreaded();
+	let blocked_ntt = SingleThreadedNTT::<P::Scalar>::new(log_domain_size)
+		.unwrap()
+		.blocked_with_params(6, 2);
+	let blocked_precompute_ntt = SingleThreadedNTT::<P::Scalar>::new(log_domain_size)
+		.unwrap()
+		.precompute_twiddles()
+		.blocked_with_params(7, 3);
 	let dynamic_dispatch_ntt = DynamicDispatchNTT::SingleThreaded(
 		SingleThreadedNTT::<P::Scalar>::new(log_domain_size).unwrap(),
 	);
@@ -109,6 +116,20 @@ fn check_roundtrip_all_ntts<P>(
 			cosets.clone(),
 			log_batch_size,
 		);
+		check_roundtrip_with_reference(
+			&simple_ntt,
+			&blocked_ntt,
+			&mut data,
+			cosets.clone(),
+			log_batch_size,
+		);
+		check_roundtrip_with_reference(
+			&simple_ntt,
+			&blocked_precompute_ntt,
+			&mut data,
+			cosets.clone(),
+			log_batch_size,
+		);
 		check_roundtrip_with_reference(
 			&simple_ntt,
 			&dynamic_dispatch_ntt,
@@ -198,6 +219,13 @@ fn check_packed_extension_roundtrip_all_ntts<P, PE>(
 		.unwrap()
 		.precompute_twiddles()
 		.multithreaded();
+	let blocked_ntt = SingleThreadedNTT::<P::Scalar>::new(log_domain_size)
+		.unwrap()
+		.blocked_with_params(6, 2);
+	let blocked_precompute_ntt = SingleThreadedNTT::<P::Scalar>::new(log_domain_size)
+		.unwrap()
+		.precompute_twiddles()
+		.blocked_with_params(7, 3);
 	let dynamic_dispatch_ntt = DynamicDispatchNTT::SingleThreaded(
 		SingleThreadedNTT::<P::Scalar>::new(log_domain_size).unwrap(),
 	);
@@ -232,6 +260,18 @@ fn check_packed_extension_roundtrip_all_ntts<P, PE>(
 		&mut data,
 		cosets.clone(),
 	);
+	check_packed_extension_roundtrip_with_reference(
+		&simple_ntt,
+		&blocked_ntt,
+		&mut data,
+		cosets.clone(),
+	);
+	check_packed_extension_roundtrip_with_reference(
+		&simple_ntt,
+		&blocked_precompute_ntt,
+		&mut data,
+		cosets.clone(),
+	);
 	check_packed_extension_roundtrip_with_reference(
 		&simple_ntt,
 		&dynamic_dispatch_ntt,
@@ -98,17 +98,18 @@ fn main() -> Result<()> {
 	let zero_oracle_carry =
 		transparent::constant(&mut builder, "zero carry", log_size, BinaryField1b::ZERO).unwrap();
 
-	let _modded_product = byte_sliced_modular_mul::<_, _, TowerLevel4, TowerLevel8>(
-		&mut builder,
-		"lasso_bytesliced_mul",
-		&mult_a,
-		&mult_b,
-		&modulus_input,
-		log_size,
-		zero_oracle_byte,
-		zero_oracle_carry,
-	)
-	.unwrap();
+	let _modded_product =
+		byte_sliced_modular_mul::<_, CanonicalTowerFamily, TowerLevel4, TowerLevel8>(
+			&mut builder,
+			"lasso_bytesliced_mul",
+			&mult_a,
+			&mult_b,
+			&modulus_input,
+			log_size,
+			zero_oracle_byte,
+			zero_oracle_carry,
+		)
+		.unwrap();
 
 	let witness = builder.take_witness().unwrap();
 	let constraint_system = builder.build().unwrap();
@@ -77,14 +77,14 @@ fn main() -> Result<()> {
 	let zero_oracle_carry =
 		transparent::constant(&mut builder, "zero carry", log_n_muls, BinaryField1b::ZERO).unwrap();
 
-	let lookup_t_mul = mul_lookup(&mut builder, "mul lookup")?;
-	let lookup_t_add = add_lookup(&mut builder, "add lookup")?;
-	let lookup_t_dci = dci_lookup(&mut builder, "dci lookup")?;
+	let lookup_t_mul = mul_lookup::<_, CanonicalTowerFamily>(&mut builder, "mul lookup")?;
+	let lookup_t_add = add_lookup::<_, CanonicalTowerFamily>(&mut builder, "add lookup")?;
+	let lookup_t_dci = dci_lookup::<_, CanonicalTowerFamily>(&mut builder, "dci lookup")?;
 
 	let mut lookup_batch_mul = LookupBatch::new([lookup_t_mul]);
 	let mut lookup_batch_add = LookupBatch::new([lookup_t_add]);
 	let mut lookup_batch_dci = LookupBatch::new([lookup_t_dci]);
-	let _mul_and_cout = byte_sliced_mul::<_, _, TowerLevel4, TowerLevel8>(
+	let _mul_and_cout = byte_sliced_mul::<_, CanonicalTowerFamily, TowerLevel4, TowerLevel8>(
 		&mut builder,
 		"lasso_bytesliced_mul",
 		&in_a,
@@ -57,11 +57,13 @@ fn main() -> Result<()> {
 		log_n_multiplications,
 	)?;
 
-	let mul_lookup_table = lookups::u8_arithmetic::mul_lookup(&mut builder, "mul table").unwrap();
+	let mul_lookup_table =
+		lookups::u8_arithmetic::mul_lookup::<_, CanonicalTowerFamily>(&mut builder, "mul table")
+			.unwrap();
 
 	let mut lookup_batch = LookupBatch::new([mul_lookup_table]);
 
-	let _product = binius_circuits::lasso::u8mul(
+	let _product = binius_circuits::lasso::u8mul::<_, CanonicalTowerFamily>(
 		&mut builder,
 		&mut lookup_batch,
 		"out_c",